@@ -40,6 +40,10 @@ struct GreaterAndLessOrEqualF32(f32);
 #[nutype(derive(Debug, Arbitrary), validate(greater = -1.0, less_or_equal = -0.5))]
 struct GreaterOrEqualAndLessOrEqualF64(f64);
 
+// The bounds are untyped expressions, e.g. `1.0 - 0.5` is neither f32 nor f64 on its own
+#[nutype(derive(Debug, Arbitrary), validate(greater_or_equal = 1.0 - 2.0, less = 1.0 / 2.0))]
+struct ExprBoundsF32(f32);
+
 fn main() {
     arbtest::builder().run(|u| {
         let _num = UnrestrictedFloatNumber::arbitrary(u)?.into_inner();
@@ -111,4 +115,10 @@ fn main() {
         assert!((-1.0..=-0.5).contains(&value));
         Ok(())
     });
+
+    arbtest::builder().run(|u| {
+        let value: f32 = ExprBoundsF32::arbitrary(u)?.into_inner();
+        assert!((-1.0..0.5).contains(&value));
+        Ok(())
+    });
 }
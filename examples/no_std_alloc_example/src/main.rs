@@ -0,0 +1,100 @@
+// This example exists to ensure that code generated by nutype macro
+// can compile in no_std environment with `alloc` crate (e.g. for String types).
+#![no_main]
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+use core::{
+    alloc::{GlobalAlloc, Layout},
+    panic::PanicInfo,
+};
+use nutype::nutype;
+
+#[panic_handler]
+fn panic(_panic: &PanicInfo<'_>) -> ! {
+    loop {}
+}
+
+// The example only needs to compile, so the allocator never allocates.
+struct NoAlloc;
+
+unsafe impl GlobalAlloc for NoAlloc {
+    unsafe fn alloc(&self, _layout: Layout) -> *mut u8 {
+        core::ptr::null_mut()
+    }
+
+    unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {}
+}
+
+#[global_allocator]
+static ALLOCATOR: NoAlloc = NoAlloc;
+
+// String
+#[nutype(
+    sanitize(trim, lowercase),
+    validate(not_empty, len_char_min = 3, len_char_max = 32, predicate = |s| s.is_ascii()),
+    derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        FromStr,
+        AsRef,
+        Deref,
+        TryFrom,
+        Into,
+        Hash,
+        Borrow,
+        Display,
+        Default,
+        Serialize,
+        Deserialize,
+    ),
+    default = "nobody"
+)]
+pub struct Username(String);
+
+#[nutype(
+    sanitize(with = |s: String| s.replace(' ', "-")),
+    derive(Debug, Clone, PartialEq, From, Into, AsRef, Deref, Display)
+)]
+pub struct Slug(String);
+
+// Integer, collecting all the violations into `Vec`
+#[nutype(
+    validate(greater_or_equal = 1, less_or_equal = 6),
+    all_violations,
+    derive(Debug, Clone, Copy, PartialEq, Eq, TryFrom)
+)]
+pub struct GermanTaxClass(i64);
+
+// Integer, parsed from a string or converted from the narrower integer types
+#[nutype(
+    validate(greater_or_equal = 1, less_or_equal = 100),
+    try_from_lossless,
+    derive(Debug, Clone, Copy, PartialEq, Eq, FromStr, TryFrom, Serialize, Deserialize)
+)]
+pub struct Percentage(u32);
+
+// Float
+#[nutype(
+    validate(finite, greater_or_equal = 0.0),
+    try_from_lossless,
+    derive(Debug, Clone, Copy, PartialEq, FromStr, TryFrom, Serialize, Deserialize)
+)]
+pub struct Distance(f64);
+
+// Any other type
+#[nutype(
+    validate(predicate = |ids| !ids.is_empty()),
+    derive(Debug, Clone, PartialEq, AsRef, TryFrom, Serialize, Deserialize)
+)]
+pub struct Ids(Vec<u32>);
+
+// With `core_error` feature the errors implement `core::error::Error`
+fn _assert_error<E: core::error::Error>() {}
+const _: fn() = _assert_error::<UsernameError>;
@@ -60,7 +60,7 @@ struct GermanTaxClass(i64);
 )]
 struct Width(f64);
 
-// NOTE: strings are not working yet with no_std
+// NOTE: String types require `alloc` feature, see `no_std_alloc_example`
 
 // Any other type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
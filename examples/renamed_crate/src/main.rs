@@ -0,0 +1,24 @@
+use nt::nutype;
+
+#[nutype(
+    sanitize(trim),
+    validate(
+        not_empty,
+        cfg_attr(debug_assertions, len_char_max = 5),
+        cfg_attr(not(debug_assertions), len_char_max = 10),
+    ),
+    derive(Debug, AsRef, PartialEq)
+)]
+pub struct Name(String);
+
+fn main() {
+    assert_eq!(Name::new("  "), Err(NameError::NotEmptyViolated));
+    assert_eq!(Name::new(" Bob ").unwrap().as_ref(), "Bob");
+
+    let long_name = Name::new("Cassandra");
+    if cfg!(debug_assertions) {
+        assert_eq!(long_name, Err(NameError::LenCharMaxViolated));
+    } else {
+        assert_eq!(long_name.unwrap().as_ref(), "Cassandra");
+    }
+}
@@ -45,7 +45,7 @@ fn main() {
         let err = res.unwrap_err();
         assert_eq!(
             err.to_string(),
-            "Name is empty. Expected valid Name at line 3 column 27"
+            "Expected valid Name, constraint `not_empty` is violated: Name is empty. at line 3 column 27"
         );
     }
 
@@ -62,7 +62,7 @@ fn main() {
         let err = res.unwrap_err();
         assert_eq!(
             err.to_string(),
-            "ImageUrl failed the predicate test. Expected valid ImageUrl at line 4 column 60"
+            "Expected valid ImageUrl, constraint `predicate` is violated: ImageUrl failed the predicate test. at line 4 column 60"
         );
     }
 
@@ -79,7 +79,7 @@ fn main() {
         let err = res.unwrap_err();
         assert_eq!(
             err.to_string(),
-            "Price is too small. The value must be greater than 0.0. Expected valid Price at line 6 column 13"
+            "Expected valid Price, constraint `greater` is violated: Price is too small. The value must be greater than 0.0. at line 6 column 13"
         );
     }
 
@@ -156,6 +156,9 @@
 //! pub struct PhoneNumber(String);
 //! ```
 //!
+//! A pattern, which is defined in place, is compiled at compile time, so a syntax error in it is a compile error.
+//! A regex constant can be checked only at runtime, therefore `#[nutype]` generates a test that builds it.
+//!
 //!
 //! ### String derivable traits
 //!
@@ -163,6 +166,9 @@
 //! `Debug`, `Clone`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `FromStr`, `AsRef`, `Deref`,
 //! `From`, `TryFrom`, `Into`, `Hash`, `Borrow`, `Display`, `Default`, `Serialize`, `Deserialize`.
 //!
+//! `From` (available only for types without validation) and `TryFrom` are implemented for both `String` and `&str`,
+//! so string literals can be passed without `.to_string()`.
+//!
 //!
 //! ## Integer
 //!
@@ -188,7 +194,8 @@
 //!
 //! The following traits can be derived for an integer-based type:
 //! `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `FromStr`, `AsRef`, `Deref`,
-//! `Into`, `From`, `TryFrom`, `Hash`, `Borrow`, `Display`, `Default`, `Serialize`, `Deserialize`.
+//! `Into`, `From`, `TryFrom`, `Hash`, `Borrow`, `Display`, `Default`, `Serialize`, `Deserialize`,
+//! `Add`, `Sub`, `Mul`, `Div`, `AddAssign`, `SubAssign`, `MulAssign`, `DivAssign`, `Sum`, `Product`.
 //!
 //!
 //! ## Float
@@ -210,16 +217,24 @@
 //! | `greater`          | Exclusive lower bound            | `GreaterViolated`        | `greater = 0.0`                     |
 //! | `greater_or_equal` | Inclusive lower bound            | `GreaterOrEqualViolated` | `greater_or_equal = 0.0`            |
 //! | `finite`           | Check against NaN and infinity   | `FiniteViolated`         | `finite`                            |
+//! | `not_nan`          | Check against NaN                | `NotNanViolated`         | `not_nan`                           |
 //! | `predicate`        | Custom predicate                 | `PredicateViolated`      | `predicate = \|val\| val != 50.0`   |
 //!
+//! If values close to the bounds matter, flag `exact_bounds` reports a bound literal, which cannot be
+//! represented exactly by the float type (e.g. `less_or_equal = 0.3` is in fact 0.299999999999999988897769753748434595763683319091796875),
+//! with a warning that shows the rounded value. Prefer bounds like `0.25` or `0.5` then.
+//! NaN and infinity (e.g. `f64::NAN` or `f64::INFINITY`) cannot be used as bounds, use `not_nan` or `finite` instead.
+//! A bound given by a constant or an expression is checked with a const assertion, so a non-finite value fails the compilation.
+//!
 //! ### Float derivable traits
 //!
 //! The following traits can be derived for a float-based type:
 //! `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `FromStr`, `AsRef`, `Deref`,
-//! `Into`, `From`, `TryFrom`, `Hash`, `Borrow`, `Display`, `Default`, `Serialize`, `Deserialize`.
+//! `Into`, `From`, `TryFrom`, `Hash`, `Borrow`, `Display`, `Default`, `Serialize`, `Deserialize`,
+//! `Add`, `Sub`, `Mul`, `Div`, `AddAssign`, `SubAssign`, `MulAssign`, `DivAssign`, `Sum`, `Product`.
 //!
-//! It's also possible to derive `Eq` and `Ord` if the validation rules guarantee that `NaN` is excluded.
-//! This can be done by applying `finite` validation. For example:
+//! It's also possible to derive `Eq`, `Ord` and `Hash` if the validation rules guarantee that `NaN` is excluded.
+//! This can be done by applying `finite` or `not_nan` validation. For example:
 //!
 //! ```rust
 //! use nutype::nutype;
@@ -345,10 +360,51 @@
 //! pub struct Name(String);
 //! ```
 //!
-//! ### Derive `Eq` and `Ord` on float types
+//! The value of `default = ` can be any expression, e.g. a function call.
+//! It is evaluated every time a default value is created and the result goes through the sanitizers and validators:
+//!
+//! ```ignore
+//! #[nutype(
+//!     sanitize(trim, lowercase),
+//!     validate(not_empty),
+//!     derive(Default),
+//!     default = std::env::var("DEFAULT_USERNAME").unwrap_or_else(|_| "guest".to_string()),
+//! )]
+//! pub struct Username(String);
+//! ```
+//!
+//! A literal value is sanitized and validated at compile time, so `default = " ab "` with `sanitize(trim)` and `validate(len_char_min = 3)`
+//! is a compile error rather than a panic in `Default::default()`. Custom sanitizers and validators are checked at runtime.
+//!
+//! When `Deserialize` is derived, the type also gets `serde_default()` function, which returns the value from `default = `.
+//! It allows missing fields to fall back to the validated default without repeating the literal:
+//!
+//! ```ignore
+//! #[derive(Deserialize)]
+//! struct Config {
+//!     #[serde(default = "Name::serde_default")]
+//!     name: Name,
+//! }
+//! ```
+//!
+//! Types with validation and `default = ` also get `new_or_default()`, which falls back to the default value
+//! instead of returning an error:
+//!
+//! ```ignore
+//! #[nutype(
+//!     validate(not_empty),
+//!     default = "Anonymous",
+//! )]
+//! pub struct Name(String);
+//!
+//! assert_eq!(Name::new_or_default("").into_inner(), "Anonymous");
+//! ```
+//!
+//! ### Derive `Eq`, `Ord` and `Hash` on float types
 //!
-//! With nutype it's possible to derive `Eq` and `Ord` if there is `finite` validation set.
-//! The `finite` validation ensures that the valid value excludes `NaN`.
+//! With nutype it's possible to derive `Eq`, `Ord` and `Hash` if there is `finite` or `not_nan` validation set.
+//! The validation ensures that the valid value excludes `NaN`.
+//! `Hash` is computed from the bits of the value (`0.0` and `-0.0` produce the same hash).
 //!
 //! ```
 //! use nutype::nutype;
@@ -360,6 +416,958 @@
 //! pub struct Weight(f64);
 //! ```
 //!
+//! ### Derive arithmetic operators
+//!
+//! Integer and float types can derive `Add`, `Sub`, `Mul` and `Div`. The result of an operation
+//! is validated again, and the `ops` attribute defines what happens if it turns out to be invalid:
+//!
+//! * `ops = panic` (default) - panic.
+//! * `ops = saturate` - clamp the value into the range defined by the validators.
+//! * `ops = result` - the operators return `Result<Self, Error>`.
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     ops = saturate,
+//!     validate(greater_or_equal = 0, less_or_equal = 100),
+//!     derive(Debug, Clone, Copy, PartialEq, Add, Sub),
+//! )]
+//! pub struct Percentage(u8);
+//!
+//! let sixty = Percentage::new(60).unwrap();
+//! assert_eq!(sixty + sixty, Percentage::new(100).unwrap());
+//! ```
+//!
+//! The assigning operators `AddAssign`, `SubAssign`, `MulAssign` and `DivAssign` follow `panic` and `saturate` policies.
+//! They cannot be derived with `ops = result`, since they have no way to return the error.
+//!
+//! `Sum` and `Product` are implemented over `Self` and `&Self` and fold the values the same way as `Add` and `Mul` do.
+//! With `ops = result` they are implemented for `Result<Self, Error>`, so the total is collected with `let total: Result<Amount, AmountError> = amounts.iter().sum();`.
+//!
+//! Overflow of an integer inner type panics with `panic` policy. With `result` policy the operators return `Overflow` error
+//! on overflow and on division by zero, the variant is added to the error type only in this case.
+//! `ops = saturate` is not supported together with `predicate` validator, and for floats it requires the boundaries to be inclusive.
+//!
+//! ### Custom error type
+//!
+//! By default the error type is named after the type with suffix `Error` (e.g. `AgeError`) and has the same visibility.
+//! Use `error = ` to pick a different name and, optionally, a visibility for the error type:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(greater_or_equal = 18),
+//!     derive(Debug, PartialEq),
+//!     error = pub(crate) InvalidAge,
+//! )]
+//! pub struct Age(u8);
+//!
+//! assert_eq!(Age::new(7), Err(InvalidAge::GreaterOrEqualViolated));
+//! ```
+//!
+//! Without a name only the visibility is changed, e.g. `error = pub(crate)`.
+//!
+//! To keep the error types out of the module of the type, place them into a generated module with `error_module = `:
+//!
+//! ```ignore
+//! #[nutype(
+//!     validate(greater_or_equal = 18),
+//!     derive(Debug, FromStr),
+//!     error_module = age_errors,
+//! )]
+//! pub struct Age(u8);
+//!
+//! use age_errors::{AgeError, AgeParseError};
+//! ```
+//!
+//! The module is generated next to the type and has the same visibility, so every type needs a module with its own name.
+//!
+//! The `Display` implementation of the error type produces developer-facing messages, e.g. `Username is too short. ...`.
+//! Use `messages(...)` to replace them with your own texts, keyed by validator:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(not_empty, len_char_min = 3),
+//!     messages(len_char_min = "username must be at least 3 characters"),
+//! )]
+//! pub struct Username(String);
+//! ```
+//!
+//! To localize the messages, use `error_display = none` and implement `Display` yourself, or route it through
+//! a function with `error_display = localize`, where `localize` has signature `fn(&UsernameError, &mut fmt::Formatter) -> fmt::Result`.
+//! The variants and their data stay generated, so the function can match on them.
+//!
+//! By default the error variants carry no data. Use `error_fields(value)` to include the rejected (sanitized) value
+//! into every variant, e.g. `LoginError::LenCharMaxViolated { value: String }`, so the error can be logged or reported
+//! without passing the input alongside. The inner type needs to implement `Debug`, `Clone`, `PartialEq` and `Eq`
+//! (float types are an exception: their error types do not implement `Eq` then).
+//!
+//! With `error_fields(bounds)` the variants carry the parameters of validators: `min` (`greater`, `greater_or_equal`, `len_char_min`),
+//! `max` (`less`, `less_or_equal`, `len_char_max`) and `pattern` (`regex`), so e.g. a form can render "must be between 1 and 100"
+//! without repeating the numbers. Both can be combined: `error_fields(value, bounds)`.
+//!
+//! The error type derives `Debug`, `Clone`, `PartialEq` and `Eq`. Use `error_derive(...)` to derive a different set of traits,
+//! e.g. `error_derive(Clone, PartialEq, Eq, Hash, serde::Serialize)`, so the error fits into your application error machinery.
+//! `Debug` is always derived, because `std::error::Error` requires it.
+//!
+//! Integrations with other crates are opted into per type with `error_integrations(...)`.
+//! E.g. `error_integrations(axum)` implements axum's `IntoResponse` for the error type,
+//! `error_integrations(thiserror)` derives `Error` with `thiserror` (the `Display` stays the same)
+//! and `error_integrations(miette)` implements miette's `Diagnostic`. They can be combined, e.g. `error_integrations(thiserror, miette)`.
+//! With `extends = ` the base type needs `error_integrations(miette)` too, because its help text is reused.
+//! Every integration also requires the feature of `nutype` with the same name (see [Feature flags](#feature-flags)).
+//!
+//! `new()` stops on the first violated validator. With `all_violations` flag the type gets also
+//! `new_collecting_violations()`, which runs all the validators and returns `Result<Self, Vec<Error>>`,
+//! so e.g. a form can show all the problems with the input at once.
+//!
+//! Every error has `code()` method, which returns a stable machine-readable code, e.g. `"age.greater_or_equal"`.
+//! By default the codes are prefixed with the type name in snake case, use `error_code_prefix = "user.age"` to set a different prefix.
+//!
+//! Library authors can mark the error enum `#[non_exhaustive]` with `error_non_exhaustive` flag,
+//! so adding a validator later is not a breaking change for the downstream code, that matches on the error.
+//!
+//! An application that handles many newtypes can collect their errors into one shared enum with `error_into(...)`.
+//! For every listed type `From` is generated, which wraps the error into the variant named after the newtype:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[derive(Debug)]
+//! enum ValidationError {
+//!     Age(AgeError),
+//!     Email(EmailError),
+//! }
+//!
+//! #[nutype(validate(greater_or_equal = 18), error_into(ValidationError))]
+//! pub struct Age(u8);
+//!
+//! #[nutype(validate(not_empty), error_into(ValidationError))]
+//! pub struct Email(String);
+//!
+//! fn register(age: u8, email: String) -> Result<(Age, Email), ValidationError> {
+//!     Ok((Age::new(age)?, Email::new(email)?))
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! With `error_return = path::to::UserError` the constructor returns the user-defined error directly,
+//! so no `map_err` is needed at the call sites: `Age::new(17)` returns `Result<Age, UserError>`.
+//! `From` is generated the same way as with `error_into(...)`, so `UserError` must have the variant named after the newtype.
+//! The traits built on top of the constructor (e.g. `TryFrom` and `FromStr`) use `UserError` as well.
+//!
+//! ### Customize serde
+//!
+//! Derived `Serialize` and `Deserialize` work with the inner value by default. The `serde(...)` attribute
+//! allows to use a different wire format with `serialize_with` and `deserialize_with`, which accept functions
+//! with the same signatures as serde's field attributes. The deserialized value is still sanitized and validated.
+//!
+//! ```ignore
+//! fn serialize_cents<S: serde::Serializer>(cents: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+//!     serializer.serialize_str(&format!("{}.{:02}", cents / 100, cents % 100))
+//! }
+//!
+//! fn deserialize_cents<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+//!     // parse "12.05" into 1205
+//! }
+//!
+//! #[nutype(
+//!     validate(less_or_equal = 100_000),
+//!     derive(Serialize, Deserialize),
+//!     serde(serialize_with = serialize_cents, deserialize_with = deserialize_cents),
+//! )]
+//! pub struct Price(u64);
+//! ```
+//!
+//! By default the value is serialized transparently as the inner value. Use `serde(wrap = "value")`
+//! to serialize it as a struct with a single field instead, e.g. `{"value": 42}`, which is handy for formats
+//! that wrap scalars into objects. Unknown fields are ignored on deserialization.
+//!
+//! String-based newtypes own their value (`String`), so deserialization allocates for every value the same way
+//! a plain `String` field does. To avoid the allocation, use `Cow<'a, str>` as the inner type. `Deserialize` of such type
+//! borrows the string from the input when the format allows it (e.g. a JSON string without escapes), and falls back to an owned
+//! string otherwise. Like with a plain `Cow<'a, str>` field, the field of the containing struct needs `#[serde(borrow)]`:
+//!
+//! ```rs
+//! use std::borrow::Cow;
+//!
+//! #[nutype(
+//!     validate(predicate = |name| !name.is_empty()),
+//!     derive(Debug, AsRef, Deserialize),
+//! )]
+//! pub struct Name<'a>(Cow<'a, str>);
+//!
+//! #[derive(Deserialize)]
+//! struct Person<'a> {
+//!     #[serde(borrow)]
+//!     name: Name<'a>,
+//! }
+//! ```
+//!
+//! `serde(wrap = ...)` is not supported for such types.
+//!
+//! Deserialization runs sanitizers and validators the same way `new()` does. Use `serde(skip_sanitization)`
+//! to preserve the wire value exactly and only validate it, so that e.g. data read from a database is not silently trimmed.
+//!
+//! ### Constants with `const_fn`
+//!
+//! Integer and float types without sanitizers, which are validated only by bounds given by literals,
+//! get `new()` and `into_inner()` as `const fn`, so valid values can be built within `const` items and statics
+//! without `new_unchecked`:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(validate(greater_or_equal = 1, less_or_equal = 12))]
+//! pub struct Month(u8);
+//!
+//! const DECEMBER: Month = match Month::new(12) {
+//!     Ok(month) => month,
+//!     Err(_) => panic!("Invalid month"),
+//! };
+//! ```
+//!
+//! Other types can be marked with `const_fn` to get the same. Then the sanitizers and validators have to be
+//! const-evaluable: numeric bounds given by constants, `finite` and `not_nan` are, custom closures are not.
+//! They are not detected automatically, because e.g. a bound may refer to a `static`, which cannot be read
+//! in const context. `const_fn` is not supported for String types.
+//!
+//! Types with `const fn` constructors and validation also get `from_literal()`, which panics on an invalid value.
+//! Within `const` context this turns an invalid literal into a compile error with zero runtime cost:
+//!
+//! ```ignore
+//! const JANUARY: Month = Month::from_literal(1);
+//! // const INVALID: Month = Month::from_literal(13); // error: evaluation panicked: Invalid Month
+//! ```
+//!
+//! Types without sanitizers and validators (pure branding) get `new()` as `const fn` even without `const_fn`,
+//! except for String types:
+//!
+//! ```ignore
+//! #[nutype(derive(Debug))]
+//! pub struct UserId(u64);
+//!
+//! const ADMIN: UserId = UserId::new(1);
+//! ```
+//!
+//! ### Constants `MIN`, `MAX` and `ZERO`
+//!
+//! Numeric types get the associated constants `MIN`, `MAX` and `ZERO` (the bounds set by the validators and zero).
+//! The values are checked against the bounds at compile time, and only the constants that satisfy them are generated:
+//!
+//! ```ignore
+//! #[nutype(validate(greater = 0, less_or_equal = 100))]
+//! pub struct Percentage(u8);
+//!
+//! assert_eq!(Percentage::MIN.into_inner(), 1);
+//! assert_eq!(Percentage::MAX.into_inner(), 100);
+//! // Compile error: there is no `Percentage::ZERO`, because 0 violates `greater = 0`
+//! // let none = Percentage::ZERO;
+//! ```
+//!
+//! The constants are not generated for types with sanitizers, `predicate` validators, bounds that are not literals
+//! (e.g. `less_or_equal = Port::MAX_RAW`) or `extends = `, since their validity cannot be checked at compile time.
+//! Float types get no `MIN` (`MAX`) if the lower (upper) bound is exclusive.
+//!
+//! The raw values of the bounds are available as `MIN_RAW` and `MAX_RAW` constants of the inner type,
+//! so related types can share the bounds of a source-of-truth type:
+//!
+//! ```ignore
+//! #[nutype(validate(greater = 0, less_or_equal = 65_535))]
+//! pub struct Port(u32);
+//!
+//! #[nutype(validate(greater_or_equal = 1024, less_or_equal = Port::MAX_RAW))]
+//! pub struct UserPort(u32);
+//!
+//! assert_eq!(UserPort::MAX_RAW, 65_535);
+//! ```
+//!
+//! Float types get `MIN_RAW` (`MAX_RAW`) only if the lower (upper) bound is inclusive and the type does not use `extends = `.
+//!
+//! ### Widening conversions
+//!
+//! `widen_into(...)` generates `From` conversions of an integer type into looser types with the same inner type,
+//! which is handy for refinement-style hierarchies:
+//!
+//! ```ignore
+//! #[nutype(validate(greater_or_equal = 1))]
+//! pub struct Port(u16);
+//!
+//! #[nutype(validate(greater_or_equal = 1024), widen_into(Port))]
+//! pub struct NonPrivilegedPort(u16);
+//!
+//! let port: Port = NonPrivilegedPort::new(8080)?.into();
+//! ```
+//!
+//! It is checked at compile time that the target type accepts the smallest and the greatest valid values of the source type,
+//! so widening `Port` into `NonPrivilegedPort` would be a compile error.
+//! The target type must be validated only by bounds: a target with sanitizers, `predicate` or `extends = ` is a compile error,
+//! because it is not known at compile time which values it accepts. The source type may have any guard.
+//!
+//! ### Parsing from strings
+//!
+//! With derived `FromStr` integer, float and `Any` types also get `parse()`, which parses the inner type first and then validates the value.
+//! The returned `{Type}ParseError` tells the parse failures (`Parse` variant) from the validation errors (`Validate` variant):
+//!
+//! ```ignore
+//! #[nutype(
+//!     validate(less_or_equal = 99),
+//!     derive(FromStr),
+//! )]
+//! pub struct Age(u8);
+//!
+//! assert!(matches!(Age::parse("forty"), Err(AgeParseError::Parse(_))));
+//! assert!(matches!(Age::parse("100"), Err(AgeParseError::Validate(_))));
+//! ```
+//!
+//! ### Lossless `TryFrom` for numbers
+//!
+//! Derived `TryFrom` of numeric types accepts only the inner type.
+//! With `try_from_lossless` flag it also accepts the integer types, that can be converted into the inner type without loss
+//! (e.g. `u8`, `u16` and `u32` for `u64`), which is handy for generic conversion code:
+//!
+//! ```ignore
+//! #[nutype(
+//!     try_from_lossless,
+//!     validate(less_or_equal = 1000),
+//!     derive(TryFrom),
+//! )]
+//! pub struct Amount(u64);
+//!
+//! let amount = Amount::try_from(5u8)?;
+//! ```
+//!
+//! The flag is opt-in, because with several `TryFrom` implementations integer literals need a type suffix (`5u64` instead of `5`).
+//! Derived `TryFrom` of string types accepts `String`, `&str` and `Cow<'_, str>`.
+//!
+//! ### Custom constructor name
+//!
+//! The constructor is called `new()` by default. Use `constructor = ` to give it a different name,
+//! e.g. to follow the conventions of your codebase or to implement your own `new()` in a separate `impl` block:
+//!
+//! ```ignore
+//! #[nutype(
+//!     constructor = parse,
+//!     validate(not_empty),
+//! )]
+//! pub struct Username(String);
+//!
+//! let username = Username::parse("bob")?;
+//! ```
+//!
+//! Derived traits like `TryFrom` and `FromStr` use the renamed constructor.
+//!
+//! The constructor can get its own visibility, e.g. to keep it within the crate while the type itself is public,
+//! so the values are created only through a function of your choice:
+//!
+//! ```ignore
+//! #[nutype(
+//!     constructor = pub(crate),
+//!     validate(not_empty),
+//! )]
+//! pub struct Username(String);
+//! ```
+//!
+//! The visibility and the name can be combined, e.g. `constructor = pub(crate) try_new`.
+//! It applies to `new()` and its variants like `try_new_all()` and `new_or_default()`.
+//! The derived traits, e.g. `TryFrom` or `Deserialize`, stay public.
+//!
+//! ### Reusable guards
+//!
+//! When multiple types share the same rules, define them once with `nutype_guard!` and apply them with `guard = `:
+//!
+//! ```ignore
+//! use nutype::{nutype, nutype_guard};
+//!
+//! nutype_guard! {
+//!     pub(crate) UsernameRules = sanitize(trim, lowercase), validate(len_char_min = 3, len_char_max = 20)
+//! }
+//!
+//! #[nutype(guard = UsernameRules, derive(Debug, AsRef))]
+//! pub struct Username(String);
+//!
+//! #[nutype(guard = crate::rules::UsernameRules, derive(Debug))]
+//! pub struct Nickname(String);
+//! ```
+//!
+//! A guard can contain only `sanitize(...)` and `validate(...)`, the rest of the attributes are set per type.
+//! The rules are checked against the inner type of every type the guard is applied to.
+//! The guard is a `macro_rules!` macro under the hood, so it follows the scoping of macros: it can be shared within the crate
+//! (`pub(crate)` at most), it must be defined before it's used within the same module,
+//! and `nutype` must be available as `::nutype`.
+//!
+//! ### Extending another type
+//!
+//! A type can inherit the sanitizers and validators of another nutype with `extends = ` and add stricter ones:
+//!
+//! ```ignore
+//! #[nutype(sanitize(trim), validate(not_empty, len_char_max = 20), derive(Debug, AsRef))]
+//! pub struct Username(String);
+//!
+//! #[nutype(extends = Username, validate(len_char_max = 10), derive(Debug, AsRef))]
+//! pub struct ShortUsername(String);
+//!
+//! assert_eq!(
+//!     ShortUsername::new("   "),
+//!     Err(ShortUsernameError::UsernameViolated(UsernameError::NotEmptyViolated))
+//! );
+//!
+//! let username: Username = ShortUsername::new(" bob ")?.into();
+//! ```
+//!
+//! The sanitizers and validators of the base type run before the own ones. Its errors are wrapped into a variant
+//! named after the base type, and `From<ShortUsername> for Username` is generated. The conversion wraps the value
+//! as it is, since it has already passed the sanitizers and validators of the base type.
+//! Both types must have the same inner type, the base type must have validation and its error type must have the default name.
+//! The base type must be defined in the same crate, since the helpers it provides for the extending types are crate-private.
+//!
+//! ### Domain presets
+//!
+//! Common domain types can be defined with `preset = ` instead of writing the sanitizers and validators by hand:
+//!
+//! ```ignore
+//! #[nutype(preset = email, derive(Debug, AsRef))]
+//! pub struct Email(String);
+//!
+//! assert_eq!(Email::new(" Alice@Example.com ")?.as_ref(), "alice@example.com");
+//! assert_eq!(Email::new("alice"), Err(EmailError::PredicateViolated));
+//! ```
+//!
+//! | Preset       | Inner type      | Rules                                                                              |
+//! |--------------|-----------------|------------------------------------------------------------------------------------|
+//! | `email`      | `String`        | trimmed and lowercased, at most 254 characters, `local@domain.tld`                 |
+//! | `slug`       | `String`        | trimmed and lowercased, at most 100 characters of `a-z`, `0-9` and single dashes   |
+//! | `uuid`       | `String`        | trimmed and lowercased, hyphenated form, e.g. `67e55044-10b1-426f-9247-bb680e5fe0c8` |
+//! | `url`        | `String`        | trimmed, at most 2048 characters, starts with `http://` or `https://`              |
+//! | `percentage` | integer, float  | from 0 to 100 (floats must be finite)                                              |
+//!
+//! The presets are checks of the shape, not full parsers of the standard formats. A preset cannot be combined with `sanitize(...)` or `validate(...)`,
+//! but the error messages can be adjusted with `messages(predicate = "...")`.
+//!
+//! ### Conditional rules with `cfg_attr`
+//!
+//! A sanitizer or a validator can be enabled only under some configuration with `cfg_attr(predicate, ...)`,
+//! e.g. a looser limit in tests or a stricter rule behind a feature:
+//!
+//! ```ignore
+//! #[nutype(
+//!     sanitize(trim, cfg_attr(feature = "strict-validation", lowercase)),
+//!     validate(
+//!         not_empty,
+//!         cfg_attr(test, len_char_max = 1000),
+//!         cfg_attr(not(test), len_char_max = 100),
+//!     ),
+//! )]
+//! pub struct Comment(String);
+//! ```
+//!
+//! Every check of a conditional rule gets `#[cfg(...)]` of its predicate, and so do the error variant of a conditional validator
+//! and the match arms on it. The code matching on such a variant must be conditional as well.
+//! `cfg(predicate, ...)` is accepted as a shorthand.
+//!
+//! The rules are checked against each other regardless of their predicates, e.g. `len_char_min` of `test` cannot be greater
+//! than `len_char_max` of `not(test)`. The JSON schema describes the unconditional validators only, `new_clamped()` and
+//! the well-known constants are not generated, and `const_fn`, `factory`, `metadata`, `ops = saturate` as well as
+//! `derive(Arbitrary, Distribution, Zero, One, Bounded)` are not supported together with conditional rules.
+//!
+//! ### Docs of the generated items
+//!
+//! The doc comment of the struct is kept as is, and the generated items can be documented with `docs(...)`:
+//!
+//! ```ignore
+//! /// A name of a user.
+//! #[nutype(
+//!     sanitize(trim),
+//!     validate(not_empty, len_char_max = 20),
+//!     docs(
+//!         new = "Creates a username out of a trimmed string.",
+//!         into_inner = "Returns the username as a string.",
+//!         error = "The reason why a string is not a valid username.",
+//!     ),
+//! )]
+//! pub struct Username(String);
+//! ```
+//!
+//! A key is `error`, `parse_error` or a name of a generated method (e.g. `new`, or the name set with `constructor = `).
+//! The variants and the fields of a documented error type get short generated docs, unless they are documented already.
+//!
+//! ### Invariants in the docs
+//!
+//! Nutype appends "Invariants" section to the docs of the type, which lists the sanitizers and the validators,
+//! so the users of the type know the contract without reading the attributes. E.g. for
+//!
+//! ```ignore
+//! /// A name of a user.
+//! #[nutype(sanitize(trim), validate(not_empty, len_char_max = 20))]
+//! pub struct Username(String);
+//! ```
+//!
+//! the docs of `Username` read:
+//!
+//! ```text
+//! A name of a user.
+//!
+//! Invariants
+//!
+//! Sanitizers, applied in order:
+//! * `trim`: leading and trailing whitespace is removed
+//!
+//! Validators:
+//! * `not_empty`: not empty
+//! * `len_char_max = 20`: at most 20 character(s)
+//! ```
+//!
+//! ### Metadata for tooling
+//!
+//! With `metadata` flag the type gets a hidden `NUTYPE_METADATA` constant, which describes the sanitizers and the validators as JSON,
+//! so external tools (e.g. OpenAPI generators or linters) can read the rules without parsing the attributes of `#[nutype]`:
+//!
+//! ```ignore
+//! #[nutype(metadata, sanitize(trim), validate(not_empty, len_char_max = 20))]
+//! pub struct Username(String);
+//!
+//! assert_eq!(
+//!     Username::NUTYPE_METADATA,
+//!     r#"{"name":"Username","inner_type":"String","sanitizers":[{"kind":"trim"}],"validators":[{"kind":"not_empty"},{"kind":"len_char_max","value":20}]}"#
+//! );
+//! ```
+//!
+//! Bounds given by expressions are kept as code (e.g. `{"kind":"len_char_max","expr":"MAX_LEN"}`), custom functions as paths and regex literals as patterns.
+//!
+//! ### Inspecting the generated code
+//!
+//! With `debug_expansion` flag the generated code of the type is written to `target/nutype-expansions/crate_name-TypeName.rs`,
+//! which is quicker than running `cargo expand` on the whole crate:
+//!
+//! ```ignore
+//! #[nutype(debug_expansion, sanitize(trim), validate(not_empty))]
+//! pub struct Username(String);
+//! ```
+//!
+//! The code is not formatted, run `rustfmt` on the file if needed. The flag emits a warning with the path of the file, so it's not forgotten in the code.
+//! The file is overwritten on every expansion. Types of the same name in different modules of a crate get a suffix, e.g. `crate_name-TypeName-2.rs`.
+//!
+//! ### Types within function bodies
+//!
+//! Nutype puts the generated items into a hidden module, which cannot see the items of a function body
+//! (e.g. a local type used as the inner type). With `local` flag the items are generated in place:
+//!
+//! ```ignore
+//! #[test]
+//! fn test_sizes() {
+//!     #[derive(Debug, Clone, PartialEq)]
+//!     struct Size {
+//!         width: u32,
+//!         height: u32,
+//!     }
+//!
+//!     #[nutype(local, derive(Debug, Clone, PartialEq))]
+//!     struct WindowSize(Size);
+//!
+//!     let size = WindowSize::new(Size { width: 800, height: 600 });
+//!     assert_eq!(size.into_inner().width, 800);
+//! }
+//! ```
+//!
+//! Without the module the inner value of a `local` type is accessible within the function body, so the sanitizers
+//! and the validators could be bypassed. Therefore `local` cannot be used for types with sanitizers, validators or `extends = `.
+//!
+//! ### Leaving out generated items with `omit`
+//!
+//! When a generated method or trait implementation conflicts with a hand-written one, it can be left out with `omit(...)`:
+//!
+//! ```ignore
+//! #[nutype(
+//!     sanitize(trim),
+//!     validate(not_empty),
+//!     omit(into, sanitize),
+//!     derive(Debug, AsRef, Into, TryFrom),
+//! )]
+//! pub struct Title(String);
+//!
+//! impl From<Title> for String {
+//!     fn from(title: Title) -> String {
+//!         title.into_inner().to_uppercase()
+//!     }
+//! }
+//! ```
+//!
+//! An item is a name of a generated method (e.g. `sanitize` or `new_clamped`) or a trait in snake case:
+//! `try_from` leaves out the implementations of `TryFrom` for the type, and `into` leaves out the implementations of `From<Title>` for other types.
+//! The constructor cannot be omitted, since other generated items depend on it.
+//!
+//! ### Strict mode
+//!
+//! Flag `strict` requires the type to have at least one validator, so a newtype, which accepts any value by accident, does not slip through:
+//!
+//! ```ignore
+//! use nutype::nutype;
+//!
+//! #[nutype(strict, validate(not_empty, len_char_max = 20))]
+//! pub struct Username(String);
+//! ```
+//!
+//! Types, which exist only to distinguish values of the same inner type, are marked with `brand`:
+//!
+//! ```ignore
+//! use nutype::nutype;
+//!
+//! #[nutype(strict, brand, sanitize(trim))]
+//! pub struct Comment(String);
+//! ```
+//!
+//! Redundant rules, e.g. `new_unchecked` on a type without sanitizers and validators, usually indicate a copy-paste bug.
+//! They are reported as warnings, and in strict mode as errors. Redundant sanitizer chains, e.g. `trim` listed twice
+//! or `lowercase` together with `uppercase`, are always errors.
+//!
+//! ### Runtime bounds with `factory`
+//!
+//! Sometimes the limits are known only at runtime, e.g. they differ from tenant to tenant.
+//! With `factory` flag nutype generates `{Type}Factory`, which allows to set the bounds of some validators at runtime,
+//! while the type and the rest of the validation stay static:
+//!
+//! ```ignore
+//! #[nutype(
+//!     factory,
+//!     sanitize(trim),
+//!     validate(not_empty, len_char_max = 255),
+//! )]
+//! pub struct Username(String);
+//!
+//! let factory = Username::with_len_char_max(tenant.max_username_len);
+//! let username = factory.build("bob")?;
+//! ```
+//!
+//! The runtime bounds are checked in addition to the static ones, so they can only narrow down the set of valid values.
+//! The supported validators are `len_char_min` and `len_char_max` for string types
+//! and `greater`, `greater_or_equal`, `less`, `less_or_equal` for numeric types.
+//!
+//! ### Clamping numbers into range
+//!
+//! Numeric types whose validators are range bounds also get `new_clamped()`.
+//! It moves an out-of-range value to the closest valid one instead of returning an error,
+//! while `new()` keeps rejecting invalid values:
+//!
+//! ```ignore
+//! #[nutype(validate(greater_or_equal = 1, less_or_equal = 12))]
+//! pub struct Month(u8);
+//!
+//! assert_eq!(Month::new_clamped(0).into_inner(), 1);
+//! assert_eq!(Month::new_clamped(13).into_inner(), 12);
+//! ```
+//!
+//! For float types only inclusive bounds (`greater_or_equal` and `less_or_equal`) are supported.
+//! Types with `predicate`, `finite` or `not_nan` do not get `new_clamped()`.
+//!
+//! ### Mutating the value in place
+//!
+//! Types with validation can get `try_mutate()` with `methods(try_mutate)`. It applies a closure to a copy of the inner value,
+//! sanitizes and validates the result, and only then stores it.
+//! If validation fails the error is returned and the value stays unchanged:
+//!
+//! ```ignore
+//! #[nutype(
+//!     sanitize(trim),
+//!     validate(not_empty, len_char_max = 10),
+//!     methods(try_mutate),
+//! )]
+//! pub struct Username(String);
+//!
+//! let mut username = Username::new("bob")?;
+//! username.try_mutate(|name| name.push_str("_1"))?;
+//! assert!(username.try_mutate(|name| name.push_str(" the builder")).is_err());
+//! ```
+//!
+//! The inner type has to implement `Clone` for `try_mutate()` to be callable.
+//!
+//! `try_map()` (generated with `methods(try_map)`) does the same for an owned value: it applies a closure to the inner value
+//! and passes the result through the constructor. Types without validation can get an infallible `map()` with `methods(map)` instead:
+//!
+//! ```ignore
+//! #[nutype(validate(less_or_equal = 100), methods(try_map))]
+//! pub struct Percentage(u8);
+//!
+//! let percentage = Percentage::new(40)?.try_map(|p| p * 2)?;
+//! ```
+//!
+//! To replace the value entirely use `set()`, which is generated with `methods(set)`. It keeps the old value if the new one is invalid:
+//!
+//! ```ignore
+//! #[nutype(validate(less_or_equal = 100), methods(set))]
+//! pub struct Percentage(u8);
+//!
+//! let mut percentage = Percentage::new(40)?;
+//! percentage.set(50)?;
+//! assert!(percentage.set(101).is_err());
+//! ```
+//!
+//! ### Validating collections
+//!
+//! Types with validation can get `try_new_all()` with `methods(try_new_all)`. It constructs values out of every item of an iterator.
+//! On failure it returns the index of the first invalid item together with the error, which is handy for CSV rows or bulk API payloads:
+//!
+//! ```ignore
+//! #[nutype(validate(less_or_equal = 100), derive(Debug, PartialEq), methods(try_new_all))]
+//! pub struct Percentage(u8);
+//!
+//! assert_eq!(
+//!     Percentage::try_new_all([10, 200, 30]),
+//!     Err((1, PercentageError::LessOrEqualViolated)),
+//! );
+//! ```
+//!
+//! With `all_violations` there is also `try_new_all_collecting_violations()`, which returns all invalid items with their violations.
+//!
+//! ### Deriving third-party traits with `derive_unsafe`
+//!
+//! With `derive_unsafe` feature enabled, traits unknown to nutype can be derived with `derive_unsafe(..)`.
+//! The derive macros are applied to the generated struct as is, together with their helper attributes:
+//!
+//! ```ignore
+//! #[nutype(
+//!     validate(not_empty),
+//!     derive(Debug, AsRef),
+//!     derive_unsafe(derive_more::Display),
+//! )]
+//! #[display("@{_0}")]
+//! pub struct Handle(String);
+//! ```
+//!
+//! Nutype cannot check what the derived code does. A derive, that constructs or mutates values (e.g. `derive_more::FromStr` or `derive_more::AddAssign`), bypasses sanitizers and validators.
+//!
+//! ### Forwarding attributes
+//!
+//! Attributes written below `#[nutype]` (e.g. `#[must_use]`, `#[deprecated]` or `#[cfg_attr(..)]`) are forwarded to the generated struct.
+//! `#[cfg(..)]` is applied to all the generated items, so the type is either generated entirely or not at all:
+//!
+//! ```ignore
+//! #[nutype(sanitize(trim), derive(Debug, AsRef))]
+//! #[must_use]
+//! #[cfg(feature = "accounts")]
+//! pub struct Nickname(String);
+//! ```
+//!
+//! Note, that `#[derive(..)]` is still intercepted (see the note about `#[derive(...)]` below).
+//! It also applies to `#[cfg_attr(.., derive(..))]`, since the compiler expands `cfg_attr` before `#[nutype]` gets the struct.
+//!
+//! ### Structs with a named field
+//!
+//! Besides tuple structs, `#[nutype]` accepts structs with exactly one named field.
+//! The generated code is the same: the field stays private, so the inner value is accessible via `into_inner()` and the derived traits.
+//!
+//! ```ignore
+//! #[nutype(
+//!     validate(greater_or_equal = 0),
+//!     derive(Debug, Clone, Copy, PartialEq),
+//! )]
+//! pub struct Amount {
+//!     value: i64,
+//! }
+//!
+//! assert_eq!(Amount::new(42)?.into_inner(), 42);
+//! ```
+//!
+//! ### Structs with multiple fields
+//!
+//! A tuple struct with two or more fields is handled as a type with a custom inner type:
+//! the fields are kept together as a tuple, which is received and returned by sanitizers and validators.
+//! It allows to keep invariants, that span over several fields, within the type:
+//!
+//! ```ignore
+//! #[nutype(
+//!     validate(predicate = |&(start, end)| start <= end),
+//!     derive(Debug, Clone, Copy, PartialEq, Into),
+//! )]
+//! pub struct Range(i64, i64);
+//!
+//! let range = Range::new((1, 10))?;
+//! assert_eq!(range.into_inner(), (1, 10));
+//! assert_eq!(Range::new((10, 1)), Err(RangeError::PredicateViolated));
+//! ```
+//!
+//! ### Generic types
+//!
+//! Types with a custom inner type can have generic parameters, lifetimes and a where clause.
+//! The bounds needed by sanitizers, validators and derived traits have to be declared by the type itself:
+//!
+//! ```ignore
+//! #[nutype(
+//!     validate(predicate = |items| !items.is_empty()),
+//!     derive(Debug, Clone, PartialEq, AsRef, Deref),
+//! )]
+//! pub struct NonEmptyVec<T>(Vec<T>);
+//!
+//! let numbers = NonEmptyVec::new(vec![1, 2, 3])?;
+//! assert_eq!(numbers.len(), 3);
+//! assert!(NonEmptyVec::<String>::new(vec![]).is_err());
+//! ```
+//!
+//! `FromStr`, `Deserialize` and most of the traits from the third party crates cannot be derived for generic types yet.
+//! The exception is `Deserialize` of a type over `Cow<'a, str>` (see [Customize serde](#customize-serde)).
+//! `String`, integer and float types can have const generic parameters, which can be used as bounds of the validators.
+//! It allows to define a family of fixed-length codes with a single macro invocation:
+//!
+//! ```ignore
+//! #[nutype(
+//!     sanitize(trim, uppercase),
+//!     validate(len_char_min = N, len_char_max = N),
+//!     derive(Debug, PartialEq, AsRef),
+//! )]
+//! pub struct Code<const N: usize>(String);
+//!
+//! pub type CurrencyCode = Code<3>;
+//! pub type CountryCode = Code<2>;
+//!
+//! assert_eq!(CurrencyCode::new("usd")?.as_ref(), "USD");
+//! assert_eq!(CountryCode::new("usd"), Err(CodeError::LenCharMaxViolated));
+//! ```
+//!
+//! The error type gets the const parameters as well (e.g. `CodeError<3>`), so they can be part of the error messages.
+//!
+//! ### Transparent representation
+//!
+//! With `repr = transparent` the newtype gets `#[repr(transparent)]`, so it has exactly the layout of the inner type and can be used in FFI.
+//! The compiler guarantees, that the struct stays single-field. The following helpers convert slices and vectors without copying:
+//!
+//! * `as_inner_slice(&[Self]) -> &[Inner]` and `into_inner_vec(Vec<Self>) -> Vec<Inner>`.
+//! * Without sanitizers: `try_from_inner_slice(&[Inner])` and `try_from_inner_vec(Vec<Inner>)`, which validate every value and return the index of the first invalid one.
+//!   Without validation they are infallible: `from_inner_slice()` and `from_inner_vec()`.
+//!
+//! ```ignore
+//! #[nutype(repr = transparent, validate(less_or_equal = 100), derive(Debug, PartialEq))]
+//! pub struct Percentage(u8);
+//!
+//! let percentages: Vec<Percentage> = Percentage::try_from_inner_vec(vec![0, 50, 100])?;
+//! assert_eq!(Percentage::as_inner_slice(&percentages), &[0, 50, 100]);
+//! assert_eq!(
+//!     Percentage::try_from_inner_vec(vec![10, 101]),
+//!     Err((1, PercentageError::LessOrEqualViolated))
+//! );
+//! ```
+//!
+//! The helpers are implemented with `unsafe` code, so they cannot be used in crates with `#![forbid(unsafe_code)]`.
+//!
+//! ### Checking values without constructing them
+//!
+//! Types with validation can get `validate()` with `methods(validate)`. It runs the sanitizers and validators against a reference to a value,
+//! which is useful to pre-check input, e.g. for live feedback in forms, without constructing and discarding the newtype.
+//! The method is generated only on request, so it does not collide with a `validate()` implemented by hand:
+//!
+//! ```ignore
+//! #[nutype(sanitize(trim), validate(len_char_max = 5), methods(validate))]
+//! pub struct Nickname(String);
+//!
+//! assert_eq!(Nickname::validate("  bob  "), Ok(()));
+//! assert_eq!(Nickname::validate("alice-bob"), Err(NicknameError::LenCharMaxViolated));
+//! ```
+//!
+//! The sanitizers are applied to a copy of the value. For other inner types with sanitizers the newtype has to derive `Clone`, otherwise `methods(validate)` is a compile error.
+//!
+//! ### Previewing sanitization
+//!
+//! Types with sanitizers get `sanitize()`, which applies only the sanitizers, e.g. to show users what their input will be normalized to:
+//!
+//! ```ignore
+//! #[nutype(sanitize(trim, lowercase), validate(not_empty))]
+//! pub struct Email(String);
+//!
+//! assert_eq!(Email::sanitize("  Bob@Example.COM "), "bob@example.com");
+//! ```
+//!
+//! ### Delegating to `String` methods
+//!
+//! String types can forward read-only methods to the inner string with `delegate(...)`,
+//! so inspecting the value does not require `as_ref()`. Supported methods are `len`, `is_empty`, `chars` and `as_str`:
+//!
+//! ```ignore
+//! #[nutype(
+//!     sanitize(trim),
+//!     validate(len_char_max = 100),
+//!     delegate(len, is_empty, as_str),
+//! )]
+//! pub struct Title(String);
+//!
+//! let title = Title::new(" Nutype ")?;
+//! assert_eq!(title.len(), 6);
+//! assert_eq!(title.as_str(), "Nutype");
+//! ```
+//!
+//! ### Custom accessor
+//!
+//! Besides `into_inner()` and the derived `AsRef`, a method returning a reference to the inner value can be generated
+//! with a name matching the conventions of the codebase, e.g. `accessor = value`:
+//!
+//! ```ignore
+//! #[nutype(validate(not_empty), accessor = value)]
+//! pub struct Email(String);
+//!
+//! let email = Email::new("bob@example.com")?;
+//! let value: &String = email.value();
+//! ```
+//!
+//! With `const_fn` the accessor is a `const fn` as well. Secret types cannot have an accessor.
+//!
+//! ### Interning strings
+//!
+//! With `intern` feature enabled string types get `new_interned()`. It sanitizes and validates the value like `new()`,
+//! but returns `&'static Self`, which is shared by all equal values. It suits workloads with lots of repeated values, like tags or currency codes:
+//!
+//! ```ignore
+//! #[nutype(sanitize(trim, uppercase), validate(len_char_min = 3, len_char_max = 3))]
+//! pub struct CurrencyCode(String);
+//!
+//! let usd: &'static CurrencyCode = CurrencyCode::new_interned("usd")?;
+//! assert!(std::ptr::eq(usd, CurrencyCode::new_interned(" USD ")?));
+//! ```
+//!
+//! The interned values are never freed, so do not intern values from unbounded sets. Secret types do not get `new_interned()`.
+//!
+//! ### Leaking strings
+//!
+//! String types without `secret` can get `leak()` with `methods(leak)`. It consumes the value and returns `&'static str`
+//! (see `String::leak`). It is meant for values created once and kept for the whole program, e.g. identifiers read from a config at startup:
+//!
+//! ```ignore
+//! #[nutype(validate(not_empty), methods(leak))]
+//! pub struct ServiceName(String);
+//!
+//! let name: &'static str = ServiceName::new("billing")?.leak();
+//! ```
+//!
+//! ### Testing compile errors
+//!
+//! With `testing` feature (meant for `[dev-dependencies]`) the rules of a type can be covered by compile-fail tests:
+//! files, that define the type with a rule violated, are compiled by [trybuild](https://crates.io/crates/trybuild)
+//! and the compiler output is compared with the `.stderr` file next to each of them.
+//!
+//! ```ignore
+//! // tests/compile_fail.rs
+//! nutype::compile_fail_tests! {
+//!     invalid_usernames => "tests/compile_fail/username/*.rs",
+//! }
+//! ```
+//!
+//! Run the tests with `TRYBUILD=overwrite` to record or update the `.stderr` files.
+//! `nutype::testing::assert_compile_fail()` can be called from a regular test as well.
+//!
+//! ### no_std
+//!
+//! With `default-features = false` the generated code works with `#![no_std]`. Integer, float and any other
+//! inner types need only `core`. String types and the methods, which return `Vec` (e.g. `try_new_all()`),
+//! need `alloc` feature:
+//!
+//! ```toml
+//! [dependencies]
+//! nutype = { version = "0.4", default-features = false, features = ["alloc"] }
+//! ```
+//!
+//! The error types implement `std::error::Error` only with `std`. Enable `core_error` feature to implement
+//! `core::error::Error` instead (requires Rust 1.81 or later).
+//!
 //! ## How to break the constraints?
 //!
 //! It's discouraged, but it's possible to bypass the constraints by enabling `new_unchecked` crate feature and marking a type with `new_unchecked`:
@@ -368,8 +1376,8 @@
 //! use nutype::nutype;
 //!
 //! #[nutype(
-//!     new_unchecked
-//!     sanitize(trim)
+//!     new_unchecked(no_debug_assert),
+//!     sanitize(trim),
 //!     validate(len_char_min = 8)
 //! )]
 //! pub struct Name(String);
@@ -381,14 +1389,54 @@
 //! assert_eq!(name.into_inner(), " boo ");
 //! ```
 //!
+//! With validation `new_unchecked()` still checks the value (without sanitizing it) when `debug_assertions` are enabled
+//! and panics if it's invalid, so misuse is caught in tests and development without any cost in release builds.
+//! Use `new_unchecked(no_debug_assert)` to opt out, like in the example above.
+//! A type without sanitizers and validators gets a warning for `new_unchecked`, since `new()` already accepts any value as is.
+//!
+//! For performance-critical code that mutates the value in place, types with `new_unchecked` also get
+//! `unsafe fn as_mut_unchecked(&mut self) -> &mut Inner`. The same contract applies: it's on you to keep the value valid.
+//!
+//! Codebases with `#![forbid(unsafe_code)]` can mark the type with `new_unchecked(safe)` instead,
+//! which generates both functions without `unsafe`. Options can be combined, e.g. `new_unchecked(safe, no_debug_assert)`.
+//!
+//! The `new_unchecked` feature also allows to skip sanitization and validation on deserialization with `serde(skip_validation)`.
+//! It's meant for hot paths that read data from a trusted source (e.g. own database snapshots), which was validated before:
+//!
+//! ```ignore
+//! #[nutype(
+//!     validate(regex = EMAIL_REGEX),
+//!     derive(Serialize, Deserialize),
+//!     serde(skip_validation),
+//! )]
+//! pub struct TrustedEmail(String);
+//! ```
+//!
 //! ## Feature flags
 //!
+//! * `actix` - allows to derive [`FromRequest`](https://docs.rs/actix-web/4/actix_web/trait.FromRequest.html) of [actix-web](https://crates.io/crates/actix-web) crate. The value is extracted from the path, guard violations are responded with `422 Unprocessable Entity` (with `web` feature the status is taken from `status_code()` of the error). To take the value from the query string or a JSON body, derive `Deserialize` and use the newtype within a struct extracted with `web::Query` or `web::Json`: the guard is checked on deserialization and violations are handled by `QueryConfig` or `JsonConfig` of actix-web.
+//! * `alloc` - enables String types and the methods working with `Vec` (e.g. `try_new_all()`) without `std`, using `alloc` crate. Enabled by `std`.
 //! * `arbitrary` - enables derive of [`arbitrary::Arbitrary`](https://docs.rs/arbitrary/latest/arbitrary/trait.Arbitrary.html).
+//! * `axum` - allows the error types to implement [`IntoResponse`](https://docs.rs/axum/0.8/axum/response/trait.IntoResponse.html) of [axum](https://crates.io/crates/axum) crate with `error_integrations(axum)`. The response is `422 Unprocessable Entity` (with `web` feature the status is taken from `status_code()` of the error) with a JSON body containing the violated constraint and the error message. Note: axum's `json` feature must be enabled.
+//! * `clap` - allows to derive [`ValueParserFactory`](https://docs.rs/clap/4/clap/builder/trait.ValueParserFactory.html) of [clap](https://crates.io/crates/clap) crate, so newtypes can be used as CLI arguments. Invalid arguments are reported with the validation error message.
+//! * `core_error` - without `std` the error types implement `core::error::Error` (requires Rust 1.81 or later).
+//! * `intern` - string types get `new_interned()`, which returns a `&'static` reference to a value shared by all equal values.
+//! * `juniper` - allows to derive [`GraphQLScalar`](https://docs.rs/juniper/0.16.0/juniper/derive.GraphQLScalar.html) of [juniper](https://crates.io/crates/juniper) crate. The input is validated during parsing.
 //! * `new_unchecked` - enables generation of unsafe `::new_unchecked()` function.
+//! * `num-traits` - allows to derive `Zero`, `One`, `Bounded`, `ToPrimitive` and `FromPrimitive` traits of [num-traits](https://crates.io/crates/num-traits) crate for integer and float types. `Bounded` respects the boundaries defined by the validators. `Zero` and `One` are rejected when zero or one violates the validation rules, which is checked at compile time for bounds given by constant expressions. None of them can be derived for a type with `with` sanitizer.
+//! * `rand` - allows to derive `Distribution` for integer and float types, which implements [`Distribution`](https://docs.rs/rand/0.8/rand/distributions/trait.Distribution.html) for `Standard` and [`SampleUniform`](https://docs.rs/rand/0.8/rand/distributions/uniform/trait.SampleUniform.html) of [rand](https://crates.io/crates/rand) crate. Values are sampled only within the range defined by the validators.
 //! * `regex` - allows to use `regex = ` validation on string-based types. Note: your crate also has to explicitly have `regex` and `lazy_static` within dependencies.
-//! * `serde` - integrations with [`serde`](https://crates.io/crates/serde) crate. Allows to derive `Serialize` and `Deserialize` traits.
+//! * `rocket` - allows to derive [`FromParam`](https://api.rocket.rs/v0.5/rocket/request/trait.FromParam.html) and [`FromFormField`](https://api.rocket.rs/v0.5/rocket/form/trait.FromFormField.html) of [rocket](https://crates.io/crates/rocket) crate. Guard violations are reported as validation errors (`422 Unprocessable Entity`).
+//! * `serde` - integrations with [`serde`](https://crates.io/crates/serde) crate. Allows to derive `Serialize` and `Deserialize` traits. Deserialization errors name the type and the violated constraint; to get the path of a failing field in nested structures, wrap the deserializer with [serde_path_to_error](https://crates.io/crates/serde_path_to_error).
 //! * `schemars08` - allows to derive [`JsonSchema`](https://docs.rs/schemars/0.8.12/schemars/trait.JsonSchema.html) trait of [schemars](https://crates.io/crates/schemars) crate. Note that at the moment validation rules are not respected.
-//! * `std` - enabled by default. Use `default-features = false` to disable.
+//! * `std` - enabled by default. Use `default-features = false` to disable, so the generated code works with `#![no_std]`. Features that integrate with `std`-only crates (e.g. `axum`, `clap`, `intern`, `miette`, `regex`, `rocket`) enable `std`.
+//! * `zeroize` - allows to derive `Zeroize` and `ZeroizeOnDrop` of [zeroize](https://crates.io/crates/zeroize) crate for string-based and any inner types, so secrets are wiped from memory on drop. `Zeroize` cannot be derived for a type with validation, because the zeroized value may violate it; derive `ZeroizeOnDrop` instead. With `ZeroizeOnDrop` the inner type must implement `Default` to be taken out with `into_inner()`.
+//! * `secrecy` - allows to mark a type as `secret`, integrating it with [secrecy](https://crates.io/crates/secrecy) crate. A secret type implements `ExposeSecret` and zeroizes its inner value on drop; `Zeroize` is implemented only when there is no validation; `into_inner()` is not generated and `Into`, `Display`, `Deref`, `AsRef` and `Borrow` cannot be derived. A derived `Debug` prints `[REDACTED]` instead of the value. `Serialize` can be derived only with `secret(serialize)`, then the type also implements `SerializableSecret`. The inner type must implement `Zeroize` and `Default`.
+//! * `serde_with` - allows to derive `SerializeAs` and `DeserializeAs` of [serde_with](https://crates.io/crates/serde_with) crate, so a field of the inner type can be (de)serialized as the newtype with `#[serde_as(as = "Vec<Email>")]`. Requires `Serialize` and `Deserialize` to be derived respectively.
+//! * `thiserror` - allows the error types to derive `Error` with [thiserror](https://crates.io/crates/thiserror) crate with `error_integrations(thiserror)`. The parse error of `FromStr` exposes the underlying parse or validation error as `source()` and implements `From` for the validation error, so errors compose with `?` and `#[from]`. Note: your crate also has to explicitly have `thiserror` within dependencies.
+//! * `miette` - allows the error types to implement `Diagnostic` of [miette](https://crates.io/crates/miette) crate with `error_integrations(miette)`. The code of a diagnostic is the machine-readable `code()` of the error, the help text is derived from the validator parameters (e.g. "expected a value between 0 and 100"). Note: your crate also has to explicitly have `miette` within dependencies.
+//! * `web` - the error types get `status_code()` method, which maps the error to an HTTP status code: `422` by default, overridable per validator with `status_codes(predicate = 409)`.
+//! * `testing` - enables `nutype::testing` module and `compile_fail_tests!` macro to check with [trybuild](https://crates.io/crates/trybuild), that invalid type definitions fail to compile with the expected errors.
 //!
 //! ## Support Ukrainian military forces 🇺🇦
 //!
@@ -409,7 +1457,18 @@
 // Set `no_std` flag if `std` feature is disabled.
 #![cfg_attr(not(feature = "std"), no_std)]
 
-pub use nutype_macros::nutype;
+pub use nutype_macros::{nutype, nutype_guard};
+
+// Not a public API. The generated code refers to `alloc` crate through the re-export,
+// because a `no_std` crate has to declare `extern crate alloc;` to use it directly.
+#[doc(hidden)]
+#[cfg(feature = "alloc")]
+pub mod __private {
+    pub extern crate alloc;
+}
+
+#[cfg(feature = "testing")]
+pub mod testing;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,36 @@
+//! Helpers to assert that invalid `#[nutype]` configurations fail to compile.
+//!
+//! The helpers are built on [trybuild](https://crates.io/crates/trybuild): every file matching
+//! the pattern is compiled as a separate binary, and the compiler output is compared with the
+//! `.stderr` file next to it. Run the tests with `TRYBUILD=overwrite` to record the output.
+//!
+//! ```ignore
+//! // tests/compile_fail.rs
+//! nutype::compile_fail_tests! {
+//!     invalid_usernames => "tests/compile_fail/username/*.rs",
+//!     invalid_amounts => "tests/compile_fail/amount/*.rs",
+//! }
+//! ```
+
+use std::path::Path;
+
+/// Assert that every file matching the glob pattern fails to compile with the output recorded
+/// in the `.stderr` file next to it. The pattern is relative to the root of the crate.
+pub fn assert_compile_fail<P: AsRef<Path>>(pattern: P) {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail(pattern);
+}
+
+/// Generate a test per pattern, which asserts that the matching files fail to compile.
+/// See [`assert_compile_fail`].
+#[macro_export]
+macro_rules! compile_fail_tests {
+    ($($name:ident => $pattern:expr),+ $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                $crate::testing::assert_compile_fail($pattern);
+            }
+        )+
+    };
+}
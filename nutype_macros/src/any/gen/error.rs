@@ -2,42 +2,81 @@ use proc_macro2::TokenStream;
 use quote::quote;
 
 use crate::{
-    any::models::AnyValidator,
+    any::models::{AnyInnerType, AnyValidator},
     common::{
-        gen::error::{gen_error_type_name, gen_impl_error_trait},
-        models::{ErrorTypeName, TypeName},
+        gen::error::{
+            gen_custom_message_arm, gen_error_derive, gen_impl_axum_into_response,
+            gen_impl_display_trait_override, gen_impl_error_code, gen_impl_error_status_code,
+            gen_impl_error_trait, gen_impl_miette_diagnostic, gen_impl_violated_constraint,
+            gen_variant_fields, gen_variant_pattern,
+        },
+        gen::extends::{gen_extends_arm, gen_extends_variant},
+        models::{ErrorAttributes, ErrorTypeName, TypeName},
     },
 };
 
-pub fn gen_validation_error_type(type_name: &TypeName, validators: &[AnyValidator]) -> TokenStream {
-    let error_type_name = gen_error_type_name(type_name);
-    let definition = gen_definition(&error_type_name, validators);
-    let impl_display_trait = gen_impl_display_trait(type_name, &error_type_name, validators);
-    let impl_error_trait = gen_impl_error_trait(&error_type_name);
+pub fn gen_validation_error_type(
+    type_name: &TypeName,
+    inner_type: &AnyInnerType,
+    error_type_name: &ErrorTypeName,
+    validators: &[AnyValidator],
+    error: &ErrorAttributes,
+) -> TokenStream {
+    let definition = gen_definition(inner_type, error_type_name, validators, error);
+    let impl_display_trait = gen_impl_display_trait_override(error_type_name, error, || {
+        gen_impl_display_trait(type_name, error_type_name, validators, error)
+    });
+    let impl_error_trait = gen_impl_error_trait(error_type_name, error);
+    let impl_violated_constraint = gen_impl_violated_constraint(error_type_name, validators, error);
+    let impl_error_code = gen_impl_error_code(type_name, error_type_name, validators, error);
+    let impl_error_status_code = gen_impl_error_status_code(error_type_name, validators, error);
+    let impl_axum_into_response = gen_impl_axum_into_response(error_type_name, error);
+    let impl_miette_diagnostic = gen_impl_miette_diagnostic(
+        error_type_name,
+        error,
+        gen_help_arms(error_type_name, validators, error),
+    );
+
+    let derive = gen_error_derive(error, quote!(Debug, Clone, PartialEq, Eq));
 
     quote! {
-        #[derive(Debug, Clone, PartialEq, Eq)]
+        #derive
         #definition
 
         #impl_display_trait
+        #impl_violated_constraint
+        #impl_error_code
+        #impl_error_status_code
         #impl_error_trait
+        #impl_axum_into_response
+        #impl_miette_diagnostic
     }
 }
 
-fn gen_definition(error_type_name: &ErrorTypeName, validators: &[AnyValidator]) -> TokenStream {
+fn gen_definition(
+    inner_type: &AnyInnerType,
+    error_type_name: &ErrorTypeName,
+    validators: &[AnyValidator],
+    error: &ErrorAttributes,
+) -> TokenStream {
     let error_variants: TokenStream = validators
         .iter()
-        .map(|validator| match validator {
-            AnyValidator::Predicate(_) => {
-                quote!(PredicateViolated,)
-            }
+        .map(|validator| {
+            let (variant, bound) = match validator {
+                AnyValidator::Predicate(_) => (quote!(PredicateViolated), None),
+            };
+            let fields = gen_variant_fields(error.fields, quote!(value: #inner_type), bound);
+            quote!(#variant #fields,)
         })
         .collect();
 
+    let extends_variant = gen_extends_variant(error);
+
     quote! {
         #[allow(clippy::enum_variant_names)]
         pub enum #error_type_name {
             #error_variants
+            #extends_variant
         }
     }
 }
@@ -46,12 +85,25 @@ fn gen_impl_display_trait(
     type_name: &TypeName,
     error_type_name: &ErrorTypeName,
     validators: &[AnyValidator],
+    error: &ErrorAttributes,
 ) -> TokenStream {
-    let match_arms = validators.iter().map(|validator| match validator {
-        AnyValidator::Predicate(_) => quote! {
-             #error_type_name::PredicateViolated => write!(f, "{} failed the predicate test.", stringify!(#type_name))
-        },
+    let match_arms = validators.iter().map(|validator| {
+        if let Some(arm) = gen_custom_message_arm(error_type_name, validator, error) {
+            return arm;
+        }
+        let pattern = gen_variant_pattern(error, validator);
+        match validator {
+            AnyValidator::Predicate(_) => quote! {
+                 #error_type_name::PredicateViolated #pattern => write!(f, "{} failed the predicate test.", stringify!(#type_name))
+            },
+        }
     });
+    let extends_arm = gen_extends_arm(
+        error_type_name,
+        error,
+        quote!(::core::fmt::Display::fmt(err, f)),
+    );
+    let match_arms = match_arms.chain(extends_arm);
 
     quote! {
         impl ::core::fmt::Display for #error_type_name {
@@ -63,3 +115,21 @@ fn gen_impl_display_trait(
         }
     }
 }
+
+fn gen_help_arms(
+    error_type_name: &ErrorTypeName,
+    validators: &[AnyValidator],
+    error: &ErrorAttributes,
+) -> Vec<TokenStream> {
+    validators
+        .iter()
+        .map(|validator| {
+            let pattern = gen_variant_pattern(error, validator);
+            match validator {
+                AnyValidator::Predicate(_) => {
+                    quote!(#error_type_name::PredicateViolated #pattern => None)
+                }
+            }
+        })
+        .collect()
+}
@@ -9,10 +9,13 @@ use syn::parse_quote;
 
 use crate::common::{
     gen::{
-        error::gen_error_type_name, tests::gen_test_should_have_valid_default_value,
+        error::gen_variant_fields, tests::gen_test_should_have_valid_default_value,
         traits::GeneratedTraits, GenerateNewtype,
     },
-    models::{ErrorTypeName, Guard, TypeName, TypedCustomFunction},
+    models::{
+        ErrorAttributes, ErrorIntegrations, ErrorTypeName, Guard, OpsPolicy, SerdeAttributes,
+        TypeName, TypedCustomFunction,
+    },
 };
 
 use self::error::gen_validation_error_type;
@@ -30,6 +33,8 @@ impl GenerateNewtype for AnyNewtype {
     type InnerType = AnyInnerType;
     type TypedTrait = AnyDeriveTrait;
 
+    const INNER_TYPE_IS_CLONE: bool = false;
+
     fn gen_fn_sanitize(
         inner_type: &Self::InnerType,
         sanitizers: &[Self::Sanitizer],
@@ -62,15 +67,23 @@ impl GenerateNewtype for AnyNewtype {
 
     fn gen_fn_validate(
         inner_type: &Self::InnerType,
-        type_name: &TypeName,
+        error_name: &ErrorTypeName,
         validators: &[Self::Validator],
+        error: &ErrorAttributes,
     ) -> TokenStream {
-        let error_name = gen_error_type_name(type_name);
+        let gen_fields = |bound: Option<TokenStream>| {
+            gen_variant_fields(
+                error.fields,
+                quote!(value: ::core::clone::Clone::clone(val)),
+                bound,
+            )
+        };
 
         let validations: TokenStream = validators
             .iter()
             .map(|validator| match validator {
                 AnyValidator::Predicate(predicate) => {
+                    let fields = gen_fields(None);
                     let inner_type_ref: syn::Type = parse_quote!(
                         &'a #inner_type
                     );
@@ -80,7 +93,7 @@ impl GenerateNewtype for AnyNewtype {
                         .expect("Failed to convert predicate into a typed closure");
                     quote!(
                         if !(#typed_predicate)(val) {
-                            return Err(#error_name::PredicateViolated);
+                            return Err(#error_name::PredicateViolated #fields);
                         }
                     )
                 }
@@ -97,9 +110,12 @@ impl GenerateNewtype for AnyNewtype {
 
     fn gen_validation_error_type(
         type_name: &TypeName,
+        inner_type: &Self::InnerType,
+        error_type_name: &ErrorTypeName,
         validators: &[Self::Validator],
+        error: &ErrorAttributes,
     ) -> TokenStream {
-        gen_validation_error_type(type_name, validators)
+        gen_validation_error_type(type_name, inner_type, error_type_name, validators, error)
     }
 
     fn gen_traits(
@@ -109,6 +125,9 @@ impl GenerateNewtype for AnyNewtype {
         traits: HashSet<Self::TypedTrait>,
         maybe_default_value: Option<syn::Expr>,
         guard: &AnyGuard,
+        _ops: OpsPolicy,
+        serde: &SerdeAttributes,
+        error_integrations: ErrorIntegrations,
     ) -> Result<GeneratedTraits, syn::Error> {
         gen_traits(
             type_name,
@@ -117,6 +136,8 @@ impl GenerateNewtype for AnyNewtype {
             traits,
             maybe_default_value,
             guard,
+            serde,
+            error_integrations,
         )
     }
 
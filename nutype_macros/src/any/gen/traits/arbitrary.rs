@@ -26,7 +26,7 @@ pub fn gen_impl_trait_arbitrary(
         impl ::arbitrary::Arbitrary<'_> for #type_name {
             fn arbitrary(u: &mut ::arbitrary::Unstructured<'_>) -> ::arbitrary::Result<Self> {
                 let inner_value: #inner_type = u.arbitrary()?;
-                Ok(#type_name::new(inner_value))
+                Ok(#type_name::__nutype_new(inner_value))
             }
         }
 
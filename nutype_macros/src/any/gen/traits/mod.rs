@@ -8,14 +8,20 @@ use crate::{
     any::models::AnyDeriveTrait,
     any::models::{AnyGuard, AnyInnerType},
     common::{
+        gen::generics::borrowed_str_lifetime,
         gen::traits::{
-            gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_default,
-            gen_impl_trait_deref, gen_impl_trait_display, gen_impl_trait_from,
-            gen_impl_trait_from_str, gen_impl_trait_into, gen_impl_trait_serde_deserialize,
-            gen_impl_trait_serde_serialize, gen_impl_trait_try_from, split_into_generatable_traits,
+            gen_impl_trait_actix_from_request, gen_impl_trait_as_ref, gen_impl_trait_borrow,
+            gen_impl_trait_clap_value_parser_factory, gen_impl_trait_default, gen_impl_trait_deref,
+            gen_impl_trait_display, gen_impl_trait_from, gen_impl_trait_from_str,
+            gen_impl_trait_into, gen_impl_trait_juniper_graphql_scalar,
+            gen_impl_trait_rocket_from_form_field, gen_impl_trait_rocket_from_param,
+            gen_impl_trait_serde_deserialize, gen_impl_trait_serde_deserialize_borrowed,
+            gen_impl_trait_serde_serialize, gen_impl_trait_serde_with_deserialize_as,
+            gen_impl_trait_serde_with_serialize_as, gen_impl_trait_try_from,
+            gen_impl_trait_zeroize, gen_impl_trait_zeroize_on_drop, split_into_generatable_traits,
             GeneratableTrait, GeneratableTraits, GeneratedTraits,
         },
-        models::{ErrorTypeName, TypeName},
+        models::{ErrorIntegrations, ErrorTypeName, SerdeAttributes, TypeName},
     },
 };
 
@@ -54,6 +60,33 @@ impl From<AnyDeriveTrait> for AnyGeneratableTrait {
             AnyDeriveTrait::ArbitraryArbitrary => {
                 AnyGeneratableTrait::Irregular(AnyIrregularTrait::ArbitraryArbitrary)
             }
+            AnyDeriveTrait::JuniperGraphQLScalar => {
+                AnyGeneratableTrait::Irregular(AnyIrregularTrait::JuniperGraphQLScalar)
+            }
+            AnyDeriveTrait::RocketFromParam => {
+                AnyGeneratableTrait::Irregular(AnyIrregularTrait::RocketFromParam)
+            }
+            AnyDeriveTrait::RocketFromFormField => {
+                AnyGeneratableTrait::Irregular(AnyIrregularTrait::RocketFromFormField)
+            }
+            AnyDeriveTrait::ActixFromRequest => {
+                AnyGeneratableTrait::Irregular(AnyIrregularTrait::ActixFromRequest)
+            }
+            AnyDeriveTrait::ClapValueParserFactory => {
+                AnyGeneratableTrait::Irregular(AnyIrregularTrait::ClapValueParserFactory)
+            }
+            AnyDeriveTrait::ZeroizeZeroize => {
+                AnyGeneratableTrait::Irregular(AnyIrregularTrait::ZeroizeZeroize)
+            }
+            AnyDeriveTrait::ZeroizeZeroizeOnDrop => {
+                AnyGeneratableTrait::Irregular(AnyIrregularTrait::ZeroizeZeroizeOnDrop)
+            }
+            AnyDeriveTrait::SerdeWithSerializeAs => {
+                AnyGeneratableTrait::Irregular(AnyIrregularTrait::SerdeWithSerializeAs)
+            }
+            AnyDeriveTrait::SerdeWithDeserializeAs => {
+                AnyGeneratableTrait::Irregular(AnyIrregularTrait::SerdeWithDeserializeAs)
+            }
         }
     }
 }
@@ -103,8 +136,18 @@ enum AnyIrregularTrait {
     SerdeSerialize,
     SerdeDeserialize,
     ArbitraryArbitrary,
+    JuniperGraphQLScalar,
+    RocketFromParam,
+    RocketFromFormField,
+    ActixFromRequest,
+    ClapValueParserFactory,
+    ZeroizeZeroize,
+    ZeroizeZeroizeOnDrop,
+    SerdeWithSerializeAs,
+    SerdeWithDeserializeAs,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn gen_traits(
     type_name: &TypeName,
     inner_type: &AnyInnerType,
@@ -112,6 +155,8 @@ pub fn gen_traits(
     traits: HashSet<AnyDeriveTrait>,
     maybe_default_value: Option<syn::Expr>,
     guard: &AnyGuard,
+    serde: &SerdeAttributes,
+    error_integrations: ErrorIntegrations,
 ) -> Result<GeneratedTraits, syn::Error> {
     let GeneratableTraits {
         transparent_traits,
@@ -131,6 +176,8 @@ pub fn gen_traits(
         irregular_traits,
         maybe_default_value,
         guard,
+        serde,
+        error_integrations,
     )?;
 
     Ok(GeneratedTraits {
@@ -139,6 +186,7 @@ pub fn gen_traits(
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 fn gen_implemented_traits(
     type_name: &TypeName,
     inner_type: &AnyInnerType,
@@ -146,6 +194,8 @@ fn gen_implemented_traits(
     impl_traits: Vec<AnyIrregularTrait>,
     maybe_default_value: Option<syn::Expr>,
     guard: &AnyGuard,
+    serde: &SerdeAttributes,
+    error_integrations: ErrorIntegrations,
 ) -> Result<TokenStream, syn::Error> {
     impl_traits
         .iter()
@@ -157,7 +207,7 @@ fn gen_implemented_traits(
             AnyIrregularTrait::Deref => Ok(gen_impl_trait_deref(type_name, inner_type)),
             AnyIrregularTrait::Borrow => Ok(gen_impl_trait_borrow(type_name, inner_type)),
             AnyIrregularTrait::FromStr => Ok(
-                gen_impl_trait_from_str(type_name, inner_type, maybe_error_type_name.as_ref())
+                gen_impl_trait_from_str(type_name, inner_type, maybe_error_type_name.as_ref(), error_integrations)
             ),
             AnyIrregularTrait::TryFrom => Ok(
                 gen_impl_trait_try_from(type_name, inner_type, maybe_error_type_name.as_ref())
@@ -176,12 +226,44 @@ fn gen_implemented_traits(
                 }
             ),
             AnyIrregularTrait::SerdeSerialize => Ok(
-                gen_impl_trait_serde_serialize(type_name)
-            ),
-            AnyIrregularTrait::SerdeDeserialize => Ok(
-                gen_impl_trait_serde_deserialize(type_name, inner_type, maybe_error_type_name.as_ref())
+                gen_impl_trait_serde_serialize(type_name, inner_type, serde)
             ),
+            AnyIrregularTrait::SerdeDeserialize => match borrowed_str_lifetime(inner_type) {
+                Some(lifetime) => {
+                    if let Some(key) = &serde.wrap {
+                        let msg = "`serde(wrap = ...)` is not supported for a type over borrowed `Cow<'a, str>`.";
+                        return Err(syn::Error::new(key.span(), msg));
+                    }
+                    Ok(gen_impl_trait_serde_deserialize_borrowed(type_name, inner_type, &lifetime, maybe_error_type_name.as_ref(), &maybe_default_value, serde))
+                }
+                None => Ok(
+                    gen_impl_trait_serde_deserialize(type_name, inner_type, maybe_error_type_name.as_ref(), &maybe_default_value, serde)
+                ),
+            },
             AnyIrregularTrait::ArbitraryArbitrary => arbitrary::gen_impl_trait_arbitrary(type_name, inner_type, guard),
+            AnyIrregularTrait::JuniperGraphQLScalar => Ok(
+                gen_impl_trait_juniper_graphql_scalar(type_name, inner_type, maybe_error_type_name.as_ref())
+            ),
+            AnyIrregularTrait::RocketFromParam => Ok(
+                gen_impl_trait_rocket_from_param(type_name, inner_type, maybe_error_type_name.as_ref())
+            ),
+            AnyIrregularTrait::RocketFromFormField => Ok(
+                gen_impl_trait_rocket_from_form_field(type_name, inner_type, maybe_error_type_name.as_ref())
+            ),
+            AnyIrregularTrait::ActixFromRequest => Ok(
+                gen_impl_trait_actix_from_request(type_name, inner_type, maybe_error_type_name.as_ref())
+            ),
+            AnyIrregularTrait::ClapValueParserFactory => Ok(
+                gen_impl_trait_clap_value_parser_factory(type_name, inner_type, maybe_error_type_name.as_ref())
+            ),
+            AnyIrregularTrait::ZeroizeZeroize => Ok(gen_impl_trait_zeroize(type_name)),
+            AnyIrregularTrait::ZeroizeZeroizeOnDrop => Ok(gen_impl_trait_zeroize_on_drop(type_name)),
+            AnyIrregularTrait::SerdeWithSerializeAs => Ok(
+                gen_impl_trait_serde_with_serialize_as(type_name, inner_type, serde),
+            ),
+            AnyIrregularTrait::SerdeWithDeserializeAs => Ok(
+                gen_impl_trait_serde_with_deserialize_as(type_name, inner_type),
+            ),
         })
         .collect()
 }
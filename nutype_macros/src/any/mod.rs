@@ -10,7 +10,7 @@ use self::models::{AnyDeriveTrait, AnyGuard, AnyInnerType, AnySanitizer, AnyVali
 use crate::common::gen::GenerateNewtype;
 use crate::{
     any::validate::validate_any_derive_traits,
-    common::models::{Attributes, GenerateParams, Newtype, SpannedDeriveTrait},
+    common::models::{Attributes, ConditionalRules, GenerateParams, Newtype, SpannedDeriveTrait},
 };
 
 pub struct AnyNewtype;
@@ -35,7 +35,12 @@ impl Newtype for AnyNewtype {
     }
 
     fn generate(
-        params: GenerateParams<AnyInnerType, Self::TypedTrait, AnyGuard>,
+        params: GenerateParams<
+            AnyInnerType,
+            Self::TypedTrait,
+            AnyGuard,
+            ConditionalRules<Self::Sanitizer, Self::Validator>,
+        >,
     ) -> Result<TokenStream, syn::Error> {
         AnyNewtype::gen_nutype(params)
     }
@@ -4,7 +4,12 @@ use quote::ToTokens;
 use std::fmt::Debug;
 use syn::Field;
 
-use crate::common::models::{CustomFunction, Guard, RawGuard, SpannedItem, TypeTrait};
+use crate::common::{
+    gen::{invariants::describe_custom_function, metadata::metadata_of_custom_function},
+    models::{
+        CustomFunction, Describe, Guard, HasBound, Metadata, RawGuard, SpannedItem, TypeTrait,
+    },
+};
 
 /// Sanitizer for "any" type.
 #[derive(Debug, Kinded)]
@@ -22,6 +27,50 @@ pub enum AnyValidator {
     Predicate(CustomFunction),
 }
 
+impl Describe for AnySanitizer {
+    fn describe(&self) -> String {
+        match self {
+            Self::With(function) => {
+                describe_custom_function("with", function.path(), "transformed with")
+            }
+        }
+    }
+}
+
+impl Metadata for AnySanitizer {
+    fn metadata(&self) -> String {
+        match self {
+            Self::With(function) => metadata_of_custom_function("with", function.path()),
+        }
+    }
+}
+
+impl Describe for AnyValidator {
+    fn describe(&self) -> String {
+        match self {
+            Self::Predicate(function) => {
+                describe_custom_function("predicate", function.path(), "satisfies")
+            }
+        }
+    }
+}
+
+impl Metadata for AnyValidator {
+    fn metadata(&self) -> String {
+        match self {
+            Self::Predicate(function) => metadata_of_custom_function("predicate", function.path()),
+        }
+    }
+}
+
+impl HasBound for AnyValidator {
+    fn has_bound(&self) -> bool {
+        match self {
+            Self::Predicate(_) => false,
+        }
+    }
+}
+
 pub type SpannedAnyValidator = SpannedItem<AnyValidator>;
 
 #[derive(Debug, Eq, PartialEq, Hash)]
@@ -49,12 +98,33 @@ pub enum AnyDeriveTrait {
     SerdeSerialize,
     SerdeDeserialize,
     ArbitraryArbitrary,
+    JuniperGraphQLScalar,
+    RocketFromParam,
+    RocketFromFormField,
+    ActixFromRequest,
+    ClapValueParserFactory,
+    ZeroizeZeroize,
+    ZeroizeZeroizeOnDrop,
+    SerdeWithSerializeAs,
+    SerdeWithDeserializeAs,
 }
 
 impl TypeTrait for AnyDeriveTrait {
     fn is_from_str(&self) -> bool {
         self == &AnyDeriveTrait::FromStr
     }
+
+    fn is_drop(&self) -> bool {
+        self == &AnyDeriveTrait::ZeroizeZeroizeOnDrop
+    }
+
+    fn is_debug(&self) -> bool {
+        self == &AnyDeriveTrait::Debug
+    }
+
+    fn is_clone(&self) -> bool {
+        self == &AnyDeriveTrait::Clone
+    }
 }
 
 pub type AnyRawGuard = RawGuard<SpannedAnySanitizer, SpannedAnyValidator>;
@@ -1,8 +1,22 @@
-use crate::common::{
-    models::{Attributes, CustomFunction, SpannedDeriveTrait},
-    parse::{parse_sanitizer_kind, parse_validator_kind, ParseableAttributes},
+use crate::{
+    common::{
+        models::{Attributes, CustomFunction, Factory, OpsPolicy, SpannedDeriveTrait},
+        parse::{
+            meta::SUPPORTED_INNER_TYPES, parse_sanitizer_kind, parse_validator_kind,
+            ParseableAttributes,
+        },
+        validate::{
+            detect_redundant_new_unchecked, validate_constructor_name, validate_default_derive,
+            validate_error_messages, validate_error_status_codes, validate_local,
+            validate_no_ops_policy, validate_warnings,
+        },
+    },
+    float::models::{FloatSanitizerKind, FloatValidatorKind},
+    integer::models::{IntegerSanitizerKind, IntegerValidatorKind},
+    string::models::{StringSanitizerKind, StringValidatorKind},
 };
-use proc_macro2::TokenStream;
+use kinded::Kind;
+use proc_macro2::{Ident, Span, TokenStream};
 use syn::{
     parse::{Parse, ParseStream},
     Token,
@@ -27,23 +41,110 @@ pub fn parse_attributes(
         new_unchecked,
         default,
         derive_traits,
+        ops,
+        secret,
+        const_fn,
+        constructor,
+        constructor_vis,
+        factory,
+        try_from_lossless,
+        exact_bounds,
+        delegate,
+        accessor,
+        widen_into,
+        derive_unsafe,
+        repr,
+        local,
+        docs,
+        omit,
+        methods,
+        metadata,
+        debug_expansion,
+        strict,
+        serde,
+        error,
     } = attrs;
+    if let Some(target) = widen_into.first() {
+        let msg = "`widen_into` is supported only for integer types.";
+        return Err(syn::Error::new_spanned(target, msg));
+    }
+    if let Some(method) = delegate.first() {
+        let msg = "`delegate` is supported only for String types.";
+        return Err(syn::Error::new(method.span(), msg));
+    }
+    if methods.leak {
+        let msg = "`methods(leak)` is supported only for String types.";
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
+    if factory == Factory::On {
+        let msg = "`factory` is not supported for Any types, because they have no validators with bounds.";
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
+    if exact_bounds {
+        let msg = "`exact_bounds` is supported only for float types.";
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
+    if try_from_lossless {
+        let msg = "`try_from_lossless` is supported only for integer and float types.";
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
+    validate_constructor_name(&constructor, &derive_traits)?;
+    validate_default_derive(&derive_traits, &default)?;
+    validate_error_messages(&error.messages, &validators)?;
+    validate_error_status_codes(&error.status_codes, &validators)?;
     let raw_guard = AnyRawGuard {
         sanitizers,
         validators,
     };
     let guard = validate_any_guard(raw_guard)?;
+    let warnings = validate_warnings(
+        detect_redundant_new_unchecked(&new_unchecked, &guard)
+            .into_iter()
+            .collect(),
+        strict,
+    )?;
+    validate_no_ops_policy(ops)?;
+    let local = validate_local(local, &guard, &error)?;
     Ok(Attributes {
         new_unchecked,
         guard,
         default,
         derive_traits,
+        ops: OpsPolicy::default(),
+        secret,
+        const_fn,
+        constructor,
+        constructor_vis,
+        factory,
+        try_from_lossless,
+        delegate,
+        accessor,
+        widen_into,
+        derive_unsafe,
+        repr,
+        local,
+        docs,
+        omit,
+        methods,
+        metadata,
+        debug_expansion,
+        warnings,
+        serde,
+        error,
     })
 }
 
 impl Parse for SpannedAnySanitizer {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let (kind, _ident) = parse_sanitizer_kind(input)?;
+        let fork = input.fork();
+        let (kind, _ident) = parse_sanitizer_kind(input).map_err(|err| {
+            let typed_kinds = [
+                ("`String`", kind_names::<StringSanitizerKind>()),
+                ("integer", kind_names::<IntegerSanitizerKind>()),
+                ("float", kind_names::<FloatSanitizerKind>()),
+            ];
+            typed_kind_error(&fork, "sanitizer", &typed_kinds).unwrap_or(err)
+        })?;
 
         match kind {
             AnySanitizerKind::With => {
@@ -61,7 +162,15 @@ impl Parse for SpannedAnySanitizer {
 
 impl Parse for SpannedAnyValidator {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let (kind, _ident) = parse_validator_kind(input)?;
+        let fork = input.fork();
+        let (kind, _ident) = parse_validator_kind(input).map_err(|err| {
+            let typed_kinds = [
+                ("`String`", kind_names::<StringValidatorKind>()),
+                ("integer", kind_names::<IntegerValidatorKind>()),
+                ("float", kind_names::<FloatValidatorKind>()),
+            ];
+            typed_kind_error(&fork, "validator", &typed_kinds).unwrap_or(err)
+        })?;
 
         match kind {
             AnyValidatorKind::Predicate => {
@@ -76,3 +185,34 @@ impl Parse for SpannedAnyValidator {
         }
     }
 }
+
+fn kind_names<K: Kind + ToString + 'static>() -> Vec<String> {
+    K::all()
+        .iter()
+        .map(ToString::to_string)
+        .filter(|name| name != "phantom") // filter out _Phantom variant
+        .collect()
+}
+
+/// A sanitizer or validator of `String`, integer or float types (e.g. `trim`) is reported along
+/// with the supported inner types, since the inner type may be just not recognized, e.g.
+/// `std::string::String` instead of `String`.
+fn typed_kind_error(
+    input: ParseStream,
+    attr_type: &str,
+    typed_kinds: &[(&str, Vec<String>)],
+) -> Option<syn::Error> {
+    let ident: Ident = input.parse().ok()?;
+    let name = ident.to_string();
+    let inner_types: Vec<&str> = typed_kinds
+        .iter()
+        .filter(|(_, names)| names.contains(&name))
+        .map(|(inner_type, _)| *inner_type)
+        .collect();
+    if inner_types.is_empty() {
+        return None;
+    }
+    let inner_types = inner_types.join(" and ");
+    let msg = format!("The {attr_type} `{name}` is supported only for {inner_types} inner types, but the inner type is handled as any other type.\n{SUPPORTED_INNER_TYPES}");
+    Some(syn::Error::new(ident.span(), msg))
+}
@@ -35,7 +35,7 @@ fn validate_validators(
     validators: Vec<SpannedAnyValidator>,
 ) -> Result<Vec<AnyValidator>, syn::Error> {
     validate_duplicates(&validators, |kind| {
-        format!("Duplicated validators `{kind}`.\nOh, maybe it's a time to take a break?")
+        format!("Duplicated validator `{kind}`.\nOh, maybe it's a time to take a break?")
     })?;
 
     let validators: Vec<AnyValidator> = validators.into_iter().map(|v| v.item).collect();
@@ -71,7 +71,7 @@ pub fn validate_any_derive_traits(
 
 fn to_any_derive_trait(
     tr: DeriveTrait,
-    _has_validation: bool,
+    has_validation: bool,
     span: Span,
 ) -> Result<AnyDeriveTrait, syn::Error> {
     match tr {
@@ -95,10 +95,88 @@ fn to_any_derive_trait(
         DeriveTrait::SerdeDeserialize => Ok(AnyDeriveTrait::SerdeDeserialize),
         DeriveTrait::Hash => Ok(AnyDeriveTrait::Hash),
         DeriveTrait::ArbitraryArbitrary => Ok(AnyDeriveTrait::ArbitraryArbitrary),
-        DeriveTrait::SchemarsJsonSchema => {
+        DeriveTrait::JuniperGraphQLScalar => Ok(AnyDeriveTrait::JuniperGraphQLScalar),
+        DeriveTrait::RocketFromParam => Ok(AnyDeriveTrait::RocketFromParam),
+        DeriveTrait::RocketFromFormField => Ok(AnyDeriveTrait::RocketFromFormField),
+        DeriveTrait::ActixFromRequest => Ok(AnyDeriveTrait::ActixFromRequest),
+        DeriveTrait::ClapValueParserFactory => Ok(AnyDeriveTrait::ClapValueParserFactory),
+        DeriveTrait::SchemarsJsonSchema | DeriveTrait::RandDistribution => {
             let msg =
                 format!("Deriving of trait `{tr:?}` is not (yet) supported for an arbitrary type");
             Err(syn::Error::new(span, msg))
         }
+        DeriveTrait::NumTraitsZero => {
+            let msg = "Deriving `Zero` trait is supported only for integer and float types.\nRemove `Zero` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::NumTraitsOne => {
+            let msg = "Deriving `One` trait is supported only for integer and float types.\nRemove `One` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::NumTraitsBounded => {
+            let msg = "Deriving `Bounded` trait is supported only for integer and float types.\nRemove `Bounded` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::NumTraitsToPrimitive => {
+            let msg = "Deriving `ToPrimitive` trait is supported only for integer and float types.\nRemove `ToPrimitive` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::NumTraitsFromPrimitive => {
+            let msg = "Deriving `FromPrimitive` trait is supported only for integer and float types.\nRemove `FromPrimitive` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::Add => {
+            let msg = "Deriving `Add` trait is supported only for integer and float types.\nRemove `Add` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::Sub => {
+            let msg = "Deriving `Sub` trait is supported only for integer and float types.\nRemove `Sub` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::Mul => {
+            let msg = "Deriving `Mul` trait is supported only for integer and float types.\nRemove `Mul` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::Div => {
+            let msg = "Deriving `Div` trait is supported only for integer and float types.\nRemove `Div` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::AddAssign => {
+            let msg = "Deriving `AddAssign` trait is supported only for integer and float types.\nRemove `AddAssign` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::SubAssign => {
+            let msg = "Deriving `SubAssign` trait is supported only for integer and float types.\nRemove `SubAssign` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::MulAssign => {
+            let msg = "Deriving `MulAssign` trait is supported only for integer and float types.\nRemove `MulAssign` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::DivAssign => {
+            let msg = "Deriving `DivAssign` trait is supported only for integer and float types.\nRemove `DivAssign` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::Sum => {
+            let msg = "Deriving `Sum` trait is supported only for integer and float types.\nRemove `Sum` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::Product => {
+            let msg = "Deriving `Product` trait is supported only for integer and float types.\nRemove `Product` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::ZeroizeZeroize => {
+            if has_validation {
+                Err(syn::Error::new(
+                    span,
+                    "#[nutype] cannot derive `Zeroize` trait, because there is validation defined and the zeroized value may violate it.\nDerive `ZeroizeOnDrop` instead, which zeroizes the value only when it is dropped.",
+                ))
+            } else {
+                Ok(AnyDeriveTrait::ZeroizeZeroize)
+            }
+        }
+        DeriveTrait::ZeroizeZeroizeOnDrop => Ok(AnyDeriveTrait::ZeroizeZeroizeOnDrop),
+        DeriveTrait::SerdeWithSerializeAs => Ok(AnyDeriveTrait::SerdeWithSerializeAs),
+        DeriveTrait::SerdeWithDeserializeAs => Ok(AnyDeriveTrait::SerdeWithDeserializeAs),
     }
 }
@@ -0,0 +1,29 @@
+//! Paths to the items of `alloc` crate (e.g. `String` and `Vec`) for the generated code.
+//!
+//! A `no_std` crate has to declare `extern crate alloc;` to refer to `::alloc`, so without `std`
+//! the generated code goes through the re-export of `nutype` crate instead.
+
+use cfg_if::cfg_if;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The path of `alloc` crate, e.g. `#alloc::vec::Vec`.
+/// Returns `None` if neither `std` nor `alloc` feature is enabled, so the code, that allocates,
+/// is not generated.
+pub fn gen_alloc_path() -> Option<TokenStream> {
+    cfg_if! {
+        if #[cfg(feature = "std")] {
+            Some(quote!(::std))
+        } else if #[cfg(feature = "alloc")] {
+            Some(quote!(::nutype::__private::alloc))
+        } else {
+            None
+        }
+    }
+}
+
+/// The path of `alloc` crate for the code, which cannot be generated without allocations,
+/// e.g. the code of String types, which are rejected without `alloc` feature.
+pub fn gen_required_alloc_path() -> TokenStream {
+    gen_alloc_path().unwrap_or_else(|| quote!(::alloc))
+}
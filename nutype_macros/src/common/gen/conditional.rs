@@ -0,0 +1,220 @@
+//! Code of the sanitizers and validators within `cfg_attr(predicate, ...)`.
+//! See `common::parse::conditional`.
+//!
+//! Every conditional rule is checked in its own step under `#[cfg(...)]` of its predicate,
+//! and its error variant together with the match arms on it is added to the error type
+//! of the unconditional validators.
+
+use std::collections::HashMap;
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{parse_quote, visit_mut::VisitMut};
+
+use crate::common::models::{ConditionalRule, ErrorTypeName};
+
+/// `#[cfg(...)]` of a conditional rule, or nothing for an unconditional one.
+pub fn gen_cfg(predicate: Option<&TokenStream>) -> TokenStream {
+    match predicate {
+        Some(predicate) => quote!(#[cfg(#predicate)]),
+        None => quote!(),
+    }
+}
+
+/// Generate a step per rule in the order of declaration. The steps of the conditional rules
+/// are put under `#[cfg(...)]`.
+pub fn gen_steps<T>(
+    rules: &[T],
+    conditional: &[ConditionalRule<T>],
+    gen_step: impl Fn(&T) -> TokenStream,
+) -> Vec<TokenStream> {
+    let mut steps = Vec::new();
+    for position in 0..=rules.len() {
+        for rule in conditional.iter().filter(|rule| rule.position == position) {
+            let cfg = gen_cfg(Some(&rule.predicate));
+            let step = gen_step(&rule.rule);
+            steps.push(quote!(#cfg #step));
+        }
+        if let Some(rule) = rules.get(position) {
+            steps.push(gen_step(rule));
+        }
+    }
+    steps
+}
+
+/// Generate `fn sanitize()`, which applies every sanitizer within its own `fn sanitize()`.
+pub fn gen_fn_sanitize_conditional<Sanitizer>(
+    inner_type: &impl ToTokens,
+    sanitizers: &[Sanitizer],
+    conditional: &[ConditionalRule<Sanitizer>],
+    gen_fn_sanitize: impl Fn(&[Sanitizer]) -> TokenStream,
+) -> TokenStream {
+    let steps = gen_steps(sanitizers, conditional, |sanitizer| {
+        let sanitize = gen_fn_sanitize(std::slice::from_ref(sanitizer));
+        quote!(let value: #inner_type = { #sanitize sanitize(value) };)
+    });
+    quote!(
+        fn sanitize(value: #inner_type) -> #inner_type {
+            #(#steps)*
+            value
+        }
+    )
+}
+
+/// Generate `fn validate()`, which checks every validator within its own `fn validate()`.
+pub fn gen_fn_validate_conditional<Validator>(
+    borrowed_inner_type: &TokenStream,
+    error_type_name: &ErrorTypeName,
+    validators: &[Validator],
+    conditional: &[ConditionalRule<Validator>],
+    gen_fn_validate: impl Fn(&[Validator]) -> TokenStream,
+) -> TokenStream {
+    let steps = gen_steps(validators, conditional, |validator| {
+        let validate = gen_fn_validate(std::slice::from_ref(validator));
+        quote!({
+            #validate
+            validate(value)?;
+        })
+    });
+    quote!(
+        fn validate(value: &#borrowed_inner_type) -> ::core::result::Result<(), #error_type_name> {
+            #(#steps)*
+            Ok(())
+        }
+    )
+}
+
+/// Add the variants of the conditional validators to the error type of the unconditional ones.
+/// The error type of every conditional validator alone provides its variant and the match arms
+/// on it, which are put under `#[cfg(...)]`. Validators of the same kind share the variant,
+/// and a match arm applies only if no earlier validator of the kind is enabled.
+pub fn gen_conditional_error_type<Validator>(
+    error_type: TokenStream,
+    error_type_name: &ErrorTypeName,
+    conditional: &[ConditionalRule<Validator>],
+    gen_error_type: impl Fn(&[Validator]) -> TokenStream,
+) -> Result<TokenStream, syn::Error> {
+    let mut file: syn::File = syn::parse2(error_type)?;
+    let error_type_name = error_type_name.to_string();
+    let mut matches = MatchesOnSelf::default();
+    matches.visit_file_mut(&mut file);
+    let had_arms: Vec<bool> = matches.arms.iter().map(|arms| !arms.is_empty()).collect();
+    let mut variants: Vec<syn::Variant> = Vec::new();
+    let mut predicates: HashMap<Ident, Vec<TokenStream>> = HashMap::new();
+
+    for rule in conditional {
+        let mut single: syn::File = syn::parse2(gen_error_type(std::slice::from_ref(&rule.rule)))?;
+        // The variants of the unconditional validators, `extends = ` and `Overflow` are known
+        let existing: Vec<Ident> = find_enum(&mut file, &error_type_name)
+            .variants
+            .iter()
+            .map(|variant| variant.ident.clone())
+            .collect();
+        let Some(variant) = find_enum(&mut single, &error_type_name)
+            .variants
+            .iter()
+            .find(|variant| !existing.contains(&variant.ident))
+            .cloned()
+        else {
+            continue;
+        };
+
+        let earlier = predicates.entry(variant.ident.clone()).or_default();
+        let predicate = &rule.predicate;
+        let cfg: syn::Attribute = if earlier.is_empty() {
+            parse_quote!(#[cfg(#predicate)])
+        } else {
+            parse_quote!(#[cfg(all(#predicate, not(any(#(#earlier),*))))])
+        };
+        if earlier.is_empty() {
+            variants.push(variant.clone());
+        }
+        earlier.push(predicate.clone());
+
+        let mut single_matches = MatchesOnSelf::default();
+        single_matches.visit_file_mut(&mut single);
+        for (arms, single_arms) in matches.arms.iter_mut().zip(single_matches.arms) {
+            let new_arms = single_arms
+                .into_iter()
+                .filter(|arm| pattern_variant(&arm.pat) == Some(&variant.ident))
+                .map(|mut arm| {
+                    arm.attrs.push(cfg.clone());
+                    arm
+                });
+            arms.extend(new_arms);
+        }
+    }
+
+    let error_enum = find_enum(&mut file, &error_type_name);
+    for mut variant in variants {
+        let variant_predicates = &predicates[&variant.ident];
+        variant
+            .attrs
+            .push(parse_quote!(#[cfg(any(#(#variant_predicates),*))]));
+        error_enum.variants.push(variant);
+    }
+    // Without the unconditional variants the error type may have no variants at all
+    for (arms, had_arms) in matches.arms.iter_mut().zip(had_arms) {
+        if !had_arms {
+            arms.push(parse_quote!(
+                #[allow(unreachable_patterns)]
+                _ => unreachable!()
+            ));
+        }
+    }
+    matches.write_back(&mut file);
+    Ok(file.into_token_stream())
+}
+
+fn find_enum<'a>(file: &'a mut syn::File, name: &str) -> &'a mut syn::ItemEnum {
+    file.items
+        .iter_mut()
+        .find_map(|item| match item {
+            syn::Item::Enum(item_enum) if item_enum.ident == name => Some(item_enum),
+            _ => None,
+        })
+        .expect("the error type is generated as an enum")
+}
+
+/// The variant, which a pattern like `Error::LenCharMaxViolated { .. }` matches.
+fn pattern_variant(pat: &syn::Pat) -> Option<&Ident> {
+    let path = match pat {
+        syn::Pat::Path(pat) => &pat.path,
+        syn::Pat::Struct(pat) => &pat.path,
+        syn::Pat::TupleStruct(pat) => &pat.path,
+        _ => return None,
+    };
+    path.segments.last().map(|segment| &segment.ident)
+}
+
+/// The arms of every `match self { ... }` in the order of appearance.
+#[derive(Default)]
+struct MatchesOnSelf {
+    arms: Vec<Vec<syn::Arm>>,
+    index: usize,
+    writing: bool,
+}
+
+impl MatchesOnSelf {
+    fn write_back(&mut self, file: &mut syn::File) {
+        self.index = 0;
+        self.writing = true;
+        self.visit_file_mut(file);
+    }
+}
+
+impl VisitMut for MatchesOnSelf {
+    fn visit_expr_match_mut(&mut self, expr_match: &mut syn::ExprMatch) {
+        let is_self =
+            matches!(&*expr_match.expr, syn::Expr::Path(path) if path.path.is_ident("self"));
+        if is_self {
+            if self.writing {
+                expr_match.arms = std::mem::take(&mut self.arms[self.index]);
+                self.index += 1;
+            } else {
+                self.arms.push(expr_match.arms.clone());
+            }
+        }
+        syn::visit_mut::visit_expr_match_mut(self, expr_match);
+    }
+}
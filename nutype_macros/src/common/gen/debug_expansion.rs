@@ -0,0 +1,163 @@
+//! `debug_expansion` flag: the generated code of a type is written to
+//! `target/nutype-expansions/crate_name-TypeName.rs`, so a single type can be inspected
+//! without running `cargo expand` on the whole crate. The file is overwritten on every expansion.
+//!
+//! A procedural macro does not know the module of the type, so the types of the same name
+//! in different modules of a crate are told apart by a suffix: `crate_name-TypeName-2.rs`, etc.
+//!
+//! The target directory is taken from `CARGO_TARGET_DIR`, otherwise it's `target` next to
+//! `Cargo.lock` of the workspace (or next to `Cargo.toml` of the crate, if there is no lock file).
+//! The code is broken into lines and indented, but not formatted, run `rustfmt` on the file
+//! to get it formatted.
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    path::PathBuf,
+    sync::{Mutex, OnceLock},
+};
+
+use proc_macro2::{Delimiter, Spacing, Span, TokenStream, TokenTree};
+
+use crate::common::models::{TypeName, Warning};
+
+/// Write the expansion into the file and return a warning with its path, which reminds to remove
+/// the flag.
+pub fn write_expansion(
+    output: &TokenStream,
+    type_name: &TypeName,
+    span: Span,
+) -> Result<Warning, syn::Error> {
+    let code = format_tokens(output.clone());
+    let dir = target_dir().join("nutype-expansions");
+    let path = dir.join(file_name(type_name, &code));
+    std::fs::create_dir_all(&dir)
+        .and_then(|()| std::fs::write(&path, code))
+        .map_err(|err| {
+            let msg = format!(
+                "Failed to write the expansion of `{type_name}` to `{}`: {err}",
+                path.display()
+            );
+            syn::Error::new(span, msg)
+        })?;
+    let message = format!(
+        "The expansion of `{type_name}` is written to `{}`.\nRemove `debug_expansion` when done.",
+        path.display()
+    );
+    Ok(Warning { span, message })
+}
+
+/// The hashes of the expanded code of every type name within every crate, which are expanded
+/// by the current process. The order of the hashes gives the suffixes of the file names.
+static EXPANDED_TYPES: OnceLock<Mutex<HashMap<String, Vec<u64>>>> = OnceLock::new();
+
+fn file_name(type_name: &TypeName, code: &str) -> String {
+    // Cargo sets the name of the crate, which is being compiled
+    let crate_name = std::env::var("CARGO_CRATE_NAME").unwrap_or_else(|_| "unknown".to_string());
+    let name = format!("{crate_name}-{type_name}");
+
+    // The same type can be expanded more than once by a long-running process (e.g. an IDE),
+    // so the same code keeps its file
+    let mut hasher = DefaultHasher::new();
+    code.hash(&mut hasher);
+    let hash = hasher.finish();
+    let mut expanded_types = EXPANDED_TYPES
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    let hashes = expanded_types.entry(name.clone()).or_default();
+    let index = match hashes.iter().position(|&expanded| expanded == hash) {
+        Some(index) => index,
+        None => {
+            hashes.push(hash);
+            hashes.len() - 1
+        }
+    };
+
+    match index {
+        0 => format!("{name}.rs"),
+        index => format!("{name}-{}.rs", index + 1),
+    }
+}
+
+fn target_dir() -> PathBuf {
+    if let Some(dir) = std::env::var_os("CARGO_TARGET_DIR") {
+        return PathBuf::from(dir);
+    }
+    let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_default();
+    let workspace_dir = manifest_dir
+        .ancestors()
+        .find(|dir| dir.join("Cargo.lock").is_file())
+        .unwrap_or(&manifest_dir);
+    workspace_dir.join("target")
+}
+
+/// Break the tokens into lines after `;`, attributes and braces, and indent the blocks.
+fn format_tokens(tokens: TokenStream) -> String {
+    let mut out = String::new();
+    write_tokens(&mut out, tokens, 0);
+    out.push('\n');
+    out
+}
+
+fn write_tokens(out: &mut String, tokens: TokenStream, indent: usize) {
+    // A joint punctuation (e.g. the first `:` of `::`) is glued to the next token
+    let mut joint = false;
+    let mut after_pound = false;
+    for tree in tokens {
+        let separate = !joint;
+        joint = false;
+        match tree {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                out.push_str(" {");
+                if !group.stream().is_empty() {
+                    new_line(out, indent + 1);
+                    write_tokens(out, group.stream(), indent + 1);
+                }
+                new_line(out, indent);
+                out.push('}');
+                new_line(out, indent);
+            }
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::Brace | Delimiter::None => ("", ""),
+                };
+                push_token(out, open, separate);
+                write_tokens(out, group.stream(), indent);
+                out.push_str(close);
+                if after_pound && group.delimiter() == Delimiter::Bracket {
+                    new_line(out, indent);
+                }
+            }
+            TokenTree::Punct(punct) if punct.as_char() == ';' => {
+                out.push(';');
+                new_line(out, indent);
+            }
+            TokenTree::Punct(punct) => {
+                push_token(out, &punct.to_string(), separate);
+                joint = punct.spacing() == Spacing::Joint;
+            }
+            tree => push_token(out, &tree.to_string(), separate),
+        }
+        after_pound = out.ends_with('#');
+    }
+}
+
+fn push_token(out: &mut String, token: &str, separate: bool) {
+    let glued = out.is_empty() || out.ends_with([' ', '\n', '(', '[', '#']);
+    if separate && !glued && !token.is_empty() {
+        out.push(' ');
+    }
+    out.push_str(token);
+}
+
+fn new_line(out: &mut String, indent: usize) {
+    let trimmed_len = out.trim_end().len();
+    out.truncate(trimmed_len);
+    out.push('\n');
+    out.push_str(&"    ".repeat(indent));
+}
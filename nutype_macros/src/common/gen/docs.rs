@@ -0,0 +1,176 @@
+//! Doc comments of the generated items provided with `docs(...)`, e.g.
+//! `docs(new = "Creates a username.", error = "Invalid username.")`.
+//!
+//! The docs are applied to the generated code afterwards: a doc of a method replaces the default
+//! one (if any), and the error types get the docs for their variants and fields as well,
+//! so no generated item of the public API is left undocumented.
+
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{
+    visit_mut::{self, VisitMut},
+    Attribute, Fields, ImplItemFn, Item, ItemEnum, ItemImpl, ItemStruct, LitStr, Type,
+};
+
+use crate::common::{
+    gen::{error::to_snake_case, extends::gen_extends_variant_name},
+    models::{ErrorTypeName, ItemDoc, ParseErrorTypeName, TypeName},
+};
+
+/// The names of the generated items, which can get docs besides the methods.
+pub struct DocTargets {
+    pub type_name: TypeName,
+    pub error_type_name: ErrorTypeName,
+    pub parse_error_type_name: ParseErrorTypeName,
+    pub extends: Option<syn::Path>,
+}
+
+pub fn apply_docs(
+    output: TokenStream,
+    targets: &DocTargets,
+    docs: &[ItemDoc],
+) -> Result<TokenStream, syn::Error> {
+    if docs.is_empty() {
+        return Ok(output);
+    }
+
+    let mut file: syn::File = syn::parse2(output)?;
+    let mut applier = DocsApplier {
+        targets,
+        docs,
+        applied: vec![false; docs.len()],
+    };
+    applier.visit_file_mut(&mut file);
+
+    for (item_doc, applied) in docs.iter().zip(&applier.applied) {
+        if !applied {
+            let msg = format!("There is no generated item `{}` to document. Expected `error`, `parse_error` or a name of a generated method, e.g. `new` or `into_inner`.", item_doc.item);
+            return Err(syn::Error::new(item_doc.item.span(), msg));
+        }
+    }
+    Ok(file.into_token_stream())
+}
+
+struct DocsApplier<'a> {
+    targets: &'a DocTargets,
+    docs: &'a [ItemDoc],
+    applied: Vec<bool>,
+}
+
+impl DocsApplier<'_> {
+    fn take_doc(&mut self, item: &str) -> Option<LitStr> {
+        let index = self.docs.iter().position(|d| d.item == item)?;
+        self.applied[index] = true;
+        Some(self.docs[index].doc.clone())
+    }
+
+    /// The item, which gets the doc with `error` or `parse_error` key.
+    fn error_key(&self, ident: &syn::Ident) -> Option<&'static str> {
+        if ident == &self.targets.error_type_name.to_string() {
+            Some("error")
+        } else if ident == &self.targets.parse_error_type_name.to_string() {
+            Some("parse_error")
+        } else {
+            None
+        }
+    }
+
+    fn variant_doc(&self, variant: &syn::Ident) -> String {
+        let is_extends_variant = self
+            .targets
+            .extends
+            .as_ref()
+            .is_some_and(|base| &gen_extends_variant_name(base) == variant);
+        if is_extends_variant {
+            let base = self.targets.extends.to_token_stream().to_string();
+            return format!(
+                "The value violates the validators of `{}`.",
+                base.replace(' ', "")
+            );
+        }
+        // The variants of the parse error
+        if variant == "Parse" {
+            return "The value cannot be parsed into the inner type.".to_string();
+        }
+        if variant == "Validate" {
+            return "The parsed value violates the validators.".to_string();
+        }
+        match variant.to_string().strip_suffix("Violated") {
+            Some(validator) => format!(
+                "The value violates `{}` validator.",
+                to_snake_case(validator)
+            ),
+            None => format!("`{variant}`"),
+        }
+    }
+}
+
+impl VisitMut for DocsApplier<'_> {
+    fn visit_item_impl_mut(&mut self, item: &mut ItemImpl) {
+        let is_own_impl = item.trait_.is_none()
+            && matches!(&*item.self_ty, Type::Path(tp) if tp.path.is_ident(&self.targets.type_name.to_string()));
+        if is_own_impl {
+            visit_mut::visit_item_impl_mut(self, item);
+        }
+    }
+
+    fn visit_impl_item_fn_mut(&mut self, item: &mut ImplItemFn) {
+        if let Some(doc) = self.take_doc(&item.sig.ident.to_string()) {
+            set_doc(&mut item.attrs, &doc.value());
+        }
+    }
+
+    fn visit_item_enum_mut(&mut self, item: &mut ItemEnum) {
+        let Some(key) = self.error_key(&item.ident) else {
+            return;
+        };
+        let Some(doc) = self.take_doc(key) else {
+            return;
+        };
+        set_doc(&mut item.attrs, &doc.value());
+        for variant in item.variants.iter_mut() {
+            if !has_doc(&variant.attrs) {
+                let variant_doc = self.variant_doc(&variant.ident);
+                set_doc(&mut variant.attrs, &variant_doc);
+            }
+            document_fields(&mut variant.fields);
+        }
+    }
+
+    fn visit_item_struct_mut(&mut self, item: &mut ItemStruct) {
+        let Some(key) = self.error_key(&item.ident) else {
+            return;
+        };
+        if let Some(doc) = self.take_doc(key) {
+            set_doc(&mut item.attrs, &doc.value());
+            document_fields(&mut item.fields);
+        }
+    }
+
+    fn visit_item_mut(&mut self, item: &mut Item) {
+        // The functions and the constants of the generated code cannot get the docs
+        match item {
+            Item::Impl(_) | Item::Enum(_) | Item::Struct(_) | Item::Mod(_) => {
+                visit_mut::visit_item_mut(self, item)
+            }
+            _ => {}
+        }
+    }
+}
+
+fn document_fields(fields: &mut Fields) {
+    for field in fields.iter_mut() {
+        if let (false, Some(ident)) = (has_doc(&field.attrs), &field.ident) {
+            set_doc(&mut field.attrs, &format!("`{ident}` of the violation."));
+        }
+    }
+}
+
+fn has_doc(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| attr.path().is_ident("doc"))
+}
+
+fn set_doc(attrs: &mut Vec<Attribute>, doc: &str) {
+    attrs.retain(|attr| !attr.path().is_ident("doc"));
+    attrs.insert(0, syn::parse_quote!(#[doc = #doc]));
+}
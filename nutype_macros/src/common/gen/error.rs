@@ -1,18 +1,131 @@
+use std::fmt::{Debug, Display};
+
 use cfg_if::cfg_if;
+use kinded::Kinded;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
-use crate::common::models::{ErrorTypeName, TypeName};
+use crate::common::{
+    gen::extends::gen_extends_arm,
+    models::{ErrorAttributes, ErrorDisplay, ErrorFields, ErrorTypeName, HasBound, TypeName},
+};
 
 pub fn gen_error_type_name(type_name: &TypeName) -> ErrorTypeName {
     let ident = format_ident!("{type_name}Error");
     ErrorTypeName::new(ident)
 }
 
-// NOTE: There is no `::core::error::Error` yet in stable Rust.
-// So for `no_std` we just don't implement `Error` trait.
+/// Generate `#[derive(...)]` of the error type. Traits provided with `error_derive(...)` replace
+/// the default ones, but `Debug` is always derived, because `Error` trait requires it.
+/// With `error_integrations(thiserror)` `Error` trait is derived with `thiserror` as well.
+/// The enum is marked `#[non_exhaustive]`, if requested with `error_non_exhaustive`.
+pub fn gen_error_derive(error: &ErrorAttributes, default_traits: TokenStream) -> TokenStream {
+    // No variant has `#[error(...)]`, so `thiserror` keeps the generated `Display`.
+    let thiserror = if error.integrations.thiserror {
+        quote!(::thiserror::Error,)
+    } else {
+        quote!()
+    };
+    let non_exhaustive = if error.non_exhaustive {
+        quote!(#[non_exhaustive])
+    } else {
+        quote!()
+    };
+    let derive = match &error.derive {
+        None => quote!(#[derive(#thiserror #default_traits)]),
+        Some(traits) => {
+            let debug = if traits.iter().any(|path| path.is_ident("Debug")) {
+                quote!()
+            } else {
+                quote!(Debug,)
+            };
+            quote!(#[derive(#thiserror #debug #(#traits),*)])
+        }
+    };
+    quote! {
+        #derive
+        #non_exhaustive
+    }
+}
+
+/// Generate `Display` of the error type, unless it's overridden with `error_display = `.
+/// In the latter case the implementation is either omitted or delegates to the user function.
+pub fn gen_impl_display_trait_override(
+    error_type_name: &ErrorTypeName,
+    error: &ErrorAttributes,
+    gen_default: impl FnOnce() -> TokenStream,
+) -> TokenStream {
+    match &error.display {
+        ErrorDisplay::Generated => gen_default(),
+        ErrorDisplay::None => quote!(),
+        ErrorDisplay::Custom(display_fn) => quote! {
+            impl ::core::fmt::Display for #error_type_name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    #display_fn(self, f)
+                }
+            }
+        },
+    }
+}
+
+/// Generate `From` implementations, that wrap the error into the shared error types
+/// provided with `error_into(...)`, e.g. `ValidationError::Email(EmailError)`.
+pub fn gen_impl_from_error_into(
+    type_name: &TypeName,
+    error_type_name: &ErrorTypeName,
+    error: &ErrorAttributes,
+) -> TokenStream {
+    error
+        .into
+        .iter()
+        .map(|target| {
+            quote! {
+                impl ::core::convert::From<#error_type_name> for #target {
+                    fn from(error: #error_type_name) -> Self {
+                        #target::#type_name(error)
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+/// Name of the error type returned by the constructor.
+/// With `error_return = ` it's an alias of the user-defined type, see `gen_return_error_type_alias()`.
+pub fn gen_return_error_type_name(
+    type_name: &TypeName,
+    error_type_name: &ErrorTypeName,
+    error: &ErrorAttributes,
+) -> ErrorTypeName {
+    match error.return_type {
+        Some(_) => ErrorTypeName::new(format_ident!("__Nutype{type_name}ReturnError")),
+        None => error_type_name.clone(),
+    }
+}
+
+/// The user-defined error type provided with `error_return = ` can be an arbitrary path,
+/// so it's aliased to be referred by `ErrorTypeName` within the generated code.
+pub fn gen_return_error_type_alias(
+    return_error_type_name: &ErrorTypeName,
+    error: &ErrorAttributes,
+) -> TokenStream {
+    match &error.return_type {
+        Some(return_type) => quote!(type #return_error_type_name = #return_type;),
+        None => quote!(),
+    }
+}
+
+// NOTE: `::core::error::Error` is stable only since Rust 1.81.
+// So for `no_std` the `Error` trait is implemented only with `core_error` feature.
+// With `error_integrations(thiserror)` the trait is derived instead, see `gen_error_derive()`.
 #[allow(unused_variables)]
-pub fn gen_impl_error_trait(error_type_name: &ErrorTypeName) -> TokenStream {
+pub fn gen_impl_error_trait(
+    error_type_name: &ErrorTypeName,
+    error: &ErrorAttributes,
+) -> TokenStream {
+    if error.integrations.thiserror {
+        return quote!();
+    }
     cfg_if! {
         if #[cfg(feature = "std")] {
             quote! {
@@ -22,8 +135,351 @@ pub fn gen_impl_error_trait(error_type_name: &ErrorTypeName) -> TokenStream {
                     }
                 }
             }
+        } else if #[cfg(feature = "core_error")] {
+            quote! {
+                impl ::core::error::Error for #error_type_name {
+                    fn source(&self) -> Option<&(dyn ::core::error::Error + 'static)> {
+                        None
+                    }
+                }
+            }
         } else {
             quote!{}
         }
     }
 }
+
+/// Generate `code()` method on the error type, which returns a stable machine-readable code of the error,
+/// e.g. `"username.len_char_min"`. The prefix can be set with `error_code_prefix = `.
+pub fn gen_impl_error_code<Validator>(
+    type_name: &TypeName,
+    error_type_name: &ErrorTypeName,
+    validators: &[Validator],
+    error: &ErrorAttributes,
+) -> TokenStream
+where
+    Validator: Kinded + HasBound,
+    <Validator as Kinded>::Kind: Debug + Display,
+{
+    let prefix = match &error.code_prefix {
+        Some(prefix) => prefix.value(),
+        None => to_snake_case(&type_name.to_string()),
+    };
+    let match_arms = validators.iter().map(|validator| {
+        let pattern = gen_variant_pattern(error, validator);
+        let kind = validator.kind();
+        let variant = format_ident!("{kind:?}Violated");
+        let code = format!("{prefix}.{kind}");
+        quote! {
+            #error_type_name::#variant #pattern => #code
+        }
+    });
+    // The error of the base type keeps its own code
+    let match_arms = match_arms.chain(gen_extends_arm(error_type_name, error, quote!(err.code())));
+    let overflow_code = format!("{prefix}.overflow");
+    let match_arms = match_arms.chain(gen_overflow_arm(
+        error_type_name,
+        error,
+        quote!(#overflow_code),
+    ));
+
+    quote! {
+        impl #error_type_name {
+            /// Returns a stable machine-readable code of the error.
+            pub fn code(&self) -> &'static str {
+                match self {
+                    #(#match_arms,)*
+                }
+            }
+        }
+    }
+}
+
+/// Generate `status_code()`, which maps the error to an HTTP status code.
+/// Unless it's overridden with `status_codes(...)`, a violation is `422 Unprocessable Entity`.
+#[allow(unused_variables)]
+pub fn gen_impl_error_status_code<Validator>(
+    error_type_name: &ErrorTypeName,
+    validators: &[Validator],
+    error: &ErrorAttributes,
+) -> TokenStream
+where
+    Validator: Kinded + HasBound,
+    <Validator as Kinded>::Kind: Debug + Display,
+{
+    cfg_if! {
+        if #[cfg(feature = "web")] {
+            let match_arms = validators.iter().map(|validator| {
+                let pattern = gen_variant_pattern(error, validator);
+                let kind = validator.kind();
+                let variant = format_ident!("{kind:?}Violated");
+                let status = error.status_code_for(&kind.to_string()).unwrap_or(422);
+                quote! {
+                    #error_type_name::#variant #pattern => #status
+                }
+            });
+            let match_arms = match_arms
+                .chain(gen_extends_arm(error_type_name, error, quote!(err.status_code())))
+                .chain(gen_overflow_arm(error_type_name, error, quote!(422)));
+
+            quote! {
+                impl #error_type_name {
+                    /// Returns the HTTP status code, which corresponds to the error.
+                    pub fn status_code(&self) -> u16 {
+                        match self {
+                            #(#match_arms,)*
+                        }
+                    }
+                }
+            }
+        } else {
+            quote!()
+        }
+    }
+}
+
+/// Generate an expression with the HTTP status code (`u16`) of the error, which is given by `error`.
+/// With `web` feature it's taken from `status_code()`, so the overrides of `status_codes(...)`
+/// apply, otherwise it's `422 Unprocessable Entity`, the default of `status_code()`.
+#[allow(unused_variables)]
+pub fn gen_violation_status_code(error: TokenStream) -> TokenStream {
+    cfg_if! {
+        if #[cfg(feature = "web")] {
+            quote!(#error.status_code())
+        } else {
+            quote!(422u16)
+        }
+    }
+}
+
+pub fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut output = String::with_capacity(name.len() + 4);
+    for (index, &ch) in chars.iter().enumerate() {
+        if ch.is_uppercase() && index > 0 {
+            let prev = chars[index - 1];
+            let next_is_lowercase = chars.get(index + 1).is_some_and(|c| c.is_lowercase());
+            // `UserName` -> `user_name`, `HTTPStatus` -> `http_status`
+            if prev != '_' && (!prev.is_uppercase() || next_is_lowercase) {
+                output.push('_');
+            }
+        }
+        output.extend(ch.to_lowercase());
+    }
+    output
+}
+
+/// Generate the fields of an error variant according to `error_fields(...)`, e.g. `{ value: String, max: usize }`,
+/// or nothing for a unit variant. The same is used to construct a variant, e.g. `{ value: val, max: 255 }`.
+pub fn gen_variant_fields(
+    fields: ErrorFields,
+    value: TokenStream,
+    bound: Option<TokenStream>,
+) -> TokenStream {
+    let value = fields.value.then_some(value);
+    let bound = bound.filter(|_| fields.bounds);
+    let fields: Vec<TokenStream> = value.into_iter().chain(bound).collect();
+    if fields.is_empty() {
+        quote!()
+    } else {
+        quote!({ #(#fields),* })
+    }
+}
+
+/// Generate the part of a pattern, that matches an error variant regardless of its fields.
+pub fn gen_variant_pattern(error: &ErrorAttributes, validator: &impl HasBound) -> TokenStream {
+    if error.fields.has_fields(validator) {
+        quote!({ .. })
+    } else {
+        quote!()
+    }
+}
+
+/// Generate a crate-private `violated_constraint()` method on the error type, which returns
+/// the name of the validator that failed, e.g. `"len_char_max"`.
+/// It is used to give more context in the errors produced by integrations (serde, axum).
+pub fn gen_impl_violated_constraint<Validator>(
+    error_type_name: &ErrorTypeName,
+    validators: &[Validator],
+    error: &ErrorAttributes,
+) -> TokenStream
+where
+    Validator: Kinded + HasBound,
+    <Validator as Kinded>::Kind: Debug + Display,
+{
+    let match_arms = validators.iter().map(|validator| {
+        let pattern = gen_variant_pattern(error, validator);
+        let kind = validator.kind();
+        let variant = format_ident!("{kind:?}Violated");
+        let constraint = kind.to_string();
+        quote! {
+            #error_type_name::#variant #pattern => #constraint
+        }
+    });
+    let match_arms = match_arms
+        .chain(gen_extends_arm(
+            error_type_name,
+            error,
+            quote!(err.violated_constraint()),
+        ))
+        .chain(gen_overflow_arm(error_type_name, error, quote!("overflow")));
+
+    quote! {
+        impl #error_type_name {
+            #[allow(dead_code)]
+            pub(crate) fn violated_constraint(&self) -> &'static str {
+                match self {
+                    #(#match_arms,)*
+                }
+            }
+        }
+    }
+}
+
+/// Generate `Overflow` variant of the error type, see [ErrorAttributes::overflow].
+pub fn gen_overflow_variant(error: &ErrorAttributes) -> TokenStream {
+    if error.overflow {
+        quote!(Overflow,)
+    } else {
+        quote!()
+    }
+}
+
+/// Generate a match arm on the error type for `Overflow` variant, if the error type has it.
+pub fn gen_overflow_arm(
+    error_type_name: &ErrorTypeName,
+    error: &ErrorAttributes,
+    body: TokenStream,
+) -> Option<TokenStream> {
+    error.overflow.then(|| {
+        quote! {
+            #error_type_name::Overflow => #body
+        }
+    })
+}
+
+/// Generate a match arm of the `Display` implementation, if a custom message for the validator
+/// is provided with `messages(...)`.
+pub fn gen_custom_message_arm<Validator>(
+    error_type_name: &ErrorTypeName,
+    validator: &Validator,
+    error: &ErrorAttributes,
+) -> Option<TokenStream>
+where
+    Validator: Kinded + HasBound,
+    <Validator as Kinded>::Kind: Debug + Display,
+{
+    let kind = validator.kind();
+    let message = error.message_for(&kind.to_string())?;
+    let variant = format_ident!("{kind:?}Violated");
+    let pattern = gen_variant_pattern(error, validator);
+    Some(quote! {
+        #error_type_name::#variant #pattern => f.write_str(#message)
+    })
+}
+
+/// Implement axum's `IntoResponse` for the error type, if requested with `error_integrations(axum)`,
+/// so the error can be returned from handlers directly. The status is `422 Unprocessable Entity`
+/// or, with `web` feature, the one of `status_code()`. The body is JSON,
+/// e.g.: `{"constraint": "len_char_max", "message": "Name is too long. ..."}`
+pub fn gen_impl_axum_into_response(
+    error_type_name: &ErrorTypeName,
+    error: &ErrorAttributes,
+) -> TokenStream {
+    if !error.integrations.axum {
+        return quote!();
+    }
+    let status_code = gen_violation_status_code(quote!(self));
+    quote! {
+        impl ::axum::response::IntoResponse for #error_type_name {
+            fn into_response(self) -> ::axum::response::Response {
+                let constraint = self.violated_constraint();
+                let mut body: ::std::collections::BTreeMap<&'static str, ::std::string::String> = ::std::collections::BTreeMap::new();
+                body.insert("constraint", constraint.to_owned());
+                body.insert("message", ::std::string::ToString::to_string(&self));
+                let status = ::axum::http::StatusCode::from_u16(#status_code)
+                    .unwrap_or(::axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+                (status, ::axum::Json(body)).into_response()
+            }
+        }
+    }
+}
+
+/// Implement miette's `Diagnostic` for the error type, if requested with `error_integrations(miette)`.
+/// The code of a diagnostic is taken from `code()`, and `help_arms` must provide `Option<String>`
+/// with the help text for every variant.
+pub fn gen_impl_miette_diagnostic(
+    error_type_name: &ErrorTypeName,
+    error: &ErrorAttributes,
+    help_arms: Vec<TokenStream>,
+) -> TokenStream {
+    if !error.integrations.miette {
+        return quote!();
+    }
+    let extends_arm = gen_extends_arm(
+        error_type_name,
+        error,
+        quote!(
+            ::miette::Diagnostic::help(err).map(|help| ::std::string::ToString::to_string(&help))
+        ),
+    );
+    let overflow_arm = gen_overflow_arm(error_type_name, error, quote!(None));
+    let help_arms = help_arms.into_iter().chain(extends_arm).chain(overflow_arm);
+    quote! {
+        impl ::miette::Diagnostic for #error_type_name {
+            fn code<'a>(&'a self) -> Option<::std::boxed::Box<dyn ::core::fmt::Display + 'a>> {
+                Some(::std::boxed::Box::new(self.code()))
+            }
+
+            fn help<'a>(&'a self) -> Option<::std::boxed::Box<dyn ::core::fmt::Display + 'a>> {
+                let help: Option<::std::string::String> = match self {
+                    #(#help_arms,)*
+                };
+                help.map(|help| ::std::boxed::Box::new(help) as ::std::boxed::Box<dyn ::core::fmt::Display + 'a>)
+            }
+        }
+    }
+}
+
+/// A boundary of the valid range, used to build help texts.
+pub struct RangeBound {
+    pub value: TokenStream,
+    pub inclusive: bool,
+}
+
+/// Generate an expression, that builds the help text for a numeric range,
+/// e.g. "expected a value between 0 and 100" or "expected a value greater than 0".
+pub fn gen_numeric_range_help(
+    lower: Option<&RangeBound>,
+    upper: Option<&RangeBound>,
+) -> TokenStream {
+    let (description, args): (String, Vec<&TokenStream>) = match (lower, upper) {
+        (Some(lower), Some(upper)) if lower.inclusive && upper.inclusive => (
+            "between {} and {}".to_string(),
+            vec![&lower.value, &upper.value],
+        ),
+        _ => {
+            let lower = lower.map(|bound| {
+                let text = if bound.inclusive {
+                    "greater or equal to {}"
+                } else {
+                    "greater than {}"
+                };
+                (text, &bound.value)
+            });
+            let upper = upper.map(|bound| {
+                let text = if bound.inclusive {
+                    "less or equal to {}"
+                } else {
+                    "less than {}"
+                };
+                (text, &bound.value)
+            });
+            let (texts, args): (Vec<&str>, Vec<&TokenStream>) =
+                lower.into_iter().chain(upper).unzip();
+            (texts.join(" and "), args)
+        }
+    };
+    let format_str = format!("expected a value {description}");
+    quote!(::std::format!(#format_str, #(#args),*))
+}
@@ -0,0 +1,107 @@
+//! Composition of newtypes with `extends = Base`.
+//!
+//! A procedural macro cannot look into the definition of the base type, so every type with
+//! validation has crate-private helpers, which the extending types call: the sanitizers of the
+//! base type run before the own ones, and so do the validators. The error of the base type is
+//! wrapped into a variant of the own error type, e.g. `UsernameViolated(UsernameError)`.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::common::models::{ErrorAttributes, ErrorTypeName, TypeName};
+
+/// The variant of the error type, which wraps the error of the base type, e.g. `UsernameViolated`.
+pub fn gen_extends_variant_name(base: &syn::Path) -> Ident {
+    format_ident!("{}Violated", base_ident(base))
+}
+
+/// The error type of the base type, e.g. `models::UsernameError` for `models::Username`.
+fn gen_base_error_type(base: &syn::Path) -> syn::Path {
+    let mut error_type = base.clone();
+    if let Some(last) = error_type.segments.last_mut() {
+        last.ident = format_ident!("{}Error", last.ident);
+    }
+    error_type
+}
+
+fn base_ident(base: &syn::Path) -> &Ident {
+    &base
+        .segments
+        .last()
+        .expect("extends: the path of the base type cannot be empty")
+        .ident
+}
+
+pub fn gen_extends_variant(error: &ErrorAttributes) -> TokenStream {
+    match &error.extends {
+        Some(base) => {
+            let variant = gen_extends_variant_name(base);
+            let base_error_type = gen_base_error_type(base);
+            quote!(#variant(#base_error_type),)
+        }
+        None => quote!(),
+    }
+}
+
+/// Generate a match arm on the error type for the variant, which wraps the error of the base type.
+/// `body` receives the error of the base type as `err`.
+pub fn gen_extends_arm(
+    error_type_name: &ErrorTypeName,
+    error: &ErrorAttributes,
+    body: TokenStream,
+) -> Option<TokenStream> {
+    let base = error.extends.as_ref()?;
+    let variant = gen_extends_variant_name(base);
+    Some(quote! {
+        #error_type_name::#variant(err) => #body
+    })
+}
+
+/// Generate crate-private helpers, which are used by the types extending this one:
+/// they run the sanitizers and the validators separately and wrap a value, which has passed them
+/// on construction of an extending type.
+/// The helpers are constants rather than functions, so they do not show up among the associated
+/// functions suggested by the compiler.
+pub fn gen_extension_helpers(
+    type_name: &TypeName,
+    inner_type: TokenStream,
+    borrowed_inner_type: TokenStream,
+    error_type_name: &ErrorTypeName,
+    sanitize: TokenStream,
+    validate: TokenStream,
+) -> TokenStream {
+    quote! {
+        impl #type_name {
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            pub(crate) const __NUTYPE_SANITIZE: fn(#inner_type) -> #inner_type = |value| {
+                #sanitize
+                sanitize(value)
+            };
+
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            pub(crate) const __NUTYPE_VALIDATE: fn(&#borrowed_inner_type) -> ::core::result::Result<(), #error_type_name> =
+                |value| {
+                    #validate
+                    validate(value)
+                };
+
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            pub(crate) const __NUTYPE_FROM_EXTENSION: fn(#inner_type) -> Self = |value| Self(value);
+        }
+    }
+}
+
+/// Generate `From<Type> for Base`. The value has passed the sanitizers and the validators of the
+/// base type on construction, so it's wrapped into the base type as it is.
+pub fn gen_impl_from_extension(type_name: &TypeName, base: &syn::Path) -> TokenStream {
+    quote! {
+        impl ::core::convert::From<#type_name> for #base {
+            fn from(value: #type_name) -> Self {
+                (<#base>::__NUTYPE_FROM_EXTENSION)(value.into_inner())
+            }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use crate::common::models::{ErrorAttributes, ErrorTypeName, FactoryTypeName, TypeName};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+/// A validator bound that can be set at runtime with the factory.
+pub struct FactoryParam {
+    /// Name of the validator, e.g. `len_char_max`
+    pub name: Ident,
+
+    /// Type of the bound
+    pub bound_type: TokenStream,
+
+    /// Expression that tells whether `val: &Inner` violates `bound`
+    pub violated: TokenStream,
+
+    /// Expression that instantiates the error variant of the validator
+    pub error: TokenStream,
+}
+
+pub fn gen_factory_type_name(type_name: &TypeName) -> FactoryTypeName {
+    FactoryTypeName::new(format_ident!("{type_name}Factory"))
+}
+
+/// Generate the factory type, which builds values with some of the validator bounds set at runtime.
+/// The runtime bounds are checked in addition to the static ones, so they can only narrow down
+/// the set of valid values.
+pub fn gen_factory(
+    type_name: &TypeName,
+    factory_type_name: &FactoryTypeName,
+    input_type: TokenStream,
+    return_error_type_name: &ErrorTypeName,
+    error: &ErrorAttributes,
+    params: &[FactoryParam],
+) -> TokenStream {
+    let fields = params.iter().map(
+        |FactoryParam {
+             name, bound_type, ..
+         }| { quote!(#name: ::core::option::Option<#bound_type>) },
+    );
+
+    let setters = params.iter().map(|FactoryParam { name, bound_type, .. }| {
+        let setter = format_ident!("with_{name}");
+        let doc = format!(" Sets the bound of `{name}` validator, which is checked in addition to the static one.");
+        quote!(
+            #[doc = #doc]
+            pub fn #setter(mut self, #name: #bound_type) -> Self {
+                self.#name = ::core::option::Option::Some(#name);
+                self
+            }
+        )
+    });
+
+    let shortcuts = params.iter().map(
+        |FactoryParam {
+             name, bound_type, ..
+         }| {
+            let setter = format_ident!("with_{name}");
+            let doc =
+                format!(" Creates a factory with the bound of `{name}` validator set at runtime.");
+            quote!(
+                #[doc = #doc]
+                pub fn #setter(#name: #bound_type) -> #factory_type_name {
+                    #factory_type_name::default().#setter(#name)
+                }
+            )
+        },
+    );
+
+    let checks = params.iter().map(
+        |FactoryParam {
+             name,
+             violated,
+             error: error_variant,
+             ..
+         }| {
+            let err = match error.return_type {
+                Some(_) => quote!(::core::convert::Into::into(#error_variant)),
+                None => quote!(#error_variant),
+            };
+            quote!(
+                if let ::core::option::Option::Some(bound) = self.#name {
+                    if #violated {
+                        return Err(#err);
+                    }
+                }
+            )
+        },
+    );
+
+    quote!(
+        #[derive(Debug, Clone, Copy, Default)]
+        pub struct #factory_type_name {
+            #(#fields),*
+        }
+
+        impl #factory_type_name {
+            #(#setters)*
+
+            /// Creates a value, checking the runtime bounds after the regular sanitization and validation.
+            pub fn build(&self, raw_value: #input_type) -> ::core::result::Result<#type_name, #return_error_type_name> {
+                let value = #type_name::__nutype_new(raw_value)?;
+                {
+                    let val = &value.0;
+                    #(#checks)*
+                }
+                Ok(value)
+            }
+        }
+
+        impl #type_name {
+            #(#shortcuts)*
+        }
+    )
+}
@@ -0,0 +1,380 @@
+//! Support of generic parameters, e.g. `struct Wrapper<T>(Vec<T>);`.
+//!
+//! The code is generated for the type as if it had no generic parameters, then the generated
+//! items are rewritten: the struct gets the generics, every mention of the type in type position
+//! gets the generic arguments and every `impl` block, that involves the type, gets the generic
+//! parameters and the where clause. The helper functions (e.g. `sanitize()` and `validate()`),
+//! that are nested within the generated functions, cannot refer to the generic parameters of
+//! the outer item, so they get their own copy of the generics and are called with turbofish.
+//! The error types get the const parameters only, e.g. `struct Code<const N: usize>(String);`
+//! gets `CodeError<N>`.
+
+use proc_macro2::{Ident, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{
+    parse::Parser,
+    visit_mut::{self, VisitMut},
+    AngleBracketedGenericArguments, Block, ExprPath, Field, GenericArgument, GenericParam,
+    Generics, Item, ItemEnum, ItemFn, ItemImpl, ItemStruct, Lifetime, PathArguments, Stmt, Type,
+    TypePath,
+};
+
+use crate::common::{
+    gen::parse_error::gen_parse_error_name,
+    models::{DeriveTrait, ErrorTypeName, ModuleName, SpannedDeriveTrait, TypeName},
+};
+
+/// Derivable traits which generate auxiliary items (e.g. the parse error of `FromStr` or
+/// the visitor of `Deserialize`) and therefore cannot be used with generic types yet.
+/// The parse error of `FromStr` is fine as long as the inner type does not depend on the generics.
+pub fn validate_generic_derive_traits(
+    derive_traits: &[SpannedDeriveTrait],
+    generics: &Generics,
+    inner_type: impl ToTokens,
+) -> Result<(), syn::Error> {
+    if generics.params.is_empty() {
+        return Ok(());
+    }
+    let generic_idents = param_idents(generics);
+    let inner_type_tokens = inner_type.into_token_stream();
+    let inner_type_is_generic = mentions_ident(inner_type_tokens.clone(), &|ident| {
+        generic_idents.iter().any(|param| ident == param)
+    });
+    // `Deserialize` of a type over `Cow<'a, str>` borrows the string from the input
+    let is_borrowed_str = generics
+        .params
+        .iter()
+        .all(|param| matches!(param, GenericParam::Lifetime(_)))
+        && borrowed_str_lifetime(&inner_type_tokens).is_some();
+    for spanned_trait in derive_traits {
+        match spanned_trait.item {
+            DeriveTrait::FromStr if !inner_type_is_generic => {}
+            DeriveTrait::SerdeDeserialize if is_borrowed_str => {}
+            DeriveTrait::FromStr
+            | DeriveTrait::SerdeDeserialize
+            | DeriveTrait::SchemarsJsonSchema
+            | DeriveTrait::ArbitraryArbitrary
+            | DeriveTrait::JuniperGraphQLScalar
+            | DeriveTrait::RocketFromParam
+            | DeriveTrait::RocketFromFormField
+            | DeriveTrait::ActixFromRequest
+            | DeriveTrait::ClapValueParserFactory
+            | DeriveTrait::SerdeWithDeserializeAs => {
+                let msg = "This trait cannot be derived for a type with generic parameters.";
+                return Err(syn::Error::new(spanned_trait.span, msg));
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// The lifetime of the inner type `Cow<'a, str>`, unless it is `'static`.
+pub fn borrowed_str_lifetime(inner_type: impl ToTokens) -> Option<Lifetime> {
+    let field = Field::parse_unnamed
+        .parse2(inner_type.into_token_stream())
+        .ok()?;
+    let Type::Path(type_path) = field.ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.iter().collect::<Vec<_>>().as_slice() {
+        [GenericArgument::Lifetime(lifetime), GenericArgument::Type(Type::Path(str_path))]
+            if segment.ident == "Cow"
+                && lifetime.ident != "static"
+                && str_path.qself.is_none()
+                && str_path.path.is_ident("str") =>
+        {
+            Some(lifetime.clone())
+        }
+        _ => None,
+    }
+}
+
+pub fn apply_generics(
+    output: TokenStream,
+    type_name: &TypeName,
+    error_type_name: &ErrorTypeName,
+    module_name: &ModuleName,
+    generics: &Generics,
+) -> Result<TokenStream, syn::Error> {
+    if generics.params.is_empty() {
+        return Ok(output);
+    }
+
+    // The error types get the const parameters, so the bounds of the validators (e.g.
+    // `len_char_max = N`) can be used within the error messages.
+    let error_generics = Generics {
+        params: generics
+            .params
+            .iter()
+            .filter(|param| matches!(param, GenericParam::Const(_)))
+            .cloned()
+            .collect(),
+        ..Generics::default()
+    };
+    let error_names = if error_generics.params.is_empty() {
+        Vec::new()
+    } else {
+        vec![
+            error_type_name.to_string(),
+            gen_parse_error_name(type_name).to_string(),
+        ]
+    };
+
+    let mut file: syn::File = syn::parse2(output)?;
+    let mut applier = GenericsApplier {
+        type_name: type_name.to_string(),
+        type_arguments: type_arguments(generics)?,
+        generics: generics.clone(),
+        error_names,
+        error_type_arguments: type_arguments(&error_generics)?,
+        error_generics,
+        param_idents: param_idents(generics),
+        lifetime_idents: lifetime_idents(generics),
+        turbofish_fns: Vec::new(),
+    };
+
+    let generated_module = file.items.iter_mut().find_map(|item| match item {
+        Item::Mod(item_mod) if item_mod.ident == module_name.to_string() => {
+            item_mod.content.as_mut().map(|(_, items)| items)
+        }
+        _ => None,
+    });
+    // With `local` flag the items are generated in place, without the module
+    let items = match generated_module {
+        Some(items) => items,
+        None => &mut file.items,
+    };
+    // The generated tests cannot pick the generic arguments
+    items.retain(|item| !is_test_fn(item));
+    for item in items.iter_mut() {
+        applier.visit_item_mut(item);
+    }
+
+    Ok(file.into_token_stream())
+}
+
+struct GenericsApplier {
+    type_name: String,
+    generics: Generics,
+    type_arguments: AngleBracketedGenericArguments,
+    error_names: Vec<String>,
+    error_generics: Generics,
+    error_type_arguments: AngleBracketedGenericArguments,
+    param_idents: Vec<Ident>,
+    lifetime_idents: Vec<Ident>,
+    turbofish_fns: Vec<Ident>,
+}
+
+impl GenericsApplier {
+    fn mentions_type(&self, tokens: impl ToTokens) -> bool {
+        mentions_ident(tokens.into_token_stream(), &|ident| {
+            ident == &self.type_name
+        })
+    }
+
+    fn mentions_error_type(&self, tokens: impl ToTokens) -> bool {
+        mentions_ident(tokens.into_token_stream(), &|ident| {
+            self.error_names.iter().any(|name| ident == name)
+        })
+    }
+
+    fn mentions_params(&self, tokens: impl ToTokens) -> bool {
+        let tokens = tokens.into_token_stream();
+        mentions_ident(tokens.clone(), &|ident| {
+            self.param_idents.iter().any(|param| ident == param)
+        }) || mentions_lifetime(tokens, &self.lifetime_idents)
+    }
+
+    // A function that declares a parameter with the same name shadows the outer one.
+    // It is also the case for a function, which has already got the generics merged.
+    fn shadows_params(&self, item: &ItemFn) -> bool {
+        item.sig.generics.params.iter().any(|param| match param {
+            GenericParam::Type(tp) => self.param_idents.contains(&tp.ident),
+            GenericParam::Const(cp) => self.param_idents.contains(&cp.ident),
+            GenericParam::Lifetime(lp) => self.lifetime_idents.contains(&lp.lifetime.ident),
+        })
+    }
+
+    fn turbofish(&self) -> AngleBracketedGenericArguments {
+        let mut arguments = self.type_arguments.clone();
+        // Lifetimes of a function can be inferred and often cannot be specified explicitly
+        arguments.args = arguments
+            .args
+            .into_iter()
+            .filter(|arg| !matches!(arg, GenericArgument::Lifetime(_)))
+            .collect();
+        arguments.colon2_token = Some(Default::default());
+        arguments
+    }
+}
+
+fn merge_generics(target: &mut Generics, generics: &Generics) {
+    let own_params = std::mem::take(&mut target.params);
+    let (lifetimes, others): (Vec<GenericParam>, Vec<GenericParam>) = own_params
+        .into_iter()
+        .chain(generics.params.iter().cloned())
+        .partition(|param| matches!(param, GenericParam::Lifetime(_)));
+    // Lifetimes must be declared prior to other parameters
+    target.params = lifetimes.into_iter().chain(others).collect();
+    target.lt_token.get_or_insert_with(Default::default);
+    target.gt_token.get_or_insert_with(Default::default);
+    if let Some(where_clause) = &generics.where_clause {
+        target
+            .make_where_clause()
+            .predicates
+            .extend(where_clause.predicates.iter().cloned());
+    }
+}
+
+impl VisitMut for GenericsApplier {
+    fn visit_item_struct_mut(&mut self, item: &mut ItemStruct) {
+        if item.ident == self.type_name {
+            item.generics = self.generics.clone();
+        } else if self.error_names.contains(&item.ident.to_string()) {
+            item.generics = self.error_generics.clone();
+        }
+        visit_mut::visit_item_struct_mut(self, item);
+    }
+
+    fn visit_item_enum_mut(&mut self, item: &mut ItemEnum) {
+        if self.error_names.contains(&item.ident.to_string()) {
+            item.generics = self.error_generics.clone();
+        }
+        visit_mut::visit_item_enum_mut(self, item);
+    }
+
+    fn visit_item_impl_mut(&mut self, item: &mut ItemImpl) {
+        let trait_path = item.trait_.as_ref().map(|(_, path, _)| path);
+        let involves_type = self.mentions_type(&item.self_ty) || self.mentions_type(trait_path);
+        let involves_error_type =
+            self.mentions_error_type(&item.self_ty) || self.mentions_error_type(trait_path);
+        visit_mut::visit_item_impl_mut(self, item);
+        if involves_type {
+            merge_generics(&mut item.generics, &self.generics);
+        } else if involves_error_type {
+            merge_generics(&mut item.generics, &self.error_generics);
+        }
+    }
+
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        // Nested functions cannot refer to the generic parameters of the outer item, so they get
+        // their own copy. The parameters cannot always be inferred from the arguments
+        // (e.g. a validator with `len_char_max = N`), so the functions are called with turbofish.
+        let mut turbofish_fns = Vec::new();
+        for stmt in block.stmts.iter_mut() {
+            if let Stmt::Item(Item::Fn(item)) = stmt {
+                visit_mut::visit_item_fn_mut(self, item);
+                let has_own_params = item
+                    .sig
+                    .generics
+                    .params
+                    .iter()
+                    .any(|param| !matches!(param, GenericParam::Lifetime(_)));
+                if !self.shadows_params(item) && self.mentions_params(&*item) {
+                    merge_generics(&mut item.sig.generics, &self.generics);
+                    // Lifetimes are inferred, so turbofish is needed only for the other parameters
+                    if !has_own_params && !self.param_idents.is_empty() {
+                        turbofish_fns.push(item.sig.ident.clone());
+                    }
+                }
+            }
+        }
+        let scope_len = self.turbofish_fns.len();
+        self.turbofish_fns.extend(turbofish_fns);
+        for stmt in block.stmts.iter_mut() {
+            if !matches!(stmt, Stmt::Item(Item::Fn(_))) {
+                self.visit_stmt_mut(stmt);
+            }
+        }
+        self.turbofish_fns.truncate(scope_len);
+    }
+
+    fn visit_expr_path_mut(&mut self, expr: &mut ExprPath) {
+        visit_mut::visit_expr_path_mut(self, expr);
+        if expr.qself.is_none() && expr.path.segments.len() == 1 {
+            let segment = &mut expr.path.segments[0];
+            if segment.arguments.is_none() && self.turbofish_fns.contains(&segment.ident) {
+                segment.arguments = PathArguments::AngleBracketed(self.turbofish());
+            }
+        }
+    }
+
+    fn visit_type_path_mut(&mut self, type_path: &mut TypePath) {
+        visit_mut::visit_type_path_mut(self, type_path);
+        if type_path.qself.is_none() && type_path.path.segments.len() == 1 {
+            let segment = &mut type_path.path.segments[0];
+            if !segment.arguments.is_none() {
+                return;
+            }
+            if segment.ident == self.type_name {
+                segment.arguments = PathArguments::AngleBracketed(self.type_arguments.clone());
+            } else if self.error_names.contains(&segment.ident.to_string()) {
+                segment.arguments =
+                    PathArguments::AngleBracketed(self.error_type_arguments.clone());
+            }
+        }
+    }
+}
+
+fn type_arguments(generics: &Generics) -> Result<AngleBracketedGenericArguments, syn::Error> {
+    if generics.params.is_empty() {
+        return Ok(syn::parse_quote!(<>));
+    }
+    let (_, ty_generics, _) = generics.split_for_impl();
+    syn::parse2(ty_generics.to_token_stream())
+}
+
+fn param_idents(generics: &Generics) -> Vec<Ident> {
+    generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(tp) => Some(tp.ident.clone()),
+            GenericParam::Const(cp) => Some(cp.ident.clone()),
+            GenericParam::Lifetime(_) => None,
+        })
+        .collect()
+}
+
+fn lifetime_idents(generics: &Generics) -> Vec<Ident> {
+    generics
+        .lifetimes()
+        .map(|param| param.lifetime.ident.clone())
+        .collect()
+}
+
+fn mentions_lifetime(tokens: TokenStream, lifetimes: &[Ident]) -> bool {
+    let mut after_apostrophe = false;
+    tokens.into_iter().any(|tree| {
+        let is_lifetime = match &tree {
+            TokenTree::Ident(ident) => after_apostrophe && lifetimes.contains(ident),
+            TokenTree::Group(group) => mentions_lifetime(group.stream(), lifetimes),
+            TokenTree::Punct(_) | TokenTree::Literal(_) => false,
+        };
+        after_apostrophe = matches!(&tree, TokenTree::Punct(punct) if punct.as_char() == '\'');
+        is_lifetime
+    })
+}
+
+fn mentions_ident(tokens: TokenStream, predicate: &dyn Fn(&Ident) -> bool) -> bool {
+    tokens.into_iter().any(|tree| match tree {
+        TokenTree::Ident(ident) => predicate(&ident),
+        TokenTree::Group(group) => mentions_ident(group.stream(), predicate),
+        TokenTree::Punct(_) | TokenTree::Literal(_) => false,
+    })
+}
+
+fn is_test_fn(item: &Item) -> bool {
+    match item {
+        Item::Fn(item_fn) => item_fn
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("test")),
+        _ => false,
+    }
+}
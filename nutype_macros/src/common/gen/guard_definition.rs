@@ -0,0 +1,37 @@
+//! Reusable guards defined with `nutype_guard!`.
+//!
+//! A procedural macro cannot look into other items, so the guard is defined as a `macro_rules!`
+//! macro, which carries the sanitizers and validators. `#[nutype(guard = Rules, ..)]` calls it
+//! back with the rest of the attributes and the type definition, and the macro expands into
+//! `#[nutype(..)]` with the rules of the guard prepended.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::common::models::GuardDefinition;
+
+pub fn gen_guard_definition(definition: GuardDefinition) -> TokenStream {
+    let GuardDefinition { vis, name, rules } = definition;
+
+    quote! {
+        macro_rules! #name {
+            ([$($attrs:tt)*] $($type_definition:tt)*) => {
+                #[::nutype::nutype(#rules, $($attrs)*)]
+                $($type_definition)*
+            };
+        }
+
+        #[allow(unused_imports)]
+        #vis use #name;
+    }
+}
+
+pub fn gen_guard_application(
+    guard: &syn::Path,
+    attrs: TokenStream,
+    type_definition: TokenStream,
+) -> TokenStream {
+    quote! {
+        #guard! { [#attrs] #type_definition }
+    }
+}
@@ -0,0 +1,97 @@
+//! "Invariants" section, which is appended to the docs of the type, so the users of the type
+//! know the sanitizers and the validators without reading the attributes of `#[nutype]`.
+
+use std::fmt::Display;
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+use crate::common::models::{
+    ConditionalRule, ConditionalRules, Describe, ErrorAttributes, Guard, ValueOrExpr,
+};
+
+pub fn gen_invariants_doc<Sanitizer: Describe, Validator: Describe>(
+    guard: &Guard<Sanitizer, Validator>,
+    conditional: &ConditionalRules<Sanitizer, Validator>,
+    error: &ErrorAttributes,
+) -> TokenStream {
+    let (sanitizers, validators) = match guard {
+        Guard::WithoutValidation { sanitizers } => (sanitizers.as_slice(), [].as_slice()),
+        Guard::WithValidation {
+            sanitizers,
+            validators,
+        } => (sanitizers.as_slice(), validators.as_slice()),
+    };
+    if sanitizers.is_empty()
+        && validators.is_empty()
+        && conditional.is_empty()
+        && error.extends.is_none()
+    {
+        return quote!();
+    }
+
+    let mut lines: Vec<String> = vec![String::new(), "# Invariants".to_string()];
+    if let Some(base) = &error.extends {
+        let base = base.to_token_stream().to_string().replace(' ', "");
+        lines.push(String::new());
+        lines.push(format!(
+            "The sanitizers and the validators of [`{base}`] run first."
+        ));
+    }
+    if !sanitizers.is_empty() || !conditional.sanitizers.is_empty() {
+        lines.push(String::new());
+        lines.push("Sanitizers, applied in order:".to_string());
+        lines.extend(describe_rules(sanitizers, &conditional.sanitizers));
+    }
+    if !validators.is_empty() || !conditional.validators.is_empty() {
+        lines.push(String::new());
+        lines.push("Validators:".to_string());
+        lines.extend(describe_rules(validators, &conditional.validators));
+    }
+
+    let docs = lines.iter().map(|line| quote!(#[doc = #line]));
+    quote!(#(#docs)*)
+}
+
+/// The list items of the rules in the order of declaration. A conditional rule mentions its
+/// predicate, e.g. "`len_char_max = 20`: at most 20 characters (with `cfg(test)`)".
+fn describe_rules<T: Describe>(rules: &[T], conditional: &[ConditionalRule<T>]) -> Vec<String> {
+    let mut lines = Vec::new();
+    for position in 0..=rules.len() {
+        for rule in conditional.iter().filter(|rule| rule.position == position) {
+            let predicate = rule.predicate.to_string().replace(' ', "");
+            lines.push(format!(
+                "* {} (with `cfg({predicate})`)",
+                rule.rule.describe()
+            ));
+        }
+        if let Some(rule) = rules.get(position) {
+            lines.push(format!("* {}", rule.describe()));
+        }
+    }
+    lines
+}
+
+/// A bound as it is written in the attributes (e.g. `20` or `MAX_LEN`) and as it is put into
+/// the prose, where the expressions are formatted as code.
+pub fn describe_bound<T: Display>(bound: &ValueOrExpr<T>) -> (String, String) {
+    match bound {
+        ValueOrExpr::Value(value) => (value.to_string(), value.to_string()),
+        ValueOrExpr::Expr(expr) => {
+            let expr = expr.to_token_stream().to_string().replace(" :: ", "::");
+            (expr.clone(), format!("`{expr}`"))
+        }
+    }
+}
+
+/// `with = ` or `predicate = ` with a function, e.g. "`predicate = is_valid`: satisfies `is_valid`".
+/// Closures (`path` is `None`) are not spelled out, since they are an implementation detail.
+pub fn describe_custom_function(attr: &str, path: Option<&syn::Path>, description: &str) -> String {
+    match path {
+        Some(path) => {
+            let path = path.to_token_stream().to_string().replace(' ', "");
+            format!("`{attr} = {path}`: {description} `{path}`")
+        }
+        None => format!("`{attr}`: {description} a custom closure"),
+    }
+}
@@ -0,0 +1,134 @@
+//! `NUTYPE_METADATA` constant, generated with `metadata` flag: the guard of the type described
+//! as JSON, so external tools (e.g. OpenAPI generators or linters) can read the sanitizers and
+//! the validators without parsing the attributes of `#[nutype]`.
+//!
+//! ```json
+//! {"name":"Username","inner_type":"String","sanitizers":[{"kind":"trim"}],"validators":[{"kind":"len_char_max","value":20}]}
+//! ```
+//!
+//! Bounds given by expressions are kept as code, e.g. `{"kind":"len_char_max","expr":"MAX_LEN"}`,
+//! custom functions are kept as paths, closures are not spelled out.
+
+use std::fmt::Display;
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::Generics;
+
+use crate::common::models::{ErrorAttributes, Guard, Metadata, TypeName, ValueOrExpr};
+
+pub fn gen_metadata_const<Sanitizer: Metadata, Validator: Metadata>(
+    type_name: &TypeName,
+    inner_type: impl ToTokens,
+    generics: &Generics,
+    guard: &Guard<Sanitizer, Validator>,
+    error: &ErrorAttributes,
+) -> TokenStream {
+    let (sanitizers, validators) = match guard {
+        Guard::WithoutValidation { sanitizers } => (sanitizers.as_slice(), [].as_slice()),
+        Guard::WithValidation {
+            sanitizers,
+            validators,
+        } => (sanitizers.as_slice(), validators.as_slice()),
+    };
+    let mut fields = vec![
+        json_field("name", &json_string(&type_name.to_string())),
+        json_field("inner_type", &json_string(&type_code(inner_type))),
+    ];
+    if let Some(base) = &error.extends {
+        fields.push(json_field("extends", &json_string(&type_code(base))));
+    }
+    let sanitizers: Vec<String> = sanitizers.iter().map(Metadata::metadata).collect();
+    let validators: Vec<String> = validators.iter().map(Metadata::metadata).collect();
+    fields.push(json_field(
+        "sanitizers",
+        &format!("[{}]", sanitizers.join(",")),
+    ));
+    fields.push(json_field(
+        "validators",
+        &format!("[{}]", validators.join(",")),
+    ));
+    let metadata = format!("{{{}}}", fields.join(","));
+
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics #type_name #type_generics #where_clause {
+            /// The sanitizers and the validators of the type as JSON, generated by `#[nutype]`.
+            #[doc(hidden)]
+            pub const NUTYPE_METADATA: &'static str = #metadata;
+        }
+    }
+}
+
+/// A sanitizer or a validator without parameters, e.g. `{"kind":"trim"}`.
+pub fn metadata_of_kind(kind: impl Display) -> String {
+    format!(
+        "{{{}}}",
+        json_field("kind", &json_string(&kind.to_string()))
+    )
+}
+
+/// A bound of a validator, e.g. `{"kind":"len_char_max","value":20}`.
+pub fn metadata_of_bound<T: Display>(kind: impl Display, bound: &ValueOrExpr<T>) -> String {
+    let value = match bound {
+        ValueOrExpr::Value(value) => json_field("value", &value.to_string()),
+        ValueOrExpr::Expr(expr) => json_field("expr", &json_string(&expr_code(expr))),
+    };
+    format!(
+        "{{{},{value}}}",
+        json_field("kind", &json_string(&kind.to_string()))
+    )
+}
+
+/// `with = ` or `predicate = ` with a function, e.g. `{"kind":"predicate","function":"is_valid"}`.
+pub fn metadata_of_custom_function(kind: impl Display, path: Option<&syn::Path>) -> String {
+    let kind = json_field("kind", &json_string(&kind.to_string()));
+    match path {
+        Some(path) => format!(
+            "{{{kind},{}}}",
+            json_field("function", &json_string(&type_code(path)))
+        ),
+        None => format!("{{{kind}}}"),
+    }
+}
+
+/// `regex = ` with a literal pattern, e.g. `{"kind":"regex","pattern":"^[a-z]+$"}`.
+pub fn metadata_of_pattern(kind: impl Display, pattern: &str) -> String {
+    let kind = json_field("kind", &json_string(&kind.to_string()));
+    format!(
+        "{{{kind},{}}}",
+        json_field("pattern", &json_string(pattern))
+    )
+}
+
+fn json_field(name: &str, value: &str) -> String {
+    format!("\"{name}\":{value}")
+}
+
+fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// A type or a path as it is written, e.g. `Vec<String>`.
+fn type_code(tokens: impl ToTokens) -> String {
+    tokens.into_token_stream().to_string().replace(' ', "")
+}
+
+/// An expression as it is written, e.g. `MAX_LEN * 2`.
+fn expr_code(tokens: impl ToTokens) -> String {
+    tokens.into_token_stream().to_string().replace(" :: ", "::")
+}
@@ -1,26 +1,54 @@
+pub mod alloc;
+pub mod conditional;
+pub mod debug_expansion;
+pub mod docs;
 pub mod error;
+pub mod extends;
+pub mod factory;
+pub mod generics;
+pub mod guard_definition;
+pub mod invariants;
+pub mod metadata;
 pub mod new_unchecked;
+pub mod omit;
 pub mod parse_error;
+pub mod secret;
 pub mod tests;
 pub mod traits;
+pub mod warning;
 
 use std::{collections::HashSet, hash::Hash};
 
-use self::traits::GeneratedTraits;
+use self::traits::{gen_default_value, GeneratedTraits};
 
 use super::models::{
-    ErrorTypeName, GenerateParams, Guard, NewUnchecked, ParseErrorTypeName, TypeName, TypeTrait,
+    ConditionalRule, ConditionalRules, ConstFn, Describe, ErrorAttributes, ErrorIntegrations,
+    ErrorTypeName, Factory, FactoryTypeName, GenerateParams, Guard, NewUnchecked, OpsPolicy,
+    OptionalMethods, ParseErrorTypeName, Repr, Secret, SerdeAttributes, TypeName, TypeTrait,
 };
 use crate::common::{
     gen::{
-        error::gen_error_type_name, new_unchecked::gen_new_unchecked,
+        alloc::gen_alloc_path,
+        conditional::{
+            gen_conditional_error_type, gen_fn_sanitize_conditional, gen_fn_validate_conditional,
+            gen_steps,
+        },
+        error::{
+            gen_error_type_name, gen_impl_from_error_into, gen_return_error_type_alias,
+            gen_return_error_type_name,
+        },
+        extends::{gen_extends_variant_name, gen_extension_helpers, gen_impl_from_extension},
+        factory::{gen_factory, gen_factory_type_name, FactoryParam},
+        invariants::gen_invariants_doc,
+        new_unchecked::gen_new_unchecked,
         parse_error::gen_parse_error_name,
+        secret::gen_secret,
     },
     models::ModuleName,
 };
-use proc_macro2::{Punct, Spacing, TokenStream, TokenTree};
+use proc_macro2::{Ident, Punct, Spacing, Span, TokenStream, TokenTree};
 use quote::{format_ident, quote, ToTokens};
-use syn::Visibility;
+use syn::{LitStr, Visibility};
 
 /// Inject an inner type into a closure, so compiler does not complain if the token stream matchers
 /// the expected closure pattern.
@@ -97,22 +125,49 @@ pub fn gen_module_name_for_type(type_name: &TypeName) -> ModuleName {
     ModuleName::new(ident)
 }
 
+/// Put `#[cfg(..)]` attributes of the type on every generated item (the module and the
+/// reimports), so the type is either generated entirely or not generated at all.
+pub fn apply_cfg_attrs(
+    output: TokenStream,
+    cfg_attrs: &[syn::Attribute],
+) -> Result<TokenStream, syn::Error> {
+    if cfg_attrs.is_empty() {
+        return Ok(output);
+    }
+    let file: syn::File = syn::parse2(output)?;
+    let items = file.items.into_iter().map(|item| {
+        quote! {
+            #(#cfg_attrs)*
+            #item
+        }
+    });
+    Ok(quote!(#(#items)*))
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn gen_reimports(
     vis: Visibility,
     type_name: &TypeName,
     module_name: &ModuleName,
     maybe_error_type_name: Option<&ErrorTypeName>,
+    maybe_error_vis: Option<Visibility>,
+    maybe_error_module: Option<&Ident>,
     maybe_parse_error_type_name: Option<&ParseErrorTypeName>,
+    maybe_factory_type_name: Option<&FactoryTypeName>,
 ) -> TokenStream {
+    // The type can be marked as `#[deprecated]`, but only its usage should be reported
     let reimport_main_type = quote! {
+        #[allow(deprecated)]
         #vis use #module_name::#type_name;
     };
 
+    // The error type has the same visibility as the main type, unless it's set with `error = `
+    let error_vis = maybe_error_vis.unwrap_or_else(|| vis.clone());
     let reimport_error_type_if_needed = match maybe_error_type_name {
         None => quote!(),
         Some(ref error_type_name) => {
             quote! (
-                #vis use #module_name::#error_type_name;
+                #error_vis use #module_name::#error_type_name;
             )
         }
     };
@@ -126,27 +181,245 @@ pub fn gen_reimports(
         }
     };
 
+    // With `error_module = ` the error types are reimported within the given module instead.
+    // The module is nested, so the visibility of the error type has to be adjusted.
+    let (reimport_error_type_if_needed, reimport_parse_error_type_if_needed) =
+        match maybe_error_module {
+            None => (
+                reimport_error_type_if_needed,
+                reimport_parse_error_type_if_needed,
+            ),
+            Some(error_module) => {
+                let nested_error_vis = gen_nested_visibility(&error_vis);
+                let error_type_names = maybe_error_type_name.into_iter();
+                let parse_error_type_names = maybe_parse_error_type_name.into_iter();
+                let module = quote! {
+                    #vis mod #error_module {
+                        #(#nested_error_vis use super::#module_name::#error_type_names;)*
+                        #(pub use super::#module_name::#parse_error_type_names;)*
+                    }
+                };
+                (module, quote!())
+            }
+        };
+
+    let reimport_factory_type_if_needed = match maybe_factory_type_name {
+        None => quote!(),
+        Some(factory_type_name) => {
+            quote! (
+                #vis use #module_name::#factory_type_name;
+            )
+        }
+    };
+
     quote! {
         #reimport_main_type
         #reimport_error_type_if_needed
         #reimport_parse_error_type_if_needed
+        #reimport_factory_type_if_needed
     }
 }
 
-pub fn gen_impl_into_inner(type_name: &TypeName, inner_type: impl ToTokens) -> TokenStream {
+/// Generate the method set with `accessor = `, which returns a reference to the inner value.
+pub fn gen_impl_accessor(
+    type_name: &TypeName,
+    inner_type: impl ToTokens,
+    accessor: Option<&Ident>,
+    const_fn: ConstFn,
+) -> TokenStream {
+    let Some(accessor) = accessor else {
+        return quote!();
+    };
     quote! {
         impl #type_name {
+            /// Returns a reference to the inner value.
             #[inline]
-            pub fn into_inner(self) -> #inner_type {
-                self.0
+            pub #const_fn fn #accessor(&self) -> &#inner_type {
+                &self.0
+            }
+        }
+    }
+}
+
+pub fn gen_impl_into_inner(
+    type_name: &TypeName,
+    inner_type: impl ToTokens,
+    has_drop: bool,
+    secret: Secret,
+    const_fn: ConstFn,
+) -> TokenStream {
+    // Secret types do not expose `into_inner()` publicly, but generated code may still use it
+    // within the module.
+    let (vis, allow_dead_code) = match secret {
+        Secret::Off => (quote!(pub), quote!()),
+        Secret::On { .. } => (quote!(), quote!(#[allow(dead_code)])),
+    };
+
+    if has_drop {
+        // The inner value cannot be moved out of a type that implements `Drop`,
+        // so it's taken out leaving the default value in place.
+        quote! {
+            impl #type_name {
+                #[inline]
+                #allow_dead_code
+                #vis fn into_inner(mut self) -> #inner_type {
+                    ::core::mem::take(&mut self.0)
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl #type_name {
+                #[inline]
+                #vis #const_fn fn into_inner(self) -> #inner_type {
+                    self.0
+                }
+            }
+        }
+    }
+}
+
+/// Generate `try_map()` for types with validation and `map()` for types without it.
+/// Both pass the result of the closure through the constructor, so sanitizers and validators are applied.
+pub fn gen_impl_map(
+    type_name: &TypeName,
+    inner_type: impl ToTokens,
+    maybe_return_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    match maybe_return_error_type_name {
+        Some(return_error_type_name) => quote! {
+            impl #type_name {
+                /// Transforms the inner value with `f` and constructs a new value out of the result.
+                pub fn try_map(self, f: impl FnOnce(#inner_type) -> #inner_type) -> ::core::result::Result<Self, #return_error_type_name> {
+                    Self::__nutype_new(f(self.into_inner()))
+                }
+            }
+        },
+        None => quote! {
+            impl #type_name {
+                /// Transforms the inner value with `f` and constructs a new value out of the result.
+                pub fn map(self, f: impl FnOnce(#inner_type) -> #inner_type) -> Self {
+                    Self::__nutype_new(f(self.into_inner()))
+                }
+            }
+        },
+    }
+}
+
+/// Generate `new_clamped()` out of the clamping statements for lower and upper bounds.
+/// The lower bounds are applied first, so with consistent bounds the result satisfies all of them.
+pub fn gen_new_clamped(
+    type_name: &TypeName,
+    inner_type: impl ToTokens,
+    sanitize: TokenStream,
+    lower_clamps: Vec<TokenStream>,
+    upper_clamps: Vec<TokenStream>,
+    constructor_vis: &Visibility,
+) -> TokenStream {
+    quote! {
+        impl #type_name {
+            /// Sanitizes the value and clamps it into the range set by the validators.
+            // `clamp()` cannot be used for exclusive bounds and it panics if the bounds overlap.
+            #[allow(clippy::manual_clamp)]
+            #constructor_vis fn new_clamped(raw_value: #inner_type) -> Self {
+                #sanitize
+
+                let mut value: #inner_type = sanitize(raw_value);
+                #(#lower_clamps)*
+                #(#upper_clamps)*
+                Self(value)
+            }
+        }
+    }
+}
+
+/// Refer to the environment variables used with `env!("NAME")` in the attributes, so
+/// rustc rebuilds the crate when any of them changes.
+pub fn gen_env_tracking(names: &[LitStr]) -> TokenStream {
+    quote! {
+        #(const _: &str = ::core::env!(#names);)*
+    }
+}
+
+/// Translate the visibility of the constructors set with `constructor = ` into the visibility
+/// within the generated module, which is nested into the module where the type is defined.
+/// E.g. private constructors must be accessible within the parent module, so they become `pub(super)`.
+pub fn gen_nested_visibility(vis: &Visibility) -> Visibility {
+    match vis {
+        Visibility::Public(_) => vis.clone(),
+        Visibility::Inherited => syn::parse_quote!(pub(super)),
+        Visibility::Restricted(restricted) => {
+            let path = &restricted.path;
+            match path.segments.first() {
+                Some(first) if first.ident == "self" => {
+                    let rest = path.segments.iter().skip(1);
+                    syn::parse_quote!(pub(in super #(::#rest)*))
+                }
+                Some(first) if first.ident == "super" => syn::parse_quote!(pub(in super::#path)),
+                // Absolute paths, e.g. `pub(crate)` or `pub(in crate::models)`
+                _ => vis.clone(),
             }
         }
     }
 }
 
+/// Generate `from_literal()` for types with `const_fn` and validation.
+/// Used within `const` context it turns an invalid value into a compile error.
+pub fn gen_impl_from_literal(
+    type_name: &TypeName,
+    inner_type: impl ToTokens,
+    constructor: &Ident,
+    constructor_vis: &Visibility,
+    const_fn: ConstFn,
+) -> TokenStream {
+    match const_fn {
+        ConstFn::Off => quote!(),
+        ConstFn::On => {
+            let msg = format!("Invalid {type_name}: the value violates the validation rules");
+            quote! {
+                impl #type_name {
+                    /// Creates a value from a literal, panicking if the value is invalid.
+                    /// Within `const` context an invalid value results in a compile error.
+                    #constructor_vis const fn from_literal(raw_value: #inner_type) -> Self {
+                        match Self::#constructor(raw_value) {
+                            Ok(value) => value,
+                            Err(_) => panic!(#msg),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Generate associated constants (e.g. `MIN`, `MAX`, `ZERO`) out of the given names and values.
+/// The values are checked against the bounds of the validators at expansion, so they're built
+/// directly. Sanitizers and the guard of the base type of `extends = ` cannot be checked at
+/// expansion, so such types get no constants.
+pub fn gen_well_known_constants<S, V>(
+    type_name: &TypeName,
+    constants: Vec<(&str, TokenStream)>,
+    guard: &Guard<S, V>,
+    error: &ErrorAttributes,
+) -> TokenStream {
+    if !guard.sanitizers().is_empty() || error.extends.is_some() || constants.is_empty() {
+        return quote!();
+    }
+    let consts = constants.into_iter().map(|(name, value)| {
+        let name = format_ident!("{name}");
+        quote!(pub const #name: Self = Self(#value);)
+    });
+
+    quote! {
+        impl #type_name {
+            #(#consts)*
+        }
+    }
+}
+
 pub trait GenerateNewtype {
-    type Sanitizer;
-    type Validator;
+    type Sanitizer: Describe;
+    type Validator: Describe;
     type InnerType: ToTokens;
     type TypedTrait: Hash + TypeTrait;
 
@@ -155,6 +428,7 @@ pub trait GenerateNewtype {
     /// happens in 2 stages:
     /// * &str -> inner type (parsing)
     /// * inner type -> nutype (validation)
+    ///
     /// But for the String based types there is no first stage, so the parse error is the same as
     /// validation error.
     const HAS_DEDICATED_PARSE_ERROR: bool = true;
@@ -162,20 +436,93 @@ pub trait GenerateNewtype {
     /// If it's true, then `::new()` function receives `impl Into<T>` instead of `T`.
     const NEW_CONVERT_INTO_INNER_TYPE: bool = false;
 
+    /// If it's false, the inner type is known to be `Clone` only when the newtype derives `Clone`.
+    const INNER_TYPE_IS_CLONE: bool = true;
+
     fn gen_fn_sanitize(inner_type: &Self::InnerType, sanitizers: &[Self::Sanitizer])
         -> TokenStream;
 
     fn gen_fn_validate(
         inner_type: &Self::InnerType,
-        type_name: &TypeName,
+        error_type_name: &ErrorTypeName,
         validators: &[Self::Validator],
+        error: &ErrorAttributes,
     ) -> TokenStream;
 
+    /// Generate `fn sanitize()`, which with `extends = ` runs the sanitizers of the base type first.
+    fn gen_fn_sanitize_extended(
+        inner_type: &Self::InnerType,
+        sanitizers: &[Self::Sanitizer],
+        conditional: &ConditionalRules<Self::Sanitizer, Self::Validator>,
+        error: &ErrorAttributes,
+    ) -> TokenStream {
+        let sanitize = if conditional.sanitizers.is_empty() {
+            Self::gen_fn_sanitize(inner_type, sanitizers)
+        } else {
+            gen_fn_sanitize_conditional(
+                inner_type,
+                sanitizers,
+                &conditional.sanitizers,
+                |sanitizers| Self::gen_fn_sanitize(inner_type, sanitizers),
+            )
+        };
+        match &error.extends {
+            None => sanitize,
+            Some(base) => quote!(
+                fn sanitize(value: #inner_type) -> #inner_type {
+                    #sanitize
+                    sanitize((<#base>::__NUTYPE_SANITIZE)(value))
+                }
+            ),
+        }
+    }
+
+    /// Generate `fn validate()`, which with `extends = ` runs the validators of the base type first.
+    fn gen_fn_validate_extended(
+        inner_type: &Self::InnerType,
+        error_type_name: &ErrorTypeName,
+        validators: &[Self::Validator],
+        conditional: &ConditionalRules<Self::Sanitizer, Self::Validator>,
+        error: &ErrorAttributes,
+    ) -> TokenStream {
+        let validate = if conditional.validators.is_empty() {
+            Self::gen_fn_validate(inner_type, error_type_name, validators, error)
+        } else {
+            gen_fn_validate_conditional(
+                &Self::gen_borrowed_inner_type(inner_type),
+                error_type_name,
+                validators,
+                &conditional.validators,
+                |validators| Self::gen_fn_validate(inner_type, error_type_name, validators, error),
+            )
+        };
+        match &error.extends {
+            None => validate,
+            Some(base) => {
+                let borrowed_inner_type = Self::gen_borrowed_inner_type(inner_type);
+                let variant = gen_extends_variant_name(base);
+                quote!(
+                    fn validate(value: &#borrowed_inner_type) -> ::core::result::Result<(), #error_type_name> {
+                        #validate
+                        if let Err(err) = (<#base>::__NUTYPE_VALIDATE)(value) {
+                            return Err(#error_type_name::#variant(err));
+                        }
+                        validate(value)
+                    }
+                )
+            }
+        }
+    }
+
     fn gen_validation_error_type(
         type_name: &TypeName,
+        inner_type: &Self::InnerType,
+        error_type_name: &ErrorTypeName,
         validators: &[Self::Validator],
+        error: &ErrorAttributes,
     ) -> TokenStream;
 
+    #[allow(clippy::too_many_arguments)]
     fn gen_traits(
         type_name: &TypeName,
         inner_type: &Self::InnerType,
@@ -183,18 +530,58 @@ pub trait GenerateNewtype {
         traits: HashSet<Self::TypedTrait>,
         maybe_default_value: Option<syn::Expr>,
         guard: &Guard<Self::Sanitizer, Self::Validator>,
+        ops: OpsPolicy,
+        serde: &SerdeAttributes,
+        error_integrations: ErrorIntegrations,
     ) -> Result<GeneratedTraits, syn::Error>;
 
+    #[allow(clippy::too_many_arguments)]
     fn gen_new_with_validation(
         type_name: &TypeName,
         inner_type: &Self::InnerType,
+        error_type_name: &ErrorTypeName,
         sanitizers: &[Self::Sanitizer],
         validators: &[Self::Validator],
+        conditional: &ConditionalRules<Self::Sanitizer, Self::Validator>,
+        error: &ErrorAttributes,
+        constructor: &Ident,
+        constructor_vis: &Visibility,
+        const_fn: ConstFn,
     ) -> TokenStream {
-        let sanitize = Self::gen_fn_sanitize(inner_type, sanitizers);
-        let validation_error = Self::gen_validation_error_type(type_name, validators);
-        let error_type_name = gen_error_type_name(type_name);
-        let validate = Self::gen_fn_validate(inner_type, type_name, validators);
+        let sanitize = Self::gen_fn_sanitize_extended(inner_type, sanitizers, conditional, error);
+        let validate = Self::gen_fn_validate_extended(
+            inner_type,
+            error_type_name,
+            validators,
+            conditional,
+            error,
+        );
+        let return_error_type_name = gen_return_error_type_name(type_name, error_type_name, error);
+        // Used by the arithmetic operators with `ops = result`, which refer only to the returned error type
+        let impl_overflow = if error.overflow {
+            quote! {
+                #[inline]
+                fn __nutype_overflow() -> #return_error_type_name {
+                    ::core::convert::From::from(#error_type_name::Overflow)
+                }
+            }
+        } else {
+            quote!()
+        };
+        // With `const_fn` the generated `fn sanitize()` and `fn validate()` are prefixed with `const`.
+        // `?` cannot be used within `const fn`, so the result of validation is matched explicitly.
+        let check_validation = match const_fn {
+            ConstFn::Off => quote! {
+                validate(&sanitized_value)?;
+                Ok(#type_name(sanitized_value))
+            },
+            ConstFn::On => quote! {
+                match validate(&sanitized_value) {
+                    Ok(()) => Ok(#type_name(sanitized_value)),
+                    Err(err) => Err(err),
+                }
+            },
+        };
 
         let (input_type, convert_raw_value_if_necessary) = if Self::NEW_CONVERT_INTO_INNER_TYPE {
             (
@@ -206,20 +593,232 @@ pub trait GenerateNewtype {
         };
 
         quote!(
-            #validation_error
-
             impl #type_name {
-                pub fn new(raw_value: #input_type) -> ::core::result::Result<Self, #error_type_name> {
+                #constructor_vis #const_fn fn #constructor(raw_value: #input_type) -> ::core::result::Result<Self, #return_error_type_name> {
                     // Keep sanitize() and validate() within new() so they do not overlap with outer
                     // scope imported with `use super::*`.
-                    #sanitize
+                    #const_fn #sanitize
+                    #const_fn #validate
+
+                    #convert_raw_value_if_necessary
+
+                    let sanitized_value: #inner_type = sanitize(raw_value);
+                    #check_validation
+                }
+
+                #[inline]
+                #[allow(dead_code)]
+                #const_fn fn __nutype_new(raw_value: #input_type) -> ::core::result::Result<Self, #return_error_type_name> {
+                    Self::#constructor(raw_value)
+                }
+
+                #impl_overflow
+            }
+        )
+    }
+
+    /// Generate `validate()`, which checks a value without constructing the newtype.
+    /// The sanitizers are applied to a clone of the value, so with sanitizers the inner type
+    /// must be clonable.
+    #[allow(clippy::too_many_arguments)]
+    fn gen_validate_only(
+        type_name: &TypeName,
+        inner_type: &Self::InnerType,
+        error_type_name: &ErrorTypeName,
+        sanitizers: &[Self::Sanitizer],
+        validators: &[Self::Validator],
+        conditional: &ConditionalRules<Self::Sanitizer, Self::Validator>,
+        error: &ErrorAttributes,
+        is_inner_clone: bool,
+        span: Span,
+    ) -> Result<TokenStream, syn::Error> {
+        let validate = Self::gen_fn_validate_extended(
+            inner_type,
+            error_type_name,
+            validators,
+            conditional,
+            error,
+        );
+        let return_error_type_name = gen_return_error_type_name(type_name, error_type_name, error);
+        let borrowed_inner_type = Self::gen_borrowed_inner_type(inner_type);
+        // The base type of `extends = ` may have sanitizers as well
+        let check_validation = if sanitizers.is_empty()
+            && conditional.sanitizers.is_empty()
+            && error.extends.is_none()
+        {
+            quote! {
+                validate(raw_value)?;
+                Ok(())
+            }
+        } else if is_inner_clone {
+            let sanitize =
+                Self::gen_fn_sanitize_extended(inner_type, sanitizers, conditional, error);
+            // The borrowed inner type of String types is `str`, so it needs `ToOwned`
+            let to_owned = match gen_alloc_path() {
+                Some(alloc) => quote!(#alloc::borrow::ToOwned::to_owned),
+                None => quote!(::core::clone::Clone::clone),
+            };
+            quote! {
+                #sanitize
+                let sanitized_value: #inner_type = sanitize(#to_owned(raw_value));
+                validate(&sanitized_value)?;
+                Ok(())
+            }
+        } else {
+            let msg = format!("`methods(validate)` requires the inner type of {type_name} to be `Clone`, because the sanitizers run on a copy of the value.\nDerive `Clone` or remove `validate` from `methods(...)`.");
+            return Err(syn::Error::new(span, msg));
+        };
+
+        Ok(quote!(
+            impl #type_name {
+                /// Runs the sanitizers and validators against the value without constructing the newtype.
+                pub fn validate(raw_value: &#borrowed_inner_type) -> ::core::result::Result<(), #return_error_type_name> {
                     #validate
+                    #check_validation
+                }
+            }
+        ))
+    }
+
+    /// Generate `set()`, which replaces the inner value only if the new value passes the guard.
+    fn gen_set(
+        type_name: &TypeName,
+        inner_type: &Self::InnerType,
+        error_type_name: &ErrorTypeName,
+        error: &ErrorAttributes,
+    ) -> TokenStream {
+        let return_error_type_name = gen_return_error_type_name(type_name, error_type_name, error);
+        let input_type = if Self::NEW_CONVERT_INTO_INNER_TYPE {
+            quote!(impl Into<#inner_type>)
+        } else {
+            quote!(#inner_type)
+        };
+
+        quote!(
+            impl #type_name {
+                /// Replaces the value with a new one, keeping the old value if the new one is invalid.
+                pub fn set(&mut self, raw_value: #input_type) -> ::core::result::Result<(), #return_error_type_name> {
+                    *self = Self::__nutype_new(raw_value)?;
+                    Ok(())
+                }
+            }
+        )
+    }
+
+    /// Generate `sanitize()`, which applies only the sanitizers to the value.
+    fn gen_sanitize_only(
+        type_name: &TypeName,
+        inner_type: &Self::InnerType,
+        sanitizers: &[Self::Sanitizer],
+        conditional: &ConditionalRules<Self::Sanitizer, Self::Validator>,
+        error: &ErrorAttributes,
+        const_fn: ConstFn,
+    ) -> TokenStream {
+        if sanitizers.is_empty() && conditional.sanitizers.is_empty() && error.extends.is_none() {
+            return quote!();
+        }
+        let sanitize = Self::gen_fn_sanitize_extended(inner_type, sanitizers, conditional, error);
+        let (input_type, convert_raw_value_if_necessary) = if Self::NEW_CONVERT_INTO_INNER_TYPE {
+            (
+                quote!(impl Into<#inner_type>),
+                quote!(let raw_value = raw_value.into();),
+            )
+        } else {
+            (quote!(#inner_type), quote!())
+        };
+
+        quote!(
+            impl #type_name {
+                /// Applies the sanitizers to the value, without validating it.
+                pub #const_fn fn sanitize(raw_value: #input_type) -> #inner_type {
+                    #const_fn #sanitize
+
+                    #convert_raw_value_if_necessary
+
+                    sanitize(raw_value)
+                }
+            }
+        )
+    }
+
+    /// The type `validate()` receives a reference to.
+    fn gen_borrowed_inner_type(inner_type: &Self::InnerType) -> TokenStream {
+        quote!(#inner_type)
+    }
+
+    /// Generate `new_collecting_violations()`, which unlike `new()` does not stop on the first
+    /// violated validator, but returns errors of all of them.
+    #[allow(clippy::too_many_arguments)]
+    fn gen_new_collecting_violations(
+        type_name: &TypeName,
+        inner_type: &Self::InnerType,
+        error_type_name: &ErrorTypeName,
+        sanitizers: &[Self::Sanitizer],
+        validators: &[Self::Validator],
+        conditional: &ConditionalRules<Self::Sanitizer, Self::Validator>,
+        error: &ErrorAttributes,
+        constructor_vis: &Visibility,
+    ) -> TokenStream {
+        let sanitize = Self::gen_fn_sanitize_extended(inner_type, sanitizers, conditional, error);
+
+        // The validators of the base type of `extends = ` are checked at once.
+        let base_check = error.extends.as_ref().map(|base| {
+            let variant = gen_extends_variant_name(base);
+            quote! {
+                if let Err(err) = (<#base>::__NUTYPE_VALIDATE)(&sanitized_value) {
+                    violations.push(#error_type_name::#variant(err));
+                }
+            }
+        });
+
+        // Every validator gets its own `validate()` function within a separate block.
+        let checks = gen_steps(validators, &conditional.validators, |validator| {
+            let validate = Self::gen_fn_validate(
+                inner_type,
+                error_type_name,
+                std::slice::from_ref(validator),
+                error,
+            );
+            quote! {
+                {
+                    #validate
+                    if let Err(err) = validate(&sanitized_value) {
+                        violations.push(err);
+                    }
+                }
+            }
+        });
+
+        let (input_type, convert_raw_value_if_necessary) = if Self::NEW_CONVERT_INTO_INNER_TYPE {
+            (
+                quote!(impl Into<#inner_type>),
+                quote!(let raw_value = raw_value.into();),
+            )
+        } else {
+            (quote!(#inner_type), quote!())
+        };
+
+        // `all_violations` is rejected without `alloc` feature, see `parse_attributes()`
+        let Some(alloc) = gen_alloc_path() else {
+            return quote!();
+        };
+
+        quote!(
+            impl #type_name {
+                #constructor_vis fn new_collecting_violations(raw_value: #input_type) -> ::core::result::Result<Self, #alloc::vec::Vec<#error_type_name>> {
+                    #sanitize
 
                     #convert_raw_value_if_necessary
 
                     let sanitized_value: #inner_type = sanitize(raw_value);
-                    validate(&sanitized_value)?;
-                    Ok(#type_name(sanitized_value))
+                    let mut violations: #alloc::vec::Vec<#error_type_name> = #alloc::vec::Vec::new();
+                    #base_check
+                    #(#checks)*
+                    if violations.is_empty() {
+                        Ok(#type_name(sanitized_value))
+                    } else {
+                        Err(violations)
+                    }
                 }
             }
         )
@@ -229,8 +828,21 @@ pub trait GenerateNewtype {
         type_name: &TypeName,
         inner_type: &Self::InnerType,
         sanitizers: &[Self::Sanitizer],
+        conditional: &ConditionalRules<Self::Sanitizer, Self::Validator>,
+        constructor: &Ident,
+        constructor_vis: &Visibility,
+        const_fn: ConstFn,
     ) -> TokenStream {
-        let sanitize = Self::gen_fn_sanitize(inner_type, sanitizers);
+        let sanitize = if conditional.sanitizers.is_empty() {
+            Self::gen_fn_sanitize(inner_type, sanitizers)
+        } else {
+            gen_fn_sanitize_conditional(
+                inner_type,
+                sanitizers,
+                &conditional.sanitizers,
+                |sanitizers| Self::gen_fn_sanitize(inner_type, sanitizers),
+            )
+        };
 
         let (input_type, convert_raw_value_if_necessary) = if Self::NEW_CONVERT_INTO_INNER_TYPE {
             (
@@ -241,42 +853,710 @@ pub trait GenerateNewtype {
             (quote!(#inner_type), quote!())
         };
 
+        // Without sanitizers the constructor only wraps the value, so it can always be `const`
+        // (unless the value has to be converted with `.into()` first).
+        let const_fn = if sanitizers.is_empty()
+            && conditional.sanitizers.is_empty()
+            && !Self::NEW_CONVERT_INTO_INNER_TYPE
+        {
+            ConstFn::On
+        } else {
+            const_fn
+        };
+
         quote!(
             impl #type_name {
-                pub fn new(raw_value: #input_type) -> Self {
-                    #sanitize
+                #constructor_vis #const_fn fn #constructor(raw_value: #input_type) -> Self {
+                    #const_fn #sanitize
 
                     #convert_raw_value_if_necessary
 
                     Self(sanitize(raw_value))
                 }
+
+                #[inline]
+                #[allow(dead_code)]
+                #const_fn fn __nutype_new(raw_value: #input_type) -> Self {
+                    Self::#constructor(raw_value)
+                }
+            }
+        )
+    }
+
+    /// Generate module-private `new_without_sanitization()`, which is used by `Deserialize`
+    /// with `serde(skip_sanitization)`.
+    fn gen_new_without_sanitization(
+        type_name: &TypeName,
+        inner_type: &Self::InnerType,
+        error_type_name: &ErrorTypeName,
+        validators: &[Self::Validator],
+        conditional: &ConditionalRules<Self::Sanitizer, Self::Validator>,
+        error: &ErrorAttributes,
+    ) -> TokenStream {
+        let validate = Self::gen_fn_validate_extended(
+            inner_type,
+            error_type_name,
+            validators,
+            conditional,
+            error,
+        );
+
+        quote!(
+            impl #type_name {
+                fn new_without_sanitization(raw_value: #inner_type) -> ::core::result::Result<Self, #error_type_name> {
+                    #validate
+
+                    validate(&raw_value)?;
+                    Ok(#type_name(raw_value))
+                }
+            }
+        )
+    }
+
+    /// Generate `try_mutate()`, which changes the inner value in place and re-runs the sanitizers
+    /// and validators, leaving the value untouched if the result is invalid.
+    fn gen_try_mutate(
+        type_name: &TypeName,
+        inner_type: &Self::InnerType,
+        error_type_name: &ErrorTypeName,
+        sanitizers: &[Self::Sanitizer],
+        validators: &[Self::Validator],
+        conditional: &ConditionalRules<Self::Sanitizer, Self::Validator>,
+        error: &ErrorAttributes,
+    ) -> TokenStream {
+        let sanitize = Self::gen_fn_sanitize_extended(inner_type, sanitizers, conditional, error);
+        let validate = Self::gen_fn_validate_extended(
+            inner_type,
+            error_type_name,
+            validators,
+            conditional,
+            error,
+        );
+        let return_error_type_name = gen_return_error_type_name(type_name, error_type_name, error);
+
+        // The bound is higher-ranked, so it's checked only when `try_mutate()` is used:
+        // the inner type of `Any` newtypes does not have to implement `Clone`.
+        quote!(
+            impl #type_name {
+                /// Applies `f` to the inner value, then sanitizes and validates the result.
+                /// If the result is invalid, the value stays unchanged and the error is returned.
+                pub fn try_mutate(&mut self, f: impl FnOnce(&mut #inner_type)) -> ::core::result::Result<(), #return_error_type_name>
+                where
+                    for<'a> #inner_type: ::core::clone::Clone,
+                {
+                    #sanitize
+                    #validate
+
+                    let mut value: #inner_type = ::core::clone::Clone::clone(&self.0);
+                    f(&mut value);
+                    let value = sanitize(value);
+                    validate(&value)?;
+                    self.0 = value;
+                    Ok(())
+                }
+            }
+        )
+    }
+
+    /// Generate `new_or_default()` for types with validation and `default = `, which falls back
+    /// to the default value instead of returning an error.
+    fn gen_new_or_default(
+        type_name: &TypeName,
+        inner_type: &Self::InnerType,
+        default_value: &syn::Expr,
+        constructor_vis: &Visibility,
+    ) -> TokenStream {
+        let default = gen_default_value(type_name, default_value, true);
+        let input_type = if Self::NEW_CONVERT_INTO_INNER_TYPE {
+            quote!(impl Into<#inner_type>)
+        } else {
+            quote!(#inner_type)
+        };
+
+        quote!(
+            impl #type_name {
+                #constructor_vis fn new_or_default(raw_value: #input_type) -> Self {
+                    Self::__nutype_new(raw_value).unwrap_or_else(|_| #default)
+                }
             }
         )
     }
 
+    /// Generate helpers of a `#[repr(transparent)]` type, which convert slices and vectors
+    /// between the newtype and the inner type without copying.
+    /// The inner values are converted into the newtype only if there are no sanitizers,
+    /// since the values cannot be changed in place.
+    #[allow(clippy::too_many_arguments)]
+    fn gen_repr_transparent_helpers(
+        type_name: &TypeName,
+        inner_type: &Self::InnerType,
+        error_type_name: &ErrorTypeName,
+        guard: &Guard<Self::Sanitizer, Self::Validator>,
+        conditional: &ConditionalRules<Self::Sanitizer, Self::Validator>,
+        error: &ErrorAttributes,
+        has_drop: bool,
+        secret: Secret,
+        constructor_vis: &Visibility,
+    ) -> TokenStream {
+        // Secret types do not give access to the inner value
+        let impl_as_inner_slice = match secret {
+            Secret::Off => quote!(
+                /// Views the values as the inner values without copying.
+                pub fn as_inner_slice(values: &[Self]) -> &[#inner_type] {
+                    // SAFETY: the type is `#[repr(transparent)]`, so it has the layout of the inner type
+                    unsafe { &*(values as *const [Self] as *const [#inner_type]) }
+                }
+            ),
+            Secret::On { .. } => quote!(),
+        };
+        // The conversions of vectors are generated only if `Vec` is available
+        let alloc = gen_alloc_path();
+        let gen_with_vec = |gen: &dyn Fn(&TokenStream) -> TokenStream| match &alloc {
+            Some(alloc) => gen(alloc),
+            None => quote!(),
+        };
+        // Reinterpreting the vector would skip `Drop` of the newtype
+        let impl_into_inner_vec = if secret == Secret::Off && !has_drop {
+            gen_with_vec(&|alloc| {
+                quote!(
+                    /// Converts the values into the inner values reusing the allocation.
+                    pub fn into_inner_vec(values: #alloc::vec::Vec<Self>) -> #alloc::vec::Vec<#inner_type> {
+                        let mut values = ::core::mem::ManuallyDrop::new(values);
+                        let (ptr, len, capacity) = (values.as_mut_ptr(), values.len(), values.capacity());
+                        // SAFETY: the type is `#[repr(transparent)]`, so it has the layout of the inner type
+                        unsafe { #alloc::vec::Vec::from_raw_parts(ptr as *mut #inner_type, len, capacity) }
+                    }
+                )
+            })
+        } else {
+            quote!()
+        };
+
+        let impl_from_inner = match guard {
+            Guard::WithoutValidation { sanitizers }
+                if sanitizers.is_empty() && conditional.sanitizers.is_empty() =>
+            {
+                let impl_from_inner_vec = gen_with_vec(&|alloc| {
+                    quote!(
+                        /// Converts the inner values into the values reusing the allocation.
+                        #constructor_vis fn from_inner_vec(raw_values: #alloc::vec::Vec<#inner_type>) -> #alloc::vec::Vec<Self> {
+                            let mut raw_values = ::core::mem::ManuallyDrop::new(raw_values);
+                            let (ptr, len, capacity) = (raw_values.as_mut_ptr(), raw_values.len(), raw_values.capacity());
+                            // SAFETY: the type is `#[repr(transparent)]` and there are no guards
+                            unsafe { #alloc::vec::Vec::from_raw_parts(ptr as *mut Self, len, capacity) }
+                        }
+                    )
+                });
+                quote!(
+                    /// Views the inner values as the values without copying.
+                    #constructor_vis fn from_inner_slice(raw_values: &[#inner_type]) -> &[Self] {
+                        // SAFETY: the type is `#[repr(transparent)]` and there are no guards
+                        unsafe { &*(raw_values as *const [#inner_type] as *const [Self]) }
+                    }
+
+                    #impl_from_inner_vec
+                )
+            }
+            // The base type of `extends = ` may have sanitizers
+            Guard::WithValidation {
+                sanitizers,
+                validators,
+            } if sanitizers.is_empty()
+                && conditional.sanitizers.is_empty()
+                && error.extends.is_none() =>
+            {
+                let validate = Self::gen_fn_validate_extended(
+                    inner_type,
+                    error_type_name,
+                    validators,
+                    conditional,
+                    error,
+                );
+                let return_error_type_name =
+                    gen_return_error_type_name(type_name, error_type_name, error);
+                let impl_try_from_inner_vec = gen_with_vec(&|alloc| {
+                    quote!(
+                        /// Validates the inner values and converts them into the values reusing the allocation.
+                        /// The error contains the index of the first invalid value.
+                        #constructor_vis fn try_from_inner_vec(raw_values: #alloc::vec::Vec<#inner_type>) -> ::core::result::Result<#alloc::vec::Vec<Self>, (usize, #return_error_type_name)> {
+                            #validate
+                            for (index, raw_value) in raw_values.iter().enumerate() {
+                                validate(raw_value).map_err(|err| (index, ::core::convert::From::from(err)))?;
+                            }
+                            let mut raw_values = ::core::mem::ManuallyDrop::new(raw_values);
+                            let (ptr, len, capacity) = (raw_values.as_mut_ptr(), raw_values.len(), raw_values.capacity());
+                            // SAFETY: the type is `#[repr(transparent)]` and all the values are valid
+                            Ok(unsafe { #alloc::vec::Vec::from_raw_parts(ptr as *mut Self, len, capacity) })
+                        }
+                    )
+                });
+                quote!(
+                    /// Validates the inner values and views them as the values without copying.
+                    /// The error contains the index of the first invalid value.
+                    #constructor_vis fn try_from_inner_slice(raw_values: &[#inner_type]) -> ::core::result::Result<&[Self], (usize, #return_error_type_name)> {
+                        #validate
+                        for (index, raw_value) in raw_values.iter().enumerate() {
+                            validate(raw_value).map_err(|err| (index, ::core::convert::From::from(err)))?;
+                        }
+                        // SAFETY: the type is `#[repr(transparent)]` and all the values are valid
+                        Ok(unsafe { &*(raw_values as *const [#inner_type] as *const [Self]) })
+                    }
+
+                    #impl_try_from_inner_vec
+                )
+            }
+            _ => quote!(),
+        };
+
+        quote!(
+            impl #type_name {
+                #impl_as_inner_slice
+                #impl_into_inner_vec
+                #impl_from_inner
+            }
+        )
+    }
+
+    /// Generate `try_new_all()`, which constructs values out of every item of an iterator and
+    /// reports the index of the first invalid item. With `error(all_violations)`
+    /// `try_new_all_collecting_violations()` is generated as well, reporting all the invalid items.
+    fn gen_try_new_all(
+        type_name: &TypeName,
+        inner_type: &Self::InnerType,
+        error_type_name: &ErrorTypeName,
+        error: &ErrorAttributes,
+        constructor_vis: &Visibility,
+    ) -> TokenStream {
+        let Some(alloc) = gen_alloc_path() else {
+            return quote!();
+        };
+        let return_error_type_name = gen_return_error_type_name(type_name, error_type_name, error);
+        let iter_bounds = if Self::NEW_CONVERT_INTO_INNER_TYPE {
+            quote!(I: IntoIterator, I::Item: Into<#inner_type>)
+        } else {
+            quote!(I: IntoIterator<Item = #inner_type>)
+        };
+
+        let impl_collecting_violations = if error.all_violations {
+            quote!(
+                /// Constructs values out of every item, returning the indices of all invalid
+                /// items together with their violations.
+                #constructor_vis fn try_new_all_collecting_violations<I>(iter: I) -> ::core::result::Result<#alloc::vec::Vec<Self>, #alloc::vec::Vec<(usize, #alloc::vec::Vec<#error_type_name>)>>
+                where
+                    #iter_bounds
+                {
+                    let mut values = #alloc::vec::Vec::new();
+                    let mut failures = #alloc::vec::Vec::new();
+                    for (index, raw_value) in iter.into_iter().enumerate() {
+                        match Self::new_collecting_violations(raw_value) {
+                            Ok(value) => values.push(value),
+                            Err(violations) => failures.push((index, violations)),
+                        }
+                    }
+                    if failures.is_empty() {
+                        Ok(values)
+                    } else {
+                        Err(failures)
+                    }
+                }
+            )
+        } else {
+            quote!()
+        };
+
+        quote!(
+            impl #type_name {
+                /// Constructs values out of every item, stopping on the first invalid one.
+                /// The error contains the index of the invalid item.
+                #constructor_vis fn try_new_all<I>(iter: I) -> ::core::result::Result<#alloc::vec::Vec<Self>, (usize, #return_error_type_name)>
+                where
+                    #iter_bounds
+                {
+                    iter.into_iter()
+                        .enumerate()
+                        .map(|(index, raw_value)| Self::__nutype_new(raw_value).map_err(|err| (index, err)))
+                        .collect()
+                }
+
+                #impl_collecting_violations
+            }
+        )
+    }
+
+    /// Generate the parameters of the factory type: one per validator, which bound can be set
+    /// at runtime.
+    fn gen_factory_params(
+        _inner_type: &Self::InnerType,
+        _error_type_name: &ErrorTypeName,
+        _validators: &[Self::Validator],
+        _error: &ErrorAttributes,
+    ) -> Vec<FactoryParam> {
+        Vec::new()
+    }
+
+    /// Names and values of the well-known constants, that are generated for the type.
+    /// Only the values, which are known to satisfy the validators, are returned.
+    fn well_known_constants(
+        _inner_type: &Self::InnerType,
+        _validators: &[Self::Validator],
+        _error: &ErrorAttributes,
+    ) -> Vec<(&'static str, TokenStream)> {
+        Vec::new()
+    }
+
+    /// Generate `MIN_RAW` and `MAX_RAW`: the smallest and the greatest values of the inner type,
+    /// that satisfy the bounds of the validators. Other types can refer to them in their
+    /// validators, so the bounds are defined once. Only numeric types support it.
+    fn gen_raw_bounds(
+        _type_name: &TypeName,
+        _inner_type: &Self::InnerType,
+        _validators: &[Self::Validator],
+        _conditional: &[ConditionalRule<Self::Validator>],
+        _error: &ErrorAttributes,
+    ) -> TokenStream {
+        quote!()
+    }
+
+    /// Generate the methods listed in `delegate(...)`, which forward to the inner value.
+    /// Only string types support it.
+    fn gen_delegated_methods(_type_name: &TypeName, _methods: &[Ident]) -> TokenStream {
+        quote!()
+    }
+
+    /// Generate `From` conversions into the types listed in `widen_into(...)` together with
+    /// the hidden helpers, that are used by the conversions of other types into this one.
+    /// Only integer types support it.
+    #[allow(clippy::too_many_arguments)]
+    fn gen_widen_into(
+        _type_name: &TypeName,
+        _inner_type: &Self::InnerType,
+        _guard: &Guard<Self::Sanitizer, Self::Validator>,
+        _conditional: &ConditionalRules<Self::Sanitizer, Self::Validator>,
+        _error: &ErrorAttributes,
+        _constructor: &Ident,
+        _constructor_vis: &Visibility,
+        _targets: &[syn::Path],
+    ) -> TokenStream {
+        quote!()
+    }
+
+    /// Generate `new_interned()`, which shares the storage of equal values.
+    /// Only string types support it, with `intern` feature enabled.
+    fn gen_new_interned(
+        _type_name: &TypeName,
+        _maybe_return_error_type_name: Option<&ErrorTypeName>,
+        _constructor_vis: &Visibility,
+    ) -> TokenStream {
+        quote!()
+    }
+
+    /// Generate `leak()`, which turns the value into a `&'static` reference.
+    /// Only string types support it.
+    fn gen_leak(_type_name: &TypeName) -> TokenStream {
+        quote!()
+    }
+
+    /// Generate `TryFrom` for the types, that can be converted into the inner type without loss.
+    /// Used with `try_from_lossless` flag.
+    fn gen_try_from_lossless(
+        _type_name: &TypeName,
+        _inner_type: &Self::InnerType,
+        _maybe_error_type_name: Option<&ErrorTypeName>,
+    ) -> TokenStream {
+        quote!()
+    }
+
+    /// Generate `new_clamped()`, which moves the value into the range set by the validators
+    /// instead of returning an error. Only numeric types with range validators support it.
+    fn gen_new_clamped(
+        _type_name: &TypeName,
+        _inner_type: &Self::InnerType,
+        _sanitizers: &[Self::Sanitizer],
+        _validators: &[Self::Validator],
+        _constructor_vis: &Visibility,
+    ) -> TokenStream {
+        quote!()
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn gen_implementation(
         type_name: &TypeName,
         inner_type: &Self::InnerType,
+        error_type_name: &ErrorTypeName,
+        error: &ErrorAttributes,
         guard: &Guard<Self::Sanitizer, Self::Validator>,
+        conditional: &ConditionalRules<Self::Sanitizer, Self::Validator>,
         new_unchecked: NewUnchecked,
-    ) -> TokenStream {
+        traits: &HashSet<Self::TypedTrait>,
+        secret: Secret,
+        constructor: &Ident,
+        constructor_vis: &Visibility,
+        const_fn: ConstFn,
+        serde: &SerdeAttributes,
+        methods: OptionalMethods,
+    ) -> Result<TokenStream, syn::Error> {
         let impl_new = match guard {
-            Guard::WithoutValidation { sanitizers } => {
-                Self::gen_new_without_validation(type_name, inner_type, sanitizers)
+            Guard::WithoutValidation { sanitizers } => Self::gen_new_without_validation(
+                type_name,
+                inner_type,
+                sanitizers,
+                conditional,
+                constructor,
+                constructor_vis,
+                const_fn,
+            ),
+            Guard::WithValidation {
+                sanitizers,
+                validators,
+            } => {
+                let impl_new_with_validation = Self::gen_new_with_validation(
+                    type_name,
+                    inner_type,
+                    error_type_name,
+                    sanitizers,
+                    validators,
+                    conditional,
+                    error,
+                    constructor,
+                    constructor_vis,
+                    const_fn,
+                );
+                let impl_from_literal = gen_impl_from_literal(
+                    type_name,
+                    inner_type,
+                    constructor,
+                    constructor_vis,
+                    const_fn,
+                );
+                // A clamped value may still violate the validators of the base type.
+                // The range of the conditional validators is not known at expansion time.
+                let impl_new_clamped = match error.extends {
+                    None if conditional.is_empty() => Self::gen_new_clamped(
+                        type_name,
+                        inner_type,
+                        sanitizers,
+                        validators,
+                        constructor_vis,
+                    ),
+                    _ => quote!(),
+                };
+                let impl_try_new_all = if methods.try_new_all {
+                    Self::gen_try_new_all(
+                        type_name,
+                        inner_type,
+                        error_type_name,
+                        error,
+                        constructor_vis,
+                    )
+                } else {
+                    quote!()
+                };
+                let impl_set = if methods.set {
+                    Self::gen_set(type_name, inner_type, error_type_name, error)
+                } else {
+                    quote!()
+                };
+                let is_inner_clone =
+                    Self::INNER_TYPE_IS_CLONE || traits.iter().any(|t| t.is_clone());
+                let impl_validate_only = match methods.validate {
+                    Some(span) => Self::gen_validate_only(
+                        type_name,
+                        inner_type,
+                        error_type_name,
+                        sanitizers,
+                        validators,
+                        conditional,
+                        error,
+                        is_inner_clone,
+                        span,
+                    )?,
+                    None => quote!(),
+                };
+                let impl_extension_helpers = gen_extension_helpers(
+                    type_name,
+                    inner_type.to_token_stream(),
+                    Self::gen_borrowed_inner_type(inner_type),
+                    error_type_name,
+                    Self::gen_fn_sanitize_extended(inner_type, sanitizers, conditional, error),
+                    Self::gen_fn_validate_extended(
+                        inner_type,
+                        error_type_name,
+                        validators,
+                        conditional,
+                        error,
+                    ),
+                );
+                quote! {
+                    #impl_new_with_validation
+                    #impl_extension_helpers
+                    #impl_from_literal
+                    #impl_new_clamped
+                    #impl_try_new_all
+                    #impl_set
+                    #impl_validate_only
+                }
+            }
+        };
+        let impl_sanitize_only = match guard {
+            Guard::WithoutValidation { sanitizers } | Guard::WithValidation { sanitizers, .. } => {
+                Self::gen_sanitize_only(
+                    type_name,
+                    inner_type,
+                    sanitizers,
+                    conditional,
+                    error,
+                    const_fn,
+                )
             }
+        };
+        let validators: &[Self::Validator] = match guard {
+            Guard::WithoutValidation { .. } => &[],
+            Guard::WithValidation { validators, .. } => validators,
+        };
+        let impl_raw_bounds = Self::gen_raw_bounds(
+            type_name,
+            inner_type,
+            validators,
+            &conditional.validators,
+            error,
+        );
+        // The constants are checked against the validators at expansion time
+        let impl_well_known_constants = if conditional.is_empty() {
+            gen_well_known_constants(
+                type_name,
+                Self::well_known_constants(inner_type, validators, error),
+                guard,
+                error,
+            )
+        } else {
+            quote!()
+        };
+        // With validation `new_unchecked()` checks the value (without sanitizing it) when
+        // `debug_assertions` are enabled, so misuse is caught in tests and development.
+        let debug_check = match (guard, &new_unchecked) {
+            (
+                Guard::WithValidation { validators, .. },
+                NewUnchecked::On {
+                    debug_assert: true, ..
+                },
+            ) => {
+                let validate = Self::gen_fn_validate_extended(
+                    inner_type,
+                    error_type_name,
+                    validators,
+                    conditional,
+                    error,
+                );
+                let msg = format!(
+                    "Invalid {type_name} passed to new_unchecked(): the value violates the validation rules"
+                );
+                quote! {
+                    #[cfg(debug_assertions)]
+                    {
+                        #const_fn #validate
+                        if validate(&inner_value).is_err() {
+                            panic!(#msg);
+                        }
+                    }
+                }
+            }
+            _ => quote!(),
+        };
+        let has_drop = secret.is_on() || traits.iter().any(|t| t.is_drop());
+        let impl_into_inner =
+            gen_impl_into_inner(type_name, inner_type, has_drop, secret, const_fn);
+        let impl_new_unchecked = gen_new_unchecked(
+            type_name,
+            inner_type,
+            new_unchecked,
+            secret,
+            const_fn,
+            debug_check,
+        );
+        let is_debug = traits.iter().any(|t| t.is_debug());
+        let impl_secret = gen_secret(
+            type_name,
+            inner_type,
+            secret,
+            is_debug,
+            guard.has_validation(),
+        );
+        let impl_new_collecting_violations = match guard {
             Guard::WithValidation {
                 sanitizers,
                 validators,
-            } => Self::gen_new_with_validation(type_name, inner_type, sanitizers, validators),
+            } if error.all_violations => Self::gen_new_collecting_violations(
+                type_name,
+                inner_type,
+                error_type_name,
+                sanitizers,
+                validators,
+                conditional,
+                error,
+                constructor_vis,
+            ),
+            _ => quote!(),
+        };
+        // Secret types do not give access to the inner value
+        let impl_try_mutate = match guard {
+            Guard::WithValidation {
+                sanitizers,
+                validators,
+            } if methods.try_mutate && secret == Secret::Off => Self::gen_try_mutate(
+                type_name,
+                inner_type,
+                error_type_name,
+                sanitizers,
+                validators,
+                conditional,
+                error,
+            ),
+            _ => quote!(),
+        };
+        let impl_map = match guard {
+            Guard::WithoutValidation { .. } if methods.map => {
+                gen_impl_map(type_name, inner_type, None)
+            }
+            Guard::WithValidation { .. } if methods.try_map => {
+                let return_error_type_name =
+                    gen_return_error_type_name(type_name, error_type_name, error);
+                gen_impl_map(type_name, inner_type, Some(&return_error_type_name))
+            }
+            _ => quote!(),
+        };
+        let impl_leak = if methods.leak {
+            Self::gen_leak(type_name)
+        } else {
+            quote!()
+        };
+        let impl_new_without_sanitization = match guard {
+            Guard::WithValidation { validators, .. } if serde.skip_sanitization => {
+                Self::gen_new_without_sanitization(
+                    type_name,
+                    inner_type,
+                    error_type_name,
+                    validators,
+                    conditional,
+                    error,
+                )
+            }
+            _ => quote!(),
         };
-        let impl_into_inner = gen_impl_into_inner(type_name, inner_type);
-        let impl_new_unchecked = gen_new_unchecked(type_name, inner_type, new_unchecked);
 
-        quote! {
+        Ok(quote! {
             #impl_new
+            #impl_raw_bounds
+            #impl_well_known_constants
+            #impl_sanitize_only
             #impl_into_inner
             #impl_new_unchecked
-        }
+            #impl_secret
+            #impl_new_without_sanitization
+            #impl_new_collecting_violations
+            #impl_try_mutate
+            #impl_map
+            #impl_leak
+        })
     }
 
     #[allow(clippy::type_complexity)]
@@ -285,26 +1565,204 @@ pub trait GenerateNewtype {
             Self::InnerType,
             Self::TypedTrait,
             Guard<Self::Sanitizer, Self::Validator>,
+            ConditionalRules<Self::Sanitizer, Self::Validator>,
         >,
     ) -> Result<TokenStream, syn::Error> {
         let GenerateParams {
-            doc_attrs,
+            struct_attrs,
             traits,
             vis,
             type_name,
+            is_generic,
             guard,
+            conditional,
             new_unchecked,
             maybe_default_value,
             inner_type,
+            ops,
+            secret,
+            const_fn,
+            constructor,
+            constructor_vis,
+            factory,
+            try_from_lossless,
+            delegate,
+            accessor,
+            widen_into,
+            derive_unsafe,
+            repr,
+            local,
+            methods,
+            serde,
+            error,
         } = params;
 
         let module_name = gen_module_name_for_type(&type_name);
-        let implementation =
-            Self::gen_implementation(&type_name, &inner_type, &guard, new_unchecked);
+        let constructor = constructor.unwrap_or_else(|| format_ident!("new"));
+        let constructor_vis = constructor_vis
+            .as_ref()
+            .map_or_else(|| syn::parse_quote!(pub), gen_nested_visibility);
+        let error_type_name = error
+            .name
+            .clone()
+            .unwrap_or_else(|| gen_error_type_name(&type_name));
+        let return_error_type_name =
+            gen_return_error_type_name(&type_name, &error_type_name, &error);
+        let invariants_doc = gen_invariants_doc(&guard, &conditional, &error);
+        let validation_error = match &guard {
+            Guard::WithoutValidation { .. } => quote!(),
+            Guard::WithValidation { validators, .. } => {
+                let validation_error_type = Self::gen_validation_error_type(
+                    &type_name,
+                    &inner_type,
+                    &error_type_name,
+                    validators,
+                    &error,
+                );
+                let validation_error_type = if conditional.validators.is_empty() {
+                    validation_error_type
+                } else {
+                    gen_conditional_error_type(
+                        validation_error_type,
+                        &error_type_name,
+                        &conditional.validators,
+                        |validators| {
+                            Self::gen_validation_error_type(
+                                &type_name,
+                                &inner_type,
+                                &error_type_name,
+                                validators,
+                                &error,
+                            )
+                        },
+                    )?
+                };
+                let impl_from_error_into =
+                    gen_impl_from_error_into(&type_name, &error_type_name, &error);
+                let return_error_type_alias =
+                    gen_return_error_type_alias(&return_error_type_name, &error);
+                quote! {
+                    #validation_error_type
+                    #impl_from_error_into
+                    #return_error_type_alias
+                }
+            }
+        };
+        let implementation = Self::gen_implementation(
+            &type_name,
+            &inner_type,
+            &error_type_name,
+            &error,
+            &guard,
+            &conditional,
+            new_unchecked,
+            &traits,
+            secret,
+            &constructor,
+            &constructor_vis,
+            const_fn,
+            &serde,
+            methods,
+        )?;
+
+        let impl_new_or_default = match (&guard, &maybe_default_value) {
+            (Guard::WithValidation { .. }, Some(default_value)) => {
+                Self::gen_new_or_default(&type_name, &inner_type, default_value, &constructor_vis)
+            }
+            _ => quote!(),
+        };
+
+        let maybe_factory = match &guard {
+            Guard::WithValidation { validators, .. } if factory == Factory::On => {
+                let factory_type_name = gen_factory_type_name(&type_name);
+                let params =
+                    Self::gen_factory_params(&inner_type, &error_type_name, validators, &error);
+                let input_type = if Self::NEW_CONVERT_INTO_INNER_TYPE {
+                    quote!(impl Into<#inner_type>)
+                } else {
+                    quote!(#inner_type)
+                };
+                let impl_factory = gen_factory(
+                    &type_name,
+                    &factory_type_name,
+                    input_type,
+                    &return_error_type_name,
+                    &error,
+                    &params,
+                );
+                Some((factory_type_name, impl_factory))
+            }
+            _ => None,
+        };
+        let (maybe_factory_type_name, impl_factory) = match maybe_factory {
+            Some((name, impl_factory)) => (Some(name), impl_factory),
+            None => (None, quote!()),
+        };
+
+        let (repr_attr, impl_repr_transparent_helpers) = match repr {
+            Repr::Rust => (quote!(), quote!()),
+            Repr::Transparent => {
+                let has_drop = secret.is_on() || traits.iter().any(|t| t.is_drop());
+                let helpers = Self::gen_repr_transparent_helpers(
+                    &type_name,
+                    &inner_type,
+                    &error_type_name,
+                    &guard,
+                    &conditional,
+                    &error,
+                    has_drop,
+                    secret,
+                    &constructor_vis,
+                );
+                (quote!(#[repr(transparent)]), helpers)
+            }
+        };
 
+        // The traits are implemented on top of `new()`, so they use the error type it returns.
+        let maybe_return_error_type_name: Option<ErrorTypeName> = match guard {
+            Guard::WithoutValidation { .. } => None,
+            Guard::WithValidation { .. } => Some(return_error_type_name),
+        };
         let maybe_error_type_name: Option<ErrorTypeName> = match guard {
             Guard::WithoutValidation { .. } => None,
-            Guard::WithValidation { .. } => Some(gen_error_type_name(&type_name)),
+            Guard::WithValidation { .. } => Some(error_type_name),
+        };
+
+        let impl_delegated_methods = Self::gen_delegated_methods(&type_name, &delegate);
+        let impl_accessor = gen_impl_accessor(&type_name, &inner_type, accessor.as_ref(), const_fn);
+        // Secret types must not be leaked. Generic types cannot have the static storage.
+        let impl_new_interned = match (secret, is_generic) {
+            (Secret::Off, false) => Self::gen_new_interned(
+                &type_name,
+                maybe_return_error_type_name.as_ref(),
+                &constructor_vis,
+            ),
+            (Secret::On { .. }, _) | (_, true) => quote!(),
+        };
+        let impl_widen_into = Self::gen_widen_into(
+            &type_name,
+            &inner_type,
+            &guard,
+            &conditional,
+            &error,
+            &constructor,
+            &constructor_vis,
+            &widen_into,
+        );
+
+        let impl_from_extension = match &error.extends {
+            Some(base) => gen_impl_from_extension(&type_name, base),
+            None => quote!(),
+        };
+
+        let impl_try_from_lossless = if try_from_lossless {
+            Self::gen_try_from_lossless(
+                &type_name,
+                &inner_type,
+                maybe_return_error_type_name.as_ref(),
+            )
+        } else {
+            quote!()
         };
 
         let has_from_str_trait = traits.iter().any(|t| t.is_from_str());
@@ -322,37 +1780,83 @@ pub trait GenerateNewtype {
             &traits,
         );
 
+        let error_integrations = error.integrations;
         let reimports = gen_reimports(
             vis,
             &type_name,
             &module_name,
             maybe_error_type_name.as_ref(),
+            error.vis,
+            error.module.as_ref(),
             maybe_parse_error_type_name.as_ref(),
+            maybe_factory_type_name.as_ref(),
         );
 
+        // A secret type gets a redacted `Debug` implementation instead of the derived one
+        let mut traits = traits;
+        if secret.is_on() {
+            traits.retain(|t| !t.is_debug());
+        }
+
         let GeneratedTraits {
             derive_transparent_traits,
             implement_traits,
         } = Self::gen_traits(
             &type_name,
             &inner_type,
-            maybe_error_type_name,
+            maybe_return_error_type_name,
             traits,
             maybe_default_value,
             &guard,
+            ops,
+            &serde,
+            error_integrations,
         )?;
 
+        // It's placed before the struct attributes, since derive helper attributes
+        // (e.g. `#[display(..)]`) must follow the derive.
+        let derive_unsafe_traits = if derive_unsafe.is_empty() {
+            quote!()
+        } else {
+            quote!(#[derive(#(#derive_unsafe),*)])
+        };
+
+        let items = quote! {
+            #derive_transparent_traits
+            #derive_unsafe_traits
+            #(#struct_attrs)*
+            #invariants_doc
+            #repr_attr
+            pub struct #type_name(#inner_type);
+
+            #validation_error
+            #implementation
+            #impl_new_or_default
+            #impl_factory
+            #impl_try_from_lossless
+            #impl_from_extension
+            #impl_delegated_methods
+            #impl_accessor
+            #impl_new_interned
+            #impl_widen_into
+            #impl_repr_transparent_helpers
+            #implement_traits
+        };
+
+        if local {
+            // A module within a function body cannot refer to the items of the function
+            // (e.g. a local inner type), so the items are generated in place.
+            // It's allowed only for types without a guard, see `validate_local()`.
+            return Ok(items);
+        }
+
         Ok(quote!(
             #[doc(hidden)]
+            #[allow(deprecated)]
             mod #module_name {
                 use super::*;
 
-                #(#doc_attrs)*
-                #derive_transparent_traits
-                pub struct #type_name(#inner_type);
-
-                #implementation
-                #implement_traits
+                #items
 
                 #tests
             }
@@ -1,4 +1,4 @@
-use crate::common::models::{NewUnchecked, TypeName};
+use crate::common::models::{ConstFn, NewUnchecked, Secret, TypeName};
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 
@@ -6,18 +6,42 @@ pub fn gen_new_unchecked(
     type_name: &TypeName,
     inner_type: impl ToTokens,
     new_unchecked: NewUnchecked,
+    secret: Secret,
+    const_fn: ConstFn,
+    debug_check: TokenStream,
 ) -> TokenStream {
     match new_unchecked {
         NewUnchecked::Off => quote! {},
-        NewUnchecked::On => quote! {
-            impl #type_name {
-                /// Creates a value of type skipping the sanitization and validation
-                /// rules. Generally, you should avoid using `::new_unchecked()` without a real need.
-                /// Use `::new()` instead when it's possible.
-                pub unsafe fn new_unchecked(inner_value: #inner_type) -> #type_name {
-                    #type_name(inner_value)
+        NewUnchecked::On { safe, .. } => {
+            // With `new_unchecked(safe)` the functions are not marked `unsafe`, so they can
+            // be used within crates with `#![forbid(unsafe_code)]`.
+            let maybe_unsafe = if safe { quote!() } else { quote!(unsafe) };
+
+            // Secret types do not give access to the inner value
+            let impl_as_mut_unchecked = match secret {
+                Secret::Off => quote! {
+                    /// Gives mutable access to the inner value skipping the sanitization and validation
+                    /// rules. The caller is responsible to keep the value valid.
+                    pub #maybe_unsafe fn as_mut_unchecked(&mut self) -> &mut #inner_type {
+                        &mut self.0
+                    }
+                },
+                Secret::On { .. } => quote! {},
+            };
+
+            quote! {
+                impl #type_name {
+                    /// Creates a value of type skipping the sanitization and validation
+                    /// rules. Generally, you should avoid using `::new_unchecked()` without a real need.
+                    /// Use `::new()` instead when it's possible.
+                    pub #const_fn #maybe_unsafe fn new_unchecked(inner_value: #inner_type) -> #type_name {
+                        #debug_check
+                        #type_name(inner_value)
+                    }
+
+                    #impl_as_mut_unchecked
                 }
             }
-        },
+        }
     }
 }
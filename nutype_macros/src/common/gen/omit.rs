@@ -0,0 +1,111 @@
+//! Generated items left out with `omit(...)`, e.g. `omit(try_from, set)`, so they don't conflict
+//! with hand-written implementations.
+//!
+//! An item is either a method of the type (e.g. `set` or `validate`) or a trait in snake case:
+//! `try_from` leaves out the implementations of `TryFrom` for the type, and `into` leaves out
+//! the implementations of `From<Type>` for other types (e.g. the inner type).
+
+use proc_macro2::{Ident, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{ImplItem, Item, ItemImpl, PathArguments, Type};
+
+use crate::common::{gen::error::to_snake_case, models::TypeName};
+
+pub fn apply_omit(
+    output: TokenStream,
+    type_name: &TypeName,
+    constructor: &Ident,
+    omit: &[Ident],
+) -> Result<TokenStream, syn::Error> {
+    if omit.is_empty() {
+        return Ok(output);
+    }
+    if let Some(item) = omit.iter().find(|item| *item == constructor) {
+        let msg = format!(
+            "The constructor `{item}` cannot be omitted, since other generated items depend on it."
+        );
+        return Err(syn::Error::new(item.span(), msg));
+    }
+
+    let mut file: syn::File = syn::parse2(output)?;
+    let mut omitted = vec![false; omit.len()];
+    let type_name = type_name.to_string();
+    omit_items(&mut file.items, &type_name, omit, &mut omitted);
+
+    for (item, omitted) in omit.iter().zip(omitted) {
+        if !omitted {
+            let msg = format!("There is no generated item `{item}` to omit. Expected a name of a generated method (e.g. `set`) or a derived trait in snake case (e.g. `try_from`).");
+            return Err(syn::Error::new(item.span(), msg));
+        }
+    }
+    Ok(file.into_token_stream())
+}
+
+fn omit_items(items: &mut Vec<Item>, type_name: &str, omit: &[Ident], omitted: &mut [bool]) {
+    items.retain_mut(|item| match item {
+        Item::Mod(item_mod) => {
+            if let Some((_, items)) = item_mod.content.as_mut() {
+                omit_items(items, type_name, omit, omitted);
+            }
+            true
+        }
+        Item::Impl(item_impl) => match trait_key(item_impl, type_name) {
+            Some(key) => !is_omitted(&key, omit, omitted),
+            None => {
+                if is_own_type(&item_impl.self_ty, type_name) && item_impl.trait_.is_none() {
+                    item_impl.items.retain(|impl_item| match impl_item {
+                        ImplItem::Fn(impl_fn) => {
+                            !is_omitted(&impl_fn.sig.ident.to_string(), omit, omitted)
+                        }
+                        _ => true,
+                    });
+                }
+                true
+            }
+        },
+        _ => true,
+    });
+}
+
+/// Tells whether the item is omitted, marking the entry of `omit(...)` as used.
+fn is_omitted(name: &str, omit: &[Ident], omitted: &mut [bool]) -> bool {
+    match omit.iter().position(|item| item == name) {
+        Some(index) => {
+            omitted[index] = true;
+            true
+        }
+        None => false,
+    }
+}
+
+/// The name to omit a trait implementation with, e.g. `try_from` for `impl TryFrom<String> for Type`
+/// and `into` for `impl From<Type> for String`.
+fn trait_key(item_impl: &ItemImpl, type_name: &str) -> Option<String> {
+    let (_, trait_path, _) = item_impl.trait_.as_ref()?;
+    let segment = trait_path.segments.last()?;
+    if is_own_type(&item_impl.self_ty, type_name) {
+        return Some(to_snake_case(&segment.ident.to_string()));
+    }
+    let is_into = segment.ident == "From"
+        && matches!(&segment.arguments, PathArguments::AngleBracketed(args) if mentions_ident(args.to_token_stream(), type_name));
+    is_into.then(|| "into".to_string())
+}
+
+fn is_own_type(tp: &Type, type_name: &str) -> bool {
+    match tp {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == type_name),
+        _ => false,
+    }
+}
+
+fn mentions_ident(tokens: TokenStream, name: &str) -> bool {
+    tokens.into_iter().any(|tree| match tree {
+        TokenTree::Ident(ident) => ident == name,
+        TokenTree::Group(group) => mentions_ident(group.stream(), name),
+        TokenTree::Punct(_) | TokenTree::Literal(_) => false,
+    })
+}
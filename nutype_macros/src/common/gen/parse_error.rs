@@ -2,7 +2,9 @@ use cfg_if::cfg_if;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
-use crate::common::models::{ErrorTypeName, InnerType, ParseErrorTypeName, TypeName};
+use crate::common::models::{
+    ErrorIntegrations, ErrorTypeName, InnerType, ParseErrorTypeName, TypeName,
+};
 
 /// Generate a name for the error which is used for FromStr trait implementation.
 pub fn gen_parse_error_name(type_name: &TypeName) -> ParseErrorTypeName {
@@ -11,22 +13,40 @@ pub fn gen_parse_error_name(type_name: &TypeName) -> ParseErrorTypeName {
 }
 
 /// Generate an error which is used for FromStr trait implementation of non-string types (e.g.
-/// floats or integers)
+/// floats or integers).
+/// With `error_integrations(thiserror)` the parse error derives `Error` with `thiserror`, so the underlying errors are
+/// available as `source()` and the validation error can be converted into the parse error with `?`.
 pub fn gen_def_parse_error(
     inner_type: impl Into<InnerType>,
     type_name: &TypeName,
     maybe_error_type_name: Option<&ErrorTypeName>,
     parse_error_type_name: &ParseErrorTypeName,
+    error_integrations: ErrorIntegrations,
 ) -> TokenStream {
     let inner_type: InnerType = inner_type.into();
     let type_name_str = type_name.to_string();
 
+    let (derive, parse_source, validate_from) = if error_integrations.thiserror {
+        // The parse error of an arbitrary inner type does not necessarily implement `Error`.
+        let parse_source = match inner_type {
+            InnerType::Any(_) => quote!(),
+            _ => quote!(#[source]),
+        };
+        (
+            quote!(#[derive(Debug, ::thiserror::Error)]),
+            parse_source,
+            quote!(#[from]),
+        )
+    } else {
+        (quote!(#[derive(Debug)]), quote!(), quote!())
+    };
+
     let definition = if let Some(error_type_name) = maybe_error_type_name {
         quote! {
-            #[derive(Debug)]
+            #derive
             pub enum #parse_error_type_name {
-                Parse(<#inner_type as ::core::str::FromStr>::Err),
-                Validate(#error_type_name),
+                Parse(#parse_source <#inner_type as ::core::str::FromStr>::Err),
+                Validate(#validate_from #error_type_name),
             }
 
             impl ::core::fmt::Display for #parse_error_type_name {
@@ -41,9 +61,9 @@ pub fn gen_def_parse_error(
         }
     } else {
         quote! {
-            #[derive(Debug)]
+            #derive
             pub enum #parse_error_type_name {
-                Parse(<#inner_type as ::core::str::FromStr>::Err),
+                Parse(#parse_source <#inner_type as ::core::str::FromStr>::Err),
             }
 
             impl ::core::fmt::Display for #parse_error_type_name {
@@ -65,12 +85,26 @@ pub fn gen_def_parse_error(
                     }
                 }
             };
+        } else if #[cfg(feature = "core_error")] {
+            let impl_std_error = quote! {
+                impl ::core::error::Error for #parse_error_type_name {
+                    fn source(&self) -> Option<&(dyn ::core::error::Error + 'static)> {
+                        None
+                    }
+                }
+            };
         } else {
-            // NOTE: There is no `::core::error::Error` yet in stable Rust.
-            // So for `no_std` we just don't implement `Error` trait.
+            // NOTE: `::core::error::Error` is stable only since Rust 1.81.
+            // So for `no_std` the `Error` trait is implemented only with `core_error` feature.
             let impl_std_error = quote! {};
         }
     };
+    // With `thiserror` the trait is derived
+    let impl_std_error = if error_integrations.thiserror {
+        quote!()
+    } else {
+        impl_std_error
+    };
 
     quote! {
         #definition
@@ -0,0 +1,75 @@
+use crate::common::models::{Secret, TypeName};
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+pub fn gen_secret(
+    type_name: &TypeName,
+    inner_type: impl ToTokens,
+    secret: Secret,
+    is_debug: bool,
+    has_validation: bool,
+) -> TokenStream {
+    match secret {
+        Secret::Off => quote! {},
+        Secret::On { serialize } => {
+            // `Serialize` of a secret type is rejected unless `secret(serialize)` is set
+            let impl_serializable_secret = if serialize {
+                quote! {
+                    impl ::secrecy::SerializableSecret for #type_name {}
+                }
+            } else {
+                quote! {}
+            };
+
+            let impl_debug = if is_debug {
+                let redacted = format!("{type_name}([REDACTED])");
+                quote! {
+                    impl ::core::fmt::Debug for #type_name {
+                        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                            f.write_str(#redacted)
+                        }
+                    }
+                }
+            } else {
+                quote! {}
+            };
+
+            // A zeroized value may violate the validation, so with validation the value is
+            // zeroized only on drop
+            let impl_zeroize = if has_validation {
+                quote! {}
+            } else {
+                quote! {
+                    impl ::secrecy::zeroize::Zeroize for #type_name {
+                        #[inline]
+                        fn zeroize(&mut self) {
+                            ::secrecy::zeroize::Zeroize::zeroize(&mut self.0);
+                        }
+                    }
+                }
+            };
+
+            quote! {
+                impl ::secrecy::ExposeSecret<#inner_type> for #type_name {
+                    #[inline]
+                    fn expose_secret(&self) -> &#inner_type {
+                        &self.0
+                    }
+                }
+
+                #impl_zeroize
+
+                impl ::core::ops::Drop for #type_name {
+                    fn drop(&mut self) {
+                        ::secrecy::zeroize::Zeroize::zeroize(&mut self.0);
+                    }
+                }
+
+                impl ::secrecy::zeroize::ZeroizeOnDrop for #type_name {}
+
+                #impl_serializable_secret
+                #impl_debug
+            }
+        }
+    }
+}
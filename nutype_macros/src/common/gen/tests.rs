@@ -27,7 +27,7 @@ Note: the test is generated automatically by #[nutype] macro.
     Some(quote!(
         #[test]
         fn should_have_consistent_lower_and_upper_boundaries() {
-            assert!(#upper >= #lower, #msg);
+            assert!(#upper >= #lower, "{}", #msg);
         }
     ))
 }
@@ -55,10 +55,9 @@ Note: the test is generated automatically by #[nutype] macro
     Some(quote!(
         #[test]
         fn should_have_valid_default_value() {
-            let default_inner_value = #type_name::default().into_inner();
-            // Typically `::default()` would already panic, but in case if the panic is removed by
-            // some reason we still want the test to fail.
-            #type_name::new(default_inner_value).expect(#msg);
+            // The value is checked with `new()` directly, because `Default` trait may be not derived
+            // (e.g. when the value is used only by `serde_default()`).
+            #type_name::__nutype_new(#default_value).expect(#msg);
         }
     ))
 }
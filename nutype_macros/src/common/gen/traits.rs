@@ -1,10 +1,15 @@
 use std::collections::HashSet;
 
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 
-use crate::common::models::{ErrorTypeName, InnerType, TypeName};
+use crate::common::models::{
+    ErrorIntegrations, ErrorTypeName, InnerType, NumericExprBound, OpsPolicy, SerdeAttributes,
+    TypeName,
+};
 
+use super::alloc::gen_required_alloc_path;
+use super::error::gen_violation_status_code;
 use super::parse_error::{gen_def_parse_error, gen_parse_error_name};
 
 /// Generated implementation of traits.
@@ -127,7 +132,7 @@ pub fn gen_impl_trait_from(type_name: &TypeName, inner_type: impl ToTokens) -> T
         impl ::core::convert::From<#inner_type> for #type_name {
             #[inline]
             fn from(raw_value: #inner_type) -> Self {
-                Self::new(raw_value)
+                Self::__nutype_new(raw_value)
             }
         }
     }
@@ -148,7 +153,7 @@ pub fn gen_impl_trait_try_from(
 
                     #[inline]
                     fn try_from(raw_value: #inner_type) -> Result<#type_name, Self::Error> {
-                        Self::new(raw_value)
+                        Self::__nutype_new(raw_value)
                     }
                 }
             }
@@ -157,12 +162,14 @@ pub fn gen_impl_trait_try_from(
             // The case when there are no validation
             //
             quote! {
+                // The user explicitly asked for TryFrom, so we don't suggest `From` instead.
+                #[allow(clippy::infallible_try_from)]
                 impl ::core::convert::TryFrom<#inner_type> for #type_name {
                     type Error = ::core::convert::Infallible;
 
                     #[inline]
                     fn try_from(raw_value: #inner_type) -> Result<#type_name, Self::Error> {
-                        Ok(Self::new(raw_value))
+                        Ok(Self::__nutype_new(raw_value))
                     }
                 }
             }
@@ -170,11 +177,45 @@ pub fn gen_impl_trait_try_from(
     }
 }
 
-/// Generate implementation of FromStr trait for non-string types (e.g. integers or floats).
+/// Generate `TryFrom` for a type, that can be converted into the inner type without loss.
+pub fn gen_impl_trait_try_from_lossless(
+    type_name: &TypeName,
+    inner_type: impl ToTokens,
+    source_type: impl ToTokens,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    let convert = quote!(<#inner_type as ::core::convert::From<#source_type>>::from(raw_value));
+    match maybe_error_type_name {
+        Some(error_type_name) => quote! {
+            impl ::core::convert::TryFrom<#source_type> for #type_name {
+                type Error = #error_type_name;
+
+                #[inline]
+                fn try_from(raw_value: #source_type) -> Result<#type_name, Self::Error> {
+                    Self::__nutype_new(#convert)
+                }
+            }
+        },
+        None => quote! {
+            #[allow(clippy::infallible_try_from)]
+            impl ::core::convert::TryFrom<#source_type> for #type_name {
+                type Error = ::core::convert::Infallible;
+
+                #[inline]
+                fn try_from(raw_value: #source_type) -> Result<#type_name, Self::Error> {
+                    Ok(Self::__nutype_new(#convert))
+                }
+            }
+        },
+    }
+}
+
+/// Generate implementation of FromStr trait and `parse()` for non-string types (e.g. integers or floats).
 pub fn gen_impl_trait_from_str(
     type_name: &TypeName,
     inner_type: impl Into<InnerType>,
     maybe_error_type_name: Option<&ErrorTypeName>,
+    error_integrations: ErrorIntegrations,
 ) -> TokenStream {
     let inner_type: InnerType = inner_type.into();
     let parse_error_type_name = gen_parse_error_name(type_name);
@@ -183,19 +224,31 @@ pub fn gen_impl_trait_from_str(
         type_name,
         maybe_error_type_name,
         &parse_error_type_name,
+        error_integrations,
     );
 
+    // `parse()` gives access to the same conversion without importing `FromStr`.
+    let impl_parse = quote! {
+        impl #type_name {
+            /// Parses the inner value from the string and then validates it.
+            pub fn parse(raw_string: &str) -> ::core::result::Result<Self, #parse_error_type_name> {
+                <Self as ::core::str::FromStr>::from_str(raw_string)
+            }
+        }
+    };
+
     if let Some(_error_type_name) = maybe_error_type_name {
         // The case with validation
         quote! {
             #def_parse_error
+            #impl_parse
 
             impl ::core::str::FromStr for #type_name {
                 type Err = #parse_error_type_name;
 
                 fn from_str(raw_string: &str) -> ::core::result::Result<Self, Self::Err> {
                     let raw_value: #inner_type = raw_string.parse().map_err(#parse_error_type_name::Parse)?;
-                    Self::new(raw_value).map_err(#parse_error_type_name::Validate)
+                    Self::__nutype_new(raw_value).map_err(#parse_error_type_name::Validate)
                 }
             }
         }
@@ -203,63 +256,200 @@ pub fn gen_impl_trait_from_str(
         // The case without validation
         quote! {
             #def_parse_error
+            #impl_parse
 
             impl ::core::str::FromStr for #type_name {
                 type Err = #parse_error_type_name;
 
                 fn from_str(raw_string: &str) -> ::core::result::Result<Self, Self::Err> {
                     let value: #inner_type = raw_string.parse().map_err(#parse_error_type_name::Parse)?;
-                    Ok(#type_name::new(value))
+                    Ok(#type_name::__nutype_new(value))
                 }
             }
         }
     }
 }
 
-pub fn gen_impl_trait_serde_serialize(type_name: &TypeName) -> TokenStream {
-    let type_name_str = type_name.to_string();
+pub fn gen_impl_trait_serde_serialize(
+    type_name: &TypeName,
+    inner_type: impl Into<InnerType>,
+    serde: &SerdeAttributes,
+) -> TokenStream {
+    let inner_type: InnerType = inner_type.into();
+    let serialize = gen_serialize_inner_value(type_name, &inner_type, serde, quote!(&self.0));
+
     quote! {
         impl ::serde::Serialize for #type_name {
             fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
             where
                 S: ::serde::Serializer
             {
-                serializer.serialize_newtype_struct(#type_name_str, &self.0)
+                #serialize
             }
         }
     }
 }
 
-pub fn gen_impl_trait_serde_deserialize(
+/// Implement `serde_with::SerializeAs<Inner>`, so the inner value is serialized the same way as
+/// the newtype would be, e.g. with `#[serde_as(as = "Vec<Email>")]` on a `Vec<String>` field.
+pub fn gen_impl_trait_serde_with_serialize_as(
     type_name: &TypeName,
     inner_type: impl Into<InnerType>,
-    maybe_error_type_name: Option<&ErrorTypeName>,
+    serde: &SerdeAttributes,
 ) -> TokenStream {
     let inner_type: InnerType = inner_type.into();
-    let raw_value_to_result: TokenStream = if maybe_error_type_name.is_some() {
-        let type_name_str = type_name.to_string();
-        quote! {
-            #type_name::new(raw_value).map_err(|validation_error| {
-                // Add a hint about which type is causing the error,
-                let err_msg = format!("{validation_error} Expected valid {}", #type_name_str);
-                <DE::Error as serde::de::Error>::custom(err_msg)
-            })
+    let serialize = gen_serialize_inner_value(type_name, &inner_type, serde, quote!(source));
+
+    quote! {
+        impl ::serde_with::SerializeAs<#inner_type> for #type_name {
+            fn serialize_as<S>(source: &#inner_type, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer
+            {
+                #serialize
+            }
         }
-    } else {
-        quote! {
-            Ok(#type_name::new(raw_value))
+    }
+}
+
+/// Implement `serde_with::DeserializeAs<Inner>`: the value is deserialized (sanitized and validated)
+/// as the newtype and then converted into the inner type.
+pub fn gen_impl_trait_serde_with_deserialize_as(
+    type_name: &TypeName,
+    inner_type: impl Into<InnerType>,
+) -> TokenStream {
+    let inner_type: InnerType = inner_type.into();
+
+    quote! {
+        impl<'de> ::serde_with::DeserializeAs<'de, #inner_type> for #type_name {
+            fn deserialize_as<D>(deserializer: D) -> ::core::result::Result<#inner_type, D::Error>
+            where
+                D: ::serde::Deserializer<'de>
+            {
+                <#type_name as ::serde::Deserialize<'de>>::deserialize(deserializer).map(#type_name::into_inner)
+            }
+        }
+    }
+}
+
+/// Generate the body of `serialize()` for the inner value given by `inner_value` expression
+/// (of type `&Inner`), respecting `serde(...)` options.
+fn gen_serialize_inner_value(
+    type_name: &TypeName,
+    inner_type: &InnerType,
+    serde: &SerdeAttributes,
+    inner_value: TokenStream,
+) -> TokenStream {
+    let type_name_str = type_name.to_string();
+
+    let (def_serialize_with, inner_value) = match &serde.serialize_with {
+        Some(serialize_with) => {
+            let def = quote! {
+                // Wrapper to serialize the inner value with the user's function
+                struct __SerializeWith<'a>(&'a #inner_type);
+
+                impl ::serde::Serialize for __SerializeWith<'_> {
+                    fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+                    where
+                        S: ::serde::Serializer
+                    {
+                        #serialize_with(self.0, serializer)
+                    }
+                }
+            };
+            (def, quote!(&__SerializeWith(#inner_value)))
         }
+        None => (quote!(), inner_value),
     };
 
-    let expecting_str = format!("tuple struct {type_name}");
+    let serialize = match &serde.wrap {
+        Some(key) => quote! {
+            let mut state = ::serde::Serializer::serialize_struct(serializer, #type_name_str, 1)?;
+            ::serde::ser::SerializeStruct::serialize_field(&mut state, #key, #inner_value)?;
+            ::serde::ser::SerializeStruct::end(state)
+        },
+        None => quote! {
+            serializer.serialize_newtype_struct(#type_name_str, #inner_value)
+        },
+    };
+
+    quote! {
+        #def_serialize_with
+        #serialize
+    }
+}
+
+pub fn gen_impl_trait_serde_deserialize(
+    type_name: &TypeName,
+    inner_type: impl Into<InnerType>,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    maybe_default_value: &Option<syn::Expr>,
+    serde: &SerdeAttributes,
+) -> TokenStream {
+    let inner_type: InnerType = inner_type.into();
+    let impl_serde_default =
+        gen_impl_serde_default(type_name, maybe_error_type_name, maybe_default_value);
+    let deserialize_inner = match &serde.deserialize_with {
+        Some(deserialize_with) => quote!(#deserialize_with(deserializer)),
+        None => quote!(<#inner_type as ::serde::Deserialize>::deserialize(deserializer)),
+    };
+    let raw_value_to_result =
+        gen_deserialize_raw_value_to_result(type_name, maybe_error_type_name, serde);
+
     let type_name_str = type_name.to_string();
 
+    let (expecting_str, visit_methods, deserialize) = match &serde.wrap {
+        Some(key) => {
+            let expecting_str = format!("struct {type_name}");
+            let visit_methods =
+                gen_visit_wrapped(&inner_type, &deserialize_inner, key, &raw_value_to_result);
+            let deserialize = quote! {
+                ::serde::de::Deserializer::deserialize_struct(
+                    deserializer,
+                    #type_name_str,
+                    &[#key],
+                    __Visitor {
+                        marker: Default::default(),
+                        lifetime: Default::default(),
+                    }
+                )
+            };
+            (expecting_str, visit_methods, deserialize)
+        }
+        None => {
+            let expecting_str = format!("tuple struct {type_name}");
+            let visit_methods = quote! {
+                fn visit_newtype_struct<DE>(self, deserializer: DE) -> Result<Self::Value, DE::Error>
+                where
+                    DE: ::serde::Deserializer<'de>
+                {
+                    let raw_value: #inner_type = match #deserialize_inner {
+                        Ok(val) => val,
+                        Err(err) => return Err(err)
+                    };
+                    #raw_value_to_result
+                }
+            };
+            let deserialize = quote! {
+                ::serde::de::Deserializer::deserialize_newtype_struct(
+                    deserializer,
+                    #type_name_str,
+                    __Visitor {
+                        marker: Default::default(),
+                        lifetime: Default::default(),
+                    }
+                )
+            };
+            (expecting_str, visit_methods, deserialize)
+        }
+    };
+
     quote! {
         impl<'de> ::serde::Deserialize<'de> for #type_name {
             fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
                 struct __Visitor<'de> {
-                    marker: ::std::marker::PhantomData<#type_name>,
-                    lifetime: ::std::marker::PhantomData<&'de ()>,
+                    marker: ::core::marker::PhantomData<#type_name>,
+                    lifetime: ::core::marker::PhantomData<&'de ()>,
                 }
 
                 impl<'de> ::serde::de::Visitor<'de> for __Visitor<'de> {
@@ -269,57 +459,949 @@ pub fn gen_impl_trait_serde_deserialize(
                         write!(formatter, #expecting_str)
                     }
 
+                    #visit_methods
+                }
+
+                #deserialize
+            }
+        }
+
+        #impl_serde_default
+    }
+}
+
+/// Generate implementation of `serde::Deserialize` for a type over `Cow<'a, str>`, which borrows
+/// the string from the input when the deserializer allows it (e.g. `serde_json::from_str`).
+pub fn gen_impl_trait_serde_deserialize_borrowed(
+    type_name: &TypeName,
+    inner_type: impl Into<InnerType>,
+    lifetime: &syn::Lifetime,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    maybe_default_value: &Option<syn::Expr>,
+    serde: &SerdeAttributes,
+) -> TokenStream {
+    let inner_type: InnerType = inner_type.into();
+    let alloc = gen_required_alloc_path();
+    let impl_serde_default =
+        gen_impl_serde_default(type_name, maybe_error_type_name, maybe_default_value);
+    let raw_value_to_result =
+        gen_deserialize_raw_value_to_result(type_name, maybe_error_type_name, serde);
+    let deserialize_inner = match &serde.deserialize_with {
+        Some(deserialize_with) => quote!(#deserialize_with(deserializer)),
+        None => quote!(::serde::Deserializer::deserialize_str(
+            deserializer,
+            __StrVisitor
+        )),
+    };
+    let type_name_str = type_name.to_string();
+    let expecting_str = format!("tuple struct {type_name}");
+
+    quote! {
+        impl<'de> ::serde::Deserialize<'de> for #type_name where 'de: #lifetime {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct __StrVisitor;
+
+                impl<'de> ::serde::de::Visitor<'de> for __StrVisitor {
+                    type Value = #alloc::borrow::Cow<'de, str>;
+
+                    fn expecting(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        formatter.write_str("a string")
+                    }
+
+                    fn visit_borrowed_str<E: ::serde::de::Error>(self, value: &'de str) -> Result<Self::Value, E> {
+                        Ok(#alloc::borrow::Cow::Borrowed(value))
+                    }
+
+                    fn visit_str<E: ::serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                        Ok(#alloc::borrow::Cow::Owned(#alloc::borrow::ToOwned::to_owned(value)))
+                    }
+
+                    fn visit_string<E: ::serde::de::Error>(self, value: #alloc::string::String) -> Result<Self::Value, E> {
+                        Ok(#alloc::borrow::Cow::Owned(value))
+                    }
+                }
+
+                struct __Visitor;
+
+                impl<'de> ::serde::de::Visitor<'de> for __Visitor {
+                    type Value = #alloc::borrow::Cow<'de, str>;
+
+                    fn expecting(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        write!(formatter, #expecting_str)
+                    }
+
                     fn visit_newtype_struct<DE>(self, deserializer: DE) -> Result<Self::Value, DE::Error>
                     where
                         DE: ::serde::Deserializer<'de>
                     {
-                        let raw_value: #inner_type = match <#inner_type as ::serde::Deserialize>::deserialize(deserializer) {
-                            Ok(val) => val,
-                            Err(err) => return Err(err)
-                        };
-                        #raw_value_to_result
+                        #deserialize_inner
                     }
                 }
 
-                ::serde::de::Deserializer::deserialize_newtype_struct(
+                let raw_value: #inner_type = ::serde::Deserializer::deserialize_newtype_struct(
                     deserializer,
                     #type_name_str,
-                    __Visitor {
-                        marker: Default::default(),
-                        lifetime: Default::default(),
-                    }
-                )
+                    __Visitor,
+                )?;
+                #raw_value_to_result
             }
         }
+
+        #impl_serde_default
     }
 }
 
-pub fn gen_impl_trait_default(
+/// Generate `serde_default()`, which returns the value of `default = `, if it is set.
+fn gen_impl_serde_default(
     type_name: &TypeName,
-    default_value: impl ToTokens,
-    has_validation: bool,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    maybe_default_value: &Option<syn::Expr>,
 ) -> TokenStream {
-    if has_validation {
-        let tp = type_name.to_string();
-        quote!(
-            impl ::core::default::Default for #type_name {
-                fn default() -> Self {
-                    Self::new(#default_value)
-                        .unwrap_or_else(|err| {
-                            let tp = #tp;
-                            panic!("\nDefault value for type `{tp}` is invalid.\nERROR: {err:?}\n");
-                        })
+    match maybe_default_value {
+        Some(default_value) => {
+            let has_validation = maybe_error_type_name.is_some();
+            let default = gen_default_value(type_name, default_value, has_validation);
+            quote! {
+                impl #type_name {
+                    /// Returns the value specified with `default = `.
+                    /// It's meant to be used with `#[serde(default = "...")]` for fields that may be missing.
+                    pub fn serde_default() -> Self {
+                        #default
+                    }
                 }
             }
-        )
+        }
+        None => quote!(),
+    }
+}
+
+/// Generate the conversion of the deserialized `raw_value` into the newtype.
+fn gen_deserialize_raw_value_to_result(
+    type_name: &TypeName,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    serde: &SerdeAttributes,
+) -> TokenStream {
+    if serde.skip_validation {
+        // The data comes from a trusted source, so sanitization and validation are skipped
+        quote! {
+            Ok(#type_name(raw_value))
+        }
+    } else if serde.skip_sanitization && maybe_error_type_name.is_none() {
+        quote! {
+            Ok(#type_name(raw_value))
+        }
+    } else if maybe_error_type_name.is_some() {
+        let type_name_str = type_name.to_string();
+        let alloc = gen_required_alloc_path();
+        let new = if serde.skip_sanitization {
+            quote!(new_without_sanitization)
+        } else {
+            quote!(new)
+        };
+        quote! {
+            #type_name::#new(raw_value).map_err(|validation_error| {
+                // Give a hint about which type and which constraint is causing the error
+                let err_msg = #alloc::format!(
+                    "Expected valid {}, constraint `{}` is violated: {validation_error}",
+                    #type_name_str,
+                    validation_error.violated_constraint(),
+                );
+                ::serde::de::Error::custom(err_msg)
+            })
+        }
     } else {
-        quote!(
-            impl ::core::default::Default for #type_name {
-                #[inline]
-                fn default() -> Self {
-                    Self::new(#default_value)
+        quote! {
+            Ok(#type_name::__nutype_new(raw_value))
+        }
+    }
+}
+
+/// Generate visitor methods to deserialize a value wrapped into a struct with a single field `key`,
+/// e.g. `{"value": 42}`. Unknown fields are ignored, like serde derive does by default.
+fn gen_visit_wrapped(
+    inner_type: &InnerType,
+    deserialize_inner: &TokenStream,
+    key: &syn::LitStr,
+    raw_value_to_result: &TokenStream,
+) -> TokenStream {
+    let def_helpers = quote! {
+        // Wrapper to deserialize the inner value as a field value
+        struct __DeserializeInner(#inner_type);
+
+        impl<'de> ::serde::Deserialize<'de> for __DeserializeInner {
+            fn deserialize<DE: ::serde::Deserializer<'de>>(deserializer: DE) -> Result<Self, DE::Error> {
+                #deserialize_inner.map(__DeserializeInner)
+            }
+        }
+
+        enum __Field {
+            Value,
+            Ignore,
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for __Field {
+            fn deserialize<DE: ::serde::Deserializer<'de>>(deserializer: DE) -> Result<Self, DE::Error> {
+                struct __FieldVisitor;
+
+                impl<'de> ::serde::de::Visitor<'de> for __FieldVisitor {
+                    type Value = __Field;
+
+                    fn expecting(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        formatter.write_str("field identifier")
+                    }
+
+                    fn visit_str<E: ::serde::de::Error>(self, value: &str) -> Result<__Field, E> {
+                        Ok(if value == #key { __Field::Value } else { __Field::Ignore })
+                    }
+
+                    fn visit_bytes<E: ::serde::de::Error>(self, value: &[u8]) -> Result<__Field, E> {
+                        Ok(if value == #key.as_bytes() { __Field::Value } else { __Field::Ignore })
+                    }
                 }
+
+                ::serde::de::Deserializer::deserialize_identifier(deserializer, __FieldVisitor)
             }
-        )
+        }
+    };
+
+    quote! {
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: ::serde::de::SeqAccess<'de>
+        {
+            #def_helpers
+
+            let raw_value: #inner_type = match seq.next_element::<__DeserializeInner>()? {
+                Some(__DeserializeInner(val)) => val,
+                None => return Err(::serde::de::Error::invalid_length(0, &self)),
+            };
+            #raw_value_to_result
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: ::serde::de::MapAccess<'de>
+        {
+            #def_helpers
+
+            let mut maybe_raw_value: Option<#inner_type> = None;
+            while let Some(field) = map.next_key::<__Field>()? {
+                match field {
+                    __Field::Value => {
+                        if maybe_raw_value.is_some() {
+                            return Err(::serde::de::Error::duplicate_field(#key));
+                        }
+                        let __DeserializeInner(val) = map.next_value()?;
+                        maybe_raw_value = Some(val);
+                    }
+                    __Field::Ignore => {
+                        map.next_value::<::serde::de::IgnoredAny>()?;
+                    }
+                }
+            }
+            let raw_value = match maybe_raw_value {
+                Some(val) => val,
+                None => return Err(::serde::de::Error::missing_field(#key)),
+            };
+            #raw_value_to_result
+        }
+    }
+}
+
+/// Generate implementation of a juniper GraphQL scalar.
+/// The value is serialized as the inner type, and the parsed input is validated with `new()`.
+/// The inner type must be one of the scalar types juniper supports natively
+/// (e.g. `String`, `i32`, `f64`, `bool`).
+pub fn gen_impl_trait_juniper_graphql_scalar(
+    type_name: &TypeName,
+    inner_type: impl Into<InnerType>,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    let inner_type: InnerType = inner_type.into();
+    let type_name_str = type_name.to_string();
+
+    let raw_value_to_result: TokenStream = if maybe_error_type_name.is_some() {
+        quote! {
+            #type_name::__nutype_new(raw_value).map_err(::juniper::FieldError::from)
+        }
+    } else {
+        quote! {
+            Ok(#type_name::__nutype_new(raw_value))
+        }
+    };
+
+    quote! {
+        #[::juniper::graphql_scalar(
+            name = #type_name_str,
+            with = __nutype_juniper_scalar,
+            parse_token(#inner_type),
+        )]
+        type __NutypeJuniperScalar = #type_name;
+
+        mod __nutype_juniper_scalar {
+            use super::*;
+
+            pub(super) fn to_output<S: ::juniper::ScalarValue>(value: &#type_name) -> ::juniper::Value<S> {
+                ::juniper::Value::scalar(::core::clone::Clone::clone(&value.0))
+            }
+
+            pub(super) fn from_input<S: ::juniper::ScalarValue>(
+                input: &::juniper::InputValue<S>,
+            ) -> ::core::result::Result<#type_name, ::juniper::FieldError<S>> {
+                let raw_value = <#inner_type as ::juniper::FromInputValue<S>>::from_input_value(input)
+                    .map_err(::juniper::IntoFieldError::into_field_error)?;
+                #raw_value_to_result
+            }
+        }
+    }
+}
+
+/// Generate implementation of rocket's `FromFormField`.
+/// The raw value is parsed as the inner type, guard violations are turned into
+/// validation errors (HTTP 422 Unprocessable Entity).
+pub fn gen_impl_trait_rocket_from_form_field(
+    type_name: &TypeName,
+    inner_type: impl Into<InnerType>,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    let inner_type: InnerType = inner_type.into();
+    let raw_value_to_result = gen_rocket_raw_value_to_result(type_name, maybe_error_type_name);
+
+    quote! {
+        impl<'v> ::rocket::form::FromFormField<'v> for #type_name {
+            fn from_value(field: ::rocket::form::ValueField<'v>) -> ::rocket::form::Result<'v, Self> {
+                let raw_value = <#inner_type as ::rocket::form::FromFormField<'v>>::from_value(field)?;
+                #raw_value_to_result
+            }
+        }
+    }
+}
+
+/// Generate implementation of rocket's `FromParam`.
+/// A route param is parsed the same way as a form value, so the errors are the same as
+/// for `FromFormField`.
+pub fn gen_impl_trait_rocket_from_param(
+    type_name: &TypeName,
+    inner_type: impl Into<InnerType>,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    let inner_type: InnerType = inner_type.into();
+    let raw_value_to_result = gen_rocket_raw_value_to_result(type_name, maybe_error_type_name);
+
+    quote! {
+        impl<'a> ::rocket::request::FromParam<'a> for #type_name {
+            type Error = ::rocket::form::Errors<'a>;
+
+            fn from_param(param: &'a str) -> ::core::result::Result<Self, Self::Error> {
+                let field = ::rocket::form::ValueField::from_value(param);
+                let raw_value = <#inner_type as ::rocket::form::FromFormField<'a>>::from_value(field)?;
+                #raw_value_to_result
+            }
+        }
+    }
+}
+
+fn gen_rocket_raw_value_to_result(
+    type_name: &TypeName,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    if maybe_error_type_name.is_some() {
+        quote! {
+            #type_name::__nutype_new(raw_value).map_err(|validation_error| {
+                let msg = ::std::string::ToString::to_string(&validation_error);
+                ::rocket::form::Errors::from(::rocket::form::Error::validation(msg))
+            })
+        }
+    } else {
+        quote! {
+            Ok(#type_name::__nutype_new(raw_value))
+        }
+    }
+}
+
+/// Generate implementation of actix-web's `FromRequest`.
+/// The inner value is extracted from the path (the same way as `web::Path<Inner>` does),
+/// guard violations are responded with the status of the error (see `gen_violation_status_code`)
+/// and the error message in the body.
+pub fn gen_impl_trait_actix_from_request(
+    type_name: &TypeName,
+    inner_type: impl Into<InnerType>,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    let inner_type: InnerType = inner_type.into();
+
+    let raw_value_to_result: TokenStream = if maybe_error_type_name.is_some() {
+        let status_code = gen_violation_status_code(quote!(validation_error));
+        quote! {
+            #type_name::__nutype_new(raw_value).map_err(|validation_error| {
+                let status = ::actix_web::http::StatusCode::from_u16(#status_code)
+                    .unwrap_or(::actix_web::http::StatusCode::UNPROCESSABLE_ENTITY);
+                ::actix_web::error::InternalError::new(validation_error, status).into()
+            })
+        }
+    } else {
+        quote! {
+            Ok(#type_name::__nutype_new(raw_value))
+        }
+    };
+
+    quote! {
+        impl ::actix_web::FromRequest for #type_name {
+            type Error = ::actix_web::Error;
+            type Future = ::core::future::Ready<::core::result::Result<Self, Self::Error>>;
+
+            fn from_request(
+                req: &::actix_web::HttpRequest,
+                payload: &mut ::actix_web::dev::Payload,
+            ) -> Self::Future {
+                let path_result = <::actix_web::web::Path<#inner_type> as ::actix_web::FromRequest>::from_request(req, payload).into_inner();
+                let raw_value = match path_result {
+                    Ok(path) => path.into_inner(),
+                    Err(err) => return ::core::future::ready(Err(err)),
+                };
+                ::core::future::ready(#raw_value_to_result)
+            }
+        }
+    }
+}
+
+/// Generate implementation of clap's `ValueParserFactory`, so the type can be used
+/// for CLI arguments with `value_parser!`.
+/// The argument is parsed as the inner type with `FromStr` and then validated,
+/// so an invalid argument is reported by clap with the validator-specific message.
+pub fn gen_impl_trait_clap_value_parser_factory(
+    type_name: &TypeName,
+    inner_type: impl Into<InnerType>,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    let inner_type: InnerType = inner_type.into();
+
+    let parse_raw_value = match inner_type {
+        InnerType::String(_) => quote! {
+            let raw_value = raw_string;
+        },
+        _ => quote! {
+            let raw_value: #inner_type = raw_string
+                .parse()
+                .map_err(|err| ::std::string::ToString::to_string(&err))?;
+        },
+    };
+
+    let raw_value_to_result: TokenStream = if maybe_error_type_name.is_some() {
+        quote! {
+            #type_name::__nutype_new(raw_value)
+                .map_err(|validation_error| ::std::string::ToString::to_string(&validation_error))
+        }
+    } else {
+        quote! {
+            Ok(#type_name::__nutype_new(raw_value))
+        }
+    };
+
+    quote! {
+        impl ::clap::builder::ValueParserFactory for #type_name {
+            type Parser = ::clap::builder::ValueParser;
+
+            fn value_parser() -> Self::Parser {
+                fn parse(raw_string: &str) -> ::core::result::Result<#type_name, ::std::string::String> {
+                    #parse_raw_value
+                    #raw_value_to_result
+                }
+                ::clap::builder::ValueParser::new(parse)
+            }
+        }
+    }
+}
+
+/// Generate implementation of rand's `SampleUniform`, so `Uniform<Type>` and `rng.gen_range()`
+/// can be used with numeric newtypes.
+/// The sampling is delegated to the uniform sampler of the inner type. Since both boundaries
+/// of the range are valid values and the numeric validators define a continuous range,
+/// every sampled value is valid as well.
+pub fn gen_impl_trait_rand_sample_uniform(
+    type_name: &TypeName,
+    inner_type: impl Into<InnerType>,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    let inner_type: InnerType = inner_type.into();
+    let sampler_name = format_ident!("{type_name}UniformSampler");
+
+    let construct_value = if maybe_error_type_name.is_some() {
+        let error_text = format!("Uniform sampler generated an invalid value for {type_name}");
+        quote!(#type_name::__nutype_new(inner_value).expect(#error_text))
+    } else {
+        quote!(#type_name::__nutype_new(inner_value))
+    };
+
+    quote! {
+        impl ::rand::distributions::uniform::SampleUniform for #type_name {
+            type Sampler = #sampler_name;
+        }
+
+        #[doc(hidden)]
+        #[derive(Clone, Copy, Debug)]
+        pub struct #sampler_name(<#inner_type as ::rand::distributions::uniform::SampleUniform>::Sampler);
+
+        impl ::rand::distributions::uniform::UniformSampler for #sampler_name {
+            type X = #type_name;
+
+            fn new<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: ::rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+                B2: ::rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+            {
+                let sampler = <<#inner_type as ::rand::distributions::uniform::SampleUniform>::Sampler as ::rand::distributions::uniform::UniformSampler>::new(low.borrow().0, high.borrow().0);
+                Self(sampler)
+            }
+
+            fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: ::rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+                B2: ::rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+            {
+                let sampler = <<#inner_type as ::rand::distributions::uniform::SampleUniform>::Sampler as ::rand::distributions::uniform::UniformSampler>::new_inclusive(low.borrow().0, high.borrow().0);
+                Self(sampler)
+            }
+
+            fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+                let inner_value = ::rand::distributions::uniform::UniformSampler::sample(&self.0, rng);
+                #construct_value
+            }
+        }
+    }
+}
+
+/// Generate implementation of `num_traits::Zero`.
+/// Note, that `Zero` requires `Add<Output = Self>` to be implemented for the newtype.
+/// Zero is checked against literal bounds during validation (see `validate_numeric_zero_one`)
+/// and against bounds given by expressions with a compile time assertion.
+pub fn gen_impl_trait_num_traits_zero<V: NumericExprBound>(
+    type_name: &TypeName,
+    inner_type: impl Into<InnerType>,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    validators: &[V],
+) -> TokenStream {
+    let inner_type: InnerType = inner_type.into();
+    let construct_value = gen_num_traits_construct_value(type_name, maybe_error_type_name, "zero");
+    let assert_bounds = gen_const_assert_expr_bounds(
+        validators,
+        quote!((0 as #inner_type)),
+        &format!("zero is not a valid value of {type_name}"),
+    );
+
+    quote! {
+        #assert_bounds
+
+        impl ::num_traits::Zero for #type_name {
+            fn zero() -> Self {
+                let inner_value = <#inner_type as ::num_traits::Zero>::zero();
+                #construct_value
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                ::num_traits::Zero::is_zero(&self.0)
+            }
+        }
+    }
+}
+
+/// Generate implementation of `num_traits::One`.
+/// Note, that `One` requires `Mul<Output = Self>` to be implemented for the newtype.
+/// One is checked against the bounds the same way as zero for `Zero`.
+pub fn gen_impl_trait_num_traits_one<V: NumericExprBound>(
+    type_name: &TypeName,
+    inner_type: impl Into<InnerType>,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    validators: &[V],
+) -> TokenStream {
+    let inner_type: InnerType = inner_type.into();
+    let construct_value = gen_num_traits_construct_value(type_name, maybe_error_type_name, "one");
+    let assert_bounds = gen_const_assert_expr_bounds(
+        validators,
+        quote!((1 as #inner_type)),
+        &format!("one is not a valid value of {type_name}"),
+    );
+
+    quote! {
+        #assert_bounds
+
+        impl ::num_traits::One for #type_name {
+            fn one() -> Self {
+                let inner_value = <#inner_type as ::num_traits::One>::one();
+                #construct_value
+            }
+        }
+    }
+}
+
+/// Assert at compile time, that `value` satisfies the bounds given by expressions,
+/// e.g. `greater_or_equal = MIN`.
+fn gen_const_assert_expr_bounds<V: NumericExprBound>(
+    validators: &[V],
+    value: TokenStream,
+    msg: &str,
+) -> TokenStream {
+    let conditions: Vec<TokenStream> = validators
+        .iter()
+        .filter_map(|validator| validator.expr_bound_condition(&value))
+        .collect();
+    if conditions.is_empty() {
+        return quote!();
+    }
+    quote! {
+        const _: () = assert!(#(#conditions)&&*, #msg);
+    }
+}
+
+fn gen_num_traits_construct_value(
+    type_name: &TypeName,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    value_name: &str,
+) -> TokenStream {
+    if maybe_error_type_name.is_some() {
+        let error_text = format!("{value_name} is not a valid value of {type_name}");
+        quote!(#type_name::__nutype_new(inner_value).expect(#error_text))
+    } else {
+        quote!(#type_name::__nutype_new(inner_value))
+    }
+}
+
+/// Generate implementation of `num_traits::ToPrimitive`, which simply delegates to the inner type.
+pub fn gen_impl_trait_num_traits_to_primitive(type_name: &TypeName) -> TokenStream {
+    quote! {
+        impl ::num_traits::ToPrimitive for #type_name {
+            #[inline]
+            fn to_i64(&self) -> Option<i64> {
+                ::num_traits::ToPrimitive::to_i64(&self.0)
+            }
+
+            #[inline]
+            fn to_u64(&self) -> Option<u64> {
+                ::num_traits::ToPrimitive::to_u64(&self.0)
+            }
+
+            #[inline]
+            fn to_i128(&self) -> Option<i128> {
+                ::num_traits::ToPrimitive::to_i128(&self.0)
+            }
+
+            #[inline]
+            fn to_u128(&self) -> Option<u128> {
+                ::num_traits::ToPrimitive::to_u128(&self.0)
+            }
+
+            #[inline]
+            fn to_f64(&self) -> Option<f64> {
+                ::num_traits::ToPrimitive::to_f64(&self.0)
+            }
+        }
+    }
+}
+
+/// Generate implementation of `num_traits::FromPrimitive`.
+/// `None` is returned if the number can not be represented by the inner type or if the value
+/// violates the validation rules.
+pub fn gen_impl_trait_num_traits_from_primitive(
+    type_name: &TypeName,
+    inner_type: impl Into<InnerType>,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    let inner_type: InnerType = inner_type.into();
+    let inner_value_to_option = if maybe_error_type_name.is_some() {
+        quote!(#type_name::__nutype_new(inner_value).ok())
+    } else {
+        quote!(Some(#type_name::__nutype_new(inner_value)))
+    };
+
+    let methods = [
+        (quote!(from_i64), quote!(i64)),
+        (quote!(from_u64), quote!(u64)),
+        (quote!(from_i128), quote!(i128)),
+        (quote!(from_u128), quote!(u128)),
+        (quote!(from_f64), quote!(f64)),
+    ]
+    .into_iter()
+    .map(|(method, num_type)| {
+        quote! {
+            fn #method(n: #num_type) -> Option<Self> {
+                let inner_value = <#inner_type as ::num_traits::FromPrimitive>::#method(n)?;
+                #inner_value_to_option
+            }
+        }
+    });
+
+    quote! {
+        impl ::num_traits::FromPrimitive for #type_name {
+            #(#methods)*
+        }
+    }
+}
+
+pub fn gen_impl_trait_default(
+    type_name: &TypeName,
+    default_value: impl ToTokens,
+    has_validation: bool,
+) -> TokenStream {
+    let default = gen_default_value(type_name, default_value, has_validation);
+    let inline = if has_validation {
+        quote!()
+    } else {
+        quote!(#[inline])
+    };
+
+    quote!(
+        impl ::core::default::Default for #type_name {
+            #inline
+            fn default() -> Self {
+                #default
+            }
+        }
+    )
+}
+
+/// Generate an expression that instantiates the type with the value from `default = `.
+/// With validation the value is checked at runtime and it panics if the value is invalid.
+pub fn gen_default_value(
+    type_name: &TypeName,
+    default_value: impl ToTokens,
+    has_validation: bool,
+) -> TokenStream {
+    if has_validation {
+        let tp = type_name.to_string();
+        quote!(
+            Self::__nutype_new(#default_value)
+                .unwrap_or_else(|err| {
+                    let tp = #tp;
+                    panic!("\nDefault value for type `{tp}` is invalid.\nERROR: {err:?}\n");
+                })
+        )
+    } else {
+        quote!(
+            Self::__nutype_new(#default_value)
+        )
+    }
+}
+
+/// Arithmetic operator traits from `core::ops` that can be derived for numeric types.
+#[derive(Debug, Clone, Copy)]
+pub enum ArithOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl ArithOp {
+    fn trait_name(self) -> proc_macro2::Ident {
+        format_ident!("{self:?}")
+    }
+
+    fn assign_trait_name(self) -> proc_macro2::Ident {
+        format_ident!("{self:?}Assign")
+    }
+
+    /// Name of the method, e.g. `add`. Integer methods like `checked_add` and `saturating_add`
+    /// share the same suffix.
+    pub fn method_name(self) -> String {
+        format!("{self:?}").to_lowercase()
+    }
+}
+
+/// Generate implementation of an arithmetic operator trait (e.g. `Add`).
+/// `compute_value` is an expression that computes the new inner value out of the inner values
+/// `lhs` and `rhs`. The result is passed to `::new()`, what happens with an invalid result
+/// depends on the ops policy.
+pub fn gen_impl_trait_arith_op(
+    type_name: &TypeName,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    op: ArithOp,
+    ops: OpsPolicy,
+    compute_value: TokenStream,
+) -> TokenStream {
+    let trait_name = op.trait_name();
+    let method = format_ident!("{}", op.method_name());
+
+    let (output, construct_value) = match (ops, maybe_error_type_name) {
+        (_, None) => (quote!(Self), quote!(Self::__nutype_new(value))),
+        (OpsPolicy::Result, Some(error_type_name)) => (
+            quote!(::core::result::Result<Self, #error_type_name>),
+            quote!(Self::__nutype_new(value)),
+        ),
+        (OpsPolicy::Panic | OpsPolicy::Saturate, Some(_)) => {
+            let error_text = format!("{type_name}::{method}() produced an invalid value");
+            (
+                quote!(Self),
+                quote!(Self::__nutype_new(value).expect(#error_text)),
+            )
+        }
+    };
+
+    quote! {
+        impl ::core::ops::#trait_name for #type_name {
+            type Output = #output;
+
+            #[inline]
+            fn #method(self, rhs: Self) -> Self::Output {
+                let lhs = self.0;
+                let rhs = rhs.0;
+                let value = #compute_value;
+                #construct_value
+            }
+        }
+    }
+}
+
+/// Generate implementation of an assigning arithmetic operator trait (e.g. `AddAssign`).
+/// The inner value is mutated only if the result passes the guard, otherwise it panics.
+/// With `ops = result` the assigning operators cannot be derived.
+pub fn gen_impl_trait_arith_assign_op(
+    type_name: &TypeName,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    op: ArithOp,
+    ops: OpsPolicy,
+    compute_value: TokenStream,
+) -> TokenStream {
+    let trait_name = op.assign_trait_name();
+    let method = format_ident!("{}_assign", op.method_name());
+
+    let assign_value = match (ops, maybe_error_type_name) {
+        (_, None) => quote!(*self = Self::__nutype_new(value);),
+        (_, Some(_)) => {
+            let error_text = format!("{type_name}::{method}() produced an invalid value");
+            quote!(*self = Self::__nutype_new(value).expect(#error_text);)
+        }
+    };
+
+    quote! {
+        impl ::core::ops::#trait_name for #type_name {
+            #[inline]
+            fn #method(&mut self, rhs: Self) {
+                let lhs = self.0;
+                let rhs = rhs.0;
+                let value = #compute_value;
+                #assign_value
+            }
+        }
+    }
+}
+
+/// Iterator traits from `core::iter` that fold the elements with an arithmetic operator.
+#[derive(Debug, Clone, Copy)]
+pub enum IterOp {
+    Sum,
+    Product,
+}
+
+impl IterOp {
+    pub fn arith_op(self) -> ArithOp {
+        match self {
+            Self::Sum => ArithOp::Add,
+            Self::Product => ArithOp::Mul,
+        }
+    }
+}
+
+/// Generate implementations of `Sum` or `Product` over `Self` and over `&Self`.
+/// The inner values are folded starting with the first element using `compute_value`,
+/// which is the same expression as used by the corresponding arithmetic operator.
+/// An empty iterator produces `empty_value`, which is the identity (zero or one), clamped into
+/// the valid range with `ops = saturate`.
+/// With `ops = result` the traits are implemented for `Result<Self, Error>` instead of `Self`.
+pub fn gen_impl_trait_iter_op(
+    type_name: &TypeName,
+    inner_type: impl Into<InnerType>,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    iter_op: IterOp,
+    ops: OpsPolicy,
+    empty_value: TokenStream,
+    compute_value: TokenStream,
+) -> TokenStream {
+    let inner_type: InnerType = inner_type.into();
+    let trait_name = format_ident!("{iter_op:?}");
+    let method = format_ident!("{}", format!("{iter_op:?}").to_lowercase());
+
+    let (output, construct_value) = match (ops, maybe_error_type_name) {
+        (_, None) => (quote!(#type_name), quote!(#type_name::__nutype_new(value))),
+        (OpsPolicy::Result, Some(error_type_name)) => (
+            quote!(::core::result::Result<#type_name, #error_type_name>),
+            quote!(#type_name::__nutype_new(value)),
+        ),
+        (OpsPolicy::Panic | OpsPolicy::Saturate, Some(_)) => {
+            let error_text = format!("{type_name}::{method}() produced an invalid value");
+            (
+                quote!(#type_name),
+                quote!(#type_name::__nutype_new(value).expect(#error_text)),
+            )
+        }
+    };
+
+    // With `ops = result` computing the value may fail on its own (e.g. on integer overflow)
+    let fold = match (ops, maybe_error_type_name) {
+        (OpsPolicy::Result, Some(error_type_name)) => quote! {
+            iter.try_fold(first, |lhs, rhs| -> ::core::result::Result<#inner_type, #error_type_name> {
+                let rhs = rhs.0;
+                let value = #compute_value;
+                Ok(value)
+            })?
+        },
+        _ => quote! {
+            iter.fold(first, |lhs, rhs| {
+                let rhs = rhs.0;
+                #compute_value
+            })
+        },
+    };
+    let body = quote! {
+        let mut iter = iter;
+        let value: #inner_type = match iter.next() {
+            ::core::option::Option::None => #empty_value,
+            ::core::option::Option::Some(first) => {
+                let first = first.0;
+                #fold
+            }
+        };
+        #construct_value
+    };
+
+    quote! {
+        impl ::core::iter::#trait_name<#type_name> for #output {
+            fn #method<I: Iterator<Item = #type_name>>(iter: I) -> Self {
+                #body
+            }
+        }
+
+        impl<'a> ::core::iter::#trait_name<&'a #type_name> for #output {
+            fn #method<I: Iterator<Item = &'a #type_name>>(iter: I) -> Self {
+                #body
+            }
+        }
+    }
+}
+
+/// Generate implementation of `zeroize::Zeroize`, which zeroizes the inner value.
+/// It is derivable only for types without validation, since the zeroized value may violate it.
+pub fn gen_impl_trait_zeroize(type_name: &TypeName) -> TokenStream {
+    quote! {
+        impl ::zeroize::Zeroize for #type_name {
+            #[inline]
+            fn zeroize(&mut self) {
+                ::zeroize::Zeroize::zeroize(&mut self.0);
+            }
+        }
+    }
+}
+
+/// Generate implementation of `Drop` which zeroizes the inner value, and the marker trait
+/// `zeroize::ZeroizeOnDrop`.
+pub fn gen_impl_trait_zeroize_on_drop(type_name: &TypeName) -> TokenStream {
+    quote! {
+        impl ::core::ops::Drop for #type_name {
+            fn drop(&mut self) {
+                ::zeroize::Zeroize::zeroize(&mut self.0);
+            }
+        }
+
+        impl ::zeroize::ZeroizeOnDrop for #type_name {}
     }
 }
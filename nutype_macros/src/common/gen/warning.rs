@@ -0,0 +1,24 @@
+//! Warnings about the attributes of `#[nutype]`, e.g. a redundant validator.
+//!
+//! `proc_macro::Diagnostic` is not available on stable Rust, so a warning is emitted as a use of
+//! a deprecated item, whose note carries the message. The use is spanned with the attribute,
+//! so the warning points at it.
+
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+
+use crate::common::models::Warning;
+
+pub fn gen_warnings(warnings: &[Warning]) -> TokenStream {
+    let warnings = warnings.iter().map(|Warning { span, message }| {
+        let usage = quote_spanned!(*span=> let _ = NutypeWarning;);
+        quote! {
+            const _: () = {
+                #[deprecated(note = #message)]
+                struct NutypeWarning;
+                #usage
+            };
+        }
+    });
+    quote!(#(#warnings)*)
+}
@@ -1,11 +1,11 @@
 use kinded::Kinded;
 use std::{collections::HashSet, fmt::Debug};
 
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, ToTokens};
 use syn::{
     parse::{Parse, ParseStream},
-    Attribute, ExprClosure, Path,
+    Attribute, Expr, ExprClosure, LitStr, Path,
 };
 
 use crate::{
@@ -13,7 +13,25 @@ use crate::{
     string::models::StringInnerType,
 };
 
-use super::gen::type_custom_closure;
+use super::parse::{
+    conditional::{split_conditional_rules, take_conditional_rules},
+    strip_expr_groups,
+};
+use super::validate::validate_conditional_derive_traits;
+
+use super::gen::{
+    apply_cfg_attrs,
+    debug_expansion::write_expansion,
+    docs::{apply_docs, DocTargets},
+    error::gen_error_type_name,
+    gen_module_name_for_type,
+    generics::{apply_generics, validate_generic_derive_traits},
+    metadata::gen_metadata_const,
+    omit::apply_omit,
+    parse_error::gen_parse_error_name,
+    type_custom_closure,
+    warning::gen_warnings,
+};
 
 /// A spanned item. An item can be anything that cares a domain value.
 /// Keeping a span allows to throw good precise error messages at the validation stage.
@@ -43,6 +61,7 @@ impl<T: Kinded> Kinded for SpannedItem<T> {
 
 /// Represents the inner type of a newtype.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::large_enum_variant)]
 pub enum InnerType {
     String(StringInnerType),
     Integer(IntegerInnerType),
@@ -148,28 +167,38 @@ define_ident_type!(ErrorTypeName);
 // For example, if `TypeName` is `Amount`, then this would be `AmountParseError`.
 define_ident_type!(ParseErrorTypeName);
 
+// A type that represents a factory name, which is generated with `factory` flag.
+// For example, if `TypeName` is `Username`, then this would be `UsernameFactory`.
+define_ident_type!(FactoryTypeName);
+
 // Module name, where the type is placed.
 define_ident_type!(ModuleName);
 
 #[derive(Debug)]
 pub struct Meta {
     pub type_name: TypeName,
+    pub generics: syn::Generics,
     pub inner_type: InnerType,
     pub vis: syn::Visibility,
-    pub doc_attrs: Vec<Attribute>,
+    pub struct_attrs: Vec<Attribute>,
+    pub cfg_attrs: Vec<Attribute>,
 }
 
 impl Meta {
     pub fn into_typed_meta(self, attrs: TokenStream) -> (TypedMeta, InnerType) {
         let Self {
-            doc_attrs,
+            struct_attrs,
+            cfg_attrs,
             type_name,
+            generics,
             inner_type,
             vis,
         } = self;
         let typed_meta = TypedMeta {
-            doc_attrs,
+            struct_attrs,
+            cfg_attrs,
             type_name,
+            generics,
             attrs,
             vis,
         };
@@ -183,11 +212,20 @@ impl Meta {
 pub struct TypedMeta {
     pub type_name: TypeName,
 
+    /// Generic parameters of the type
+    pub generics: syn::Generics,
+
     /// Attributes given to #[nutype] macro
     pub attrs: TokenStream,
 
     pub vis: syn::Visibility,
-    pub doc_attrs: Vec<Attribute>,
+
+    /// Attributes of the struct (e.g. doc comments, `#[must_use]` or `#[cfg_attr(..)]`),
+    /// that are forwarded to the generated type
+    pub struct_attrs: Vec<Attribute>,
+
+    /// `#[cfg(..)]` attributes, that are applied to all the generated items
+    pub cfg_attrs: Vec<Attribute>,
 }
 
 /// Validated model, that represents precisely what needs to be generated.
@@ -202,6 +240,184 @@ pub enum Guard<Sanitizer, Validator> {
     },
 }
 
+/// Customization of the generated error type.
+#[derive(Debug, Default, Clone)]
+pub struct ErrorAttributes {
+    /// Custom name of the error type, provided with `error = `.
+    /// If not set, the name is derived from the type name, e.g. `EmailError` for `Email`.
+    pub name: Option<ErrorTypeName>,
+
+    /// Visibility of the error type, e.g. `error = pub(crate) EmailError`.
+    /// If not set, the error type has the same visibility as the newtype.
+    pub vis: Option<syn::Visibility>,
+
+    /// Module, which the error types are placed into, provided with `error_module = `.
+    /// If not set, the error types are placed next to the newtype.
+    pub module: Option<Ident>,
+
+    /// Nutype, which sanitizers and validators run before the own ones, provided with `extends = `.
+    /// Its error is wrapped into a variant of the error type, e.g. `UsernameViolated(UsernameError)`.
+    pub extends: Option<syn::Path>,
+
+    /// Custom messages of the `Display` implementation, provided with `messages(...)`.
+    pub messages: Vec<ErrorMessage>,
+
+    /// Extra data carried by the error variants, provided with `error_fields(...)`.
+    pub fields: ErrorFields,
+
+    /// Traits to derive on the error type, provided with `error_derive(...)`.
+    /// If not set, the default set of traits is derived.
+    pub derive: Option<Vec<syn::Path>>,
+
+    /// Generate `new_collecting_violations()`, which runs all the validators and returns all the errors.
+    /// Provided with `all_violations` flag.
+    pub all_violations: bool,
+
+    /// Prefix of the error codes returned by `code()`, provided with `error_code_prefix = `.
+    /// If not set, the type name in snake case is used, e.g. `user_name` for `UserName`.
+    pub code_prefix: Option<syn::LitStr>,
+
+    /// Mark the error enum `#[non_exhaustive]`, provided with `error_non_exhaustive` flag.
+    pub non_exhaustive: bool,
+
+    /// How `Display` of the error type is implemented, provided with `error_display = `.
+    pub display: ErrorDisplay,
+
+    /// Shared user-defined error types, the error converts into, provided with `error_into(...)`.
+    /// Every one of them must have a variant named after the newtype, that wraps the error.
+    pub into: Vec<syn::Path>,
+
+    /// User-defined error type returned by the constructor, provided with `error_return = `.
+    /// The generated error converts into it the same way as with `error_into(...)`.
+    pub return_type: Option<syn::Path>,
+
+    /// HTTP status codes returned by `status_code()` per validator, provided with `status_codes(...)`.
+    /// Validators without a custom status code are mapped to `422 Unprocessable Entity`.
+    pub status_codes: Vec<ErrorStatusCode>,
+
+    /// Integrations with third-party crates implemented for the error type, provided with `error_integrations(...)`.
+    pub integrations: ErrorIntegrations,
+
+    /// Add `Overflow` variant, which the arithmetic operators of an integer type with `ops = result`
+    /// return if the result does not fit into the inner type or on division by zero.
+    pub overflow: bool,
+}
+
+impl ErrorAttributes {
+    pub fn message_for(&self, validator_kind: &str) -> Option<&syn::LitStr> {
+        self.messages
+            .iter()
+            .find(|m| m.validator == validator_kind)
+            .map(|m| &m.message)
+    }
+
+    #[cfg_attr(not(feature = "web"), allow(dead_code))]
+    pub fn status_code_for(&self, validator_kind: &str) -> Option<u16> {
+        self.status_codes
+            .iter()
+            .find(|code| code.validator == validator_kind)
+            .map(|code| code.status)
+    }
+}
+
+/// Implementation of `Display` for the error type.
+#[derive(Debug, Default, Clone)]
+pub enum ErrorDisplay {
+    /// The messages are generated by nutype (and can be adjusted with `messages(...)`).
+    #[default]
+    Generated,
+
+    /// `Display` is not generated, so it has to be implemented manually, e.g. to localize the messages:
+    ///     error_display = none
+    None,
+
+    /// `Display` is routed through a function with signature `fn(&Error, &mut Formatter) -> fmt::Result`:
+    ///     error_display = localize_error
+    Custom(syn::Path),
+}
+
+/// Data carried by the error variants, e.g. `LenCharMaxViolated { value: String, max: usize }`.
+/// Without any fields the error variants are unit variants.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ErrorFields {
+    /// Include the rejected (sanitized) value as `value` field.
+    pub value: bool,
+
+    /// Include the parameters of the validator, e.g. `max` for `len_char_max` or `pattern` for `regex`.
+    pub bounds: bool,
+}
+
+impl ErrorFields {
+    pub fn is_any(&self) -> bool {
+        self.value || self.bounds
+    }
+
+    /// Whether an error variant of the validator has any fields.
+    pub fn has_fields(&self, validator: &impl HasBound) -> bool {
+        self.value || (self.bounds && validator.has_bound())
+    }
+}
+
+/// Integrations with third-party crates implemented for the error type, e.g. `error_integrations(axum)`.
+/// Every integration requires the corresponding feature of `nutype` and the crate within the dependencies.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ErrorIntegrations {
+    /// Implement axum's `IntoResponse`.
+    pub axum: bool,
+
+    /// Derive `Error` with `thiserror`, so the parse error carries its source.
+    pub thiserror: bool,
+
+    /// Implement miette's `Diagnostic`.
+    pub miette: bool,
+}
+
+impl ErrorIntegrations {
+    pub fn is_any(&self) -> bool {
+        self.axum || self.thiserror || self.miette
+    }
+}
+
+/// Custom message for a violated validator, e.g. `len_char_min = "must be at least 3 characters"`.
+#[derive(Debug, Clone)]
+pub struct ErrorMessage {
+    /// Name of the validator, e.g. `len_char_min`
+    pub validator: proc_macro2::Ident,
+
+    pub message: syn::LitStr,
+}
+
+/// Custom HTTP status code for a violated validator, e.g. `predicate = 409`.
+#[derive(Debug, Clone)]
+pub struct ErrorStatusCode {
+    /// Name of the validator, e.g. `predicate`
+    pub validator: proc_macro2::Ident,
+
+    pub status: u16,
+}
+
+/// Options parsed from `serde(...)` attribute.
+#[derive(Debug, Default, Clone)]
+pub struct SerdeAttributes {
+    /// Function to serialize the inner value with: `fn(&Inner, S) -> Result<S::Ok, S::Error>`
+    pub serialize_with: Option<syn::Path>,
+
+    /// Function to deserialize the inner value with: `fn(D) -> Result<Inner, D::Error>`.
+    /// The deserialized value is still sanitized and validated.
+    pub deserialize_with: Option<syn::Path>,
+
+    /// Field name to wrap the value into, e.g. `{"value": 42}`.
+    /// If not set, the value is serialized transparently as the inner value.
+    pub wrap: Option<syn::LitStr>,
+
+    /// Skip sanitization and validation on deserialization, trusting the source of the data.
+    /// Requires `new_unchecked` feature.
+    pub skip_validation: bool,
+
+    /// Skip sanitization on deserialization, so the value is only validated.
+    pub skip_sanitization: bool,
+}
+
 /// Parsed attributes (`sanitize`, `validate`, `new_unchecked`).
 #[derive(Debug)]
 pub struct Attributes<G, DT> {
@@ -215,6 +431,113 @@ pub struct Attributes<G, DT> {
     pub default: Option<syn::Expr>,
 
     pub derive_traits: Vec<DT>,
+
+    /// Policy for derived arithmetic operators. Provided with `ops = `
+    pub ops: OpsPolicy,
+
+    /// `secret` flag
+    pub secret: Secret,
+
+    /// `const_fn` flag
+    pub const_fn: ConstFn,
+
+    /// Name of the constructor, provided with `constructor = `. Defaults to `new`.
+    pub constructor: Option<proc_macro2::Ident>,
+
+    /// Visibility of the constructor and other functions, that create values.
+    /// If not set, they are public.
+    pub constructor_vis: Option<syn::Visibility>,
+
+    /// `factory` flag
+    pub factory: Factory,
+
+    /// `try_from_lossless` flag
+    pub try_from_lossless: bool,
+
+    /// Methods of the inner type to delegate to, provided with `delegate(...)`
+    pub delegate: Vec<proc_macro2::Ident>,
+
+    /// Name of the method, which returns a reference to the inner value, provided with `accessor = `
+    pub accessor: Option<Ident>,
+
+    /// Types to generate widening `From` conversions into, provided with `widen_into(...)`
+    pub widen_into: Vec<syn::Path>,
+
+    /// Derive macros, that are not known to nutype and are applied to the struct as is.
+    /// Nutype cannot guarantee, that they respect the guards.
+    pub derive_unsafe: Vec<syn::Path>,
+
+    /// Representation of the struct, e.g. `repr = transparent`.
+    pub repr: Repr,
+
+    /// `local` flag: the items are generated in place, without the wrapping module
+    pub local: bool,
+
+    /// Doc comments of the generated items, provided with `docs(...)`
+    pub docs: Vec<ItemDoc>,
+
+    /// Generated methods and trait implementations to leave out, provided with `omit(...)`
+    pub omit: Vec<Ident>,
+
+    /// Optional methods to generate, provided with `methods(...)`
+    pub methods: OptionalMethods,
+
+    /// `metadata` flag: the guard is described in `NUTYPE_METADATA` constant for external tools
+    pub metadata: bool,
+
+    /// `debug_expansion` flag: the generated code is written to `target/nutype-expansions/`
+    pub debug_expansion: Option<Span>,
+
+    /// Warnings about redundant rules, which are emitted along with the generated code
+    pub warnings: Vec<Warning>,
+
+    /// Options provided with `serde(...)`
+    pub serde: SerdeAttributes,
+
+    /// Customization of the error type
+    pub error: ErrorAttributes,
+}
+
+/// Inherent methods, which are generated only on request with `methods(...)`, e.g. `methods(validate)`,
+/// because their common names would collide with the methods implemented by the user.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OptionalMethods {
+    /// `validate()`, which runs the guard without constructing the newtype.
+    /// The span points at `validate` within `methods(...)` to report that it cannot be generated.
+    pub validate: Option<Span>,
+
+    /// `set()`, which replaces the inner value if the new one passes the guard.
+    pub set: bool,
+
+    /// `try_mutate()`, which changes the inner value in place and re-runs the guard.
+    pub try_mutate: bool,
+
+    /// `try_map()`, which passes the mapped inner value through the constructor.
+    pub try_map: bool,
+
+    /// `map()`, the infallible counterpart of `try_map()` for types without validation.
+    pub map: bool,
+
+    /// `try_new_all()`, which constructs values out of every item of an iterator.
+    pub try_new_all: bool,
+
+    /// `leak()` of String types, which returns `&'static str`.
+    pub leak: bool,
+}
+
+impl OptionalMethods {
+    /// The first requested method, which exists only for types with validation.
+    pub fn first_requiring_validation(&self) -> Option<&'static str> {
+        [
+            ("validate", self.validate.is_some()),
+            ("set", self.set),
+            ("try_mutate", self.try_mutate),
+            ("try_map", self.try_map),
+            ("try_new_all", self.try_new_all),
+        ]
+        .into_iter()
+        .find_map(|(name, requested)| requested.then_some(name))
+    }
 }
 
 /// Represents a value known at compile time or an expression.
@@ -231,13 +554,27 @@ impl<T: ToTokens> ToTokens for ValueOrExpr<T> {
             Self::Value(value) => {
                 value.to_tokens(token_stream);
             }
-            Self::Expr(expr) => {
+            Self::Expr(expr) if is_atomic_expr(expr) => {
                 expr.to_tokens(token_stream);
             }
+            Self::Expr(expr) => {
+                // The expression is interpolated into other expressions (e.g. `#upper - #lower`),
+                // so it is wrapped into parentheses to keep the precedence of the operators.
+                token_stream.extend(quote!((#expr)));
+            }
         };
     }
 }
 
+fn is_atomic_expr(expr: &syn::Expr) -> bool {
+    use syn::Expr::*;
+
+    matches!(
+        expr,
+        Lit(_) | Path(_) | Paren(_) | Block(_) | Call(_) | MethodCall(_) | Field(_) | Index(_)
+    )
+}
+
 impl<Sanitizer, Validator> Guard<Sanitizer, Validator> {
     pub fn has_validation(&self) -> bool {
         match self {
@@ -246,12 +583,29 @@ impl<Sanitizer, Validator> Guard<Sanitizer, Validator> {
         }
     }
 
+    pub fn sanitizers(&self) -> &Vec<Sanitizer> {
+        match self {
+            Self::WithValidation { sanitizers, .. } => sanitizers,
+            Self::WithoutValidation { sanitizers } => sanitizers,
+        }
+    }
+
     pub fn validators(&self) -> Option<&Vec<Validator>> {
         match self {
             Self::WithValidation { validators, .. } => Some(validators),
             Self::WithoutValidation { .. } => None,
         }
     }
+
+    pub fn into_rules(self) -> (Vec<Sanitizer>, Vec<Validator>) {
+        match self {
+            Self::WithValidation {
+                sanitizers,
+                validators,
+            } => (sanitizers, validators),
+            Self::WithoutValidation { sanitizers } => (sanitizers, Vec::new()),
+        }
+    }
 }
 
 /// Parsed by not yet validated
@@ -294,6 +648,51 @@ pub enum DeriveTrait {
 
     #[cfg_attr(not(feature = "arbitrary"), allow(dead_code))]
     ArbitraryArbitrary,
+
+    #[cfg_attr(not(feature = "juniper"), allow(dead_code))]
+    JuniperGraphQLScalar,
+
+    #[cfg_attr(not(feature = "rocket"), allow(dead_code))]
+    RocketFromParam,
+    #[cfg_attr(not(feature = "rocket"), allow(dead_code))]
+    RocketFromFormField,
+
+    #[cfg_attr(not(feature = "actix"), allow(dead_code))]
+    ActixFromRequest,
+
+    #[cfg_attr(not(feature = "clap"), allow(dead_code))]
+    ClapValueParserFactory,
+
+    #[cfg_attr(not(feature = "rand"), allow(dead_code))]
+    RandDistribution,
+    #[cfg_attr(not(feature = "num-traits"), allow(dead_code))]
+    NumTraitsZero,
+    #[cfg_attr(not(feature = "num-traits"), allow(dead_code))]
+    NumTraitsOne,
+    #[cfg_attr(not(feature = "num-traits"), allow(dead_code))]
+    NumTraitsBounded,
+    #[cfg_attr(not(feature = "num-traits"), allow(dead_code))]
+    NumTraitsToPrimitive,
+    #[cfg_attr(not(feature = "num-traits"), allow(dead_code))]
+    NumTraitsFromPrimitive,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    AddAssign,
+    SubAssign,
+    MulAssign,
+    DivAssign,
+    Sum,
+    Product,
+    #[cfg_attr(not(feature = "zeroize"), allow(dead_code))]
+    ZeroizeZeroize,
+    #[cfg_attr(not(feature = "zeroize"), allow(dead_code))]
+    ZeroizeZeroizeOnDrop,
+    #[cfg_attr(not(feature = "serde_with"), allow(dead_code))]
+    SerdeWithSerializeAs,
+    #[cfg_attr(not(feature = "serde_with"), allow(dead_code))]
+    SerdeWithDeserializeAs,
 }
 
 pub type SpannedDeriveTrait = SpannedItem<DeriveTrait>;
@@ -301,6 +700,21 @@ pub type SpannedDeriveTrait = SpannedItem<DeriveTrait>;
 pub trait TypeTrait {
     // If this is FromStr variant?
     fn is_from_str(&self) -> bool;
+
+    // If the trait comes with `Drop` implementation? If so, the inner value cannot be moved out.
+    fn is_drop(&self) -> bool {
+        false
+    }
+
+    // If this is Debug variant?
+    fn is_debug(&self) -> bool {
+        false
+    }
+
+    // If this is Clone variant?
+    fn is_clone(&self) -> bool {
+        false
+    }
 }
 
 /// The flag the indicates that a newtype will be generated with extra constructor,
@@ -313,25 +727,129 @@ pub enum NewUnchecked {
 
     // `On` variant can be constructed when `new_unchecked` feature flag is enabled.
     #[allow(dead_code)]
+    On {
+        /// Check the validation rules within `new_unchecked()` when `debug_assertions` are enabled.
+        /// Disabled with `new_unchecked(no_debug_assert)`.
+        debug_assert: bool,
+
+        /// Generate the functions without `unsafe`, so they can be used within crates
+        /// with `#![forbid(unsafe_code)]`. Enabled with `new_unchecked(safe)`.
+        safe: bool,
+
+        /// Span of `new_unchecked` attribute.
+        span: Span,
+    },
+}
+
+/// The flag that indicates that a newtype holds a secret: the inner value is accessible only with
+/// `expose_secret()` of `secrecy::ExposeSecret` trait and gets zeroized on drop.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Secret {
+    #[default]
+    Off,
+
+    // `On` variant can be constructed when `secrecy` feature flag is enabled.
+    #[allow(dead_code)]
+    On {
+        /// Set with `secret(serialize)`: the secret is serialized with derived `Serialize`.
+        serialize: bool,
+    },
+}
+
+impl Secret {
+    pub fn is_on(self) -> bool {
+        matches!(self, Self::On { .. })
+    }
+}
+
+/// Memory representation of the newtype. With `Transparent` the newtype is `#[repr(transparent)]`,
+/// so it has the layout of the inner type and slices and vectors can be converted without copying.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Repr {
+    #[default]
+    Rust,
+    Transparent,
+}
+
+/// The flag that indicates that the constructors and `into_inner()` are generated as `const fn`,
+/// so the type can be used within `const` items and statics.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ConstFn {
+    #[default]
+    Off,
     On,
 }
 
-pub struct GenerateParams<IT, Trait, Guard> {
+impl quote::ToTokens for ConstFn {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if *self == ConstFn::On {
+            quote::quote!(const).to_tokens(tokens);
+        }
+    }
+}
+
+/// The flag that indicates that a factory type is generated, which allows to set some of
+/// the validator bounds at runtime.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Factory {
+    #[default]
+    Off,
+    On,
+}
+
+/// Defines how derived arithmetic operators (`Add`, `Sub`, `Mul`, `Div`) handle results
+/// that violate the validation rules or overflow the inner type.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OpsPolicy {
+    /// Panic if the result is not valid. `Output = Self`.
+    #[default]
+    Panic,
+
+    /// Clamp the result into the range defined by the validators. `Output = Self`.
+    Saturate,
+
+    /// Return the validation error. `Output = Result<Self, Error>`.
+    Result,
+}
+
+pub type SpannedOpsPolicy = SpannedItem<OpsPolicy>;
+
+pub struct GenerateParams<IT, Trait, Guard, Conditional> {
     pub inner_type: IT,
-    pub doc_attrs: Vec<Attribute>,
+    pub struct_attrs: Vec<Attribute>,
     pub traits: HashSet<Trait>,
     pub vis: syn::Visibility,
     pub type_name: TypeName,
+    /// The type has generic parameters, which are applied to the generated code afterwards.
+    pub is_generic: bool,
     pub guard: Guard,
+    /// The rules within `cfg_attr(predicate, ...)`, which are not part of the guard.
+    pub conditional: Conditional,
     pub new_unchecked: NewUnchecked,
     pub maybe_default_value: Option<syn::Expr>,
+    pub ops: OpsPolicy,
+    pub secret: Secret,
+    pub const_fn: ConstFn,
+    pub constructor: Option<proc_macro2::Ident>,
+    pub constructor_vis: Option<syn::Visibility>,
+    pub factory: Factory,
+    pub try_from_lossless: bool,
+    pub delegate: Vec<proc_macro2::Ident>,
+    pub accessor: Option<Ident>,
+    pub widen_into: Vec<syn::Path>,
+    pub derive_unsafe: Vec<syn::Path>,
+    pub repr: Repr,
+    pub local: bool,
+    pub methods: OptionalMethods,
+    pub serde: SerdeAttributes,
+    pub error: ErrorAttributes,
 }
 
 pub trait Newtype {
-    type Sanitizer;
-    type Validator;
+    type Sanitizer: Metadata;
+    type Validator: Metadata;
     type TypedTrait;
-    type InnerType;
+    type InnerType: ToTokens;
 
     #[allow(clippy::type_complexity)]
     fn parse_attributes(
@@ -349,6 +867,7 @@ pub trait Newtype {
             Self::InnerType,
             Self::TypedTrait,
             Guard<Self::Sanitizer, Self::Validator>,
+            ConditionalRules<Self::Sanitizer, Self::Validator>,
         >,
     ) -> Result<TokenStream, syn::Error>;
 
@@ -357,29 +876,129 @@ pub trait Newtype {
         inner_type: Self::InnerType,
     ) -> Result<TokenStream, syn::Error> {
         let TypedMeta {
-            doc_attrs,
+            struct_attrs,
+            cfg_attrs,
             type_name,
+            generics,
             attrs,
             vis,
         } = typed_meta;
+        let module_name = gen_module_name_for_type(&type_name);
+        let generic_type_name = type_name.clone();
+        let (attrs, conditional_definitions) = split_conditional_rules(attrs)?;
         let Attributes {
             guard,
             new_unchecked,
             default: maybe_default_value,
             derive_traits,
+            ops,
+            secret,
+            const_fn,
+            constructor,
+            constructor_vis,
+            factory,
+            try_from_lossless,
+            delegate,
+            accessor,
+            widen_into,
+            derive_unsafe,
+            repr,
+            local,
+            docs,
+            omit,
+            methods,
+            metadata,
+            debug_expansion,
+            mut warnings,
+            serde,
+            error,
         } = Self::parse_attributes(attrs)?;
+        let (guard, conditional) =
+            take_conditional_rules(guard, conditional_definitions, |attrs| {
+                Ok(Self::parse_attributes(attrs)?.guard)
+            })?;
+        // The conditional rules are checked within non-const functions
+        let const_fn = if conditional.is_empty() {
+            const_fn
+        } else {
+            ConstFn::Off
+        };
+        if !conditional.is_empty() {
+            validate_conditional_derive_traits(&derive_traits)?;
+        }
+        validate_generic_derive_traits(&derive_traits, &generics, &inner_type)?;
+        let is_generic = !generics.params.is_empty();
+        if let (true, Some(target)) = (is_generic, widen_into.first()) {
+            let msg = "`widen_into` is not supported for types with generic parameters.";
+            return Err(syn::Error::new_spanned(target, msg));
+        }
         let traits = Self::validate(&guard, derive_traits)?;
+        let error_type_name = error
+            .name
+            .clone()
+            .unwrap_or_else(|| gen_error_type_name(&type_name));
+        let constructor_name = constructor
+            .clone()
+            .unwrap_or_else(|| quote::format_ident!("new"));
+        let metadata_const = if metadata {
+            gen_metadata_const(&type_name, &inner_type, &generics, &guard, &error)
+        } else {
+            quote!()
+        };
+        let doc_targets = DocTargets {
+            type_name: type_name.clone(),
+            error_type_name,
+            parse_error_type_name: gen_parse_error_name(&type_name),
+            extends: error.extends.clone(),
+        };
         let generated_output = Self::generate(GenerateParams {
-            doc_attrs,
+            struct_attrs,
             traits,
             vis,
             type_name,
+            is_generic,
             guard,
+            conditional,
             new_unchecked,
             maybe_default_value,
             inner_type,
+            ops,
+            secret,
+            const_fn,
+            constructor,
+            constructor_vis,
+            factory,
+            try_from_lossless,
+            delegate,
+            accessor,
+            widen_into,
+            derive_unsafe,
+            repr,
+            local,
+            methods,
+            serde,
+            error,
         })?;
-        Ok(generated_output)
+        let generated_output = apply_omit(
+            generated_output,
+            &doc_targets.type_name,
+            &constructor_name,
+            &omit,
+        )?;
+        let generated_output = apply_docs(generated_output, &doc_targets, &docs)?;
+        let generated_output = apply_generics(
+            generated_output,
+            &generic_type_name,
+            &doc_targets.error_type_name,
+            &module_name,
+            &generics,
+        )?;
+        let output = apply_cfg_attrs(quote!(#generated_output #metadata_const), &cfg_attrs)?;
+        if let Some(span) = debug_expansion {
+            warnings.push(write_expansion(&output, &doc_targets.type_name, span)?);
+        }
+        let warnings = apply_cfg_attrs(gen_warnings(&warnings), &cfg_attrs)?;
+        Ok(quote!(#output #warnings))
     }
 }
 
@@ -394,18 +1013,27 @@ pub enum CustomFunction {
 
 impl Parse for CustomFunction {
     fn parse(input: ParseStream) -> syn::Result<CustomFunction> {
-        if let Ok(path) = input.parse::<Path>() {
-            Ok(Self::Path(path))
-        } else if let Ok(closure) = input.parse::<ExprClosure>() {
-            Ok(Self::Closure(closure))
-        } else {
-            let msg = "Expected a path to function or a closure.";
-            Err(syn::Error::new(input.span(), msg))
+        let span = input.span();
+        let msg = "Expected a path to function or a closure.";
+        let expr: Expr = input.parse().map_err(|_| syn::Error::new(span, msg))?;
+        // The invisible groups and the parentheses come from `macro_rules!` wrappers, e.g. `$f:expr`
+        match strip_expr_groups(&expr).clone() {
+            Expr::Path(expr_path) if expr_path.qself.is_none() => Ok(Self::Path(expr_path.path)),
+            Expr::Closure(closure) => Ok(Self::Closure(closure)),
+            _ => Err(syn::Error::new(span, msg)),
         }
     }
 }
 
 impl CustomFunction {
+    /// The path of the function, if it's not a closure.
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            Self::Path(path) => Some(path),
+            Self::Closure(_) => None,
+        }
+    }
+
     pub fn try_into_typed(self, inner_type: &syn::Type) -> syn::Result<TypedCustomFunction> {
         match self {
             CustomFunction::Path(path) => Ok(TypedCustomFunction::Path(path)),
@@ -444,6 +1072,16 @@ pub enum TypedCustomFunction {
     Closure(ExprClosure),
 }
 
+impl TypedCustomFunction {
+    /// The path of the function, if it's not a closure.
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            Self::Path(path) => Some(path),
+            Self::Closure(_) => None,
+        }
+    }
+}
+
 impl ToTokens for TypedCustomFunction {
     fn to_tokens(&self, token_stream: &mut TokenStream) {
         match self {
@@ -457,6 +1095,24 @@ impl ToTokens for TypedCustomFunction {
     }
 }
 
+/// Tells whether a validator is configured with a parameter (e.g. `len_char_max = 255`),
+/// which an error variant carries with `error_fields(bounds)`.
+pub trait HasBound {
+    fn has_bound(&self) -> bool;
+}
+
+/// Human readable description of a sanitizer or a validator for the generated docs of the type,
+/// e.g. "`len_char_max = 20`: at most 20 characters".
+pub trait Describe {
+    fn describe(&self) -> String;
+}
+
+/// Machine-readable description of a sanitizer or a validator as a JSON object for
+/// `NUTYPE_METADATA` constant, e.g. `{"kind":"len_char_max","value":20}`.
+pub trait Metadata {
+    fn metadata(&self) -> String;
+}
+
 /// This trait allows to reuse validation of numeric validators.
 pub trait NumericBoundValidator<T: Clone> {
     fn greater(&self) -> Option<T>;
@@ -465,6 +1121,13 @@ pub trait NumericBoundValidator<T: Clone> {
     fn less_or_equal(&self) -> Option<T>;
 }
 
+/// Bounds of numeric validators, which are given by expressions and therefore cannot be checked
+/// at expansion time.
+pub trait NumericExprBound {
+    /// The condition on `value` of a bound given by an expression, e.g. `value >= MIN`.
+    fn expr_bound_condition(&self, value: &TokenStream) -> Option<TokenStream>;
+}
+
 macro_rules! impl_numeric_bound_validator {
     ($tp:ident) => {
         impl<T: Clone> crate::common::models::NumericBoundValidator<T> for $tp<T> {
@@ -500,6 +1163,22 @@ macro_rules! impl_numeric_bound_validator {
                 }
             }
         }
+
+        impl<T> crate::common::models::NumericExprBound for $tp<T> {
+            fn expr_bound_condition(
+                &self,
+                value: &::proc_macro2::TokenStream,
+            ) -> Option<::proc_macro2::TokenStream> {
+                let (comparison, expr) = match self {
+                    $tp::Greater(ValueOrExpr::Expr(expr)) => (::quote::quote!(>), expr),
+                    $tp::GreaterOrEqual(ValueOrExpr::Expr(expr)) => (::quote::quote!(>=), expr),
+                    $tp::Less(ValueOrExpr::Expr(expr)) => (::quote::quote!(<), expr),
+                    $tp::LessOrEqual(ValueOrExpr::Expr(expr)) => (::quote::quote!(<=), expr),
+                    _ => return None,
+                };
+                Some(::quote::quote!(#value #comparison #expr))
+            }
+        }
     };
 }
 
@@ -567,3 +1246,77 @@ macro_rules! impl_numeric_bound_on_vec_of {
 }
 
 pub(crate) use impl_numeric_bound_on_vec_of;
+
+/// Reusable sanitizers and validators defined with `nutype_guard!`, e.g.
+/// `nutype_guard! { pub(crate) UsernameRules = sanitize(trim), validate(not_empty) }`.
+/// The rules are validated against the inner type only when they are applied with `guard = `.
+pub struct GuardDefinition {
+    pub vis: syn::Visibility,
+    pub name: Ident,
+    pub rules: TokenStream,
+}
+
+/// A sanitizer or a validator within `cfg_attr(predicate, ...)` of `sanitize(...)` or
+/// `validate(...)`, before it's parsed. See `common::parse::conditional`.
+#[derive(Debug)]
+pub struct ConditionalRuleDefinition {
+    pub is_validator: bool,
+    pub predicate: TokenStream,
+    /// The index of the rule among the sanitizers or the validators of the attributes.
+    pub index: usize,
+    /// The number of the unconditional rules declared before the rule.
+    pub position: usize,
+    /// The attributes, in which the rule takes the place of the rule of the same kind under
+    /// another predicate, since both cannot be parsed together. `None` if the rule is parsed
+    /// along with the other attributes.
+    pub attrs: Option<TokenStream>,
+}
+
+/// A sanitizer or a validator, which is compiled only if its predicate holds.
+#[derive(Debug)]
+pub struct ConditionalRule<T> {
+    pub rule: T,
+    pub predicate: TokenStream,
+    /// The number of the unconditional rules declared before the rule, so the rules run
+    /// in the order they are declared.
+    pub position: usize,
+}
+
+/// The sanitizers and validators within `cfg_attr(predicate, ...)`. The unconditional ones are
+/// kept by the guard.
+#[derive(Debug)]
+pub struct ConditionalRules<Sanitizer, Validator> {
+    pub sanitizers: Vec<ConditionalRule<Sanitizer>>,
+    pub validators: Vec<ConditionalRule<Validator>>,
+}
+
+impl<Sanitizer, Validator> Default for ConditionalRules<Sanitizer, Validator> {
+    fn default() -> Self {
+        Self {
+            sanitizers: Vec::new(),
+            validators: Vec::new(),
+        }
+    }
+}
+
+impl<Sanitizer, Validator> ConditionalRules<Sanitizer, Validator> {
+    pub fn is_empty(&self) -> bool {
+        self.sanitizers.is_empty() && self.validators.is_empty()
+    }
+}
+
+/// A warning about the attributes, e.g. a redundant validator.
+/// In strict mode it's reported as a compile error instead.
+#[derive(Debug)]
+pub struct Warning {
+    pub span: Span,
+    pub message: String,
+}
+
+/// Doc comment of a generated item provided with `docs(...)`, e.g. `docs(new = "Creates a username.")`.
+#[derive(Debug)]
+pub struct ItemDoc {
+    /// `error`, `parse_error` or a name of a generated method, e.g. `new` or `into_inner`.
+    pub item: Ident,
+    pub doc: LitStr,
+}
@@ -0,0 +1,304 @@
+//! Sanitizers and validators, which are enabled only under some configuration, e.g.
+//! `validate(not_empty, cfg_attr(test, len_char_max = 1000), cfg_attr(not(test), len_char_max = 100))`.
+//!
+//! The conditional rules are parsed and validated together with the other attributes,
+//! then they are taken out of the guard, so every generated check, error variant and match arm
+//! of a rule is put under `#[cfg(...)]` of its predicate.
+//!
+//! The rules of the same kind (e.g. `len_char_max` of `test` and `not(test)`) cannot be parsed
+//! together, so a repeated kind is parsed within its own copy of the attributes, where it takes
+//! the place of the first occurrence.
+
+use std::collections::HashMap;
+
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use quote::quote;
+
+use crate::common::{
+    models::{ConditionalRule, ConditionalRuleDefinition, ConditionalRules, Guard},
+    parse::guard_definition::split_by_commas,
+};
+
+/// The attributes, which rely on the guard being known at compile time.
+const UNSUPPORTED_ATTRIBUTES: [&str; 4] = ["const_fn", "factory", "metadata", "ops"];
+
+/// A sanitizer or a validator within `sanitize(...)` or `validate(...)`.
+enum Rule {
+    Always(TokenStream),
+    Conditional {
+        predicate: TokenStream,
+        rules: Vec<TokenStream>,
+    },
+}
+
+/// `sanitize(...)` or `validate(...)` with the rules, or any other attribute as is.
+enum Attribute {
+    Rules {
+        name: TokenTree,
+        rules: Vec<TokenStream>,
+    },
+    Other(TokenStream),
+}
+
+/// A rule of the kind, which is already used under another predicate.
+struct RepeatedRule {
+    attribute: usize,
+    index: usize,
+    rule: TokenStream,
+}
+
+/// Inline `cfg_attr(predicate, ...)` within `sanitize(...)` and `validate(...)` into the attributes
+/// and collect the definitions of the conditional rules.
+pub fn split_conditional_rules(
+    attrs: TokenStream,
+) -> Result<(TokenStream, Vec<ConditionalRuleDefinition>), syn::Error> {
+    let mut attributes: Vec<Attribute> = Vec::new();
+    let mut definitions: Vec<ConditionalRuleDefinition> = Vec::new();
+    let mut repeated_rules: Vec<RepeatedRule> = Vec::new();
+
+    for chunk in split_by_commas(attrs.clone()) {
+        match chunk.as_slice() {
+            [name @ TokenTree::Ident(ident), TokenTree::Group(group)]
+                if (ident == "sanitize" || ident == "validate")
+                    && group.delimiter() == Delimiter::Parenthesis =>
+            {
+                let is_validator = ident == "validate";
+                let mut rules: Vec<TokenStream> = Vec::new();
+                // The index of the first conditional rule of every kind
+                let mut kinds: HashMap<String, usize> = HashMap::new();
+                let mut position = 0;
+                for rule in split_by_commas(group.stream()) {
+                    match parse_rule(rule)? {
+                        Rule::Always(rule) => {
+                            rules.push(rule);
+                            position += 1;
+                        }
+                        Rule::Conditional {
+                            predicate,
+                            rules: conditional_rules,
+                        } => {
+                            for rule in conditional_rules {
+                                let mut definition = ConditionalRuleDefinition {
+                                    is_validator,
+                                    predicate: predicate.clone(),
+                                    index: rules.len(),
+                                    position,
+                                    attrs: None,
+                                };
+                                match rule_kind(&rule).and_then(|kind| kinds.get(&kind)) {
+                                    Some(&index) => {
+                                        definition.index = index;
+                                        // Filled in once all the attributes are collected
+                                        definition.attrs = Some(TokenStream::new());
+                                        repeated_rules.push(RepeatedRule {
+                                            attribute: attributes.len(),
+                                            index,
+                                            rule,
+                                        });
+                                    }
+                                    None => {
+                                        if let Some(kind) = rule_kind(&rule) {
+                                            kinds.insert(kind, rules.len());
+                                        }
+                                        rules.push(rule);
+                                    }
+                                }
+                                definitions.push(definition);
+                            }
+                        }
+                    }
+                }
+                attributes.push(Attribute::Rules {
+                    name: name.clone(),
+                    rules,
+                });
+            }
+            _ => attributes.push(Attribute::Other(chunk.into_iter().collect())),
+        }
+    }
+
+    if definitions.is_empty() {
+        return Ok((attrs, definitions));
+    }
+    validate_attributes(&attributes)?;
+
+    let repeated_definitions = definitions
+        .iter_mut()
+        .filter(|definition| definition.attrs.is_some());
+    for (definition, repeated) in repeated_definitions.zip(&repeated_rules) {
+        definition.attrs = Some(gen_attrs(&attributes, Some(repeated)));
+    }
+    Ok((gen_attrs(&attributes, None), definitions))
+}
+
+/// Take the conditional rules out of the guard, which is parsed out of the attributes returned
+/// by `split_conditional_rules()`. `parse_guard` parses the attributes of the repeated rules.
+#[allow(clippy::type_complexity)]
+pub fn take_conditional_rules<Sanitizer, Validator>(
+    guard: Guard<Sanitizer, Validator>,
+    definitions: Vec<ConditionalRuleDefinition>,
+    parse_guard: impl Fn(TokenStream) -> Result<Guard<Sanitizer, Validator>, syn::Error>,
+) -> Result<
+    (
+        Guard<Sanitizer, Validator>,
+        ConditionalRules<Sanitizer, Validator>,
+    ),
+    syn::Error,
+> {
+    let mut conditional = ConditionalRules::default();
+    if definitions.is_empty() {
+        return Ok((guard, conditional));
+    }
+
+    // With conditional validators the type has an error type, even if no validator is enabled
+    let has_validation =
+        guard.has_validation() || definitions.iter().any(|definition| definition.is_validator);
+    let (mut sanitizers, mut validators) = guard.into_rules();
+
+    // The inlined rules are removed starting from the end, so the indices stay valid
+    let mut inlined_sanitizers: HashMap<usize, Sanitizer> = HashMap::new();
+    let mut inlined_validators: HashMap<usize, Validator> = HashMap::new();
+    let mut inlined: Vec<(bool, usize)> = definitions
+        .iter()
+        .filter(|definition| definition.attrs.is_none())
+        .map(|definition| (definition.is_validator, definition.index))
+        .collect();
+    inlined.sort_by_key(|(_, index)| std::cmp::Reverse(*index));
+    for (is_validator, index) in inlined {
+        if is_validator {
+            inlined_validators.insert(index, validators.remove(index));
+        } else {
+            inlined_sanitizers.insert(index, sanitizers.remove(index));
+        }
+    }
+
+    for definition in definitions {
+        let ConditionalRuleDefinition {
+            is_validator,
+            predicate,
+            index,
+            position,
+            attrs,
+        } = definition;
+        match (is_validator, attrs) {
+            (true, None) => conditional.validators.push(ConditionalRule {
+                rule: inlined_validators
+                    .remove(&index)
+                    .expect("inlined validator"),
+                predicate,
+                position,
+            }),
+            (false, None) => conditional.sanitizers.push(ConditionalRule {
+                rule: inlined_sanitizers
+                    .remove(&index)
+                    .expect("inlined sanitizer"),
+                predicate,
+                position,
+            }),
+            (true, Some(attrs)) => {
+                let (_, mut validators) = parse_guard(attrs)?.into_rules();
+                conditional.validators.push(ConditionalRule {
+                    rule: validators.remove(index),
+                    predicate,
+                    position,
+                });
+            }
+            (false, Some(attrs)) => {
+                let (mut sanitizers, _) = parse_guard(attrs)?.into_rules();
+                conditional.sanitizers.push(ConditionalRule {
+                    rule: sanitizers.remove(index),
+                    predicate,
+                    position,
+                });
+            }
+        }
+    }
+
+    let guard = if has_validation {
+        Guard::WithValidation {
+            sanitizers,
+            validators,
+        }
+    } else {
+        Guard::WithoutValidation { sanitizers }
+    };
+    Ok((guard, conditional))
+}
+
+fn parse_rule(rule: Vec<TokenTree>) -> Result<Rule, syn::Error> {
+    let (ident, group) = match rule.as_slice() {
+        [TokenTree::Ident(ident), TokenTree::Group(group)]
+            if (ident == "cfg_attr" || ident == "cfg")
+                && group.delimiter() == Delimiter::Parenthesis =>
+        {
+            (ident.clone(), group.clone())
+        }
+        _ => return Ok(Rule::Always(rule.into_iter().collect())),
+    };
+
+    let mut chunks = split_by_commas(group.stream()).into_iter();
+    let predicate: TokenStream = chunks.next().unwrap_or_default().into_iter().collect();
+    let rules: Vec<TokenStream> = chunks.map(|chunk| chunk.into_iter().collect()).collect();
+    if predicate.is_empty() || rules.is_empty() {
+        let msg = format!("`{ident}(...)` expects a predicate followed by sanitizers or validators, e.g. `{ident}(test, len_char_max = 1000)`.");
+        return Err(syn::Error::new(group.span(), msg));
+    }
+    Ok(Rule::Conditional { predicate, rules })
+}
+
+/// The kind of a rule is its leading identifier, e.g. `len_char_max` of `len_char_max = 1000`.
+fn rule_kind(rule: &TokenStream) -> Option<String> {
+    match rule.clone().into_iter().next() {
+        Some(TokenTree::Ident(ident)) => Some(ident.to_string()),
+        _ => None,
+    }
+}
+
+fn validate_attributes(attributes: &[Attribute]) -> Result<(), syn::Error> {
+    for attribute in attributes {
+        let Attribute::Other(tokens) = attribute else {
+            continue;
+        };
+        let Some(TokenTree::Ident(ident)) = tokens.clone().into_iter().next() else {
+            continue;
+        };
+        // `ops = panic` and `ops = result` check the result with the constructor
+        let is_saturate = tokens.to_string().ends_with("saturate");
+        if UNSUPPORTED_ATTRIBUTES.contains(&ident.to_string().as_str())
+            && (ident != "ops" || is_saturate)
+        {
+            let attribute = if ident == "ops" {
+                "ops = saturate".to_string()
+            } else {
+                ident.to_string()
+            };
+            let msg = format!("`{attribute}` cannot be used together with `cfg_attr(...)` within `sanitize(...)` or `validate(...)`, because it relies on the rules being known at compile time.");
+            return Err(syn::Error::new(ident.span(), msg));
+        }
+    }
+    Ok(())
+}
+
+fn gen_attrs(attributes: &[Attribute], repeated: Option<&RepeatedRule>) -> TokenStream {
+    let attrs = attributes
+        .iter()
+        .enumerate()
+        .map(|(attribute_index, attribute)| match attribute {
+            Attribute::Rules { name, rules } => {
+                let rules = rules
+                    .iter()
+                    .enumerate()
+                    .map(|(index, rule)| match repeated {
+                        Some(repeated)
+                            if repeated.attribute == attribute_index && repeated.index == index =>
+                        {
+                            &repeated.rule
+                        }
+                        _ => rule,
+                    });
+                quote!(#name(#(#rules),*))
+            }
+            Attribute::Other(tokens) => tokens.clone(),
+        });
+    quote!(#(#attrs),*)
+}
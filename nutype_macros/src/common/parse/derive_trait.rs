@@ -4,6 +4,58 @@ use syn::parse::{Parse, ParseStream};
 
 use crate::common::models::{DeriveTrait, SpannedDeriveTrait};
 
+use super::suggest::did_you_mean;
+
+/// Names of the derivable traits, which are suggested for a misspelled one.
+const DERIVE_TRAITS: &[&str] = &[
+    "Debug",
+    "Display",
+    "Clone",
+    "Copy",
+    "PartialEq",
+    "Eq",
+    "PartialOrd",
+    "Ord",
+    "FromStr",
+    "AsRef",
+    "Deref",
+    "TryFrom",
+    "From",
+    "Into",
+    "Hash",
+    "Borrow",
+    "Default",
+    "Serialize",
+    "Deserialize",
+    "JsonSchema",
+    "Arbitrary",
+    "GraphQLScalar",
+    "FromParam",
+    "FromFormField",
+    "FromRequest",
+    "ValueParserFactory",
+    "Distribution",
+    "Zero",
+    "One",
+    "Bounded",
+    "ToPrimitive",
+    "FromPrimitive",
+    "Add",
+    "Sub",
+    "Mul",
+    "Div",
+    "AddAssign",
+    "SubAssign",
+    "MulAssign",
+    "DivAssign",
+    "Sum",
+    "Product",
+    "Zeroize",
+    "ZeroizeOnDrop",
+    "SerializeAs",
+    "DeserializeAs",
+];
+
 impl Parse for SpannedDeriveTrait {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let ident: Ident = input.parse()?;
@@ -62,11 +114,159 @@ impl Parse for SpannedDeriveTrait {
                     }
                 }
             }
+            "GraphQLScalar" => {
+                cfg_if! {
+                    if #[cfg(feature = "juniper")] {
+                        DeriveTrait::JuniperGraphQLScalar
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive GraphQLScalar, the feature `juniper` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "FromParam" => {
+                cfg_if! {
+                    if #[cfg(feature = "rocket")] {
+                        DeriveTrait::RocketFromParam
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive FromParam, the feature `rocket` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "FromFormField" => {
+                cfg_if! {
+                    if #[cfg(feature = "rocket")] {
+                        DeriveTrait::RocketFromFormField
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive FromFormField, the feature `rocket` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "FromRequest" => {
+                cfg_if! {
+                    if #[cfg(feature = "actix")] {
+                        DeriveTrait::ActixFromRequest
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive FromRequest, the feature `actix` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "ValueParserFactory" => {
+                cfg_if! {
+                    if #[cfg(feature = "clap")] {
+                        DeriveTrait::ClapValueParserFactory
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive ValueParserFactory, the feature `clap` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "Distribution" => {
+                cfg_if! {
+                    if #[cfg(feature = "rand")] {
+                        DeriveTrait::RandDistribution
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive Distribution, the feature `rand` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "Zero" => {
+                cfg_if! {
+                    if #[cfg(feature = "num-traits")] {
+                        DeriveTrait::NumTraitsZero
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive Zero, the feature `num-traits` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "One" => {
+                cfg_if! {
+                    if #[cfg(feature = "num-traits")] {
+                        DeriveTrait::NumTraitsOne
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive One, the feature `num-traits` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "Bounded" => {
+                cfg_if! {
+                    if #[cfg(feature = "num-traits")] {
+                        DeriveTrait::NumTraitsBounded
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive Bounded, the feature `num-traits` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "ToPrimitive" => {
+                cfg_if! {
+                    if #[cfg(feature = "num-traits")] {
+                        DeriveTrait::NumTraitsToPrimitive
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive ToPrimitive, the feature `num-traits` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "FromPrimitive" => {
+                cfg_if! {
+                    if #[cfg(feature = "num-traits")] {
+                        DeriveTrait::NumTraitsFromPrimitive
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive FromPrimitive, the feature `num-traits` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "Add" => DeriveTrait::Add,
+            "Sub" => DeriveTrait::Sub,
+            "Mul" => DeriveTrait::Mul,
+            "Div" => DeriveTrait::Div,
+            "AddAssign" => DeriveTrait::AddAssign,
+            "SubAssign" => DeriveTrait::SubAssign,
+            "MulAssign" => DeriveTrait::MulAssign,
+            "DivAssign" => DeriveTrait::DivAssign,
+            "Sum" => DeriveTrait::Sum,
+            "Product" => DeriveTrait::Product,
+            "Zeroize" => {
+                cfg_if! {
+                    if #[cfg(feature = "zeroize")] {
+                        DeriveTrait::ZeroizeZeroize
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive Zeroize, the feature `zeroize` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "ZeroizeOnDrop" => {
+                cfg_if! {
+                    if #[cfg(feature = "zeroize")] {
+                        DeriveTrait::ZeroizeZeroizeOnDrop
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive ZeroizeOnDrop, the feature `zeroize` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "SerializeAs" => {
+                cfg_if! {
+                    if #[cfg(feature = "serde_with")] {
+                        DeriveTrait::SerdeWithSerializeAs
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive SerializeAs, the feature `serde_with` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "DeserializeAs" => {
+                cfg_if! {
+                    if #[cfg(feature = "serde_with")] {
+                        DeriveTrait::SerdeWithDeserializeAs
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive DeserializeAs, the feature `serde_with` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
             _ => {
-                return Err(syn::Error::new(
-                    ident.span(),
-                    format!("#[nutype] does not know how to derive `{ident}` trait."),
-                ));
+                let msg = match did_you_mean(&ident.to_string(), DERIVE_TRAITS.iter().copied()) {
+                    Some(suggestion) => format!(
+                        "#[nutype] does not know how to derive `{ident}` trait. {suggestion}"
+                    ),
+                    None => format!("#[nutype] does not know how to derive `{ident}` trait."),
+                };
+                return Err(syn::Error::new(ident.span(), msg));
             }
         };
         let spanned_trait = SpannedDeriveTrait {
@@ -0,0 +1,98 @@
+use proc_macro2::{Ident, Spacing, TokenStream, TokenTree};
+use syn::{
+    parse::{Parse, ParseStream},
+    Token, Visibility,
+};
+
+use crate::common::models::GuardDefinition;
+
+impl Parse for GuardDefinition {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let vis: Visibility = input.parse()?;
+        if let Visibility::Public(_) = vis {
+            let msg = "Guards cannot be exported from the crate. Use `pub(crate)` instead.";
+            return Err(syn::Error::new_spanned(vis, msg));
+        }
+        let name: Ident = input.parse()?;
+        let _eq: Token![=] = input.parse()?;
+        let rules: TokenStream = input.parse()?;
+
+        let chunks = split_by_commas(rules.clone());
+        if chunks.is_empty() {
+            let msg = "A guard must contain `sanitize(...)` and/or `validate(...)`.";
+            return Err(syn::Error::new(name.span(), msg));
+        }
+        for chunk in chunks {
+            let is_rule = match chunk.as_slice() {
+                [TokenTree::Ident(ident), TokenTree::Group(_)] => {
+                    ident == "sanitize" || ident == "validate"
+                }
+                _ => false,
+            };
+            if !is_rule {
+                let msg = "A guard can contain only `sanitize(...)` and `validate(...)`.";
+                let tokens: TokenStream = chunk.into_iter().collect();
+                return Err(syn::Error::new_spanned(tokens, msg));
+            }
+        }
+
+        Ok(GuardDefinition { vis, name, rules })
+    }
+}
+
+/// Take `guard = Path` out of the attributes of `#[nutype]`.
+/// Returns the path of the guard (if any) and the rest of the attributes.
+pub fn split_guard_attribute(
+    attrs: TokenStream,
+) -> Result<(Option<syn::Path>, TokenStream), syn::Error> {
+    let mut maybe_guard: Option<syn::Path> = None;
+    let mut rest: Vec<TokenStream> = Vec::new();
+
+    for chunk in split_by_commas(attrs) {
+        match chunk.as_slice() {
+            [TokenTree::Ident(ident), TokenTree::Punct(eq), path @ ..]
+                if ident == "guard" && eq.as_char() == '=' =>
+            {
+                if maybe_guard.is_some() {
+                    let msg = "`guard = ` can be set only once.";
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+                maybe_guard = Some(syn::parse2(path.iter().cloned().collect())?);
+            }
+            _ => rest.push(chunk.into_iter().collect()),
+        }
+    }
+
+    if maybe_guard.is_some() {
+        // The guard brings its own sanitizers and validators, so they cannot be mixed.
+        for chunk in &rest {
+            if let Some(TokenTree::Ident(ident)) = chunk.clone().into_iter().next() {
+                if ident == "sanitize" || ident == "validate" {
+                    let msg = format!("`{ident}(...)` cannot be used together with `guard = `, because the guard defines the sanitizers and validators.");
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+            }
+        }
+    }
+
+    Ok((maybe_guard, quote::quote!(#(#rest),*)))
+}
+
+/// Split the tokens by the top level commas, skipping empty chunks (e.g. after a trailing comma).
+pub fn split_by_commas(tokens: TokenStream) -> Vec<Vec<TokenTree>> {
+    let mut chunks = Vec::new();
+    let mut chunk = Vec::new();
+    for tree in tokens {
+        match &tree {
+            TokenTree::Punct(punct)
+                if punct.as_char() == ',' && punct.spacing() == Spacing::Alone =>
+            {
+                chunks.push(std::mem::take(&mut chunk));
+            }
+            _ => chunk.push(tree),
+        }
+    }
+    chunks.push(chunk);
+    chunks.retain(|chunk| !chunk.is_empty());
+    chunks
+}
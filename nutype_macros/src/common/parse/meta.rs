@@ -6,7 +6,7 @@ use crate::{
     any::models::AnyInnerType,
     common::{
         models::{InnerType, Meta, TypeName},
-        parse::{intercept_derive_macro, is_derive_attribute, is_doc_attribute},
+        parse::{intercept_derive_macro, is_cfg_attribute},
     },
     float::models::FloatInnerType,
     integer::models::IntegerInnerType,
@@ -22,15 +22,15 @@ pub fn parse_meta(token_stream: TokenStream) -> Result<Meta, syn::Error> {
         data,
         vis,
         ident: type_name,
-        generics: _,
+        generics,
     } = input;
 
     let type_name = TypeName::new(type_name);
 
-    validate_supported_attrs(&attrs)?;
-
     intercept_derive_macro(&attrs)?;
-    let doc_attrs: Vec<Attribute> = attrs.into_iter().filter(is_doc_attribute).collect();
+    // `#[cfg(..)]` applies to all the generated items, other attributes are forwarded to the struct
+    let (cfg_attrs, struct_attrs): (Vec<Attribute>, Vec<Attribute>) =
+        attrs.into_iter().partition(is_cfg_attribute);
 
     let data_struct = match &data {
         syn::Data::Struct(v) => v.clone(),
@@ -41,16 +41,24 @@ pub fn parse_meta(token_stream: TokenStream) -> Result<Meta, syn::Error> {
         }
     };
 
-    let fields_unnamed = match data_struct.fields {
-        syn::Fields::Unnamed(fu) => fu,
-        _ => {
+    let fields_span = data_struct.fields.span();
+    let fields = match data_struct.fields {
+        syn::Fields::Unnamed(fu) => fu.unnamed,
+        syn::Fields::Named(fields_named) => {
+            if fields_named.named.len() > 1 {
+                let msg = "#[nutype] supports structs with named fields only if there is exactly one field.";
+                return Err(syn::Error::new(fields_named.span(), msg));
+            }
+            fields_named.named
+        }
+        syn::Fields::Unit => {
             let error =
                 syn::Error::new(input_span, "#[nutype] can be used only with tuple structs.");
             return Err(error);
         }
     };
 
-    let seg = fields_unnamed.unnamed.iter().next().ok_or_else(|| {
+    let seg = fields.iter().next().ok_or_else(|| {
         let suggested_struct = quote::quote!(
             #vis struct #type_name(i32)
         )
@@ -61,18 +69,78 @@ pub fn parse_meta(token_stream: TokenStream) -> Result<Meta, syn::Error> {
              I bet you'll be luckier trying out something like this:\n\n\
              {suggested_struct};\n\n"
         );
-        syn::Error::new(fields_unnamed.span(), msg)
+        syn::Error::new(fields_span, msg)
     })?;
-    validate_inner_field_visibility(&seg.vis)?;
+    for field in fields.iter() {
+        validate_inner_field_visibility(&field.vis)?;
+    }
+
+    let inner_type = if fields.len() > 1 {
+        // The fields are kept together as a tuple, so sanitizers and validators get the whole value
+        let field_types = fields.iter().map(|field| &field.ty);
+        let field = syn::Field {
+            attrs: Vec::new(),
+            vis: Visibility::Inherited,
+            mutability: syn::FieldMutability::None,
+            ident: None,
+            colon_token: None,
+            ty: syn::parse_quote!((#(#field_types),*)),
+        };
+        InnerType::Any(AnyInnerType::new(field))
+    } else {
+        parse_inner_type(seg)?
+    };
+
+    if let InnerType::String(_) | InnerType::Integer(_) | InnerType::Float(_) = &inner_type {
+        // The inner type is fixed, so only const parameters (e.g. the bounds of validators) make sense
+        if let Some(param) = generics
+            .params
+            .iter()
+            .find(|param| !matches!(param, syn::GenericParam::Const(_)))
+        {
+            let msg = "Only const generic parameters are supported for `String`, integer and float inner types.";
+            return Err(syn::Error::new_spanned(param, msg));
+        }
+    }
+
+    Ok(Meta {
+        struct_attrs,
+        cfg_attrs,
+        type_name,
+        generics,
+        inner_type,
+        vis,
+    })
+}
+
+/// The list of the inner types, which is shown when the inner type cannot be used.
+pub const SUPPORTED_INNER_TYPES: &str = "Supported inner types are:\n\
+    - `String`\n\
+    - integers: `u8`, `u16`, `u32`, `u64`, `u128`, `usize`, `i8`, `i16`, `i32`, `i64`, `i128`, `isize`\n\
+    - floats: `f32`, `f64`\n\
+    - any other type written as a path (e.g. `Vec<String>` or `chrono::NaiveDate`), which can have only `with = ` sanitizers and `predicate = ` validators\n\
+    The built-in types are recognized by their names only, e.g. `String` rather than `std::string::String`.";
 
-    let type_path = match seg.ty.clone() {
+fn parse_inner_type(seg: &syn::Field) -> Result<InnerType, syn::Error> {
+    let type_path = match unwrap_type(&seg.ty).clone() {
         syn::Type::Path(tp) => tp,
-        _ => {
-            let error = syn::Error::new(
-                seg.span(),
-                "#[nutype] requires a simple inner type (e.g. String, i32, etc.)",
+        ty => {
+            let hint = match &ty {
+                syn::Type::Reference(reference) if is_str(&reference.elem) => {
+                    "\nUse `String` instead of `&str`: a nutype owns its value."
+                }
+                syn::Type::Reference(_) => {
+                    "\nReferences are not supported: a nutype owns its value."
+                }
+                syn::Type::Array(_) | syn::Type::Slice(_) => {
+                    "\nArrays and slices are not supported, use `Vec<T>` instead."
+                }
+                _ => "",
+            };
+            let msg = format!(
+                "#[nutype] does not support this inner type.{hint}\n{SUPPORTED_INNER_TYPES}"
             );
-            return Err(error);
+            return Err(syn::Error::new_spanned(ty, msg));
         }
     };
 
@@ -94,32 +162,31 @@ pub fn parse_meta(token_stream: TokenStream) -> Result<Meta, syn::Error> {
         "isize" => InnerType::Integer(IntegerInnerType::Isize),
         "f32" => InnerType::Float(FloatInnerType::F32),
         "f64" => InnerType::Float(FloatInnerType::F64),
-        _ => InnerType::Any(AnyInnerType::new(seg.clone())),
+        _ => {
+            // The name of a named field is not used: the field is private anyway
+            let field = syn::Field {
+                ident: None,
+                colon_token: None,
+                ..seg.clone()
+            };
+            InnerType::Any(AnyInnerType::new(field))
+        }
     };
 
-    Ok(Meta {
-        doc_attrs,
-        type_name,
-        inner_type,
-        vis,
-    })
+    Ok(inner_type)
 }
 
-fn validate_supported_attrs(attrs: &[syn::Attribute]) -> Result<(), syn::Error> {
-    fn is_supported_attr(attr: &syn::Attribute) -> bool {
-        is_doc_attribute(attr) || is_derive_attribute(attr)
-    }
+fn is_str(ty: &syn::Type) -> bool {
+    matches!(unwrap_type(ty), syn::Type::Path(tp) if tp.path.is_ident("str"))
+}
 
-    for attr in attrs {
-        if !is_supported_attr(attr) {
-            return Err(syn::Error::new(
-                attr.span(),
-                "#[nutype] does not support this attribute.",
-            ));
-        }
+/// Strip the invisible groups (e.g. `$ty` of `macro_rules!`) and the parentheses around the type.
+fn unwrap_type(ty: &syn::Type) -> &syn::Type {
+    match ty {
+        syn::Type::Group(group) => unwrap_type(&group.elem),
+        syn::Type::Paren(paren) => unwrap_type(&paren.elem),
+        _ => ty,
     }
-
-    Ok(())
 }
 
 fn validate_inner_field_visibility(vis: &Visibility) -> Result<(), syn::Error> {
@@ -1,27 +1,86 @@
+pub mod conditional;
 pub mod derive_trait;
+pub mod guard_definition;
 pub mod meta;
+pub mod preset;
+mod serde;
+mod suggest;
 
-use std::{any::type_name, fmt::Debug, str::FromStr};
+use std::{fmt::Debug, str::FromStr};
 
 use cfg_if::cfg_if;
-use proc_macro2::{Ident, Span};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
 use syn::{
     parenthesized,
     parse::{Parse, ParseStream},
+    punctuated::Punctuated,
     spanned::Spanned,
     token::Paren,
-    Expr, Lit, Token,
+    Expr, Lit, LitStr, Path, Token, Visibility,
 };
 
-use crate::common::models::SpannedDeriveTrait;
+use crate::common::{
+    models::{DeriveTrait, SpannedDeriveTrait},
+    validate::duplicate_error,
+};
 
-use super::models::{CustomFunction, NewUnchecked, TypedCustomFunction, ValueOrExpr};
+use self::suggest::did_you_mean;
 
-pub fn is_doc_attribute(attribute: &syn::Attribute) -> bool {
-    match attribute.path().segments.first() {
-        Some(path_segment) => path_segment.ident == "doc",
-        None => false,
-    }
+use super::models::{
+    ConstFn, CustomFunction, ErrorAttributes, ErrorDisplay, ErrorFields, ErrorIntegrations,
+    ErrorMessage, ErrorStatusCode, ErrorTypeName, Factory, ItemDoc, NewUnchecked, OpsPolicy,
+    OptionalMethods, Repr, Secret, SerdeAttributes, SpannedOpsPolicy, TypedCustomFunction,
+    ValueOrExpr,
+};
+
+/// Names of the attributes of `#[nutype]`, which are suggested for a misspelled one.
+const ATTRIBUTES: &[&str] = &[
+    "sanitize",
+    "validate",
+    "derive",
+    "default",
+    "ops",
+    "constructor",
+    "delegate",
+    "widen_into",
+    "derive_unsafe",
+    "repr",
+    "docs",
+    "local",
+    "omit",
+    "methods",
+    "metadata",
+    "debug_expansion",
+    "accessor",
+    "strict",
+    "brand",
+    "try_from_lossless",
+    "exact_bounds",
+    "factory",
+    "const_fn",
+    "new_unchecked",
+    "secret",
+    "error",
+    "messages",
+    "status_codes",
+    "error_fields",
+    "error_code_prefix",
+    "error_module",
+    "extends",
+    "all_violations",
+    "error_non_exhaustive",
+    "error_into",
+    "error_return",
+    "error_display",
+    "error_derive",
+    "error_integrations",
+    "serde",
+    "guard",
+    "preset",
+];
+
+pub fn is_cfg_attribute(attribute: &syn::Attribute) -> bool {
+    attribute.path().is_ident("cfg")
 }
 
 pub fn is_derive_attribute(attribute: &syn::Attribute) -> bool {
@@ -64,6 +123,72 @@ pub struct ParseableAttributes<Sanitizer, Validator> {
 
     /// Parsed from `derive(...)` attribute
     pub derive_traits: Vec<SpannedDeriveTrait>,
+
+    /// Parsed from `ops = ...` attribute
+    pub ops: Option<SpannedOpsPolicy>,
+
+    /// Parsed from `secret` attribute
+    pub secret: Secret,
+
+    /// Parsed from `const_fn` attribute
+    pub const_fn: ConstFn,
+
+    /// Parsed from `constructor = ` attribute
+    pub constructor: Option<Ident>,
+
+    /// Visibility of the constructors, e.g. `constructor = pub(crate) try_new`.
+    pub constructor_vis: Option<syn::Visibility>,
+
+    /// Parsed from `factory` attribute
+    pub factory: Factory,
+
+    /// Parsed from `try_from_lossless` attribute
+    pub try_from_lossless: bool,
+
+    /// Parsed from `exact_bounds` attribute
+    pub exact_bounds: bool,
+
+    /// Parsed from `delegate(...)` attribute
+    pub delegate: Vec<Ident>,
+
+    /// Parsed from `accessor = ` attribute
+    pub accessor: Option<Ident>,
+
+    /// Parsed from `widen_into(...)` attribute
+    pub widen_into: Vec<Path>,
+
+    /// Derive macros, that are applied to the struct as is, e.g. `derive_unsafe(derive_more::Display)`.
+    pub derive_unsafe: Vec<syn::Path>,
+
+    /// Representation of the struct, e.g. `repr = transparent`.
+    pub repr: Repr,
+
+    /// Parsed from `local` attribute
+    pub local: Option<Span>,
+
+    /// Parsed from `docs(...)` attribute
+    pub docs: Vec<ItemDoc>,
+
+    /// Parsed from `omit(...)` attribute
+    pub omit: Vec<Ident>,
+
+    /// Parsed from `methods(...)` attribute
+    pub methods: OptionalMethods,
+
+    /// Parsed from `metadata` attribute
+    pub metadata: bool,
+
+    /// Parsed from `debug_expansion` attribute
+    pub debug_expansion: Option<Span>,
+
+    /// Parsed from `strict` flag. Redundant rules are reported as errors instead of warnings.
+    pub strict: bool,
+
+    /// Parsed from `serde(...)` attribute
+    pub serde: SerdeAttributes,
+
+    /// Parsed from `error = ` attribute
+    pub error: ErrorAttributes,
 }
 
 // By some reason Default cannot be derived.
@@ -75,6 +200,28 @@ impl<Sanitizer, Validator> Default for ParseableAttributes<Sanitizer, Validator>
             new_unchecked: NewUnchecked::Off,
             default: None,
             derive_traits: vec![],
+            ops: None,
+            secret: Secret::Off,
+            const_fn: ConstFn::Off,
+            constructor: None,
+            constructor_vis: None,
+            factory: Factory::Off,
+            try_from_lossless: false,
+            exact_bounds: false,
+            delegate: vec![],
+            accessor: None,
+            widen_into: vec![],
+            derive_unsafe: Vec::new(),
+            repr: Repr::default(),
+            local: None,
+            docs: Vec::new(),
+            omit: Vec::new(),
+            methods: OptionalMethods::default(),
+            metadata: false,
+            debug_expansion: None,
+            strict: false,
+            serde: SerdeAttributes::default(),
+            error: ErrorAttributes::default(),
         }
     }
 }
@@ -82,10 +229,18 @@ impl<Sanitizer, Validator> Default for ParseableAttributes<Sanitizer, Validator>
 impl<Sanitizer: Parse, Validator: Parse> Parse for ParseableAttributes<Sanitizer, Validator> {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut attrs = ParseableAttributes::default();
+        // `strict` and `brand` affect only the validation of the attributes
+        let mut maybe_strict: Option<Span> = None;
+        let mut maybe_brand: Option<Span> = None;
+        // The spans of `sanitize(...)`, `validate(...)` and `derive(...)` to detect the repeated ones
+        let mut maybe_sanitize: Option<Span> = None;
+        let mut maybe_validate: Option<Span> = None;
+        let mut maybe_derive: Option<Span> = None;
 
         while !input.is_empty() {
             let ident: Ident = input.parse()?;
             if ident == "sanitize" {
+                validate_single_occurrence(&mut maybe_sanitize, &ident, "sanitizers")?;
                 if input.peek(Paren) {
                     let content;
                     parenthesized!(content in input);
@@ -100,6 +255,7 @@ impl<Sanitizer: Parse, Validator: Parse> Parse for ParseableAttributes<Sanitizer
                     return Err(syn::Error::new(ident.span(), msg));
                 }
             } else if ident == "validate" {
+                validate_single_occurrence(&mut maybe_validate, &ident, "validators")?;
                 if input.peek(Paren) {
                     let content;
                     parenthesized!(content in input);
@@ -114,10 +270,14 @@ impl<Sanitizer: Parse, Validator: Parse> Parse for ParseableAttributes<Sanitizer
                     return Err(syn::Error::new(ident.span(), msg));
                 }
             } else if ident == "derive" {
+                validate_single_occurrence(&mut maybe_derive, &ident, "traits")?;
                 if input.peek(Paren) {
                     let content;
                     parenthesized!(content in input);
+                    let names = content.fork();
                     let items = content.parse_terminated(SpannedDeriveTrait::parse, Token![,])?;
+                    let names = names.parse_terminated(Ident::parse, Token![,])?;
+                    validate_duplicate_derive_traits(&names)?;
                     attrs.derive_traits = items.into_iter().collect();
                 } else {
                     let msg = concat!(
@@ -131,10 +291,183 @@ impl<Sanitizer: Parse, Validator: Parse> Parse for ParseableAttributes<Sanitizer
                 let _eq: Token![=] = input.parse()?;
                 let default_expr: Expr = input.parse()?;
                 attrs.default = Some(default_expr);
+            } else if ident == "ops" {
+                let _eq: Token![=] = input.parse()?;
+                let policy_ident: Ident = input.parse()?;
+                let policy = if policy_ident == "panic" {
+                    OpsPolicy::Panic
+                } else if policy_ident == "saturate" {
+                    OpsPolicy::Saturate
+                } else if policy_ident == "result" {
+                    OpsPolicy::Result
+                } else {
+                    let msg = format!("Unknown ops policy `{policy_ident}`. Expected one of: `panic`, `saturate`, `result`.");
+                    return Err(syn::Error::new(policy_ident.span(), msg));
+                };
+                attrs.ops = Some(SpannedOpsPolicy::new(policy, policy_ident.span()));
+            } else if ident == "constructor" {
+                let _eq: Token![=] = input.parse()?;
+                let vis: Visibility = input.parse()?;
+                let has_vis = vis != Visibility::Inherited;
+                if has_vis {
+                    attrs.constructor_vis = Some(vis);
+                }
+                if input.peek(syn::Ident) {
+                    let constructor: Ident = input.parse()?;
+                    if constructor == "new" && !has_vis {
+                        let msg = "`new` is the default name of the constructor, there is no need to set it with `constructor = `.";
+                        return Err(syn::Error::new(constructor.span(), msg));
+                    }
+                    attrs.constructor = Some(constructor);
+                } else if !has_vis {
+                    let msg = "`constructor = ` expects a name and/or a visibility, e.g. `constructor = pub(crate) try_new`.";
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+            } else if ident == "delegate" {
+                if input.peek(Paren) {
+                    let content;
+                    parenthesized!(content in input);
+                    let items = content.parse_terminated(Ident::parse, Token![,])?;
+                    attrs.delegate = items.into_iter().collect();
+                } else {
+                    let msg = concat!(
+                        "`delegate` must be used with parenthesis.\n",
+                        "For example:\n\n",
+                        "    delegate(len, is_empty)\n\n"
+                    );
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+            } else if ident == "widen_into" {
+                if input.peek(Paren) {
+                    let content;
+                    parenthesized!(content in input);
+                    let items = content.parse_terminated(Path::parse, Token![,])?;
+                    attrs.widen_into = items.into_iter().collect();
+                } else {
+                    let msg = concat!(
+                        "`widen_into` must be used with parenthesis.\n",
+                        "For example:\n\n",
+                        "    widen_into(Port)\n\n"
+                    );
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+            } else if ident == "derive_unsafe" {
+                cfg_if! {
+                    if #[cfg(feature = "derive_unsafe")] {
+                        if input.peek(Paren) {
+                            let content;
+                            parenthesized!(content in input);
+                            let items = content.parse_terminated(Path::parse, Token![,])?;
+                            attrs.derive_unsafe = items.into_iter().collect();
+                        } else {
+                            let msg = concat!(
+                                "`derive_unsafe` must be used with parenthesis.\n",
+                                "For example:\n\n",
+                                "    derive_unsafe(derive_more::Display)\n\n"
+                            );
+                            return Err(syn::Error::new(ident.span(), msg));
+                        }
+                    } else {
+                        let msg = "To use `derive_unsafe(..)`, the feature `derive_unsafe` of crate `nutype` needs to be enabled.";
+                        return Err(syn::Error::new(ident.span(), msg));
+                    }
+                }
+            } else if ident == "repr" {
+                let _eq: Token![=] = input.parse()?;
+                let repr: Ident = input.parse()?;
+                if repr != "transparent" {
+                    let msg = format!("Unsupported representation `{repr}`. Only `repr = transparent` is supported.");
+                    return Err(syn::Error::new(repr.span(), msg));
+                }
+                attrs.repr = Repr::Transparent;
+            } else if ident == "docs" {
+                let content;
+                parenthesized!(content in input);
+                let docs =
+                    syn::punctuated::Punctuated::<ItemDoc, Token![,]>::parse_terminated(&content)?;
+                for doc in docs {
+                    if attrs.docs.iter().any(|d| d.item == doc.item) {
+                        let msg = format!("The docs of `{}` are already set.", doc.item);
+                        return Err(syn::Error::new(doc.item.span(), msg));
+                    }
+                    attrs.docs.push(doc);
+                }
+            } else if ident == "local" {
+                attrs.local = Some(ident.span());
+            } else if ident == "metadata" {
+                attrs.metadata = true;
+            } else if ident == "debug_expansion" {
+                attrs.debug_expansion = Some(ident.span());
+            } else if ident == "omit" {
+                let content;
+                parenthesized!(content in input);
+                let items =
+                    syn::punctuated::Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+                for item in items {
+                    if attrs.omit.contains(&item) {
+                        let msg = format!("`{item}` is already omitted.");
+                        return Err(syn::Error::new(item.span(), msg));
+                    }
+                    attrs.omit.push(item);
+                }
+            } else if ident == "methods" {
+                if input.peek(Paren) {
+                    let content;
+                    parenthesized!(content in input);
+                    attrs.methods = content.parse()?;
+                } else {
+                    let msg = concat!(
+                        "`methods` must be used with parenthesis.\n",
+                        "For example:\n\n",
+                        "    methods(validate)\n\n"
+                    );
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+            } else if ident == "accessor" {
+                let _eq: Token![=] = input.parse()?;
+                let accessor: Ident = input.parse()?;
+                if attrs.accessor.is_some() {
+                    let msg = "`accessor = ` can be set only once.";
+                    return Err(syn::Error::new(accessor.span(), msg));
+                }
+                attrs.accessor = Some(accessor);
+            } else if ident == "strict" {
+                maybe_strict = Some(ident.span());
+            } else if ident == "brand" {
+                maybe_brand = Some(ident.span());
+            } else if ident == "try_from_lossless" {
+                attrs.try_from_lossless = true;
+            } else if ident == "exact_bounds" {
+                attrs.exact_bounds = true;
+            } else if ident == "factory" {
+                attrs.factory = Factory::On;
+            } else if ident == "const_fn" {
+                attrs.const_fn = ConstFn::On;
             } else if ident == "new_unchecked" {
                 cfg_if! {
                     if #[cfg(feature = "new_unchecked")] {
-                        attrs.new_unchecked = NewUnchecked::On;
+                        let mut debug_assert = true;
+                        let mut safe = false;
+                        if input.peek(Paren) {
+                            let content;
+                            parenthesized!(content in input);
+                            let options = content.parse_terminated(Ident::parse, Token![,])?;
+                            for option in options {
+                                if option == "no_debug_assert" {
+                                    debug_assert = false;
+                                } else if option == "safe" {
+                                    safe = true;
+                                } else {
+                                    let msg = format!("Unknown option `{option}` of `new_unchecked`. Expected one of: `no_debug_assert`, `safe`.");
+                                    return Err(syn::Error::new(option.span(), msg));
+                                }
+                            }
+                        }
+                        attrs.new_unchecked = NewUnchecked::On {
+                            debug_assert,
+                            safe,
+                            span: ident.span(),
+                        };
                     } else {
                         // The feature is not enabled, so we return an error
                         let msg = concat!(
@@ -144,8 +477,181 @@ impl<Sanitizer: Parse, Validator: Parse> Parse for ParseableAttributes<Sanitizer
                         return Err(syn::Error::new(ident.span(), msg));
                     }
                 }
+            } else if ident == "secret" {
+                cfg_if! {
+                    if #[cfg(feature = "secrecy")] {
+                        let mut serialize = false;
+                        if input.peek(Paren) {
+                            let content;
+                            parenthesized!(content in input);
+                            let options = content.parse_terminated(Ident::parse, Token![,])?;
+                            for option in options {
+                                if option == "serialize" {
+                                    serialize = true;
+                                } else {
+                                    let msg = format!("Unknown option `{option}` of `secret`. Expected: `serialize`.");
+                                    return Err(syn::Error::new(option.span(), msg));
+                                }
+                            }
+                        }
+                        attrs.secret = Secret::On { serialize };
+                    } else {
+                        let msg = "To mark a type as `secret`, the feature `secrecy` of crate `nutype` needs to be enabled.";
+                        return Err(syn::Error::new(ident.span(), msg));
+                    }
+                }
+            } else if ident == "error" {
+                let _eq: Token![=] = input.parse()?;
+                let vis: Visibility = input.parse()?;
+                // The name can be omitted to change only the visibility, e.g. `error = pub(crate)`
+                if vis == Visibility::Inherited || input.peek(syn::Ident) {
+                    let name: Ident = input.parse()?;
+                    attrs.error.name = Some(ErrorTypeName::new(name));
+                }
+                attrs.error.vis = match vis {
+                    Visibility::Inherited => None,
+                    vis => Some(vis),
+                };
+            } else if ident == "messages" {
+                if input.peek(Paren) {
+                    let content;
+                    parenthesized!(content in input);
+                    let items = content.parse_terminated(ErrorMessage::parse, Token![,])?;
+                    attrs.error.messages = items.into_iter().collect();
+                } else {
+                    let msg = concat!(
+                        "`messages` must be used with parenthesis.\n",
+                        "For example:\n\n",
+                        "    messages(len_char_min = \"must be at least 3 characters\")\n\n"
+                    );
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+            } else if ident == "status_codes" {
+                cfg_if! {
+                    if #[cfg(feature = "web")] {
+                        if input.peek(Paren) {
+                            let content;
+                            parenthesized!(content in input);
+                            let items = content.parse_terminated(ErrorStatusCode::parse, Token![,])?;
+                            attrs.error.status_codes = items.into_iter().collect();
+                        } else {
+                            let msg = concat!(
+                                "`status_codes` must be used with parenthesis.\n",
+                                "For example:\n\n",
+                                "    status_codes(predicate = 409)\n\n"
+                            );
+                            return Err(syn::Error::new(ident.span(), msg));
+                        }
+                    } else {
+                        let msg = "To use `status_codes(...)` attribute, the feature `web` of crate `nutype` needs to be enabled.";
+                        return Err(syn::Error::new(ident.span(), msg));
+                    }
+                }
+            } else if ident == "error_fields" {
+                if input.peek(Paren) {
+                    let content;
+                    parenthesized!(content in input);
+                    attrs.error.fields = content.parse()?;
+                } else {
+                    let msg = concat!(
+                        "`error_fields` must be used with parenthesis.\n",
+                        "For example:\n\n",
+                        "    error_fields(value)\n\n"
+                    );
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+            } else if ident == "error_code_prefix" {
+                let _eq: Token![=] = input.parse()?;
+                attrs.error.code_prefix = Some(input.parse()?);
+            } else if ident == "error_module" {
+                let _eq: Token![=] = input.parse()?;
+                attrs.error.module = Some(input.parse()?);
+            } else if ident == "extends" {
+                let _eq: Token![=] = input.parse()?;
+                attrs.error.extends = Some(input.parse()?);
+            } else if ident == "all_violations" {
+                cfg_if! {
+                    if #[cfg(feature = "alloc")] {
+                        attrs.error.all_violations = true;
+                    } else {
+                        let msg = "`all_violations` requires the feature `alloc` (or `std`) of crate `nutype`, because the violations are collected into `Vec`.";
+                        return Err(syn::Error::new(ident.span(), msg));
+                    }
+                }
+            } else if ident == "error_non_exhaustive" {
+                attrs.error.non_exhaustive = true;
+            } else if ident == "error_into" {
+                if input.peek(Paren) {
+                    let content;
+                    parenthesized!(content in input);
+                    let items = content.parse_terminated(Path::parse, Token![,])?;
+                    attrs.error.into = items.into_iter().collect();
+                } else {
+                    let msg = concat!(
+                        "`error_into` must be used with parenthesis.\n",
+                        "For example:\n\n",
+                        "    error_into(ValidationError)\n\n"
+                    );
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+            } else if ident == "error_return" {
+                let _eq: Token![=] = input.parse()?;
+                attrs.error.return_type = Some(input.parse()?);
+            } else if ident == "error_display" {
+                let _eq: Token![=] = input.parse()?;
+                attrs.error.display = input.parse()?;
+            } else if ident == "error_derive" {
+                if input.peek(Paren) {
+                    let content;
+                    parenthesized!(content in input);
+                    let items = content.parse_terminated(Path::parse, Token![,])?;
+                    attrs.error.derive = Some(items.into_iter().collect());
+                } else {
+                    let msg = concat!(
+                        "`error_derive` must be used with parenthesis.\n",
+                        "For example:\n\n",
+                        "    error_derive(Clone, PartialEq)\n\n"
+                    );
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+            } else if ident == "error_integrations" {
+                if input.peek(Paren) {
+                    let content;
+                    parenthesized!(content in input);
+                    attrs.error.integrations = content.parse()?;
+                } else {
+                    let msg = concat!(
+                        "`error_integrations` must be used with parenthesis.\n",
+                        "For example:\n\n",
+                        "    error_integrations(axum)\n\n"
+                    );
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+            } else if ident == "serde" {
+                cfg_if! {
+                    if #[cfg(feature = "serde")] {
+                        if input.peek(Paren) {
+                            let content;
+                            parenthesized!(content in input);
+                            attrs.serde = content.parse()?;
+                        } else {
+                            let msg = concat!(
+                                "`serde` must be used with parenthesis.\n",
+                                "For example:\n\n",
+                                "    serde(serialize_with = my_serialize_fn)\n\n"
+                            );
+                            return Err(syn::Error::new(ident.span(), msg));
+                        }
+                    } else {
+                        let msg = "To use `serde(...)` attribute, the feature `serde` of crate `nutype` needs to be enabled.";
+                        return Err(syn::Error::new(ident.span(), msg));
+                    }
+                }
             } else {
-                let msg = format!("Unknown attribute `{ident}`");
+                let msg = match did_you_mean(&ident.to_string(), ATTRIBUTES.iter().copied()) {
+                    Some(suggestion) => format!("Unknown attribute `{ident}`. {suggestion}"),
+                    None => format!("Unknown attribute `{ident}`"),
+                };
                 return Err(syn::Error::new(ident.span(), msg));
             }
 
@@ -155,38 +661,355 @@ impl<Sanitizer: Parse, Validator: Parse> Parse for ParseableAttributes<Sanitizer
             }
         }
 
+        if let Secret::On { serialize } = attrs.secret {
+            validate_secret_derive_traits(&attrs.derive_traits, serialize)?;
+        }
+        validate_serde_attributes(&attrs.serde, &attrs.derive_traits)?;
+
+        if attrs.validators.is_empty() {
+            let msg = "`error = ` can be set only for a type with validation, because otherwise there is no error type.";
+            if let Some(error_type_name) = &attrs.error.name {
+                return Err(syn::Error::new_spanned(error_type_name, msg));
+            }
+            if let Some(error_vis) = &attrs.error.vis {
+                return Err(syn::Error::new_spanned(error_vis, msg));
+            }
+        }
+        if let Some(error_module) = &attrs.error.module {
+            let is_from_str = |t: &SpannedDeriveTrait| t.item == DeriveTrait::FromStr;
+            if attrs.validators.is_empty() && !attrs.derive_traits.iter().any(is_from_str) {
+                let msg = "`error_module = ` can be set only for a type with validation or derived `FromStr`, because otherwise there is no error type.";
+                return Err(syn::Error::new_spanned(error_module, msg));
+            }
+        }
+        if let Some(base) = &attrs.error.extends {
+            validate_extends(base, &attrs)?;
+        }
+        if attrs.error.all_violations && attrs.validators.is_empty() {
+            let msg = "`all_violations` can be set only for a type with validation.";
+            return Err(syn::Error::new(Span::call_site(), msg));
+        }
+        if !attrs.error.messages.is_empty()
+            && !matches!(attrs.error.display, ErrorDisplay::Generated)
+        {
+            let msg = "`messages(...)` cannot be used together with `error_display = `, because the `Display` of the error type is not generated.";
+            return Err(syn::Error::new(Span::call_site(), msg));
+        }
+        // A type extending another one has the validators of the base type
+        let has_validation = !attrs.validators.is_empty() || attrs.error.extends.is_some();
+        if let (Some(span), true) = (maybe_brand, has_validation) {
+            let msg = "`brand` marks a type without validators, but the type has validation.";
+            return Err(syn::Error::new(span, msg));
+        }
+        if let (Some(span), false, None) = (maybe_strict, has_validation, maybe_brand) {
+            let msg = concat!(
+                "Strict mode requires every type to have at least one validator.\n",
+                "Add `validate(...)` or mark the type as a branding type with `brand`."
+            );
+            return Err(syn::Error::new(span, msg));
+        }
+        attrs.strict = maybe_strict.is_some();
+        if !attrs.error.into.is_empty() && attrs.validators.is_empty() {
+            let msg = "`error_into(...)` can be set only for a type with validation, because otherwise there is no error type.";
+            return Err(syn::Error::new(Span::call_site(), msg));
+        }
+        if let Some(return_type) = &attrs.error.return_type {
+            if attrs.const_fn == ConstFn::On {
+                let msg = "`error_return = ` cannot be used with `const_fn`, because the error conversion is not `const`.";
+                return Err(syn::Error::new_spanned(return_type, msg));
+            }
+            if attrs.validators.is_empty() {
+                let msg = "`error_return = ` can be set only for a type with validation, because otherwise there is no error type.";
+                return Err(syn::Error::new_spanned(return_type, msg));
+            }
+            let is_deserialize = |t: &SpannedDeriveTrait| t.item == DeriveTrait::SerdeDeserialize;
+            if attrs.derive_traits.iter().any(is_deserialize) {
+                let msg = "`error_return = ` cannot be used with derived `Deserialize`, because deserialization reports the violated constraint of the generated error.";
+                return Err(syn::Error::new_spanned(return_type, msg));
+            }
+            if !attrs.error.into.contains(return_type) {
+                attrs.error.into.push(return_type.clone());
+            }
+        }
+        if attrs.try_from_lossless {
+            let is_try_from = |t: &SpannedDeriveTrait| t.item == DeriveTrait::TryFrom;
+            if !attrs.derive_traits.iter().any(is_try_from) {
+                let msg = "`try_from_lossless` requires `TryFrom` to be derived.";
+                return Err(syn::Error::new(Span::call_site(), msg));
+            }
+        }
+        if attrs.methods.map && has_validation {
+            let msg = "`methods(map)` can be set only for a type without validation. Use `methods(try_map)` instead.";
+            return Err(syn::Error::new(Span::call_site(), msg));
+        }
+        if let (Some(name), false) = (attrs.methods.first_requiring_validation(), has_validation) {
+            let msg = format!("`methods({name})` can be set only for a type with validation.");
+            return Err(syn::Error::new(Span::call_site(), msg));
+        }
+        if attrs.error.integrations.is_any() && attrs.validators.is_empty() {
+            let msg = "`error_integrations(...)` can be set only for a type with validation, because otherwise there is no error type.";
+            return Err(syn::Error::new(Span::call_site(), msg));
+        }
+        if attrs.error.non_exhaustive && attrs.validators.is_empty() {
+            let msg = "`error_non_exhaustive` can be set only for a type with validation, because otherwise there is no error type.";
+            return Err(syn::Error::new(Span::call_site(), msg));
+        }
+        if let (Some(accessor), Secret::On { .. }) = (&attrs.accessor, attrs.secret) {
+            let msg = "`accessor = ` cannot be used with a `secret` type, because it would expose the secret.";
+            return Err(syn::Error::new(accessor.span(), msg));
+        }
+        let exposing_method = [
+            ("try_mutate", attrs.methods.try_mutate),
+            ("try_map", attrs.methods.try_map),
+            ("map", attrs.methods.map),
+            ("leak", attrs.methods.leak),
+        ]
+        .into_iter()
+        .find_map(|(name, requested)| requested.then_some(name));
+        if let (Some(name), Secret::On { .. }) = (exposing_method, attrs.secret) {
+            let msg = format!("`methods({name})` cannot be used with a `secret` type, because it would expose the secret.");
+            return Err(syn::Error::new(Span::call_site(), msg));
+        }
+        if attrs.error.fields.value && attrs.secret.is_on() {
+            let msg = "`error_fields(value)` cannot be used with a `secret` type, because the error would expose the secret.";
+            return Err(syn::Error::new(Span::call_site(), msg));
+        }
+
         Ok(attrs)
     }
 }
 
-pub fn parse_number<T>(input: ParseStream) -> syn::Result<(T, Span)>
-where
-    T: FromStr,
-{
-    let mut number_str = String::with_capacity(16);
-    if input.peek(Token![-]) {
-        let _: Token![-] = input.parse()?;
-        number_str.push('-');
+impl Parse for ErrorFields {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut fields = ErrorFields::default();
+        let idents = input.parse_terminated(Ident::parse, Token![,])?;
+        for ident in idents {
+            if ident == "value" {
+                fields.value = true;
+            } else if ident == "bounds" {
+                fields.bounds = true;
+            } else {
+                let msg =
+                    format!("Unknown error field `{ident}`. Expected one of: `value`, `bounds`.");
+                return Err(syn::Error::new(ident.span(), msg));
+            }
+        }
+        Ok(fields)
     }
+}
 
-    let lit: Lit = input.parse()?;
-    let lit_str = match &lit {
-        Lit::Float(lf) => lf.to_string(),
-        Lit::Int(li) => li.to_string(),
-        _ => {
-            let msg = "Expected number literal";
+impl Parse for OptionalMethods {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut methods = OptionalMethods::default();
+        let idents = input.parse_terminated(Ident::parse, Token![,])?;
+        for ident in idents {
+            if ident == "validate" {
+                methods.validate = Some(ident.span());
+            } else if ident == "set" {
+                methods.set = true;
+            } else if ident == "try_mutate" {
+                methods.try_mutate = true;
+            } else if ident == "try_map" {
+                methods.try_map = true;
+            } else if ident == "map" {
+                methods.map = true;
+            } else if ident == "try_new_all" {
+                if !cfg!(feature = "alloc") {
+                    let msg = "`methods(try_new_all)` requires the feature `alloc` (or `std`) of crate `nutype`, because the values are collected into `Vec`.";
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+                methods.try_new_all = true;
+            } else if ident == "leak" {
+                methods.leak = true;
+            } else {
+                let msg = format!(
+                    "Unknown optional method `{ident}`. Expected one of: `validate`, `set`, `try_mutate`, `try_map`, `map`, `try_new_all`, `leak`."
+                );
+                return Err(syn::Error::new(ident.span(), msg));
+            }
+        }
+        Ok(methods)
+    }
+}
+
+impl Parse for ErrorIntegrations {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut integrations = ErrorIntegrations::default();
+        let idents = input.parse_terminated(Ident::parse, Token![,])?;
+        for ident in idents {
+            if ident == "axum" {
+                if !cfg!(feature = "axum") {
+                    let msg = "To use `axum` integration, the feature `axum` of crate `nutype` needs to be enabled.";
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+                integrations.axum = true;
+            } else if ident == "thiserror" {
+                if !cfg!(feature = "thiserror") {
+                    let msg = "To use `thiserror` integration, the feature `thiserror` of crate `nutype` needs to be enabled.";
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+                integrations.thiserror = true;
+            } else if ident == "miette" {
+                if !cfg!(feature = "miette") {
+                    let msg = "To use `miette` integration, the feature `miette` of crate `nutype` needs to be enabled.";
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+                integrations.miette = true;
+            } else {
+                let msg = format!(
+                    "Unknown error integration `{ident}`. Expected one of: `axum`, `thiserror`, `miette`."
+                );
+                return Err(syn::Error::new(ident.span(), msg));
+            }
+        }
+        Ok(integrations)
+    }
+}
+
+impl Parse for ErrorDisplay {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path: Path = input.parse()?;
+        if path.is_ident("none") {
+            Ok(ErrorDisplay::None)
+        } else {
+            Ok(ErrorDisplay::Custom(path))
+        }
+    }
+}
+
+impl Parse for ErrorStatusCode {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let validator: Ident = input.parse()?;
+        let _eq: Token![=] = input.parse()?;
+        let lit: syn::LitInt = input.parse()?;
+        let status: u16 = lit.base10_parse()?;
+        if !(100..=599).contains(&status) {
+            let msg = format!(
+                "`{status}` is not a valid HTTP status code. Expected a value between 100 and 599."
+            );
             return Err(syn::Error::new(lit.span(), msg));
         }
-    };
+        Ok(ErrorStatusCode { validator, status })
+    }
+}
 
-    number_str.push_str(&lit_str.replace('_', ""));
+impl Parse for ErrorMessage {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let validator: Ident = input.parse()?;
+        let _eq: Token![=] = input.parse()?;
+        let message: LitStr = input.parse()?;
+        Ok(ErrorMessage { validator, message })
+    }
+}
 
-    let number: T = number_str.parse::<T>().map_err(|_err| {
-        let msg = format!("Expected {}, got `{}`", type_name::<T>(), number_str);
-        syn::Error::new(lit.span(), msg)
-    })?;
+/// Secret types do not give away the inner value and implement zeroization themselves.
+fn validate_extends<Sanitizer, Validator>(
+    base: &Path,
+    attrs: &ParseableAttributes<Sanitizer, Validator>,
+) -> syn::Result<()> {
+    if attrs.validators.is_empty() {
+        let msg = "`extends = ` requires `validate(...)`, because the error type of the base type is wrapped into the error type.";
+        return Err(syn::Error::new_spanned(base, msg));
+    }
+    if attrs.const_fn == ConstFn::On {
+        let msg = "`extends = ` cannot be used with `const_fn`, because the guard of the base type is not `const`.";
+        return Err(syn::Error::new_spanned(base, msg));
+    }
+    for derive_trait in &attrs.derive_traits {
+        if matches!(
+            derive_trait.item,
+            DeriveTrait::ArbitraryArbitrary | DeriveTrait::RandDistribution
+        ) {
+            let msg = "This trait cannot be derived for a type with `extends = `, because the generated values may violate the validators of the base type.";
+            return Err(syn::Error::new(derive_trait.span, msg));
+        }
+    }
+    Ok(())
+}
 
-    Ok((number, lit.span()))
+fn validate_secret_derive_traits(
+    derive_traits: &[SpannedDeriveTrait],
+    serialize: bool,
+) -> syn::Result<()> {
+    for derive_trait in derive_traits {
+        let msg = match derive_trait.item {
+            DeriveTrait::SerdeSerialize if !serialize => {
+                "Trait `Serialize` cannot be derived for a `secret` type, because it would expose the secret.\nUse `secret(serialize)` to serialize the secret explicitly."
+            }
+            DeriveTrait::Into => {
+                "Trait `Into` cannot be derived for a `secret` type, because it would expose the secret.\nUse `expose_secret()` instead."
+            }
+            DeriveTrait::ZeroizeZeroize | DeriveTrait::ZeroizeZeroizeOnDrop => {
+                "A `secret` type is zeroized on drop already, there is no need to derive `Zeroize` or `ZeroizeOnDrop`."
+            }
+            DeriveTrait::Display | DeriveTrait::Deref | DeriveTrait::AsRef | DeriveTrait::Borrow => {
+                "Traits `Display`, `Deref`, `AsRef` and `Borrow` cannot be derived for a `secret` type, because they would expose the secret.\nUse `expose_secret()` instead."
+            }
+            _ => continue,
+        };
+        return Err(syn::Error::new(derive_trait.span(), msg));
+    }
+    Ok(())
+}
+
+/// Serde options and serde_with traits make sense only when the corresponding serde traits are derived.
+fn validate_serde_attributes(
+    serde: &SerdeAttributes,
+    derive_traits: &[SpannedDeriveTrait],
+) -> syn::Result<()> {
+    let has_trait = |expected: DeriveTrait| derive_traits.iter().any(|dt| dt.item == expected);
+
+    // `SerializeAs` and `DeserializeAs` of serde_with follow the serde representation of the type
+    for derive_trait in derive_traits {
+        let (required_trait, msg) = match derive_trait.item {
+            DeriveTrait::SerdeWithSerializeAs => (
+                DeriveTrait::SerdeSerialize,
+                "`SerializeAs` requires `Serialize` to be derived.",
+            ),
+            DeriveTrait::SerdeWithDeserializeAs => (
+                DeriveTrait::SerdeDeserialize,
+                "`DeserializeAs` requires `Deserialize` to be derived.",
+            ),
+            _ => continue,
+        };
+        if !has_trait(required_trait) {
+            return Err(syn::Error::new(derive_trait.span(), msg));
+        }
+    }
+
+    if let Some(path) = &serde.serialize_with {
+        if !has_trait(DeriveTrait::SerdeSerialize) {
+            let msg = "`serialize_with` requires `Serialize` to be derived.";
+            return Err(syn::Error::new(path.span(), msg));
+        }
+    }
+    if let Some(path) = &serde.deserialize_with {
+        if !has_trait(DeriveTrait::SerdeDeserialize) {
+            let msg = "`deserialize_with` requires `Deserialize` to be derived.";
+            return Err(syn::Error::new(path.span(), msg));
+        }
+    }
+    if serde.skip_validation && !has_trait(DeriveTrait::SerdeDeserialize) {
+        let msg = "`skip_validation` requires `Deserialize` to be derived.";
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
+    if serde.skip_sanitization {
+        if !has_trait(DeriveTrait::SerdeDeserialize) {
+            let msg = "`skip_sanitization` requires `Deserialize` to be derived.";
+            return Err(syn::Error::new(Span::call_site(), msg));
+        }
+        if serde.skip_validation {
+            let msg = "`skip_validation` skips sanitization as well, there is no need to use `skip_sanitization` together with it.";
+            return Err(syn::Error::new(Span::call_site(), msg));
+        }
+    }
+    if let Some(key) = &serde.wrap {
+        if !has_trait(DeriveTrait::SerdeSerialize) && !has_trait(DeriveTrait::SerdeDeserialize) {
+            let msg = "`wrap` requires `Serialize` or `Deserialize` to be derived.";
+            return Err(syn::Error::new(key.span(), msg));
+        }
+    }
+    Ok(())
 }
 
 /// Try to parse input as a number of type T (if the value specified directly)
@@ -195,12 +1018,161 @@ pub fn parse_number_or_expr<T>(input: ParseStream) -> syn::Result<(ValueOrExpr<T
 where
     T: FromStr,
 {
-    if let Ok((number, span)) = parse_number::<T>(input) {
-        Ok((ValueOrExpr::Value(number), span))
+    let expr: Expr = input.parse()?;
+    if let Some(number) = number_from_env::<T>(&expr)? {
+        return Ok(number);
+    }
+    match number_from_expr::<T>(&expr)? {
+        Some((number, span)) => Ok((ValueOrExpr::Value(number), span)),
+        None => {
+            let span = expr.span();
+            Ok((ValueOrExpr::Expr(expr), span))
+        }
+    }
+}
+
+/// Resolve `env!("NAME")` at compile time, so the bounds can be set per build,
+/// e.g. `len_char_max = env!("MAX_USERNAME_LEN")`.
+fn number_from_env<T>(expr: &Expr) -> syn::Result<Option<(ValueOrExpr<T>, Span)>>
+where
+    T: FromStr,
+{
+    let Expr::Macro(expr_macro) = strip_expr_groups(expr) else {
+        return Ok(None);
+    };
+    if !expr_macro.mac.path.is_ident("env") {
+        return Ok(None);
+    }
+    let span = expr_macro.span();
+    let args = expr_macro
+        .mac
+        .parse_body_with(syn::punctuated::Punctuated::<LitStr, Token![,]>::parse_terminated)?;
+    let Some(name) = args.first() else {
+        let msg = "`env!` expects the name of an environment variable, e.g. `env!(\"MAX_LEN\")`.";
+        return Err(syn::Error::new(span, msg));
+    };
+    let name = name.value();
+    let value = std::env::var(&name).map_err(|_| {
+        let msg = format!("Environment variable `{name}` is not set or is not valid unicode.");
+        syn::Error::new(span, msg)
+    })?;
+    let tp = std::any::type_name::<T>();
+    let number = value.trim().replace('_', "").parse::<T>().map_err(|_| {
+        let msg = format!("Environment variable `{name}` must be a valid `{tp}`, got `{value}`.");
+        syn::Error::new(span, msg)
+    })?;
+    Ok(Some((ValueOrExpr::Value(number), span)))
+}
+
+/// Find the names of all `env!("NAME")` used in the attributes, no matter how deep they are nested.
+/// The variables are read by the macro itself, so rustc doesn't know about them until
+/// the generated code refers to them too.
+pub fn env_variable_names(attrs: &TokenStream) -> Vec<LitStr> {
+    let mut names = Vec::new();
+    let mut tokens = attrs.clone().into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(ident) if ident == "env" => {
+                let Some(TokenTree::Punct(punct)) = tokens.peek() else {
+                    continue;
+                };
+                if punct.as_char() != '!' {
+                    continue;
+                }
+                tokens.next();
+                if let Some(TokenTree::Group(group)) = tokens.next() {
+                    let first = group.stream().into_iter().next();
+                    if let Ok(name) = syn::parse2::<LitStr>(first.into_iter().collect()) {
+                        names.push(name);
+                    }
+                }
+            }
+            TokenTree::Group(group) => names.extend(env_variable_names(&group.stream())),
+            _ => {}
+        }
+    }
+    names
+}
+
+/// Get the number out of a literal, which may be negated and wrapped into parentheses or
+/// invisible groups (e.g. `$max:expr` of `macro_rules!`).
+/// A literal, which is not a valid `T`, is reported on the tokens of the argument, e.g. `-5`
+/// for `len_char_max = -5`. Literals with a suffix (e.g. `5_u8`) are left for the compiler.
+pub fn number_from_expr<T>(expr: &Expr) -> syn::Result<Option<(T, Span)>>
+where
+    T: FromStr,
+{
+    let (negative, inner_expr) = match strip_expr_groups(expr) {
+        Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => (true, strip_expr_groups(expr)),
+        expr => (false, expr),
+    };
+    let Expr::Lit(syn::ExprLit { lit, .. }) = inner_expr else {
+        return Ok(None);
+    };
+    let (lit_str, span) = match lit {
+        Lit::Float(lf) if lf.suffix().is_empty() => (lf.base10_digits().to_string(), lf.span()),
+        Lit::Int(li) if li.suffix().is_empty() => (li.base10_digits().to_string(), li.span()),
+        Lit::Float(_) | Lit::Int(_) => return Ok(None),
+        _ => {
+            let tp = std::any::type_name::<T>();
+            let msg = format!("Expected a number of type `{tp}`.");
+            return Err(syn::Error::new_spanned(expr, msg));
+        }
+    };
+    let sign = if negative { "-" } else { "" };
+    let number_str = format!("{sign}{lit_str}");
+    match number_str.parse() {
+        Ok(number) => Ok(Some((number, span))),
+        Err(_) => {
+            let tp = std::any::type_name::<T>();
+            let is_float = matches!(lit, Lit::Float(_));
+            let msg = invalid_number_message(&number_str, tp, is_float);
+            Err(syn::Error::new_spanned(expr, msg))
+        }
+    }
+}
+
+/// Explain why a literal is not a valid number of the type, e.g. it's out of the range of `u8`.
+fn invalid_number_message(number: &str, tp: &str, is_float: bool) -> String {
+    let Some((min, max)) = integer_range(tp) else {
+        return format!("`{number}` is not a valid `{tp}`.");
+    };
+    if is_float {
+        format!("`{number}` is not an integer, but the value must be of type `{tp}`.")
+    } else if number.starts_with('-') && min == "0" {
+        format!("`{number}` is negative, but `{tp}` is unsigned.")
     } else {
-        let expr: Expr = input.parse()?;
-        let span = expr.span();
-        Ok((ValueOrExpr::Expr(expr), span))
+        format!(
+            "`{number}` is out of the range of `{tp}`.\nThe value must be within {min}..={max}."
+        )
+    }
+}
+
+/// The minimum and the maximum of an integer type given by its name.
+/// `usize` and `isize` are taken as they are on the machine, that builds the crate.
+fn integer_range(tp: &str) -> Option<(String, String)> {
+    macro_rules! range_of {
+        ($($int:ty),*) => {
+            match tp {
+                $(stringify!($int) => Some((<$int>::MIN.to_string(), <$int>::MAX.to_string())),)*
+                _ => None,
+            }
+        };
+    }
+    range_of!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize)
+}
+
+/// Strip the invisible groups, which wrap the fragments interpolated by `macro_rules!`
+/// (e.g. `$value:expr`), and the parentheses around the expression.
+pub fn strip_expr_groups(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Group(group) => strip_expr_groups(&group.expr),
+        Expr::Paren(paren) => strip_expr_groups(&paren.expr),
+        expr => expr,
     }
 }
 
@@ -240,6 +1212,37 @@ where
 
 /// Parse ident from ParStream and tries to parse it further into Kind of sanitizier or validator.
 /// Build a helpful error on failure.
+/// `sanitize(...)`, `validate(...)` and `derive(...)` can be set only once, otherwise the latter
+/// would replace the former.
+fn validate_single_occurrence(
+    maybe_first: &mut Option<Span>,
+    ident: &Ident,
+    items: &str,
+) -> syn::Result<()> {
+    match maybe_first {
+        Some(first) => {
+            let msg = format!(
+                "`{ident}(...)` can be set only once.\nPut all the {items} into one `{ident}(...)`."
+            );
+            Err(duplicate_error(*first, ident.span(), msg))
+        }
+        None => {
+            *maybe_first = Some(ident.span());
+            Ok(())
+        }
+    }
+}
+
+fn validate_duplicate_derive_traits(names: &Punctuated<Ident, Token![,]>) -> syn::Result<()> {
+    for (index, name) in names.iter().enumerate() {
+        if let Some(first) = names.iter().take(index).find(|first| *first == name) {
+            let msg = format!("`{name}` is derived twice.\nRemove one of them from `derive(...)`.");
+            return Err(duplicate_error(first.span(), name.span(), msg));
+        }
+    }
+    Ok(())
+}
+
 fn parse_kind<K>(attr_type: &str, input: ParseStream) -> syn::Result<(K, Ident)>
 where
     K: std::str::FromStr + kinded::Kind + std::fmt::Display + 'static,
@@ -259,13 +1262,31 @@ where
             Err(syn::Error::new(ident.span(), msg))
         }
     } else {
-        let possible_values: String = K::all()
+        let names: Vec<String> = K::all()
+            .iter()
+            .map(|k| k.to_string())
+            .filter(|s| s != "phantom") // filter out _Phantom variant
+            .collect();
+        let possible_values: String = names
             .iter()
-            .map(|k| format!("`{k}`"))
-            .filter(|s| s != "`phantom`") // filter out _Phantom variant
+            .map(|name| format!("`{name}`"))
             .collect::<Vec<_>>()
             .join(", ");
-        let msg = format!("Unknown {attr_type} `{ident}`.\nPossible values are {possible_values}.");
+        let suggestion = did_you_mean(&attr_name, names.iter().map(String::as_str))
+            .map(|suggestion| format!(" {suggestion}"))
+            .unwrap_or_default();
+        let msg = format!(
+            "Unknown {attr_type} `{ident}`.{suggestion}\nPossible values are {possible_values}."
+        );
         Err(syn::Error::new(ident.span(), msg))
     }
 }
+
+impl Parse for ItemDoc {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let item: Ident = input.parse()?;
+        let _eq: Token![=] = input.parse()?;
+        let doc: LitStr = input.parse()?;
+        Ok(ItemDoc { item, doc })
+    }
+}
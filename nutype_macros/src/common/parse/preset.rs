@@ -0,0 +1,157 @@
+//! Built-in presets of sanitizers and validators for common domain types, e.g. `preset = email`.
+//!
+//! A preset is expanded into the regular attributes before they are parsed, so the generated code
+//! is the same as if the sanitizers and validators were written by hand.
+
+use proc_macro2::{Ident, TokenStream, TokenTree};
+use quote::quote;
+
+use crate::{
+    common::{models::InnerType, parse::guard_definition::split_by_commas},
+    integer::models::IntegerInnerType,
+};
+
+const PRESETS: &str = "`email`, `slug`, `uuid`, `url`, `percentage`";
+
+/// Replace `preset = name` within the attributes of `#[nutype]` with the sanitizers and validators
+/// of the preset.
+pub fn expand_preset(
+    attrs: TokenStream,
+    inner_type: &InnerType,
+) -> Result<TokenStream, syn::Error> {
+    let mut maybe_preset: Option<Ident> = None;
+    let mut rest: Vec<TokenStream> = Vec::new();
+
+    for chunk in split_by_commas(attrs) {
+        match chunk.as_slice() {
+            [TokenTree::Ident(ident), TokenTree::Punct(eq), TokenTree::Ident(name)]
+                if ident == "preset" && eq.as_char() == '=' =>
+            {
+                if maybe_preset.is_some() {
+                    let msg = "`preset = ` can be set only once.";
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+                maybe_preset = Some(name.clone());
+            }
+            _ => rest.push(chunk.into_iter().collect()),
+        }
+    }
+
+    let Some(preset) = maybe_preset else {
+        return Ok(quote!(#(#rest),*));
+    };
+
+    // The preset defines the sanitizers and validators, so they cannot be mixed.
+    for chunk in &rest {
+        if let Some(TokenTree::Ident(ident)) = chunk.clone().into_iter().next() {
+            if ident == "sanitize" || ident == "validate" {
+                let msg = format!("`{ident}(...)` cannot be used together with `preset = `, because the preset defines the sanitizers and validators.");
+                return Err(syn::Error::new(ident.span(), msg));
+            }
+        }
+    }
+
+    let preset_attrs = gen_preset_attrs(&preset, inner_type)?;
+    // The messages of the preset come first, so `messages(...)` of the type replaces them.
+    Ok(quote!(#preset_attrs, #(#rest),*))
+}
+
+fn gen_preset_attrs(preset: &Ident, inner_type: &InnerType) -> Result<TokenStream, syn::Error> {
+    let name = preset.to_string();
+    match (name.as_str(), inner_type) {
+        ("email", InnerType::String(_)) => Ok(quote! {
+            sanitize(trim, lowercase),
+            validate(
+                not_empty,
+                len_char_max = 254,
+                predicate = |s| match s.split_once('@') {
+                    Some((local, domain)) => {
+                        !local.is_empty()
+                            && domain.contains('.')
+                            && !domain.contains('@')
+                            && !domain.starts_with('.')
+                            && !domain.ends_with('.')
+                            && !s.chars().any(char::is_whitespace)
+                    }
+                    None => false,
+                },
+            ),
+            messages(predicate = "The value is not a valid email address.")
+        }),
+        ("slug", InnerType::String(_)) => Ok(quote! {
+            sanitize(trim, lowercase),
+            validate(
+                not_empty,
+                len_char_max = 100,
+                predicate = |s| {
+                    !s.starts_with('-')
+                        && !s.ends_with('-')
+                        && !s.contains("--")
+                        && s.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+                },
+            ),
+            messages(predicate = "The value must consist of lowercase letters, digits and single dashes.")
+        }),
+        ("uuid", InnerType::String(_)) => Ok(quote! {
+            sanitize(trim, lowercase),
+            validate(
+                predicate = |s| {
+                    s.len() == 36
+                        && s.char_indices().all(|(index, c)| match index {
+                            8 | 13 | 18 | 23 => c == '-',
+                            _ => c.is_ascii_hexdigit(),
+                        })
+                },
+            ),
+            messages(predicate = "The value is not a valid UUID, e.g. `67e55044-10b1-426f-9247-bb680e5fe0c8`.")
+        }),
+        ("url", InnerType::String(_)) => Ok(quote! {
+            sanitize(trim),
+            validate(
+                not_empty,
+                len_char_max = 2048,
+                predicate = |s| {
+                    let host = s.strip_prefix("https://").or_else(|| s.strip_prefix("http://"));
+                    match host {
+                        Some(host) => {
+                            !host.is_empty()
+                                && !host.starts_with('/')
+                                && !s.chars().any(char::is_whitespace)
+                        }
+                        None => false,
+                    }
+                },
+            ),
+            messages(predicate = "The value is not a valid HTTP(S) URL.")
+        }),
+        ("percentage", InnerType::Integer(integer_inner_type)) => {
+            // The lower bound of unsigned types is already 0
+            if is_unsigned(integer_inner_type) {
+                Ok(quote!(validate(less_or_equal = 100)))
+            } else {
+                Ok(quote!(validate(greater_or_equal = 0, less_or_equal = 100)))
+            }
+        }
+        ("percentage", InnerType::Float(_)) => Ok(quote! {
+            validate(finite, greater_or_equal = 0.0, less_or_equal = 100.0)
+        }),
+        ("email" | "slug" | "uuid" | "url", _) => {
+            let msg = format!("Preset `{name}` is supported only for `String` types.");
+            Err(syn::Error::new(preset.span(), msg))
+        }
+        ("percentage", _) => {
+            let msg = "Preset `percentage` is supported only for integer and float types.";
+            Err(syn::Error::new(preset.span(), msg))
+        }
+        _ => {
+            let msg = format!("Unknown preset `{name}`. Expected one of: {PRESETS}.");
+            Err(syn::Error::new(preset.span(), msg))
+        }
+    }
+}
+
+fn is_unsigned(integer_inner_type: &IntegerInnerType) -> bool {
+    use IntegerInnerType::*;
+
+    matches!(integer_inner_type, U8 | U16 | U32 | U64 | U128 | Usize)
+}
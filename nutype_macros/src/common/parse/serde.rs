@@ -0,0 +1,60 @@
+use cfg_if::cfg_if;
+use proc_macro2::Ident;
+use syn::{
+    parse::{Parse, ParseStream},
+    Token,
+};
+
+use crate::common::models::SerdeAttributes;
+
+impl Parse for SerdeAttributes {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut attrs = SerdeAttributes::default();
+
+        while !input.is_empty() {
+            let ident: Ident = input.parse()?;
+
+            if ident == "serialize_with" {
+                let _eq: Token![=] = input.parse()?;
+                attrs.serialize_with = Some(input.parse()?);
+            } else if ident == "deserialize_with" {
+                let _eq: Token![=] = input.parse()?;
+                attrs.deserialize_with = Some(input.parse()?);
+            } else if ident == "wrap" {
+                let _eq: Token![=] = input.parse()?;
+                let key: syn::LitStr = input.parse()?;
+                if key.value().is_empty() {
+                    let msg = "`wrap` requires a non-empty field name.";
+                    return Err(syn::Error::new(key.span(), msg));
+                }
+                attrs.wrap = Some(key);
+            } else if ident == "skip_validation" {
+                cfg_if! {
+                    if #[cfg(feature = "new_unchecked")] {
+                        attrs.skip_validation = true;
+                    } else {
+                        let msg = concat!(
+                            "To use `serde(skip_validation)`, the feature `new_unchecked` of crate `nutype` needs to be enabled.\n",
+                            "Deserialization without validation breaks the constraints, use it only for trusted sources."
+                        );
+                        return Err(syn::Error::new(ident.span(), msg));
+                    }
+                }
+            } else if ident == "skip_sanitization" {
+                attrs.skip_sanitization = true;
+            } else {
+                let msg = format!(
+                    "Unknown serde option `{ident}`. Expected one of: `serialize_with`, `deserialize_with`, `wrap`, `skip_validation`, `skip_sanitization`."
+                );
+                return Err(syn::Error::new(ident.span(), msg));
+            }
+
+            // Parse `,` unless it's the end of the stream
+            if !input.is_empty() {
+                let _comma: Token![,] = input.parse()?;
+            }
+        }
+
+        Ok(attrs)
+    }
+}
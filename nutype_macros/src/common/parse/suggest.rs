@@ -0,0 +1,62 @@
+//! "Did you mean" suggestions for misspelled attributes, sanitizers, validators and derivable
+//! traits, e.g. `lowercased` -> `lowercase`.
+
+/// The names within `candidates`, which are the closest to `name` by edit distance.
+/// A candidate is considered only if the distance is small compared to the length of the name,
+/// so an unrelated name gets no suggestions.
+pub fn closest_names<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Vec<&'a str> {
+    let name = name.to_lowercase();
+    let max_distance = (name.chars().count() / 3).max(1);
+
+    let mut closest: Vec<&str> = Vec::new();
+    let mut min_distance = max_distance;
+    for candidate in candidates {
+        let distance = edit_distance(&name, &candidate.to_lowercase());
+        if distance > max_distance {
+            continue;
+        }
+        if distance < min_distance {
+            min_distance = distance;
+            closest.clear();
+        }
+        if distance == min_distance && !closest.contains(&candidate) {
+            closest.push(candidate);
+        }
+    }
+    closest
+}
+
+/// Format the suggestions as a sentence, e.g. "Did you mean `lowercase`?".
+/// Returns `None` if there is nothing to suggest.
+pub fn did_you_mean<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<String> {
+    let closest = closest_names(name, candidates);
+    let (last, rest) = closest.split_last()?;
+    let names = if rest.is_empty() {
+        format!("`{last}`")
+    } else {
+        let rest: Vec<String> = rest.iter().map(|n| format!("`{n}`")).collect();
+        format!("{} or `{last}`", rest.join(", "))
+    };
+    Some(format!("Did you mean {names}?"))
+}
+
+/// Levenshtein distance between two strings, counted in chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
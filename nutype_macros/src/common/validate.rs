@@ -1,7 +1,15 @@
+use std::{fmt::Display, str::FromStr};
+
 use kinded::Kinded;
-use proc_macro2::Span;
+use proc_macro2::{Ident, Span};
+
+use super::parse::number_from_expr;
 
-use super::models::{NumericBoundValidator, SpannedItem};
+use super::models::{
+    ConstFn, DeriveTrait, ErrorAttributes, ErrorMessage, ErrorStatusCode, Factory, Guard,
+    NewUnchecked, NumericBoundValidator, OpsPolicy, SpannedDeriveTrait, SpannedItem,
+    SpannedOpsPolicy, Warning,
+};
 
 pub fn validate_duplicates<T>(
     items: &[SpannedItem<T>],
@@ -14,13 +22,18 @@ where
         assert_eq!(item1.kind(), item2.kind());
         let kind = item1.kind();
         let msg = build_error_msg(kind);
-        let span = join_spans_or_last(item1.span(), item2.span());
-        let err = syn::Error::new(span, msg);
-        return Err(err);
+        return Err(duplicate_error(item1.span(), item2.span(), msg));
     }
     Ok(())
 }
 
+/// The error is reported at the repeated occurrence and points at the first one as well.
+pub fn duplicate_error(first: Span, repeated: Span, msg: impl Display) -> syn::Error {
+    let mut err = syn::Error::new(repeated, msg);
+    err.combine(syn::Error::new(first, "The first occurrence is here."));
+    err
+}
+
 fn detect_items_of_same_kind<T: Kinded>(items: &[T]) -> Option<(&T, &T)> {
     // Note: this has O(n^2) complexity, but it's not a problem, because size of collection is < 10.
     for (i1, item1) in items.iter().enumerate() {
@@ -33,10 +46,6 @@ fn detect_items_of_same_kind<T: Kinded>(items: &[T]) -> Option<(&T, &T)> {
     None
 }
 
-fn join_spans_or_last(span1: Span, span2: Span) -> Span {
-    span1.join(span2).unwrap_or(span2)
-}
-
 macro_rules! find_bound_variant {
     ($validators:ident, $method:ident) => {
         $validators
@@ -102,3 +111,392 @@ where
 
     Ok(())
 }
+
+/// Ops policy makes sense only for numeric types, that can derive arithmetic operators.
+pub fn validate_no_ops_policy(maybe_ops: Option<SpannedOpsPolicy>) -> Result<(), syn::Error> {
+    if let Some(ops) = maybe_ops {
+        let msg = "`ops` attribute is supported only for integer and float types.";
+        return Err(syn::Error::new(ops.span(), msg));
+    }
+    Ok(())
+}
+
+pub fn validate_numeric_ops_policy<S, V>(
+    maybe_ops: Option<SpannedOpsPolicy>,
+    guard: &Guard<S, V>,
+    derive_traits: &[SpannedDeriveTrait],
+) -> Result<OpsPolicy, syn::Error> {
+    let Some(ops) = maybe_ops else {
+        return Ok(OpsPolicy::default());
+    };
+    if ops.item == OpsPolicy::Result {
+        if !guard.has_validation() {
+            let msg = "`ops = result` requires validation. Without validation arithmetic operators can never fail, so use the default `ops = panic` instead.";
+            return Err(syn::Error::new(ops.span(), msg));
+        }
+        // An assigning operator returns nothing, so it would have to swallow the error
+        let maybe_assign_trait = derive_traits.iter().find(|t| {
+            matches!(
+                t.item,
+                DeriveTrait::AddAssign
+                    | DeriveTrait::SubAssign
+                    | DeriveTrait::MulAssign
+                    | DeriveTrait::DivAssign
+            )
+        });
+        if let Some(assign_trait) = maybe_assign_trait {
+            let msg = format!("`{:?}` cannot be derived with `ops = result`, because an assigning operator cannot return the error.\nDerive the binary operator instead and assign its result, e.g. `value = (value + rhs)?;`.", assign_trait.item);
+            return Err(syn::Error::new(assign_trait.span, msg));
+        }
+    }
+    Ok(ops.item)
+}
+
+/// With `ops = saturate` the result is clamped into the range defined by the validators,
+/// but a `predicate` does not define a range to clamp into.
+pub fn validate_saturate_with_predicate<V>(
+    maybe_ops: &Option<SpannedOpsPolicy>,
+    validators: &[SpannedItem<V>],
+    is_predicate: impl Fn(&V) -> bool,
+) -> Result<(), syn::Error> {
+    let Some(ops) = maybe_ops else {
+        return Ok(());
+    };
+    if ops.item != OpsPolicy::Saturate {
+        return Ok(());
+    }
+    if let Some(predicate) = validators.iter().find(|v| is_predicate(&v.item)) {
+        let msg = "`ops = saturate` cannot be used for a type with `predicate` validator, because the result cannot be clamped into the valid range.";
+        return Err(syn::Error::new(predicate.span, msg));
+    }
+    Ok(())
+}
+
+/// Without the wrapping module the inner value of a `local` type is accessible within the function
+/// body, so `local` is allowed only for types, which have nothing to bypass.
+pub fn validate_local<S, V>(
+    local: Option<Span>,
+    guard: &Guard<S, V>,
+    error: &ErrorAttributes,
+) -> Result<bool, syn::Error> {
+    let Some(span) = local else {
+        return Ok(false);
+    };
+    if guard.has_validation() || !guard.sanitizers().is_empty() || error.extends.is_some() {
+        let msg = "`local` cannot be used for a type with sanitizers or validators, because without the wrapping module the inner value is accessible within the function body and they could be bypassed.\nMove the type and the items it refers to out of the function body.";
+        return Err(syn::Error::new(span, msg));
+    }
+    Ok(true)
+}
+
+/// `Default` needs a value to return, which is provided with `default = `.
+pub fn validate_default_derive(
+    derive_traits: &[SpannedDeriveTrait],
+    default: &Option<syn::Expr>,
+) -> Result<(), syn::Error> {
+    let maybe_default_trait = derive_traits
+        .iter()
+        .find(|t| t.item == DeriveTrait::Default);
+    if let (Some(default_trait), None) = (maybe_default_trait, default) {
+        let msg = "Deriving `Default` requires a default value.\nAdd `default = ...` to #[nutype] or remove `Default` from `derive(...)`.";
+        return Err(syn::Error::new(default_trait.span, msg));
+    }
+    Ok(())
+}
+
+/// A literal `default = ` is checked against the bounds at compile time, so an invalid default
+/// is reported on the value instead of panicking in `Default::default()`.
+/// An expression (e.g. `default = 20 + 1`) is still checked at runtime.
+pub fn validate_numeric_default<S, V, T>(
+    guard: &Guard<S, V>,
+    default: &Option<syn::Expr>,
+) -> Result<(), syn::Error>
+where
+    V: NumericBoundValidator<T>,
+    T: Clone + PartialOrd + FromStr + Display,
+{
+    let (Some(default), Some(validators)) = (default, guard.validators()) else {
+        return Ok(());
+    };
+    // Custom sanitizers cannot be run at compile time
+    if !guard.sanitizers().is_empty() {
+        return Ok(());
+    }
+    let Some((value, _)) = number_from_expr::<T>(default)? else {
+        return Ok(());
+    };
+    if let Some(violation) = find_bound_violation(validators, &value) {
+        let msg = format!("The default value `{value}` violates `{violation}`.");
+        return Err(syn::Error::new_spanned(default, msg));
+    }
+    Ok(())
+}
+
+/// Find the first bound with a known value, which is violated by the value, e.g. `greater = 5`.
+pub fn find_bound_violation<V, T>(validators: &[V], value: &T) -> Option<String>
+where
+    V: NumericBoundValidator<T>,
+    T: Clone + PartialOrd + Display,
+{
+    validators.iter().find_map(|validator| {
+        match (
+            validator.greater(),
+            validator.greater_or_equal(),
+            validator.less(),
+            validator.less_or_equal(),
+        ) {
+            (Some(bound), _, _, _) if *value <= bound => Some(format!("greater = {bound}")),
+            (_, Some(bound), _, _) if *value < bound => Some(format!("greater_or_equal = {bound}")),
+            (_, _, Some(bound), _) if *value >= bound => Some(format!("less = {bound}")),
+            (_, _, _, Some(bound)) if *value > bound => Some(format!("less_or_equal = {bound}")),
+            _ => None,
+        }
+    })
+}
+
+/// With `ops = panic` the sum or product of an empty iterator is the identity (zero or one), which
+/// must be a valid value, otherwise `Sum` or `Product` would panic on an empty iterator.
+pub fn validate_numeric_iter_identity<S, V, T>(
+    guard: &Guard<S, V>,
+    ops: OpsPolicy,
+    derive_traits: &[SpannedDeriveTrait],
+) -> Result<(), syn::Error>
+where
+    V: NumericBoundValidator<T>,
+    T: Clone + PartialOrd + FromStr + Display,
+{
+    let Some(validators) = guard.validators() else {
+        return Ok(());
+    };
+    // Sanitizers may turn the identity into a valid value, but they cannot be run at compile time
+    if ops != OpsPolicy::Panic || !guard.sanitizers().is_empty() {
+        return Ok(());
+    }
+    for derive_trait in derive_traits {
+        let (name, identity) = match derive_trait.item {
+            DeriveTrait::Sum => ("sum", "0"),
+            DeriveTrait::Product => ("product", "1"),
+            _ => continue,
+        };
+        let Ok(value) = identity.parse::<T>() else {
+            continue;
+        };
+        if let Some(violation) = find_bound_violation(validators, &value) {
+            let msg = format!("`{:?}` cannot be derived, because the {name} of an empty iterator is {identity}, which violates `{violation}`.\nUse `ops = saturate` to clamp it into the valid range, or `ops = result` to get an error instead.", derive_trait.item);
+            return Err(syn::Error::new(derive_trait.span, msg));
+        }
+    }
+    Ok(())
+}
+
+/// `zero()` of `Zero` and `one()` of `One` cannot fail, so zero and one must be valid values.
+/// Literal bounds are checked here, bounds given by expressions are asserted at compile time
+/// within the generated code.
+pub fn validate_numeric_zero_one<S, V, T>(
+    guard: &Guard<S, V>,
+    derive_traits: &[SpannedDeriveTrait],
+    is_predicate: impl Fn(&V) -> bool,
+) -> Result<(), syn::Error>
+where
+    V: NumericBoundValidator<T>,
+    T: Clone + PartialOrd + FromStr + Display,
+{
+    for derive_trait in derive_traits {
+        let (name, value_name, value) = match derive_trait.item {
+            DeriveTrait::NumTraitsZero => ("Zero", "zero", "0"),
+            DeriveTrait::NumTraitsOne => ("One", "one", "1"),
+            _ => continue,
+        };
+        let msg = if !guard.sanitizers().is_empty() {
+            format!("`{name}` cannot be derived for a type with `with` sanitizer, because {value_name} could be sanitized into another value.")
+        } else if let Some(validators) = guard.validators() {
+            if validators.iter().any(&is_predicate) {
+                format!("`{name}` cannot be derived for a type with `predicate` validator, because {value_name} may be an invalid value.")
+            } else {
+                let Ok(value) = value.parse::<T>() else {
+                    continue;
+                };
+                match find_bound_violation(validators, &value) {
+                    Some(violation) => format!(
+                        "`{name}` cannot be derived, because {value_name} violates `{violation}`."
+                    ),
+                    None => continue,
+                }
+            }
+        } else {
+            continue;
+        };
+        return Err(syn::Error::new(derive_trait.span, msg));
+    }
+    Ok(())
+}
+
+/// Numeric types get `const fn` constructors without `const_fn` flag, when the guard can be
+/// evaluated in const context: there are no sanitizers and all the validators are bounds given by
+/// literals. Bounds given by expressions may refer to non-const items, so they require the flag.
+pub fn detect_numeric_const_fn<S, V, T>(
+    const_fn: ConstFn,
+    guard: &Guard<S, V>,
+    error: &ErrorAttributes,
+) -> ConstFn
+where
+    V: NumericBoundValidator<T>,
+    T: Clone,
+{
+    let is_literal_bound = |validator: &V| {
+        validator.greater().is_some()
+            || validator.greater_or_equal().is_some()
+            || validator.less().is_some()
+            || validator.less_or_equal().is_some()
+    };
+    let is_const_guard = guard.sanitizers().is_empty()
+        && guard
+            .validators()
+            .is_none_or(|validators| validators.iter().all(is_literal_bound));
+    // The conversion into the error of `error_return = ` and the guard of the base type
+    // of `extends = ` are not const
+    if is_const_guard && error.return_type.is_none() && error.extends.is_none() {
+        ConstFn::On
+    } else {
+        const_fn
+    }
+}
+
+/// With derived `FromStr` non-string types get `parse()`, so the constructor cannot take the name.
+pub fn validate_constructor_name(
+    constructor: &Option<Ident>,
+    derive_traits: &[SpannedDeriveTrait],
+) -> Result<(), syn::Error> {
+    if let Some(constructor) = constructor {
+        let is_from_str = |t: &SpannedDeriveTrait| t.item == DeriveTrait::FromStr;
+        if constructor == "parse" && derive_traits.iter().any(is_from_str) {
+            let msg = "`constructor = parse` conflicts with `parse()`, which is generated for derived `FromStr`.";
+            return Err(syn::Error::new(constructor.span(), msg));
+        }
+    }
+    Ok(())
+}
+
+/// The traits, which generate or bound the values according to the validators, need to know
+/// the validators at compile time, so they cannot be derived with conditional rules.
+pub fn validate_conditional_derive_traits(
+    derive_traits: &[SpannedDeriveTrait],
+) -> Result<(), syn::Error> {
+    for derive_trait in derive_traits {
+        if matches!(
+            derive_trait.item,
+            DeriveTrait::ArbitraryArbitrary
+                | DeriveTrait::RandDistribution
+                | DeriveTrait::NumTraitsZero
+                | DeriveTrait::NumTraitsOne
+                | DeriveTrait::NumTraitsBounded
+        ) {
+            let msg = "This trait cannot be derived together with `cfg_attr(...)` within `sanitize(...)` or `validate(...)`, because it relies on the rules being known at compile time.";
+            return Err(syn::Error::new(derive_trait.span, msg));
+        }
+    }
+    Ok(())
+}
+
+/// A factory makes sense only if the type has at least one validator whose bound can be set at runtime.
+pub fn validate_factory<V>(
+    factory: Factory,
+    validators: &[SpannedItem<V>],
+    is_runtime_bound: impl Fn(&V) -> bool,
+    supported_validators: &str,
+) -> Result<(), syn::Error> {
+    if factory == Factory::On && !validators.iter().any(|v| is_runtime_bound(&v.item)) {
+        let msg = format!(
+            "`factory` requires at least one validator whose bound can be set at runtime: {supported_validators}."
+        );
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
+    Ok(())
+}
+
+/// Every custom message must refer to a validator of the type, and each validator may have at most one message.
+pub fn validate_error_messages<V>(
+    messages: &[ErrorMessage],
+    validators: &[SpannedItem<V>],
+) -> Result<(), syn::Error>
+where
+    V: Kinded,
+    <V as Kinded>::Kind: Display,
+{
+    let idents: Vec<&Ident> = messages.iter().map(|message| &message.validator).collect();
+    validate_validator_references("messages", "message", &idents, validators)
+}
+
+/// Every custom status code must refer to a validator of the type, and each validator may have at most one status code.
+pub fn validate_error_status_codes<V>(
+    status_codes: &[ErrorStatusCode],
+    validators: &[SpannedItem<V>],
+) -> Result<(), syn::Error>
+where
+    V: Kinded,
+    <V as Kinded>::Kind: Display,
+{
+    let idents: Vec<&Ident> = status_codes.iter().map(|code| &code.validator).collect();
+    validate_validator_references("status_codes", "status code", &idents, validators)
+}
+
+fn validate_validator_references<V>(
+    attribute: &str,
+    item: &str,
+    idents: &[&Ident],
+    validators: &[SpannedItem<V>],
+) -> Result<(), syn::Error>
+where
+    V: Kinded,
+    <V as Kinded>::Kind: Display,
+{
+    for (index, ident) in idents.iter().enumerate() {
+        let is_known = validators
+            .iter()
+            .any(|validator| *ident == &validator.kind().to_string());
+        if !is_known {
+            let msg = format!(
+                "`{attribute}(...)` refers to `{ident}`, but the type has no such validator."
+            );
+            return Err(syn::Error::new(ident.span(), msg));
+        }
+        if idents[..index].contains(ident) {
+            let msg = format!("Duplicated {item} for `{ident}`.");
+            return Err(syn::Error::new(ident.span(), msg));
+        }
+    }
+    Ok(())
+}
+
+/// `new_unchecked()` of a type without sanitizers and validators does the same as `new()`.
+pub fn detect_redundant_new_unchecked<Sanitizer, Validator>(
+    new_unchecked: &NewUnchecked,
+    guard: &Guard<Sanitizer, Validator>,
+) -> Option<Warning> {
+    match (new_unchecked, guard) {
+        (NewUnchecked::On { span, .. }, Guard::WithoutValidation { sanitizers })
+            if sanitizers.is_empty() =>
+        {
+            let message = "`new_unchecked` is redundant, because the type has no sanitizers and validators, so `new()` already accepts any value as is.\nRemove `new_unchecked`.".to_string();
+            Some(Warning {
+                span: *span,
+                message,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// In strict mode the warnings about redundant rules are reported as errors.
+pub fn validate_warnings(warnings: Vec<Warning>, strict: bool) -> Result<Vec<Warning>, syn::Error> {
+    match warnings.first() {
+        Some(warning) if strict => {
+            let msg = format!(
+                "{}\nRedundant rules are not allowed in strict mode.",
+                warning.message
+            );
+            Err(syn::Error::new(warning.span, msg))
+        }
+        _ => Ok(warnings),
+    }
+}
@@ -2,62 +2,99 @@ use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 
 use crate::common::{
-    gen::error::{gen_error_type_name, gen_impl_error_trait},
-    models::{ErrorTypeName, TypeName},
+    gen::error::{
+        gen_custom_message_arm, gen_error_derive, gen_impl_axum_into_response,
+        gen_impl_display_trait_override, gen_impl_error_code, gen_impl_error_status_code,
+        gen_impl_error_trait, gen_impl_miette_diagnostic, gen_impl_violated_constraint,
+        gen_numeric_range_help, gen_variant_fields, gen_variant_pattern, RangeBound,
+    },
+    gen::extends::{gen_extends_arm, gen_extends_variant},
+    models::{ErrorAttributes, ErrorTypeName, TypeName},
 };
 
-use super::super::models::FloatValidator;
+use super::super::models::{FloatInnerType, FloatValidator};
 
 pub fn gen_validation_error_type<T: ToTokens>(
     type_name: &TypeName,
+    inner_type: &FloatInnerType,
+    error_type_name: &ErrorTypeName,
     validators: &[FloatValidator<T>],
+    error: &ErrorAttributes,
 ) -> TokenStream {
-    let error_type_name = gen_error_type_name(type_name);
-    let definition = gen_definition(&error_type_name, validators);
-    let impl_display_trait = gen_impl_display_trait(type_name, &error_type_name, validators);
-    let impl_error_trait = gen_impl_error_trait(&error_type_name);
+    let definition = gen_definition(inner_type, error_type_name, validators, error);
+    let impl_display_trait = gen_impl_display_trait_override(error_type_name, error, || {
+        gen_impl_display_trait(type_name, error_type_name, validators, error)
+    });
+    let impl_error_trait = gen_impl_error_trait(error_type_name, error);
+    let impl_violated_constraint = gen_impl_violated_constraint(error_type_name, validators, error);
+    let impl_error_code = gen_impl_error_code(type_name, error_type_name, validators, error);
+    let impl_error_status_code = gen_impl_error_status_code(error_type_name, validators, error);
+    let impl_axum_into_response = gen_impl_axum_into_response(error_type_name, error);
+    let impl_miette_diagnostic = gen_impl_miette_diagnostic(
+        error_type_name,
+        error,
+        gen_help_arms(error_type_name, validators, error),
+    );
+
+    // Floats do not implement `Eq`, so the error type cannot derive it, if it carries any values.
+    let default_traits = if error.fields.is_any() {
+        quote!(Debug, Clone, PartialEq)
+    } else {
+        quote!(Debug, Clone, PartialEq, Eq)
+    };
+    let derive = gen_error_derive(error, default_traits);
 
     quote! {
-        #[derive(Debug, Clone, PartialEq, Eq)]
+        #derive
         #definition
 
         #impl_display_trait
+        #impl_violated_constraint
+        #impl_error_code
+        #impl_error_status_code
         #impl_error_trait
+        #impl_axum_into_response
+        #impl_miette_diagnostic
     }
 }
 
 fn gen_definition<T>(
+    inner_type: &FloatInnerType,
     error_type_name: &ErrorTypeName,
     validators: &[FloatValidator<T>],
+    error: &ErrorAttributes,
 ) -> TokenStream {
     let error_variants: TokenStream = validators
         .iter()
-        .map(|validator| match validator {
-            FloatValidator::Greater(_) => {
-                quote!(GreaterViolated,)
-            }
-            FloatValidator::GreaterOrEqual(_) => {
-                quote!(GreaterOrEqualViolated,)
-            }
-            FloatValidator::LessOrEqual(_) => {
-                quote!(LessOrEqualViolated,)
-            }
-            FloatValidator::Less(_) => {
-                quote!(LessViolated,)
-            }
-            FloatValidator::Predicate(_) => {
-                quote!(PredicateViolated,)
-            }
-            FloatValidator::Finite => {
-                quote!(FiniteViolated,)
-            }
+        .map(|validator| {
+            let (variant, bound) = match validator {
+                FloatValidator::Greater(_) => {
+                    (quote!(GreaterViolated), Some(quote!(min: #inner_type)))
+                }
+                FloatValidator::GreaterOrEqual(_) => (
+                    quote!(GreaterOrEqualViolated),
+                    Some(quote!(min: #inner_type)),
+                ),
+                FloatValidator::Less(_) => (quote!(LessViolated), Some(quote!(max: #inner_type))),
+                FloatValidator::LessOrEqual(_) => {
+                    (quote!(LessOrEqualViolated), Some(quote!(max: #inner_type)))
+                }
+                FloatValidator::Predicate(_) => (quote!(PredicateViolated), None),
+                FloatValidator::Finite => (quote!(FiniteViolated), None),
+                FloatValidator::NotNan => (quote!(NotNanViolated), None),
+            };
+            let fields = gen_variant_fields(error.fields, quote!(value: #inner_type), bound);
+            quote!(#variant #fields,)
         })
         .collect();
 
+    let extends_variant = gen_extends_variant(error);
+
     quote! {
         #[allow(clippy::enum_variant_names)]
         pub enum #error_type_name {
             #error_variants
+            #extends_variant
         }
     }
 }
@@ -66,27 +103,43 @@ fn gen_impl_display_trait<T: ToTokens>(
     type_name: &TypeName,
     error_type_name: &ErrorTypeName,
     validators: &[FloatValidator<T>],
+    error: &ErrorAttributes,
 ) -> TokenStream {
-    let match_arms = validators.iter().map(|validator| match validator {
-        FloatValidator::Greater(val) => quote! {
-             #error_type_name::GreaterViolated => write!(f, "{} is too small. The value must be greater than {:#?}.", stringify!(#type_name), #val)
-        },
-        FloatValidator::GreaterOrEqual(val) => quote! {
-             #error_type_name::GreaterOrEqualViolated => write!(f, "{} is too small. The value must be greater or equal to {:#?}.", stringify!(#type_name), #val)
-        },
-        FloatValidator::LessOrEqual(val) => quote! {
-             #error_type_name::LessOrEqualViolated=> write!(f, "{} is too big. The value must be less than {:#?}.", stringify!(#type_name), #val)
-        },
-        FloatValidator::Less(val) => quote! {
-             #error_type_name::LessViolated=> write!(f, "{} is too big. The value must be less or equal to {:#?}.", stringify!(#type_name), #val)
-        },
-        FloatValidator::Predicate(_) => quote! {
-             #error_type_name::PredicateViolated => write!(f, "{} failed the predicate test.", stringify!(#type_name))
-        },
-        FloatValidator::Finite => quote! {
-             #error_type_name::FiniteViolated => write!(f, "{} is not finite.", stringify!(#type_name))
-        },
+    let match_arms = validators.iter().map(|validator| {
+        if let Some(arm) = gen_custom_message_arm(error_type_name, validator, error) {
+            return arm;
+        }
+        let pattern = gen_variant_pattern(error, validator);
+        match validator {
+            FloatValidator::Greater(val) => quote! {
+                 #error_type_name::GreaterViolated #pattern => write!(f, "{} is too small. The value must be greater than {:#?}.", stringify!(#type_name), #val)
+            },
+            FloatValidator::GreaterOrEqual(val) => quote! {
+                 #error_type_name::GreaterOrEqualViolated #pattern => write!(f, "{} is too small. The value must be greater or equal to {:#?}.", stringify!(#type_name), #val)
+            },
+            FloatValidator::LessOrEqual(val) => quote! {
+                 #error_type_name::LessOrEqualViolated #pattern => write!(f, "{} is too big. The value must be less than {:#?}.", stringify!(#type_name), #val)
+            },
+            FloatValidator::Less(val) => quote! {
+                 #error_type_name::LessViolated #pattern => write!(f, "{} is too big. The value must be less or equal to {:#?}.", stringify!(#type_name), #val)
+            },
+            FloatValidator::Predicate(_) => quote! {
+                 #error_type_name::PredicateViolated #pattern => write!(f, "{} failed the predicate test.", stringify!(#type_name))
+            },
+            FloatValidator::Finite => quote! {
+                 #error_type_name::FiniteViolated #pattern => write!(f, "{} is not finite.", stringify!(#type_name))
+            },
+            FloatValidator::NotNan => quote! {
+                 #error_type_name::NotNanViolated #pattern => write!(f, "{} is NaN.", stringify!(#type_name))
+            },
+        }
     });
+    let extends_arm = gen_extends_arm(
+        error_type_name,
+        error,
+        quote!(::core::fmt::Display::fmt(err, f)),
+    );
+    let match_arms = match_arms.chain(extends_arm);
 
     quote! {
         impl ::core::fmt::Display for #error_type_name {
@@ -98,3 +151,72 @@ fn gen_impl_display_trait<T: ToTokens>(
         }
     }
 }
+
+fn gen_help_arms<T: ToTokens>(
+    error_type_name: &ErrorTypeName,
+    validators: &[FloatValidator<T>],
+    error: &ErrorAttributes,
+) -> Vec<TokenStream> {
+    let mut lower = None;
+    let mut upper = None;
+    for validator in validators {
+        match validator {
+            FloatValidator::Greater(val) => {
+                lower = Some(RangeBound {
+                    value: val.to_token_stream(),
+                    inclusive: false,
+                })
+            }
+            FloatValidator::GreaterOrEqual(val) => {
+                lower = Some(RangeBound {
+                    value: val.to_token_stream(),
+                    inclusive: true,
+                })
+            }
+            FloatValidator::Less(val) => {
+                upper = Some(RangeBound {
+                    value: val.to_token_stream(),
+                    inclusive: false,
+                })
+            }
+            FloatValidator::LessOrEqual(val) => {
+                upper = Some(RangeBound {
+                    value: val.to_token_stream(),
+                    inclusive: true,
+                })
+            }
+            FloatValidator::Predicate(_) | FloatValidator::Finite | FloatValidator::NotNan => {}
+        }
+    }
+    let range_help = gen_numeric_range_help(lower.as_ref(), upper.as_ref());
+
+    validators
+        .iter()
+        .map(|validator| {
+            let pattern = gen_variant_pattern(error, validator);
+            match validator {
+                FloatValidator::Greater(_) => {
+                    quote!(#error_type_name::GreaterViolated #pattern => Some(#range_help))
+                }
+                FloatValidator::GreaterOrEqual(_) => {
+                    quote!(#error_type_name::GreaterOrEqualViolated #pattern => Some(#range_help))
+                }
+                FloatValidator::Less(_) => {
+                    quote!(#error_type_name::LessViolated #pattern => Some(#range_help))
+                }
+                FloatValidator::LessOrEqual(_) => {
+                    quote!(#error_type_name::LessOrEqualViolated #pattern => Some(#range_help))
+                }
+                FloatValidator::Predicate(_) => {
+                    quote!(#error_type_name::PredicateViolated #pattern => None)
+                }
+                FloatValidator::Finite => quote! {
+                    #error_type_name::FiniteViolated #pattern => Some(::std::string::String::from("expected a finite number"))
+                },
+                FloatValidator::NotNan => quote! {
+                    #error_type_name::NotNanViolated #pattern => Some(::std::string::String::from("expected a number, not NaN"))
+                },
+            }
+        })
+        .collect()
+}
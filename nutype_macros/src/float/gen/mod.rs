@@ -4,7 +4,9 @@ pub mod traits;
 use std::collections::HashSet;
 
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::spanned::Spanned;
+use syn::Visibility;
 
 use self::error::gen_validation_error_type;
 use super::{
@@ -14,15 +16,22 @@ use super::{
 use crate::{
     common::{
         gen::{
-            error::gen_error_type_name,
+            conditional::gen_cfg,
+            error::gen_variant_fields,
+            factory::FactoryParam,
+            gen_new_clamped,
             tests::{
                 gen_test_should_have_consistent_lower_and_upper_boundaries,
                 gen_test_should_have_valid_default_value,
             },
-            traits::GeneratedTraits,
+            traits::{gen_impl_trait_try_from_lossless, GeneratedTraits},
             GenerateNewtype,
         },
-        models::{ErrorTypeName, Guard, TypeName},
+        models::{
+            ConditionalRule, ErrorAttributes, ErrorIntegrations, ErrorTypeName, Guard, OpsPolicy,
+            SerdeAttributes, TypeName, ValueOrExpr,
+        },
+        validate::find_bound_violation,
     },
     float::models::FloatInnerType,
 };
@@ -30,7 +39,7 @@ use traits::gen_traits;
 
 impl<T> GenerateNewtype for FloatNewtype<T>
 where
-    T: FloatType + ToTokens + PartialOrd,
+    T: FloatType + ToTokens + PartialOrd + Clone,
 {
     type Sanitizer = FloatSanitizer<T>;
     type Validator = FloatValidator<T>;
@@ -65,53 +74,70 @@ where
 
     fn gen_fn_validate(
         inner_type: &Self::InnerType,
-        type_name: &TypeName,
+        error_name: &ErrorTypeName,
         validators: &[Self::Validator],
+        error: &ErrorAttributes,
     ) -> TokenStream {
-        let error_name = gen_error_type_name(type_name);
+        let gen_fields = |bound: Option<TokenStream>| {
+            gen_variant_fields(error.fields, quote!(value: val), bound)
+        };
 
         let validations: TokenStream = validators
             .iter()
             .map(|validator| match validator {
                 FloatValidator::Less(exclusive_upper_bound) => {
+                    let fields = gen_fields(Some(quote!(max: #exclusive_upper_bound)));
                     quote!(
                         if val >= #exclusive_upper_bound {
-                            return Err(#error_name::LessViolated);
+                            return Err(#error_name::LessViolated #fields);
                         }
                     )
                 }
                 FloatValidator::LessOrEqual(max) => {
+                    let fields = gen_fields(Some(quote!(max: #max)));
                     quote!(
                         if val > #max {
-                            return Err(#error_name::LessOrEqualViolated);
+                            return Err(#error_name::LessOrEqualViolated #fields);
                         }
                     )
                 }
                 FloatValidator::Greater(exclusive_lower_bound) => {
+                    let fields = gen_fields(Some(quote!(min: #exclusive_lower_bound)));
                     quote!(
                         if val <= #exclusive_lower_bound {
-                            return Err(#error_name::GreaterViolated);
+                            return Err(#error_name::GreaterViolated #fields);
                         }
                     )
                 }
                 FloatValidator::GreaterOrEqual(min) => {
+                    let fields = gen_fields(Some(quote!(min: #min)));
                     quote!(
                         if val < #min {
-                            return Err(#error_name::GreaterOrEqualViolated);
+                            return Err(#error_name::GreaterOrEqualViolated #fields);
                         }
                     )
                 }
                 FloatValidator::Predicate(custom_is_valid_fn) => {
+                    let fields = gen_fields(None);
                     quote!(
                         if !(#custom_is_valid_fn)(&val) {
-                            return Err(#error_name::PredicateViolated);
+                            return Err(#error_name::PredicateViolated #fields);
                         }
                     )
                 }
                 FloatValidator::Finite => {
+                    let fields = gen_fields(None);
                     quote!(
                         if !val.is_finite() {
-                            return Err(#error_name::FiniteViolated);
+                            return Err(#error_name::FiniteViolated #fields);
+                        }
+                    )
+                }
+                FloatValidator::NotNan => {
+                    let fields = gen_fields(None);
+                    quote!(
+                        if val.is_nan() {
+                            return Err(#error_name::NotNanViolated #fields);
                         }
                     )
                 }
@@ -127,11 +153,282 @@ where
         )
     }
 
+    fn well_known_constants(
+        inner_type: &Self::InnerType,
+        validators: &[Self::Validator],
+        error: &ErrorAttributes,
+    ) -> Vec<(&'static str, TokenStream)> {
+        // The values can be checked only against the bounds, which are known at expansion
+        let is_known_bound = |validator: &FloatValidator<T>| {
+            matches!(
+                validator,
+                FloatValidator::Greater(ValueOrExpr::Value(_))
+                    | FloatValidator::GreaterOrEqual(ValueOrExpr::Value(_))
+                    | FloatValidator::Less(ValueOrExpr::Value(_))
+                    | FloatValidator::LessOrEqual(ValueOrExpr::Value(_))
+                    | FloatValidator::Finite
+            )
+        };
+        if !validators.iter().all(is_known_bound) {
+            return Vec::new();
+        }
+        let (has_min_raw, has_max_raw) = has_raw_bounds(validators, &[], error);
+        // An exclusive bound has no closest value, so MIN (MAX) is not generated at all
+        let min = validators
+            .iter()
+            .filter_map(|validator| match validator {
+                FloatValidator::GreaterOrEqual(ValueOrExpr::Value(bound)) => Some(bound.clone()),
+                _ => None,
+            })
+            .fold(T::MIN, |min, bound| if bound > min { bound } else { min });
+        let max = validators
+            .iter()
+            .filter_map(|validator| match validator {
+                FloatValidator::LessOrEqual(ValueOrExpr::Value(bound)) => Some(bound.clone()),
+                _ => None,
+            })
+            .fold(T::MAX, |max, bound| if bound < max { bound } else { max });
+        let has_greater = validators
+            .iter()
+            .any(|v| matches!(v, FloatValidator::Greater(_)));
+        let has_less = validators
+            .iter()
+            .any(|v| matches!(v, FloatValidator::Less(_)));
+        let min_tokens = if has_min_raw {
+            quote!(Self::MIN_RAW)
+        } else {
+            quote!(<#inner_type>::MIN)
+        };
+        let max_tokens = if has_max_raw {
+            quote!(Self::MAX_RAW)
+        } else {
+            quote!(<#inner_type>::MAX)
+        };
+
+        let mut constants = Vec::new();
+        if !has_greater {
+            constants.push(("MIN", min, min_tokens));
+        }
+        if !has_less {
+            constants.push(("MAX", max, max_tokens));
+        }
+        constants.push(("ZERO", T::ZERO, quote!(0.0)));
+        constants
+            .into_iter()
+            .filter(|(_, value, _)| find_bound_violation(validators, value).is_none())
+            .map(|(name, _, tokens)| (name, tokens))
+            .collect()
+    }
+
+    fn gen_raw_bounds(
+        type_name: &TypeName,
+        inner_type: &Self::InnerType,
+        validators: &[Self::Validator],
+        conditional: &[ConditionalRule<Self::Validator>],
+        error: &ErrorAttributes,
+    ) -> TokenStream {
+        let (has_min_raw, has_max_raw) = has_raw_bounds(validators, conditional, error);
+        // The bounds of the conditional validators narrow the range under their predicates
+        let validators: Vec<(Option<&TokenStream>, &FloatValidator<T>)> = validators
+            .iter()
+            .map(|validator| (None, validator))
+            .chain(
+                conditional
+                    .iter()
+                    .map(|rule| (Some(&rule.predicate), &rule.rule)),
+            )
+            .collect();
+        let narrow_min: TokenStream = validators
+            .iter()
+            .map(|(predicate, validator)| match validator {
+                FloatValidator::GreaterOrEqual(bound) => {
+                    let cfg = gen_cfg(*predicate);
+                    quote!(
+                        #cfg
+                        if #bound > min {
+                            min = #bound;
+                        }
+                    )
+                }
+                _ => quote!(),
+            })
+            .collect();
+        let narrow_max: TokenStream = validators
+            .iter()
+            .map(|(predicate, validator)| match validator {
+                FloatValidator::LessOrEqual(bound) => {
+                    let cfg = gen_cfg(*predicate);
+                    quote!(
+                        #cfg
+                        if #bound < max {
+                            max = #bound;
+                        }
+                    )
+                }
+                _ => quote!(),
+            })
+            .collect();
+
+        let min_raw = has_min_raw.then(|| {
+            quote!(
+                /// The smallest value of the inner type, that satisfies the bounds of the validators.
+                pub const MIN_RAW: #inner_type = {
+                    #[allow(unused_mut)]
+                    let mut min: #inner_type = <#inner_type>::MIN;
+                    #narrow_min
+                    min
+                };
+            )
+        });
+        let max_raw = has_max_raw.then(|| {
+            quote!(
+                /// The greatest value of the inner type, that satisfies the bounds of the validators.
+                pub const MAX_RAW: #inner_type = {
+                    #[allow(unused_mut)]
+                    let mut max: #inner_type = <#inner_type>::MAX;
+                    #narrow_max
+                    max
+                };
+            )
+        });
+        if min_raw.is_none() && max_raw.is_none() {
+            return quote!();
+        }
+
+        quote!(
+            impl #type_name {
+                #min_raw
+                #max_raw
+            }
+        )
+    }
+
+    fn gen_new_clamped(
+        type_name: &TypeName,
+        inner_type: &Self::InnerType,
+        sanitizers: &[Self::Sanitizer],
+        validators: &[Self::Validator],
+        constructor_vis: &Visibility,
+    ) -> TokenStream {
+        // Only inclusive bounds can be reached by clamping: there is no closest float
+        // to an exclusive bound, and neither `NaN` nor a predicate can be fixed by clamping.
+        let mut lower_clamps = Vec::new();
+        let mut upper_clamps = Vec::new();
+        for validator in validators {
+            match validator {
+                FloatValidator::GreaterOrEqual(min) => lower_clamps.push(quote!(
+                    if value < #min {
+                        value = #min;
+                    }
+                )),
+                FloatValidator::LessOrEqual(max) => upper_clamps.push(quote!(
+                    if value > #max {
+                        value = #max;
+                    }
+                )),
+                FloatValidator::Greater(_)
+                | FloatValidator::Less(_)
+                | FloatValidator::Predicate(_)
+                | FloatValidator::Finite
+                | FloatValidator::NotNan => return quote!(),
+            }
+        }
+
+        gen_new_clamped(
+            type_name,
+            inner_type,
+            Self::gen_fn_sanitize(inner_type, sanitizers),
+            lower_clamps,
+            upper_clamps,
+            constructor_vis,
+        )
+    }
+
+    fn gen_factory_params(
+        inner_type: &Self::InnerType,
+        error_type_name: &ErrorTypeName,
+        validators: &[Self::Validator],
+        error: &ErrorAttributes,
+    ) -> Vec<FactoryParam> {
+        let gen_fields =
+            |bound: TokenStream| gen_variant_fields(error.fields, quote!(value: *val), Some(bound));
+
+        validators
+            .iter()
+            .filter_map(|validator| {
+                let (name, violated, variant, bound) = match validator {
+                    FloatValidator::Greater(_) => (
+                        "greater",
+                        quote!(*val <= bound),
+                        quote!(GreaterViolated),
+                        quote!(min: bound),
+                    ),
+                    FloatValidator::GreaterOrEqual(_) => (
+                        "greater_or_equal",
+                        quote!(*val < bound),
+                        quote!(GreaterOrEqualViolated),
+                        quote!(min: bound),
+                    ),
+                    FloatValidator::Less(_) => (
+                        "less",
+                        quote!(*val >= bound),
+                        quote!(LessViolated),
+                        quote!(max: bound),
+                    ),
+                    FloatValidator::LessOrEqual(_) => (
+                        "less_or_equal",
+                        quote!(*val > bound),
+                        quote!(LessOrEqualViolated),
+                        quote!(max: bound),
+                    ),
+                    FloatValidator::Predicate(_)
+                    | FloatValidator::Finite
+                    | FloatValidator::NotNan => return None,
+                };
+                let fields = gen_fields(bound);
+                Some(FactoryParam {
+                    name: format_ident!("{name}"),
+                    bound_type: inner_type.to_token_stream(),
+                    violated,
+                    error: quote!(#error_type_name::#variant #fields),
+                })
+            })
+            .collect()
+    }
+
+    fn gen_try_from_lossless(
+        type_name: &TypeName,
+        inner_type: &Self::InnerType,
+        maybe_error_type_name: Option<&ErrorTypeName>,
+    ) -> TokenStream {
+        inner_type
+            .lossless_integer_sources()
+            .iter()
+            .map(|source_type| {
+                gen_impl_trait_try_from_lossless(
+                    type_name,
+                    inner_type,
+                    source_type,
+                    maybe_error_type_name,
+                )
+            })
+            .collect()
+    }
+
     fn gen_validation_error_type(
         type_name: &TypeName,
+        inner_type: &Self::InnerType,
+        error_type_name: &ErrorTypeName,
         validators: &[Self::Validator],
+        error: &ErrorAttributes,
     ) -> TokenStream {
-        gen_validation_error_type(type_name, validators)
+        let validation_error_type =
+            gen_validation_error_type(type_name, inner_type, error_type_name, validators, error);
+        let bound_assertions = gen_bound_assertions(inner_type, validators);
+        quote! {
+            #validation_error_type
+            #bound_assertions
+        }
     }
 
     fn gen_traits(
@@ -141,6 +438,9 @@ where
         traits: HashSet<Self::TypedTrait>,
         maybe_default_value: Option<syn::Expr>,
         guard: &FloatGuard<T>,
+        ops: OpsPolicy,
+        serde: &SerdeAttributes,
+        error_integrations: ErrorIntegrations,
     ) -> Result<GeneratedTraits, syn::Error> {
         gen_traits(
             type_name,
@@ -149,6 +449,9 @@ where
             maybe_default_value,
             traits,
             guard,
+            ops,
+            serde,
+            error_integrations,
         )
     }
 
@@ -175,3 +478,50 @@ where
         }
     }
 }
+
+/// Whether `MIN_RAW` and `MAX_RAW` can be generated. The smallest value above an exclusive bound
+/// cannot be computed in const context and the bounds of the base type are not known.
+fn has_raw_bounds<T>(
+    validators: &[FloatValidator<T>],
+    conditional: &[ConditionalRule<FloatValidator<T>>],
+    error: &ErrorAttributes,
+) -> (bool, bool) {
+    if error.extends.is_some() {
+        return (false, false);
+    }
+    let validators = || {
+        validators
+            .iter()
+            .chain(conditional.iter().map(|rule| &rule.rule))
+    };
+    let has_exclusive_min = validators().any(|v| matches!(v, FloatValidator::Greater(_)));
+    let has_exclusive_max = validators().any(|v| matches!(v, FloatValidator::Less(_)));
+    (!has_exclusive_min, !has_exclusive_max)
+}
+
+/// NaN and infinity given by a constant or an expression cannot be ruled out at parse time,
+/// so the bounds are checked with const assertions, which are generated once per type.
+fn gen_bound_assertions<T: ToTokens>(
+    inner_type: &FloatInnerType,
+    validators: &[FloatValidator<T>],
+) -> TokenStream {
+    validators
+        .iter()
+        .filter_map(|validator| {
+            let (kind, bound) = match validator {
+                FloatValidator::Greater(bound) => ("greater", bound),
+                FloatValidator::GreaterOrEqual(bound) => ("greater_or_equal", bound),
+                FloatValidator::Less(bound) => ("less", bound),
+                FloatValidator::LessOrEqual(bound) => ("less_or_equal", bound),
+                _ => return None,
+            };
+            let ValueOrExpr::Expr(expr) = bound else {
+                return None;
+            };
+            let msg = format!("The bound of `{kind}` must be finite. To rule out NaN or infinity, use `not_nan` or `finite` validator instead.");
+            Some(quote_spanned! {expr.span()=>
+                const _: () = ::core::assert!(<#inner_type>::is_finite(#expr), #msg);
+            })
+        })
+        .collect()
+}
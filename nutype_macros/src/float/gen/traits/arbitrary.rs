@@ -23,13 +23,13 @@ pub fn gen_impl_trait_arbitrary<T: ToTokens>(
             });
         let type_name = type_name.to_string();
         quote!(
-            Self::new(inner_value).unwrap_or_else(|err| {
+            Self::__nutype_new(inner_value).unwrap_or_else(|err| {
                 // Panic with the maximum details about what went wrong
                 panic!("\nArbitrary generated an invalid value for {}.\nInvalid inner value: {:?}\nValidation error: {:?}\n\n{}", #type_name, inner_value, err, #report_issue_msg);
             })
         )
     } else {
-        quote!(Self::new(inner_value))
+        quote!(Self::__nutype_new(inner_value))
     };
 
     let generate_inner_value = gen_generate_valid_inner_value(inner_type, guard)?;
@@ -122,7 +122,9 @@ fn normalize_basic_value_for_boundaries(
                 let from0to1 = #arbitrary_in_01_range;
 
                 // Scale range [0; 1] to the range of the boundaries
-                let range = (#upper_value - #lower_value).abs();
+                // The type is annotated, because the bounds may be untyped expressions, e.g. `1.0 - 0.5`
+                let range: #inner_type = #upper_value - #lower_value;
+                let range = range.abs();
                 let x = #lower_value + from0to1 * range;
 
                 // Make sure we satisfy the exclusive boundaries
@@ -229,14 +231,14 @@ fn gen_in_01_range(float_type: &FloatInnerType) -> TokenStream {
     )
 }
 
-struct Boundaries {
-    lower: Option<Boundary>,
-    upper: Option<Boundary>,
+pub(super) struct Boundaries {
+    pub(super) lower: Option<Boundary>,
+    pub(super) upper: Option<Boundary>,
 }
 
-struct Boundary {
-    value: TokenStream,
-    is_inclusive: bool,
+pub(super) struct Boundary {
+    pub(super) value: TokenStream,
+    pub(super) is_inclusive: bool,
 }
 
 /// Describes a type of initial basic value that has to be generated.
@@ -261,14 +263,14 @@ fn compute_basic_value_kind(validators: &[FloatValidatorKind]) -> BasicValueKind
 
     if validators.contains(&FloatValidatorKind::Finite) {
         BasicValueKind::Finite
-    } else if has_boundaries() {
+    } else if validators.contains(&FloatValidatorKind::NotNan) || has_boundaries() {
         BasicValueKind::NotNaN
     } else {
         BasicValueKind::All
     }
 }
 
-fn compute_boundaries<T: ToTokens>(validators: &[FloatValidator<T>]) -> Boundaries {
+pub(super) fn compute_boundaries<T: ToTokens>(validators: &[FloatValidator<T>]) -> Boundaries {
     let mut lower = None;
     let mut upper = None;
 
@@ -309,7 +311,7 @@ fn compute_boundaries<T: ToTokens>(validators: &[FloatValidator<T>]) -> Boundari
                     is_inclusive,
                 });
             }
-            FloatValidator::Finite | FloatValidator::Predicate(..) => {
+            FloatValidator::Finite | FloatValidator::NotNan | FloatValidator::Predicate(..) => {
                 // We don't care about these validators here.
             }
         }
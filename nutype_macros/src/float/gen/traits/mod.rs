@@ -1,4 +1,7 @@
 pub mod arbitrary;
+mod num_traits;
+mod ops;
+mod rand;
 use std::collections::HashSet;
 
 use proc_macro2::TokenStream;
@@ -7,13 +10,20 @@ use quote::{quote, ToTokens};
 use crate::{
     common::{
         gen::traits::{
-            gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_default,
-            gen_impl_trait_deref, gen_impl_trait_display, gen_impl_trait_from,
-            gen_impl_trait_from_str, gen_impl_trait_into, gen_impl_trait_serde_deserialize,
-            gen_impl_trait_serde_serialize, gen_impl_trait_try_from, split_into_generatable_traits,
-            GeneratableTrait, GeneratableTraits, GeneratedTraits,
+            gen_impl_trait_actix_from_request, gen_impl_trait_as_ref, gen_impl_trait_borrow,
+            gen_impl_trait_clap_value_parser_factory, gen_impl_trait_default, gen_impl_trait_deref,
+            gen_impl_trait_display, gen_impl_trait_from, gen_impl_trait_from_str,
+            gen_impl_trait_into, gen_impl_trait_juniper_graphql_scalar,
+            gen_impl_trait_num_traits_from_primitive, gen_impl_trait_num_traits_one,
+            gen_impl_trait_num_traits_to_primitive, gen_impl_trait_num_traits_zero,
+            gen_impl_trait_rand_sample_uniform, gen_impl_trait_rocket_from_form_field,
+            gen_impl_trait_rocket_from_param, gen_impl_trait_serde_deserialize,
+            gen_impl_trait_serde_serialize, gen_impl_trait_serde_with_deserialize_as,
+            gen_impl_trait_serde_with_serialize_as, gen_impl_trait_try_from,
+            split_into_generatable_traits, ArithOp, GeneratableTrait, GeneratableTraits,
+            GeneratedTraits, IterOp,
         },
-        models::{ErrorTypeName, TypeName},
+        models::{ErrorIntegrations, ErrorTypeName, OpsPolicy, SerdeAttributes, TypeName},
     },
     float::models::{FloatDeriveTrait, FloatGuard, FloatInnerType},
 };
@@ -42,6 +52,7 @@ enum FloatIrregularTrait {
     From,
     Eq,
     Ord,
+    Hash,
     TryFrom,
     Borrow,
     Display,
@@ -49,6 +60,29 @@ enum FloatIrregularTrait {
     SerdeSerialize,
     SerdeDeserialize,
     ArbitraryArbitrary,
+    JuniperGraphQLScalar,
+    RocketFromParam,
+    RocketFromFormField,
+    ActixFromRequest,
+    ClapValueParserFactory,
+    RandDistribution,
+    NumTraitsZero,
+    NumTraitsOne,
+    NumTraitsBounded,
+    NumTraitsToPrimitive,
+    NumTraitsFromPrimitive,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    AddAssign,
+    SubAssign,
+    MulAssign,
+    DivAssign,
+    Sum,
+    Product,
+    SerdeWithSerializeAs,
+    SerdeWithDeserializeAs,
 }
 
 impl From<FloatDeriveTrait> for FloatGeneratableTrait {
@@ -71,6 +105,7 @@ impl From<FloatDeriveTrait> for FloatGeneratableTrait {
                 FloatGeneratableTrait::Transparent(FloatTransparentTrait::PartialOrd)
             }
             FloatDeriveTrait::Ord => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Ord),
+            FloatDeriveTrait::Hash => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Hash),
             FloatDeriveTrait::FromStr => {
                 FloatGeneratableTrait::Irregular(FloatIrregularTrait::FromStr)
             }
@@ -99,9 +134,68 @@ impl From<FloatDeriveTrait> for FloatGeneratableTrait {
             FloatDeriveTrait::ArbitraryArbitrary => {
                 FloatGeneratableTrait::Irregular(FloatIrregularTrait::ArbitraryArbitrary)
             }
+            FloatDeriveTrait::JuniperGraphQLScalar => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::JuniperGraphQLScalar)
+            }
+            FloatDeriveTrait::RocketFromParam => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::RocketFromParam)
+            }
+            FloatDeriveTrait::RocketFromFormField => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::RocketFromFormField)
+            }
+            FloatDeriveTrait::ActixFromRequest => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::ActixFromRequest)
+            }
+            FloatDeriveTrait::ClapValueParserFactory => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::ClapValueParserFactory)
+            }
+            FloatDeriveTrait::RandDistribution => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::RandDistribution)
+            }
             FloatDeriveTrait::SchemarsJsonSchema => {
                 FloatGeneratableTrait::Transparent(FloatTransparentTrait::SchemarsJsonSchema)
             }
+            FloatDeriveTrait::NumTraitsZero => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::NumTraitsZero)
+            }
+            FloatDeriveTrait::NumTraitsOne => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::NumTraitsOne)
+            }
+            FloatDeriveTrait::NumTraitsBounded => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::NumTraitsBounded)
+            }
+            FloatDeriveTrait::NumTraitsToPrimitive => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::NumTraitsToPrimitive)
+            }
+            FloatDeriveTrait::NumTraitsFromPrimitive => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::NumTraitsFromPrimitive)
+            }
+            FloatDeriveTrait::Add => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Add),
+            FloatDeriveTrait::Sub => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Sub),
+            FloatDeriveTrait::Mul => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Mul),
+            FloatDeriveTrait::Div => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Div),
+            FloatDeriveTrait::AddAssign => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::AddAssign)
+            }
+            FloatDeriveTrait::SubAssign => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::SubAssign)
+            }
+            FloatDeriveTrait::MulAssign => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::MulAssign)
+            }
+            FloatDeriveTrait::DivAssign => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::DivAssign)
+            }
+            FloatDeriveTrait::Sum => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Sum),
+            FloatDeriveTrait::Product => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::Product)
+            }
+            FloatDeriveTrait::SerdeWithSerializeAs => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::SerdeWithSerializeAs)
+            }
+            FloatDeriveTrait::SerdeWithDeserializeAs => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::SerdeWithDeserializeAs)
+            }
         }
     }
 }
@@ -120,6 +214,7 @@ impl ToTokens for FloatTransparentTrait {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn gen_traits<T: ToTokens>(
     type_name: &TypeName,
     inner_type: &FloatInnerType,
@@ -127,6 +222,9 @@ pub fn gen_traits<T: ToTokens>(
     maybe_default_value: Option<syn::Expr>,
     traits: HashSet<FloatDeriveTrait>,
     guard: &FloatGuard<T>,
+    ops: OpsPolicy,
+    serde: &SerdeAttributes,
+    error_integrations: ErrorIntegrations,
 ) -> Result<GeneratedTraits, syn::Error> {
     let GeneratableTraits {
         transparent_traits,
@@ -146,6 +244,9 @@ pub fn gen_traits<T: ToTokens>(
         maybe_default_value,
         irregular_traits,
         guard,
+        ops,
+        serde,
+        error_integrations,
     )?;
 
     Ok(GeneratedTraits {
@@ -154,6 +255,7 @@ pub fn gen_traits<T: ToTokens>(
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 fn gen_implemented_traits<T: ToTokens>(
     type_name: &TypeName,
     inner_type: &FloatInnerType,
@@ -161,6 +263,9 @@ fn gen_implemented_traits<T: ToTokens>(
     maybe_default_value: Option<syn::Expr>,
     impl_traits: Vec<FloatIrregularTrait>,
     guard: &FloatGuard<T>,
+    ops: OpsPolicy,
+    serde: &SerdeAttributes,
+    error_integrations: ErrorIntegrations,
 ) -> Result<TokenStream, syn::Error> {
     impl_traits
         .iter()
@@ -168,7 +273,7 @@ fn gen_implemented_traits<T: ToTokens>(
             FloatIrregularTrait::AsRef => Ok(gen_impl_trait_as_ref(type_name, inner_type)),
             FloatIrregularTrait::Deref => Ok(gen_impl_trait_deref(type_name, inner_type)),
             FloatIrregularTrait::FromStr => {
-                Ok(gen_impl_trait_from_str(type_name, inner_type, maybe_error_type_name.as_ref()))
+                Ok(gen_impl_trait_from_str(type_name, inner_type, maybe_error_type_name.as_ref(), error_integrations))
             }
             FloatIrregularTrait::From => Ok(gen_impl_trait_from(type_name, inner_type)),
             FloatIrregularTrait::Into => Ok(gen_impl_trait_into(type_name, inner_type)),
@@ -188,17 +293,170 @@ fn gen_implemented_traits<T: ToTokens>(
                     Err(syn::Error::new(span, msg))
                 }
             },
-            FloatIrregularTrait::SerdeSerialize => Ok(gen_impl_trait_serde_serialize(type_name)),
+            FloatIrregularTrait::SerdeSerialize => Ok(gen_impl_trait_serde_serialize(
+                type_name, inner_type, serde,
+            )),
             FloatIrregularTrait::SerdeDeserialize => Ok(gen_impl_trait_serde_deserialize(
                 type_name,
                 inner_type,
                 maybe_error_type_name.as_ref(),
+                &maybe_default_value,
+                serde,
             )),
             FloatIrregularTrait::Eq => Ok(gen_impl_trait_eq(type_name)),
             FloatIrregularTrait::Ord => Ok(gen_impl_trait_ord(type_name)),
+            FloatIrregularTrait::Hash => Ok(gen_impl_trait_hash(type_name)),
             FloatIrregularTrait::ArbitraryArbitrary => {
                 arbitrary::gen_impl_trait_arbitrary(type_name, inner_type, guard)
             }
+            FloatIrregularTrait::JuniperGraphQLScalar => Ok(gen_impl_trait_juniper_graphql_scalar(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            )),
+            FloatIrregularTrait::RocketFromParam => Ok(gen_impl_trait_rocket_from_param(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            )),
+            FloatIrregularTrait::RocketFromFormField => Ok(gen_impl_trait_rocket_from_form_field(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            )),
+            FloatIrregularTrait::ActixFromRequest => Ok(gen_impl_trait_actix_from_request(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            )),
+            FloatIrregularTrait::ClapValueParserFactory => Ok(gen_impl_trait_clap_value_parser_factory(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            )),
+            FloatIrregularTrait::RandDistribution => {
+                let impl_distribution = rand::gen_impl_trait_rand_distribution(type_name, inner_type, guard)?;
+                let impl_sample_uniform = gen_impl_trait_rand_sample_uniform(
+                    type_name,
+                    inner_type,
+                    maybe_error_type_name.as_ref(),
+                );
+                Ok(quote! {
+                    #impl_distribution
+                    #impl_sample_uniform
+                })
+            }
+            FloatIrregularTrait::NumTraitsZero => Ok(gen_impl_trait_num_traits_zero(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                guard.validators().map(Vec::as_slice).unwrap_or_default(),
+            )),
+            FloatIrregularTrait::NumTraitsOne => Ok(gen_impl_trait_num_traits_one(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                guard.validators().map(Vec::as_slice).unwrap_or_default(),
+            )),
+            FloatIrregularTrait::NumTraitsBounded => num_traits::gen_impl_trait_num_traits_bounded(
+                type_name,
+                inner_type,
+                guard,
+            ),
+            FloatIrregularTrait::NumTraitsToPrimitive => Ok(gen_impl_trait_num_traits_to_primitive(
+                type_name,
+            )),
+            FloatIrregularTrait::NumTraitsFromPrimitive => Ok(gen_impl_trait_num_traits_from_primitive(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            )),
+            FloatIrregularTrait::Add => ops::gen_impl_trait_arith_op_for_float(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                ArithOp::Add,
+                ops,
+                guard,
+            ),
+            FloatIrregularTrait::Sub => ops::gen_impl_trait_arith_op_for_float(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                ArithOp::Sub,
+                ops,
+                guard,
+            ),
+            FloatIrregularTrait::Mul => ops::gen_impl_trait_arith_op_for_float(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                ArithOp::Mul,
+                ops,
+                guard,
+            ),
+            FloatIrregularTrait::Div => ops::gen_impl_trait_arith_op_for_float(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                ArithOp::Div,
+                ops,
+                guard,
+            ),
+            FloatIrregularTrait::AddAssign => ops::gen_impl_trait_arith_assign_op_for_float(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                ArithOp::Add,
+                ops,
+                guard,
+            ),
+            FloatIrregularTrait::SubAssign => ops::gen_impl_trait_arith_assign_op_for_float(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                ArithOp::Sub,
+                ops,
+                guard,
+            ),
+            FloatIrregularTrait::MulAssign => ops::gen_impl_trait_arith_assign_op_for_float(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                ArithOp::Mul,
+                ops,
+                guard,
+            ),
+            FloatIrregularTrait::DivAssign => ops::gen_impl_trait_arith_assign_op_for_float(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                ArithOp::Div,
+                ops,
+                guard,
+            ),
+            FloatIrregularTrait::Sum => ops::gen_impl_trait_iter_op_for_float(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                IterOp::Sum,
+                ops,
+                guard,
+            ),
+            FloatIrregularTrait::Product => ops::gen_impl_trait_iter_op_for_float(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                IterOp::Product,
+                ops,
+                guard,
+            ),
+            FloatIrregularTrait::SerdeWithSerializeAs => Ok(
+                gen_impl_trait_serde_with_serialize_as(type_name, inner_type, serde),
+            ),
+            FloatIrregularTrait::SerdeWithDeserializeAs => Ok(
+                gen_impl_trait_serde_with_deserialize_as(type_name, inner_type),
+            ),
         })
         .collect()
 }
@@ -209,22 +467,31 @@ fn gen_impl_trait_eq(type_name: &TypeName) -> TokenStream {
     }
 }
 
-// The implementation below may panic.
 // Function `partial_cmp` returns `None` only for `NaN` values, but
-// `NaN` values are supposed to be excluded by `finite` validation rule.
-// Without `finite` validation deriving `Ord` is not allowed.
+// `NaN` values are supposed to be excluded by `finite` or `not_nan` validation rule.
+// Without such validation deriving `Ord` is not allowed.
+// If NaN still sneaks in (e.g. with `new_unchecked()`), `total_cmp` keeps the order total.
 fn gen_impl_trait_ord(type_name: &TypeName) -> TokenStream {
-    let tp = type_name.to_string();
     quote! {
         // Make clippy ignore this manual implementation of Ord even when PartialOrd is derived.
         #[allow(clippy::derive_ord_xor_partial_ord)]
         impl ::core::cmp::Ord for #type_name {
             fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
                 self.partial_cmp(other)
-                    .unwrap_or_else(|| {
-                        let tp = #tp;
-                        panic!("{tp}::cmp() panicked, because partial_cmp() returned None. Could it be that you're using unsafe {tp}::new_unchecked() ?", tp=tp);
-                    })
+                    .unwrap_or_else(|| self.0.total_cmp(&other.0))
+            }
+        }
+    }
+}
+
+// Hash the bits of the inner value. Without NaN, values are equal only if their bits are equal,
+// except `0.0` and `-0.0`, which are therefore normalized.
+fn gen_impl_trait_hash(type_name: &TypeName) -> TokenStream {
+    quote! {
+        impl ::core::hash::Hash for #type_name {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                let value = if self.0 == 0.0 { 0.0 } else { self.0 };
+                ::core::hash::Hash::hash(&value.to_bits(), state);
             }
         }
     }
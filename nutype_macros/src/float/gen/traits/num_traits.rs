@@ -0,0 +1,91 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+
+use crate::{
+    common::models::TypeName,
+    float::models::{FloatGuard, FloatInnerType, FloatValidator},
+};
+
+use super::arbitrary::{compute_boundaries, Boundaries, Boundary};
+
+/// Implement `num_traits::Bounded` using the boundaries defined by the validators.
+/// Exclusive boundaries (`greater` and `less`) are not supported, since the closest valid
+/// value can not be expressed.
+pub fn gen_impl_trait_num_traits_bounded<T: ToTokens>(
+    type_name: &TypeName,
+    inner_type: &FloatInnerType,
+    guard: &FloatGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    if !guard.sanitizers().is_empty() {
+        let msg = "Cannot derive trait `Bounded` for a type with `with` sanitizer";
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
+    let Boundaries { lower, upper } = match guard {
+        FloatGuard::WithoutValidation { .. } => Boundaries {
+            lower: None,
+            upper: None,
+        },
+        FloatGuard::WithValidation { validators, .. } => {
+            if validators
+                .iter()
+                .any(|v| matches!(v, FloatValidator::Predicate(_)))
+            {
+                let msg = "Cannot derive trait `Bounded` for a type with `predicate` validator";
+                return Err(syn::Error::new(Span::call_site(), msg));
+            }
+            compute_boundaries(validators)
+        }
+    };
+
+    let min = boundary_value(lower, quote!(#inner_type::MIN), "greater")?;
+    let max = boundary_value(upper, quote!(#inner_type::MAX), "less")?;
+    let min_error = format!("The lower boundary is not a valid value of {type_name}");
+    let max_error = format!("The upper boundary is not a valid value of {type_name}");
+
+    let (min_value, max_value) = if guard.has_validation() {
+        (
+            quote!(#type_name::__nutype_new(min).expect(#min_error)),
+            quote!(#type_name::__nutype_new(max).expect(#max_error)),
+        )
+    } else {
+        (
+            quote!(#type_name::__nutype_new(min)),
+            quote!(#type_name::__nutype_new(max)),
+        )
+    };
+
+    Ok(quote! {
+        impl ::num_traits::Bounded for #type_name {
+            fn min_value() -> Self {
+                let min: #inner_type = #min;
+                #min_value
+            }
+
+            fn max_value() -> Self {
+                let max: #inner_type = #max;
+                #max_value
+            }
+        }
+    })
+}
+
+fn boundary_value(
+    maybe_boundary: Option<Boundary>,
+    default_value: TokenStream,
+    exclusive_validator: &str,
+) -> Result<TokenStream, syn::Error> {
+    match maybe_boundary {
+        None => Ok(default_value),
+        Some(Boundary {
+            value,
+            is_inclusive: true,
+        }) => Ok(value),
+        Some(Boundary {
+            is_inclusive: false,
+            ..
+        }) => {
+            let msg = format!("Cannot derive trait `Bounded` for a type with `{exclusive_validator}` validator, because the boundary value itself is not valid.\nConsider using `{exclusive_validator}_or_equal` instead.");
+            Err(syn::Error::new(Span::call_site(), msg))
+        }
+    }
+}
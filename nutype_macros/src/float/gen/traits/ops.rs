@@ -0,0 +1,144 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+use crate::{
+    common::{
+        gen::traits::{
+            gen_impl_trait_arith_assign_op, gen_impl_trait_arith_op, gen_impl_trait_iter_op,
+            ArithOp, IterOp,
+        },
+        models::{ErrorTypeName, OpsPolicy, TypeName},
+    },
+    float::models::{FloatGuard, FloatInnerType},
+};
+
+use super::arbitrary::{compute_boundaries, Boundaries, Boundary};
+
+/// Implement an arithmetic operator trait for a float newtype.
+/// With `ops = saturate` the result is clamped into the inclusive range defined by the validators,
+/// therefore exclusive boundaries (`greater`, `less`) are not supported.
+pub fn gen_impl_trait_arith_op_for_float<T: ToTokens>(
+    type_name: &TypeName,
+    inner_type: &FloatInnerType,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    op: ArithOp,
+    ops: OpsPolicy,
+    guard: &FloatGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    let compute_value = gen_compute_value(inner_type, op, ops, guard);
+    Ok(gen_impl_trait_arith_op(
+        type_name,
+        maybe_error_type_name,
+        op,
+        ops,
+        compute_value,
+    ))
+}
+
+/// Implement an assigning arithmetic operator trait (e.g. `AddAssign`) for a float newtype.
+/// The value is computed the same way as for the corresponding binary operator.
+pub fn gen_impl_trait_arith_assign_op_for_float<T: ToTokens>(
+    type_name: &TypeName,
+    inner_type: &FloatInnerType,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    op: ArithOp,
+    ops: OpsPolicy,
+    guard: &FloatGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    let compute_value = gen_compute_value(inner_type, op, ops, guard);
+    Ok(gen_impl_trait_arith_assign_op(
+        type_name,
+        maybe_error_type_name,
+        op,
+        ops,
+        compute_value,
+    ))
+}
+
+/// Implement `Sum` or `Product` for a float newtype.
+/// The elements are folded the same way as with the corresponding binary operator.
+/// With `ops = saturate` the sum or product of an empty iterator is clamped into the valid range.
+pub fn gen_impl_trait_iter_op_for_float<T: ToTokens>(
+    type_name: &TypeName,
+    inner_type: &FloatInnerType,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    iter_op: IterOp,
+    ops: OpsPolicy,
+    guard: &FloatGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    let compute_value = gen_compute_value(inner_type, iter_op.arith_op(), ops, guard);
+    let identity = match iter_op {
+        IterOp::Sum => quote!(0.0),
+        IterOp::Product => quote!(1.0),
+    };
+    let empty_value = match ops {
+        OpsPolicy::Saturate => gen_saturate(inner_type, guard, identity),
+        OpsPolicy::Panic | OpsPolicy::Result => identity,
+    };
+    Ok(gen_impl_trait_iter_op(
+        type_name,
+        inner_type,
+        maybe_error_type_name,
+        iter_op,
+        ops,
+        empty_value,
+        compute_value,
+    ))
+}
+
+/// Generate an expression that computes the new inner value out of `lhs` and `rhs`.
+fn gen_compute_value<T: ToTokens>(
+    inner_type: &FloatInnerType,
+    op: ArithOp,
+    ops: OpsPolicy,
+    guard: &FloatGuard<T>,
+) -> TokenStream {
+    let operator = match op {
+        ArithOp::Add => quote!(+),
+        ArithOp::Sub => quote!(-),
+        ArithOp::Mul => quote!(*),
+        ArithOp::Div => quote!(/),
+    };
+    let raw_value = quote!(lhs #operator rhs);
+
+    match ops {
+        OpsPolicy::Panic | OpsPolicy::Result => raw_value,
+        OpsPolicy::Saturate => gen_saturate(inner_type, guard, raw_value),
+    }
+}
+
+/// Clamp the value into the inclusive range defined by the validators.
+/// `predicate` and exclusive boundaries are rejected with `ops = saturate` during validation.
+fn gen_saturate<T: ToTokens>(
+    inner_type: &FloatInnerType,
+    guard: &FloatGuard<T>,
+    value: TokenStream,
+) -> TokenStream {
+    let FloatGuard::WithValidation { validators, .. } = guard else {
+        return value;
+    };
+    let Boundaries { lower, upper } = compute_boundaries(validators);
+    let clamp_lower = gen_clamp(inner_type, lower, quote!(<));
+    let clamp_upper = gen_clamp(inner_type, upper, quote!(>));
+    quote!({
+        let value: #inner_type = #value;
+        #clamp_lower
+        #clamp_upper
+        value
+    })
+}
+
+fn gen_clamp(
+    inner_type: &FloatInnerType,
+    maybe_boundary: Option<Boundary>,
+    comparison: TokenStream,
+) -> TokenStream {
+    let Some(boundary) = maybe_boundary else {
+        return quote!();
+    };
+    let value = &boundary.value;
+    quote! {
+        let bound: #inner_type = #value;
+        let value = if value #comparison bound { bound } else { value };
+    }
+}
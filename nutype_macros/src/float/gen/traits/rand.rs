@@ -0,0 +1,88 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+
+use crate::{
+    common::models::TypeName,
+    float::models::{FloatGuard, FloatInnerType, FloatSanitizer, FloatValidator},
+};
+
+use super::arbitrary::{compute_boundaries, Boundaries, Boundary};
+
+/// Implement `Distribution<Type>` for `rand::distributions::Standard`.
+/// The values are sampled uniformly within the range defined by the validators,
+/// so both lower and upper boundaries are required.
+pub fn gen_impl_trait_rand_distribution<T: ToTokens>(
+    type_name: &TypeName,
+    inner_type: &FloatInnerType,
+    guard: &FloatGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    let (sanitizers, validators) = match guard {
+        FloatGuard::WithoutValidation { .. } => {
+            return Err(missing_boundaries_error());
+        }
+        FloatGuard::WithValidation {
+            sanitizers,
+            validators,
+        } => (sanitizers, validators),
+    };
+
+    if validators
+        .iter()
+        .any(|v| matches!(v, FloatValidator::Predicate(_)))
+    {
+        let msg = "Cannot derive trait `Distribution` for a type with `predicate` validator";
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
+    if sanitizers
+        .iter()
+        .any(|s| matches!(s, FloatSanitizer::With(_)))
+    {
+        let msg =
+            "Cannot derive trait `Distribution` for a type with `with` sanitizer and validations";
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
+
+    let Boundaries { lower, upper } = compute_boundaries(validators);
+    let (Some(lower), Some(upper)) = (lower, upper) else {
+        return Err(missing_boundaries_error());
+    };
+
+    let lower_value = &lower.value;
+    let upper_value = &upper.value;
+    let adjust_lower = gen_adjust_exclusive_boundary(&lower);
+    let adjust_upper = gen_adjust_exclusive_boundary(&upper);
+    let error_text = format!("Distribution generated an invalid value for {type_name}");
+
+    Ok(quote! {
+        impl ::rand::distributions::Distribution<#type_name> for ::rand::distributions::Standard {
+            fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> #type_name {
+                let lower: #inner_type = #lower_value;
+                let upper: #inner_type = #upper_value;
+                let x: #inner_type = rng.gen_range(lower..=upper);
+                // Exclusive boundaries are hit extremely rarely, in this case we take
+                // the middle of the range instead of sampling again.
+                let middle = lower / 2.0 + upper / 2.0;
+                #adjust_lower
+                #adjust_upper
+                let inner_value = x;
+                #type_name::__nutype_new(inner_value).expect(#error_text)
+            }
+        }
+    })
+}
+
+fn gen_adjust_exclusive_boundary(boundary: &Boundary) -> TokenStream {
+    if boundary.is_inclusive {
+        quote!()
+    } else {
+        let value = &boundary.value;
+        quote! {
+            let x = if x == #value { middle } else { x };
+        }
+    }
+}
+
+fn missing_boundaries_error() -> syn::Error {
+    let msg = "Cannot derive trait `Distribution` for a float type without both lower and upper boundaries.\nPlease specify the range with `greater`/`greater_or_equal` and `less`/`less_or_equal` validators.";
+    syn::Error::new(Span::call_site(), msg)
+}
@@ -10,7 +10,7 @@ use quote::ToTokens;
 
 use crate::common::{
     gen::GenerateNewtype,
-    models::{Attributes, GenerateParams, Guard, Newtype, SpannedDeriveTrait},
+    models::{Attributes, ConditionalRules, GenerateParams, Guard, Newtype, SpannedDeriveTrait},
 };
 
 use self::{
@@ -55,6 +55,7 @@ where
             FloatInnerType,
             Self::TypedTrait,
             Guard<Self::Sanitizer, Self::Validator>,
+            ConditionalRules<Self::Sanitizer, Self::Validator>,
         >,
     ) -> Result<TokenStream, syn::Error> {
         FloatNewtype::gen_nutype(params)
@@ -1,9 +1,15 @@
 use kinded::Kinded;
 use proc_macro2::TokenStream;
+use std::fmt::Display;
 
-use crate::common::models::{
-    impl_numeric_bound_on_vec_of, impl_numeric_bound_validator, Guard, RawGuard, SpannedItem,
-    TypeTrait, TypedCustomFunction, ValueOrExpr,
+use crate::{
+    common::gen::invariants::{describe_bound, describe_custom_function},
+    common::gen::metadata::{metadata_of_bound, metadata_of_custom_function, metadata_of_kind},
+    common::models::{
+        impl_numeric_bound_on_vec_of, impl_numeric_bound_validator, Describe, Guard, HasBound,
+        Metadata, RawGuard, SpannedItem, TypeTrait, TypedCustomFunction, ValueOrExpr,
+    },
+    integer::models::IntegerInnerType,
 };
 
 // Sanitizer
@@ -30,9 +36,83 @@ pub enum FloatValidator<T> {
     LessOrEqual(ValueOrExpr<T>),
     Predicate(TypedCustomFunction),
     Finite,
+    NotNan,
 }
 
 impl_numeric_bound_validator!(FloatValidator);
+
+impl<T> Describe for FloatSanitizer<T> {
+    fn describe(&self) -> String {
+        match self {
+            Self::With(function) => {
+                describe_custom_function("with", function.path(), "transformed with")
+            }
+            Self::_Phantom(_) => unreachable!("FloatSanitizer::_Phantom must not be used"),
+        }
+    }
+}
+
+impl<T> Metadata for FloatSanitizer<T> {
+    fn metadata(&self) -> String {
+        match self {
+            Self::With(function) => metadata_of_custom_function("with", function.path()),
+            Self::_Phantom(_) => unreachable!("FloatSanitizer::_Phantom must not be used"),
+        }
+    }
+}
+
+impl<T: Display> Describe for FloatValidator<T> {
+    fn describe(&self) -> String {
+        match self {
+            Self::Greater(bound) => {
+                let (code, prose) = describe_bound(bound);
+                format!("`greater = {code}`: greater than {prose}")
+            }
+            Self::GreaterOrEqual(bound) => {
+                let (code, prose) = describe_bound(bound);
+                format!("`greater_or_equal = {code}`: greater than or equal to {prose}")
+            }
+            Self::Less(bound) => {
+                let (code, prose) = describe_bound(bound);
+                format!("`less = {code}`: less than {prose}")
+            }
+            Self::LessOrEqual(bound) => {
+                let (code, prose) = describe_bound(bound);
+                format!("`less_or_equal = {code}`: less than or equal to {prose}")
+            }
+            Self::Predicate(function) => {
+                describe_custom_function("predicate", function.path(), "satisfies")
+            }
+            Self::Finite => "`finite`: neither NaN nor infinite".to_string(),
+            Self::NotNan => "`not_nan`: not NaN".to_string(),
+        }
+    }
+}
+
+impl<T: Display> Metadata for FloatValidator<T> {
+    fn metadata(&self) -> String {
+        match self {
+            Self::Greater(bound) => metadata_of_bound("greater", bound),
+            Self::GreaterOrEqual(bound) => metadata_of_bound("greater_or_equal", bound),
+            Self::Less(bound) => metadata_of_bound("less", bound),
+            Self::LessOrEqual(bound) => metadata_of_bound("less_or_equal", bound),
+            Self::Predicate(function) => metadata_of_custom_function("predicate", function.path()),
+            Self::Finite => metadata_of_kind("finite"),
+            Self::NotNan => metadata_of_kind("not_nan"),
+        }
+    }
+}
+
+impl<T> HasBound for FloatValidator<T> {
+    fn has_bound(&self) -> bool {
+        match self {
+            Self::Greater(_) | Self::GreaterOrEqual(_) | Self::Less(_) | Self::LessOrEqual(_) => {
+                true
+            }
+            Self::Predicate(_) | Self::Finite | Self::NotNan => false,
+        }
+    }
+}
 impl_numeric_bound_on_vec_of!(FloatValidator);
 
 pub type SpannedFloatValidator<T> = SpannedItem<FloatValidator<T>>;
@@ -58,25 +138,60 @@ pub enum FloatDeriveTrait {
     Display,
     Default,
     Deref,
+    Hash,
 
     // External crates
     SerdeSerialize,
     SerdeDeserialize,
     SchemarsJsonSchema,
     ArbitraryArbitrary,
+    JuniperGraphQLScalar,
+    RocketFromParam,
+    RocketFromFormField,
+    ActixFromRequest,
+    ClapValueParserFactory,
+    RandDistribution,
+    NumTraitsZero,
+    NumTraitsOne,
+    NumTraitsBounded,
+    NumTraitsToPrimitive,
+    NumTraitsFromPrimitive,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    AddAssign,
+    SubAssign,
+    MulAssign,
+    DivAssign,
+    Sum,
+    Product,
+    SerdeWithSerializeAs,
+    SerdeWithDeserializeAs,
 }
 
 impl TypeTrait for FloatDeriveTrait {
     fn is_from_str(&self) -> bool {
         self == &FloatDeriveTrait::FromStr
     }
+
+    fn is_debug(&self) -> bool {
+        self == &FloatDeriveTrait::Debug
+    }
 }
 
 pub type FloatRawGuard<T> = RawGuard<SpannedFloatSanitizer<T>, SpannedFloatValidator<T>>;
 pub type FloatGuard<T> = Guard<FloatSanitizer<T>, FloatValidator<T>>;
 
-pub trait FloatType {
+/// `Display` is used for the generated docs, e.g. the bounds of the validators.
+pub trait FloatType: Display + Sized {
+    const MIN: Self;
+    const MAX: Self;
+    const ZERO: Self;
+
     fn float_inner_type() -> FloatInnerType;
+    fn is_nan(&self) -> bool;
+    fn is_infinite(&self) -> bool;
 }
 
 macro_rules! define_float_inner_type {
@@ -88,9 +203,21 @@ macro_rules! define_float_inner_type {
 
         $(
             impl FloatType for $tp {
+                const MIN: Self = <$tp>::MIN;
+                const MAX: Self = <$tp>::MAX;
+                const ZERO: Self = 0.0;
+
                 fn float_inner_type() -> FloatInnerType {
                     FloatInnerType::$variant
                 }
+
+                fn is_nan(&self) -> bool {
+                    <$tp>::is_nan(*self)
+                }
+
+                fn is_infinite(&self) -> bool {
+                    <$tp>::is_infinite(*self)
+                }
             }
         )*
 
@@ -121,3 +248,14 @@ define_float_inner_type!(
     f32 => F32,
     f64 => F64
 );
+
+impl FloatInnerType {
+    /// Integer types, that can be converted into the type without loss with `From`.
+    pub fn lossless_integer_sources(&self) -> &'static [IntegerInnerType] {
+        use IntegerInnerType::*;
+        match self {
+            Self::F32 => &[U8, U16, I8, I16],
+            Self::F64 => &[U8, U16, U32, I8, I16, I32],
+        }
+    }
+}
@@ -9,8 +9,15 @@ use crate::common::{
         parse_number_or_expr, parse_sanitizer_kind, parse_typed_custom_function,
         parse_validator_kind, ParseableAttributes,
     },
+    validate::{
+        detect_numeric_const_fn, detect_redundant_new_unchecked, validate_constructor_name,
+        validate_default_derive, validate_error_messages, validate_error_status_codes,
+        validate_factory, validate_local, validate_numeric_default, validate_numeric_iter_identity,
+        validate_numeric_ops_policy, validate_numeric_zero_one, validate_saturate_with_predicate,
+        validate_warnings,
+    },
 };
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use syn::{
     parse::{Parse, ParseStream},
     Token,
@@ -18,17 +25,19 @@ use syn::{
 
 use super::{
     models::{
-        FloatGuard, FloatRawGuard, FloatSanitizer, FloatSanitizerKind, FloatValidator,
+        FloatGuard, FloatRawGuard, FloatSanitizer, FloatSanitizerKind, FloatType, FloatValidator,
         FloatValidatorKind, SpannedFloatSanitizer, SpannedFloatValidator,
     },
-    validate::validate_number_meta,
+    validate::{
+        detect_inexact_bounds, validate_number_meta, validate_saturate_with_exclusive_bounds,
+    },
 };
 
 pub fn parse_attributes<T>(
     input: TokenStream,
 ) -> Result<Attributes<FloatGuard<T>, SpannedDeriveTrait>, syn::Error>
 where
-    T: FromStr + PartialOrd + Clone,
+    T: FloatType + FromStr + PartialOrd + Clone,
     <T as FromStr>::Err: Debug + Display,
 {
     let attrs: ParseableAttributes<SpannedFloatSanitizer<T>, SpannedFloatValidator<T>> =
@@ -40,17 +49,115 @@ where
         new_unchecked,
         default,
         derive_traits,
+        ops,
+        secret,
+        const_fn,
+        constructor,
+        constructor_vis,
+        factory,
+        try_from_lossless,
+        exact_bounds,
+        delegate,
+        accessor,
+        widen_into,
+        derive_unsafe,
+        repr,
+        local,
+        docs,
+        omit,
+        methods,
+        metadata,
+        debug_expansion,
+        strict,
+        serde,
+        error,
     } = attrs;
+    if let Some(target) = widen_into.first() {
+        let msg = "`widen_into` is supported only for integer types.";
+        return Err(syn::Error::new_spanned(target, msg));
+    }
+    if let Some(method) = delegate.first() {
+        let msg = "`delegate` is supported only for String types.";
+        return Err(syn::Error::new(method.span(), msg));
+    }
+    if methods.leak {
+        let msg = "`methods(leak)` is supported only for String types.";
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
+    validate_constructor_name(&constructor, &derive_traits)?;
+    validate_default_derive(&derive_traits, &default)?;
+    validate_error_messages(&error.messages, &validators)?;
+    validate_error_status_codes(&error.status_codes, &validators)?;
+    validate_factory(
+        factory,
+        &validators,
+        |v| {
+            matches!(
+                v,
+                FloatValidator::Greater(_)
+                    | FloatValidator::GreaterOrEqual(_)
+                    | FloatValidator::Less(_)
+                    | FloatValidator::LessOrEqual(_)
+            )
+        },
+        "`greater`, `greater_or_equal`, `less`, `less_or_equal`",
+    )?;
+    validate_saturate_with_predicate(&ops, &validators, |v| {
+        matches!(v, FloatValidator::Predicate(_))
+    })?;
+    validate_saturate_with_exclusive_bounds(&ops, &validators)?;
+    let inexact_bounds = if exact_bounds {
+        detect_inexact_bounds(&validators)
+    } else {
+        vec![]
+    };
     let raw_guard = FloatRawGuard {
         sanitizers,
         validators,
     };
     let guard = validate_number_meta(raw_guard)?;
+    let mut warnings = validate_warnings(
+        detect_redundant_new_unchecked(&new_unchecked, &guard)
+            .into_iter()
+            .collect(),
+        strict,
+    )?;
+    // The rounding of a bound is not a redundant rule, so it is not an error in strict mode
+    warnings.extend(inexact_bounds);
+    validate_numeric_default(&guard, &default)?;
+    let ops = validate_numeric_ops_policy(ops, &guard, &derive_traits)?;
+    validate_numeric_iter_identity(&guard, ops, &derive_traits)?;
+    validate_numeric_zero_one(&guard, &derive_traits, |v| {
+        matches!(v, FloatValidator::Predicate(_))
+    })?;
+    let local = validate_local(local, &guard, &error)?;
+    let const_fn = detect_numeric_const_fn(const_fn, &guard, &error);
     Ok(Attributes {
         new_unchecked,
         guard,
         default,
         derive_traits,
+        ops,
+        secret,
+        const_fn,
+        constructor,
+        constructor_vis,
+        factory,
+        try_from_lossless,
+        delegate,
+        accessor,
+        widen_into,
+        derive_unsafe,
+        repr,
+        local,
+        docs,
+        omit,
+        methods,
+        metadata,
+        debug_expansion,
+        warnings,
+        serde,
+        error,
     })
 }
 
@@ -110,6 +217,13 @@ where
                     span: ident.span(),
                 })
             }
+            FloatValidatorKind::NotNan => {
+                let validator = FloatValidator::NotNan;
+                Ok(SpannedFloatValidator {
+                    item: validator,
+                    span: ident.span(),
+                })
+            }
         }
     }
 }
@@ -1,19 +1,21 @@
 use proc_macro2::Span;
-use std::collections::HashSet;
+use std::{collections::HashSet, fmt::Display};
+use syn::{Expr, ExprPath};
 
 use crate::common::{
-    models::{DeriveTrait, SpannedDeriveTrait},
+    models::{DeriveTrait, OpsPolicy, SpannedDeriveTrait, SpannedOpsPolicy, ValueOrExpr, Warning},
+    parse::strip_expr_groups,
     validate::{validate_duplicates, validate_numeric_bounds},
 };
 
 use super::models::{
-    FloatDeriveTrait, FloatGuard, FloatRawGuard, FloatSanitizer, FloatValidator,
-    FloatValidatorKind, SpannedFloatSanitizer, SpannedFloatValidator,
+    FloatDeriveTrait, FloatGuard, FloatInnerType, FloatRawGuard, FloatSanitizer, FloatType,
+    FloatValidator, FloatValidatorKind, SpannedFloatSanitizer, SpannedFloatValidator,
 };
 
 pub fn validate_number_meta<T>(raw_meta: FloatRawGuard<T>) -> Result<FloatGuard<T>, syn::Error>
 where
-    T: PartialOrd + Clone,
+    T: FloatType + PartialOrd + Clone,
 {
     let FloatRawGuard {
         sanitizers,
@@ -37,7 +39,7 @@ fn validate_validators<T>(
     validators: Vec<SpannedFloatValidator<T>>,
 ) -> Result<Vec<FloatValidator<T>>, syn::Error>
 where
-    T: PartialOrd + Clone,
+    T: FloatType + PartialOrd + Clone,
 {
     validate_duplicates(&validators, |kind| {
         format!(
@@ -45,12 +47,36 @@ where
         )
     })?;
 
+    validate_finite_bounds(&validators)?;
+
     validate_numeric_bounds(&validators)?;
 
     let validators: Vec<_> = validators.into_iter().map(|v| v.item).collect();
     Ok(validators)
 }
 
+/// With `ops = saturate` the result is clamped at the bounds, but an exclusive bound is not
+/// a valid value itself and a float has no closest value below or above it to clamp at.
+pub fn validate_saturate_with_exclusive_bounds<T>(
+    maybe_ops: &Option<SpannedOpsPolicy>,
+    validators: &[SpannedFloatValidator<T>],
+) -> Result<(), syn::Error> {
+    let Some(ops) = maybe_ops else {
+        return Ok(());
+    };
+    if ops.item != OpsPolicy::Saturate {
+        return Ok(());
+    }
+    let maybe_exclusive = validators
+        .iter()
+        .find(|v| matches!(v.item, FloatValidator::Greater(_) | FloatValidator::Less(_)));
+    if let Some(exclusive) = maybe_exclusive {
+        let msg = "`ops = saturate` cannot be used with exclusive boundaries (`greater`, `less`), because there is no closest valid value to saturate at.\nUse `greater_or_equal` and `less_or_equal` instead.";
+        return Err(syn::Error::new(exclusive.span, msg));
+    }
+    Ok(())
+}
+
 fn validate_sanitizers<T>(
     sanitizers: Vec<SpannedFloatSanitizer<T>>,
 ) -> Result<Vec<FloatSanitizer<T>>, syn::Error>
@@ -65,12 +91,178 @@ where
     Ok(sanitizers)
 }
 
+/// NaN or infinity as a bound (e.g. `less_or_equal = f64::INFINITY`) is an error: every comparison
+/// with NaN is false and an infinite bound either rules out every finite value or nothing.
+fn validate_finite_bounds<T: FloatType>(
+    validators: &[SpannedFloatValidator<T>],
+) -> Result<(), syn::Error> {
+    for validator in validators {
+        let (kind, bound) = match &validator.item {
+            FloatValidator::Greater(bound) => ("greater", bound),
+            FloatValidator::GreaterOrEqual(bound) => ("greater_or_equal", bound),
+            FloatValidator::Less(bound) => ("less", bound),
+            FloatValidator::LessOrEqual(bound) => ("less_or_equal", bound),
+            _ => continue,
+        };
+        let msg = match non_finite_bound(bound) {
+            Some(NonFinite::Nan) => format!("The bound of `{kind}` is NaN. Every comparison with NaN is false, so the validator would accept every value, NaN included.\nTo rule out NaN, use `not_nan` or `finite` validator instead."),
+            Some(NonFinite::Infinite) => format!("The bound of `{kind}` is infinite. It would rule out either every finite value or nothing.\nTo rule out infinity, use `finite` validator instead."),
+            None => continue,
+        };
+        let error = match bound {
+            ValueOrExpr::Expr(expr) => syn::Error::new_spanned(expr, msg),
+            ValueOrExpr::Value(_) => syn::Error::new(validator.span(), msg),
+        };
+        return Err(error);
+    }
+    Ok(())
+}
+
+enum NonFinite {
+    Nan,
+    Infinite,
+}
+
+/// A bound is either a value (e.g. `env!("MAX")` set to `inf`) or an expression, which refers to
+/// the constants of the float types, e.g. `f64::NAN` or `-core::f32::INFINITY`.
+fn non_finite_bound<T: FloatType>(bound: &ValueOrExpr<T>) -> Option<NonFinite> {
+    match bound {
+        ValueOrExpr::Value(value) if value.is_nan() => Some(NonFinite::Nan),
+        ValueOrExpr::Value(value) if value.is_infinite() => Some(NonFinite::Infinite),
+        ValueOrExpr::Value(_) => None,
+        ValueOrExpr::Expr(expr) => {
+            let expr = match strip_expr_groups(expr) {
+                Expr::Unary(syn::ExprUnary {
+                    op: syn::UnOp::Neg(_),
+                    expr,
+                    ..
+                }) => strip_expr_groups(expr),
+                expr => expr,
+            };
+            let Expr::Path(ExprPath {
+                qself: None, path, ..
+            }) = expr
+            else {
+                return None;
+            };
+            let mut idents = path.segments.iter().rev().map(|segment| &segment.ident);
+            let (Some(name), Some(tp)) = (idents.next(), idents.next()) else {
+                return None;
+            };
+            if tp != "f32" && tp != "f64" {
+                return None;
+            }
+            if name == "NAN" {
+                Some(NonFinite::Nan)
+            } else if name == "INFINITY" || name == "NEG_INFINITY" {
+                Some(NonFinite::Infinite)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// Detect bound literals, which cannot be represented exactly by the float type, e.g.
+/// `less_or_equal = 0.3` on `f64` is in fact 0.299999999999999988897769753748434595763683319091796875.
+pub fn detect_inexact_bounds<T: FloatType>(
+    validators: &[SpannedFloatValidator<T>],
+) -> Vec<Warning> {
+    let mantissa_bits = match T::float_inner_type() {
+        FloatInnerType::F32 => f32::MANTISSA_DIGITS,
+        FloatInnerType::F64 => f64::MANTISSA_DIGITS,
+    };
+    validators
+        .iter()
+        .filter_map(|validator| {
+            let (kind, value) = match &validator.item {
+                FloatValidator::Greater(ValueOrExpr::Value(value)) => ("greater", value),
+                FloatValidator::GreaterOrEqual(ValueOrExpr::Value(value)) => {
+                    ("greater_or_equal", value)
+                }
+                FloatValidator::Less(ValueOrExpr::Value(value)) => ("less", value),
+                FloatValidator::LessOrEqual(ValueOrExpr::Value(value)) => ("less_or_equal", value),
+                _ => return None,
+            };
+            // `Display` renders the shortest decimal, which is parsed back into the same value,
+            // so it is the literal as it was written in most cases.
+            let literal = value.to_string();
+            if is_exact_decimal(&literal, mantissa_bits)? {
+                return None;
+            }
+            let tp = T::float_inner_type();
+            let rounded = exact_decimal(value);
+            let message = format!("`{kind} = {literal}` cannot be represented exactly by `{tp}`, so the bound is {rounded}.\nValues close to the bound may be accepted or rejected unexpectedly.");
+            Some(Warning {
+                span: validator.span(),
+                message,
+            })
+        })
+        .collect()
+}
+
+/// Whether the decimal number (e.g. `-12.25`) is exactly representable by a binary float with
+/// the given number of mantissa bits. Returns `None` if the number is too long to tell.
+fn is_exact_decimal(decimal: &str, mantissa_bits: u32) -> Option<bool> {
+    let decimal = decimal.trim_start_matches('-');
+    let (int_part, frac_part) = decimal.split_once('.').unwrap_or((decimal, ""));
+    let digits = format!("{int_part}{frac_part}");
+    let digits = digits.trim_start_matches('0');
+    if digits.is_empty() {
+        return Some(true);
+    }
+
+    // The number is `mantissa * 10^exponent`
+    let trimmed = digits.trim_end_matches('0');
+    let mut exponent = (digits.len() - trimmed.len()) as i64 - frac_part.len() as i64;
+    let mut mantissa: u128 = trimmed.parse().ok()?;
+
+    // `mantissa / 10^k` is a binary fraction only if 5^k divides the mantissa
+    while exponent < 0 {
+        if !mantissa.is_multiple_of(5) {
+            return Some(false);
+        }
+        mantissa /= 5;
+        exponent += 1;
+    }
+    // `mantissa * 10^k` has 5^k within the odd part
+    for _ in 0..exponent {
+        match mantissa.checked_mul(5) {
+            Some(m) => mantissa = m,
+            None => return Some(false),
+        }
+    }
+    let odd_part = mantissa >> mantissa.trailing_zeros();
+    Some(u128::BITS - odd_part.leading_zeros() <= mantissa_bits)
+}
+
+/// All the digits of the float value, e.g. 0.1000000000000000055511151231257827021181583404541015625
+/// for `0.1_f64`. Very long fractions are cut.
+fn exact_decimal<T: Display>(value: &T) -> String {
+    const MAX_FRACTION_DIGITS: usize = 60;
+
+    // Every f64 value has at most 1074 digits after the decimal point
+    let full = format!("{value:.1100}");
+    let full = full.trim_end_matches('0');
+    let (int_part, frac_part) = full.split_once('.').unwrap_or((full, ""));
+    if frac_part.len() > MAX_FRACTION_DIGITS {
+        format!("{int_part}.{}…", &frac_part[..MAX_FRACTION_DIGITS])
+    } else if frac_part.is_empty() {
+        int_part.to_string()
+    } else {
+        format!("{int_part}.{frac_part}")
+    }
+}
+
 fn has_validation_against_nan<T>(guard: &FloatGuard<T>) -> bool {
     match guard {
         FloatGuard::WithoutValidation { .. } => false,
-        FloatGuard::WithValidation { ref validators, .. } => validators
-            .iter()
-            .any(|v| v.kind() == FloatValidatorKind::Finite),
+        FloatGuard::WithValidation { ref validators, .. } => validators.iter().any(|v| {
+            matches!(
+                v.kind(),
+                FloatValidatorKind::Finite | FloatValidatorKind::NotNan
+            )
+        }),
     }
 }
 
@@ -89,6 +281,15 @@ impl ValidationInfo {
             has_nan_validation,
         }
     }
+
+    /// What to add to the guard, so it rules out NaN.
+    fn nan_validation_hint(&self) -> &'static str {
+        if self.has_validation {
+            "Add `finite` or `not_nan` to the validators, e.g. `validate(finite, ...)`."
+        } else {
+            "Consider adding:\n    validate(finite)"
+        }
+    }
 }
 
 pub fn validate_float_derive_traits<T>(
@@ -126,17 +327,17 @@ pub fn validate_float_derive_traits<T>(
     //
     if traits.contains(&FloatDeriveTrait::Eq) && !traits.contains(&FloatDeriveTrait::PartialEq) {
         let span = get_span_for(DeriveTrait::Eq);
-        let msg = "Trait Eq requires PartialEq.\nEvery expert was once a beginner.";
+        let msg = "Trait Eq requires PartialEq. Add `PartialEq` to `derive(...)`.\nEvery expert was once a beginner.";
         return Err(syn::Error::new(span, msg));
     }
     if traits.contains(&FloatDeriveTrait::Ord) {
         if !traits.contains(&FloatDeriveTrait::PartialOrd) {
             let span = get_span_for(DeriveTrait::Ord);
-            let msg = "Trait Ord requires PartialOrd.\nÜbung macht den Meister.";
+            let msg = "Trait Ord requires PartialOrd. Add `PartialOrd` to `derive(...)`.\nÜbung macht den Meister.";
             return Err(syn::Error::new(span, msg));
         } else if !traits.contains(&FloatDeriveTrait::Eq) {
             let span = get_span_for(DeriveTrait::Ord);
-            let msg = "Trait Ord requires Eq.\nFestina lente.";
+            let msg = "Trait Ord requires Eq. Add `Eq` to `derive(...)`.\nFestina lente.";
             return Err(syn::Error::new(span, msg));
         }
     }
@@ -160,7 +361,10 @@ fn to_float_derive_trait(
             if validation.has_nan_validation {
                 Ok(FloatDeriveTrait::Eq)
             } else {
-                let msg = "To derive Eq trait on float-based type there must be validation that proves that inner value is not NaN.\nConsider adding:\n    validate(finite)";
+                let msg = format!(
+                    "To derive Eq trait on float-based type there must be validation that proves that inner value is not NaN.\n{}",
+                    validation.nan_validation_hint()
+                );
                 Err(syn::Error::new(span, msg))
             }
         }
@@ -169,24 +373,34 @@ fn to_float_derive_trait(
             if validation.has_nan_validation {
                 Ok(FloatDeriveTrait::Ord)
             } else {
-                let msg = "To derive Ord trait on float-based type there must be validation that proves that inner value is not NaN.\nConsider adding:\n    validate(finite)";
+                let msg = format!(
+                    "To derive Ord trait on float-based type there must be validation that proves that inner value is not NaN.\n{}",
+                    validation.nan_validation_hint()
+                );
                 Err(syn::Error::new(span, msg))
             }
         }
         DeriveTrait::FromStr => Ok(FloatDeriveTrait::FromStr),
         DeriveTrait::AsRef => Ok(FloatDeriveTrait::AsRef),
         DeriveTrait::Deref => Ok(FloatDeriveTrait::Deref),
-        DeriveTrait::Hash => Err(syn::Error::new(
-            span,
-            "#[nutype] cannot derive `Hash` trait for float types.",
-        )),
+        DeriveTrait::Hash => {
+            if validation.has_nan_validation {
+                Ok(FloatDeriveTrait::Hash)
+            } else {
+                let msg = format!(
+                    "To derive Hash trait on float-based type there must be validation that proves that inner value is not NaN.\n{}",
+                    validation.nan_validation_hint()
+                );
+                Err(syn::Error::new(span, msg))
+            }
+        }
         DeriveTrait::Borrow => Ok(FloatDeriveTrait::Borrow),
         DeriveTrait::Copy => Ok(FloatDeriveTrait::Copy),
         DeriveTrait::From => {
             if validation.has_validation {
                 Err(syn::Error::new(
                     span,
-                    "#[nutype] cannot derive `From` trait, because there is validation defined. Use `TryFrom` instead.",
+                    "#[nutype] cannot derive `From` trait, because there is validation defined.\nReplace `From` with `TryFrom` in `derive(...)`, or remove the validators.\nTo build values, which are known to be valid, without the checks, consider `new_unchecked` instead.",
                 ))
             } else {
                 Ok(FloatDeriveTrait::From)
@@ -197,5 +411,36 @@ fn to_float_derive_trait(
         DeriveTrait::SerdeDeserialize => Ok(FloatDeriveTrait::SerdeDeserialize),
         DeriveTrait::SchemarsJsonSchema => Ok(FloatDeriveTrait::SchemarsJsonSchema),
         DeriveTrait::ArbitraryArbitrary => Ok(FloatDeriveTrait::ArbitraryArbitrary),
+        DeriveTrait::JuniperGraphQLScalar => Ok(FloatDeriveTrait::JuniperGraphQLScalar),
+        DeriveTrait::RocketFromParam => Ok(FloatDeriveTrait::RocketFromParam),
+        DeriveTrait::RocketFromFormField => Ok(FloatDeriveTrait::RocketFromFormField),
+        DeriveTrait::ActixFromRequest => Ok(FloatDeriveTrait::ActixFromRequest),
+        DeriveTrait::ClapValueParserFactory => Ok(FloatDeriveTrait::ClapValueParserFactory),
+        DeriveTrait::RandDistribution => Ok(FloatDeriveTrait::RandDistribution),
+        DeriveTrait::NumTraitsZero => Ok(FloatDeriveTrait::NumTraitsZero),
+        DeriveTrait::NumTraitsOne => Ok(FloatDeriveTrait::NumTraitsOne),
+        DeriveTrait::NumTraitsBounded => Ok(FloatDeriveTrait::NumTraitsBounded),
+        DeriveTrait::NumTraitsToPrimitive => Ok(FloatDeriveTrait::NumTraitsToPrimitive),
+        DeriveTrait::NumTraitsFromPrimitive => Ok(FloatDeriveTrait::NumTraitsFromPrimitive),
+        DeriveTrait::Add => Ok(FloatDeriveTrait::Add),
+        DeriveTrait::Sub => Ok(FloatDeriveTrait::Sub),
+        DeriveTrait::Mul => Ok(FloatDeriveTrait::Mul),
+        DeriveTrait::Div => Ok(FloatDeriveTrait::Div),
+        DeriveTrait::AddAssign => Ok(FloatDeriveTrait::AddAssign),
+        DeriveTrait::SubAssign => Ok(FloatDeriveTrait::SubAssign),
+        DeriveTrait::MulAssign => Ok(FloatDeriveTrait::MulAssign),
+        DeriveTrait::DivAssign => Ok(FloatDeriveTrait::DivAssign),
+        DeriveTrait::Sum => Ok(FloatDeriveTrait::Sum),
+        DeriveTrait::Product => Ok(FloatDeriveTrait::Product),
+        DeriveTrait::ZeroizeZeroize => {
+            let msg = "Deriving `Zeroize` trait is supported only for string and any types.\nRemove `Zeroize` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::ZeroizeZeroizeOnDrop => {
+            let msg = "Deriving `ZeroizeOnDrop` trait is supported only for string and any types.\nRemove `ZeroizeOnDrop` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::SerdeWithSerializeAs => Ok(FloatDeriveTrait::SerdeWithSerializeAs),
+        DeriveTrait::SerdeWithDeserializeAs => Ok(FloatDeriveTrait::SerdeWithDeserializeAs),
     }
 }
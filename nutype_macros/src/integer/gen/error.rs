@@ -1,85 +1,139 @@
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 
-use super::super::models::IntegerValidator;
+use super::super::models::{IntegerInnerType, IntegerValidator};
 use crate::common::{
-    gen::error::{gen_error_type_name, gen_impl_error_trait},
-    models::{ErrorTypeName, TypeName},
+    gen::error::{
+        gen_custom_message_arm, gen_error_derive, gen_impl_axum_into_response,
+        gen_impl_display_trait_override, gen_impl_error_code, gen_impl_error_status_code,
+        gen_impl_error_trait, gen_impl_miette_diagnostic, gen_impl_violated_constraint,
+        gen_numeric_range_help, gen_overflow_arm, gen_overflow_variant, gen_variant_fields,
+        gen_variant_pattern, RangeBound,
+    },
+    gen::extends::{gen_extends_arm, gen_extends_variant},
+    models::{ErrorAttributes, ErrorTypeName, TypeName},
 };
 
 pub fn gen_validation_error_type<T: ToTokens>(
     type_name: &TypeName,
+    inner_type: &IntegerInnerType,
+    error_type_name: &ErrorTypeName,
     validators: &[IntegerValidator<T>],
+    error: &ErrorAttributes,
 ) -> TokenStream {
-    let error_type_name = gen_error_type_name(type_name);
-    let definition = gen_definition(&error_type_name, validators);
-    let impl_display_trait = gen_impl_display_trait(type_name, &error_type_name, validators);
-    let impl_error_trait = gen_impl_error_trait(&error_type_name);
+    let definition = gen_definition(inner_type, error_type_name, validators, error);
+    let impl_display_trait = gen_impl_display_trait_override(error_type_name, error, || {
+        gen_impl_display_trait(type_name, inner_type, error_type_name, validators, error)
+    });
+    let impl_error_trait = gen_impl_error_trait(error_type_name, error);
+    let impl_violated_constraint = gen_impl_violated_constraint(error_type_name, validators, error);
+    let impl_error_code = gen_impl_error_code(type_name, error_type_name, validators, error);
+    let impl_error_status_code = gen_impl_error_status_code(error_type_name, validators, error);
+    let impl_axum_into_response = gen_impl_axum_into_response(error_type_name, error);
+    let impl_miette_diagnostic = gen_impl_miette_diagnostic(
+        error_type_name,
+        error,
+        gen_help_arms(error_type_name, validators, error),
+    );
+
+    let derive = gen_error_derive(error, quote!(Debug, Clone, PartialEq, Eq));
 
     quote! {
-        #[derive(Debug, Clone, PartialEq, Eq)]
+        #derive
         #definition
 
         #impl_display_trait
+        #impl_violated_constraint
+        #impl_error_code
+        #impl_error_status_code
         #impl_error_trait
+        #impl_axum_into_response
+        #impl_miette_diagnostic
     }
 }
 
 fn gen_definition<T>(
+    inner_type: &IntegerInnerType,
     error_type_name: &ErrorTypeName,
     validators: &[IntegerValidator<T>],
+    error: &ErrorAttributes,
 ) -> TokenStream {
     let error_variants: TokenStream = validators
         .iter()
-        .map(|validator| match validator {
-            IntegerValidator::Greater(_) => {
-                quote!(GreaterViolated,)
-            }
-            IntegerValidator::GreaterOrEqual(_) => {
-                quote!(GreaterOrEqualViolated,)
-            }
-            IntegerValidator::Less(_) => {
-                quote!(LessViolated,)
-            }
-            IntegerValidator::LessOrEqual(_) => {
-                quote!(LessOrEqualViolated,)
-            }
-            IntegerValidator::Predicate(_) => {
-                quote!(PredicateViolated,)
-            }
+        .map(|validator| {
+            let (variant, bound) = match validator {
+                IntegerValidator::Greater(_) => {
+                    (quote!(GreaterViolated), Some(quote!(min: #inner_type)))
+                }
+                IntegerValidator::GreaterOrEqual(_) => (
+                    quote!(GreaterOrEqualViolated),
+                    Some(quote!(min: #inner_type)),
+                ),
+                IntegerValidator::Less(_) => (quote!(LessViolated), Some(quote!(max: #inner_type))),
+                IntegerValidator::LessOrEqual(_) => {
+                    (quote!(LessOrEqualViolated), Some(quote!(max: #inner_type)))
+                }
+                IntegerValidator::Predicate(_) => (quote!(PredicateViolated), None),
+            };
+            let fields = gen_variant_fields(error.fields, quote!(value: #inner_type), bound);
+            quote!(#variant #fields,)
         })
         .collect();
 
+    let extends_variant = gen_extends_variant(error);
+    let overflow_variant = gen_overflow_variant(error);
+
     quote! {
         #[allow(clippy::enum_variant_names)]
         pub enum #error_type_name {
             #error_variants
+            #extends_variant
+            #overflow_variant
         }
     }
 }
 
 fn gen_impl_display_trait<T: ToTokens>(
     type_name: &TypeName,
+    inner_type: &IntegerInnerType,
     error_type_name: &ErrorTypeName,
     validators: &[IntegerValidator<T>],
+    error: &ErrorAttributes,
 ) -> TokenStream {
-    let match_arms = validators.iter().map(|validator| match validator {
-        IntegerValidator::Greater(val) => quote! {
-             #error_type_name::GreaterViolated => write!(f, "{} is too small. The value must be greater than {:#?}.", stringify!(#type_name), #val)
-        },
-        IntegerValidator::GreaterOrEqual(val) => quote! {
-             #error_type_name::GreaterOrEqualViolated => write!(f, "{} is too small. The value must be greater or equal to {:#?}.", stringify!(#type_name), #val)
-        },
-        IntegerValidator::Less(val) => quote! {
-             #error_type_name::LessViolated=> write!(f, "{} is too big. The value must be less than {:#?}.", stringify!(#type_name), #val)
-        },
-        IntegerValidator::LessOrEqual(val) => quote! {
-             #error_type_name::LessOrEqualViolated=> write!(f, "{} is too big. The value must be less or equal to {:#?}.", stringify!(#type_name), #val)
-        },
-        IntegerValidator::Predicate(_) => quote! {
-             #error_type_name::PredicateViolated => write!(f, "{} failed the predicate test.", stringify!(#type_name))
-        },
+    let match_arms = validators.iter().map(|validator| {
+        if let Some(arm) = gen_custom_message_arm(error_type_name, validator, error) {
+            return arm;
+        }
+        let pattern = gen_variant_pattern(error, validator);
+        match validator {
+            IntegerValidator::Greater(val) => quote! {
+                 #error_type_name::GreaterViolated #pattern => write!(f, "{} is too small. The value must be greater than {:#?}.", stringify!(#type_name), #val)
+            },
+            IntegerValidator::GreaterOrEqual(val) => quote! {
+                 #error_type_name::GreaterOrEqualViolated #pattern => write!(f, "{} is too small. The value must be greater or equal to {:#?}.", stringify!(#type_name), #val)
+            },
+            IntegerValidator::Less(val) => quote! {
+                 #error_type_name::LessViolated #pattern => write!(f, "{} is too big. The value must be less than {:#?}.", stringify!(#type_name), #val)
+            },
+            IntegerValidator::LessOrEqual(val) => quote! {
+                 #error_type_name::LessOrEqualViolated #pattern => write!(f, "{} is too big. The value must be less or equal to {:#?}.", stringify!(#type_name), #val)
+            },
+            IntegerValidator::Predicate(_) => quote! {
+                 #error_type_name::PredicateViolated #pattern => write!(f, "{} failed the predicate test.", stringify!(#type_name))
+            },
+        }
     });
+    let extends_arm = gen_extends_arm(
+        error_type_name,
+        error,
+        quote!(::core::fmt::Display::fmt(err, f)),
+    );
+    let overflow_arm = gen_overflow_arm(
+        error_type_name,
+        error,
+        quote!(write!(f, "{} overflowed. The result of the arithmetic operation does not fit into {} or it is a division by zero.", stringify!(#type_name), stringify!(#inner_type))),
+    );
+    let match_arms = match_arms.chain(extends_arm).chain(overflow_arm);
 
     quote! {
         impl ::core::fmt::Display for #error_type_name {
@@ -91,3 +145,66 @@ fn gen_impl_display_trait<T: ToTokens>(
         }
     }
 }
+
+fn gen_help_arms<T: ToTokens>(
+    error_type_name: &ErrorTypeName,
+    validators: &[IntegerValidator<T>],
+    error: &ErrorAttributes,
+) -> Vec<TokenStream> {
+    let mut lower = None;
+    let mut upper = None;
+    for validator in validators {
+        match validator {
+            IntegerValidator::Greater(val) => {
+                lower = Some(RangeBound {
+                    value: val.to_token_stream(),
+                    inclusive: false,
+                })
+            }
+            IntegerValidator::GreaterOrEqual(val) => {
+                lower = Some(RangeBound {
+                    value: val.to_token_stream(),
+                    inclusive: true,
+                })
+            }
+            IntegerValidator::Less(val) => {
+                upper = Some(RangeBound {
+                    value: val.to_token_stream(),
+                    inclusive: false,
+                })
+            }
+            IntegerValidator::LessOrEqual(val) => {
+                upper = Some(RangeBound {
+                    value: val.to_token_stream(),
+                    inclusive: true,
+                })
+            }
+            IntegerValidator::Predicate(_) => {}
+        }
+    }
+    let range_help = gen_numeric_range_help(lower.as_ref(), upper.as_ref());
+
+    validators
+        .iter()
+        .map(|validator| {
+            let pattern = gen_variant_pattern(error, validator);
+            match validator {
+                IntegerValidator::Greater(_) => {
+                    quote!(#error_type_name::GreaterViolated #pattern => Some(#range_help))
+                }
+                IntegerValidator::GreaterOrEqual(_) => {
+                    quote!(#error_type_name::GreaterOrEqualViolated #pattern => Some(#range_help))
+                }
+                IntegerValidator::Less(_) => {
+                    quote!(#error_type_name::LessViolated #pattern => Some(#range_help))
+                }
+                IntegerValidator::LessOrEqual(_) => {
+                    quote!(#error_type_name::LessOrEqualViolated #pattern => Some(#range_help))
+                }
+                IntegerValidator::Predicate(_) => {
+                    quote!(#error_type_name::PredicateViolated #pattern => None)
+                }
+            }
+        })
+        .collect()
+}
@@ -3,8 +3,9 @@ pub mod traits;
 
 use std::collections::HashSet;
 
-use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::Visibility;
 
 use self::{error::gen_validation_error_type, traits::gen_traits};
 use super::{
@@ -16,20 +17,27 @@ use super::{
 };
 use crate::common::{
     gen::{
-        error::gen_error_type_name,
+        conditional::gen_cfg,
+        error::gen_variant_fields,
+        factory::FactoryParam,
+        gen_new_clamped,
         tests::{
             gen_test_should_have_consistent_lower_and_upper_boundaries,
             gen_test_should_have_valid_default_value,
         },
-        traits::GeneratedTraits,
+        traits::{gen_impl_trait_try_from_lossless, GeneratedTraits},
         GenerateNewtype,
     },
-    models::{ErrorTypeName, Guard, TypeName},
+    models::{
+        ConditionalRule, ConditionalRules, ErrorAttributes, ErrorIntegrations, ErrorTypeName,
+        Guard, OpsPolicy, SerdeAttributes, TypeName, ValueOrExpr,
+    },
+    validate::find_bound_violation,
 };
 
 impl<T> GenerateNewtype for IntegerNewtype<T>
 where
-    T: IntegerType + ToTokens + PartialOrd,
+    T: IntegerType + ToTokens + PartialOrd + Clone,
 {
     type Sanitizer = IntegerSanitizer<T>;
     type Validator = IntegerValidator<T>;
@@ -64,46 +72,54 @@ where
 
     fn gen_fn_validate(
         inner_type: &Self::InnerType,
-        type_name: &TypeName,
+        error_name: &ErrorTypeName,
         validators: &[Self::Validator],
+        error: &ErrorAttributes,
     ) -> TokenStream {
-        let error_name = gen_error_type_name(type_name);
+        let gen_fields = |bound: Option<TokenStream>| {
+            gen_variant_fields(error.fields, quote!(value: val), bound)
+        };
 
         let validations: TokenStream = validators
             .iter()
             .map(|validator| match validator {
                 IntegerValidator::Less(exclusive_upper_bound) => {
+                    let fields = gen_fields(Some(quote!(max: #exclusive_upper_bound)));
                     quote!(
                         if val >= #exclusive_upper_bound {
-                            return Err(#error_name::LessViolated);
+                            return Err(#error_name::LessViolated #fields);
                         }
                     )
                 }
                 IntegerValidator::LessOrEqual(max) => {
+                    let fields = gen_fields(Some(quote!(max: #max)));
                     quote!(
                         if val > #max {
-                            return Err(#error_name::LessOrEqualViolated);
+                            return Err(#error_name::LessOrEqualViolated #fields);
                         }
                     )
                 }
                 IntegerValidator::Greater(exclusive_lower_bound) => {
+                    let fields = gen_fields(Some(quote!(min: #exclusive_lower_bound)));
                     quote!(
                         if val <= #exclusive_lower_bound {
-                            return Err(#error_name::GreaterViolated);
+                            return Err(#error_name::GreaterViolated #fields);
                         }
                     )
                 }
                 IntegerValidator::GreaterOrEqual(min) => {
+                    let fields = gen_fields(Some(quote!(min: #min)));
                     quote!(
                         if val < #min {
-                            return Err(#error_name::GreaterOrEqualViolated);
+                            return Err(#error_name::GreaterOrEqualViolated #fields);
                         }
                     )
                 }
                 IntegerValidator::Predicate(custom_is_valid_fn) => {
+                    let fields = gen_fields(None);
                     quote!(
                         if !(#custom_is_valid_fn)(&val) {
-                            return Err(#error_name::PredicateViolated);
+                            return Err(#error_name::PredicateViolated #fields);
                         }
                     )
                 }
@@ -119,11 +135,350 @@ where
         )
     }
 
+    fn well_known_constants(
+        _inner_type: &Self::InnerType,
+        validators: &[Self::Validator],
+        _error: &ErrorAttributes,
+    ) -> Vec<(&'static str, TokenStream)> {
+        // The values can be checked only against the bounds, which are known at expansion
+        let is_known_bound = |validator: &IntegerValidator<T>| {
+            matches!(
+                validator,
+                IntegerValidator::Greater(ValueOrExpr::Value(_))
+                    | IntegerValidator::GreaterOrEqual(ValueOrExpr::Value(_))
+                    | IntegerValidator::Less(ValueOrExpr::Value(_))
+                    | IntegerValidator::LessOrEqual(ValueOrExpr::Value(_))
+            )
+        };
+        if !validators.iter().all(is_known_bound) {
+            return Vec::new();
+        }
+        let min = validators
+            .iter()
+            .filter_map(|validator| match validator {
+                IntegerValidator::Greater(ValueOrExpr::Value(bound)) => bound.successor(),
+                IntegerValidator::GreaterOrEqual(ValueOrExpr::Value(bound)) => Some(bound.clone()),
+                _ => None,
+            })
+            .fold(T::MIN, |min, bound| if bound > min { bound } else { min });
+        let max = validators
+            .iter()
+            .filter_map(|validator| match validator {
+                IntegerValidator::Less(ValueOrExpr::Value(bound)) => bound.predecessor(),
+                IntegerValidator::LessOrEqual(ValueOrExpr::Value(bound)) => Some(bound.clone()),
+                _ => None,
+            })
+            .fold(T::MAX, |max, bound| if bound < max { bound } else { max });
+
+        // MIN and MAX violate the bounds only if no value can satisfy them
+        [
+            ("MIN", min, quote!(Self::MIN_RAW)),
+            ("MAX", max, quote!(Self::MAX_RAW)),
+            ("ZERO", T::ZERO, quote!(0)),
+        ]
+        .into_iter()
+        .filter(|(_, value, _)| find_bound_violation(validators, value).is_none())
+        .map(|(name, _, tokens)| (name, tokens))
+        .collect()
+    }
+
+    fn gen_raw_bounds(
+        type_name: &TypeName,
+        inner_type: &Self::InnerType,
+        validators: &[Self::Validator],
+        conditional: &[ConditionalRule<Self::Validator>],
+        error: &ErrorAttributes,
+    ) -> TokenStream {
+        // The bounds of the base type apply as well
+        let (initial_min, initial_max) = match &error.extends {
+            Some(base) => (quote!(<#base>::MIN_RAW), quote!(<#base>::MAX_RAW)),
+            None => (quote!(<#inner_type>::MIN), quote!(<#inner_type>::MAX)),
+        };
+        // The bounds of the conditional validators narrow the range under their predicates
+        let validators = validators.iter().map(|validator| (None, validator)).chain(
+            conditional
+                .iter()
+                .map(|rule| (Some(&rule.predicate), &rule.rule)),
+        );
+        let (narrow_min, narrow_max): (TokenStream, TokenStream) = validators
+            .map(|(predicate, validator)| {
+                let cfg = gen_cfg(predicate);
+                match validator {
+                    IntegerValidator::Greater(bound) => (
+                        quote!(
+                            #cfg
+                            if #bound + 1 > min {
+                                min = #bound + 1;
+                            }
+                        ),
+                        quote!(),
+                    ),
+                    IntegerValidator::GreaterOrEqual(bound) => (
+                        quote!(
+                            #cfg
+                            if #bound > min {
+                                min = #bound;
+                            }
+                        ),
+                        quote!(),
+                    ),
+                    IntegerValidator::Less(bound) => (
+                        quote!(),
+                        quote!(
+                            #cfg
+                            if #bound - 1 < max {
+                                max = #bound - 1;
+                            }
+                        ),
+                    ),
+                    IntegerValidator::LessOrEqual(bound) => (
+                        quote!(),
+                        quote!(
+                            #cfg
+                            if #bound < max {
+                                max = #bound;
+                            }
+                        ),
+                    ),
+                    IntegerValidator::Predicate(_) => (quote!(), quote!()),
+                }
+            })
+            .unzip();
+
+        quote!(
+            impl #type_name {
+                /// The smallest value of the inner type, that satisfies the bounds of the validators.
+                pub const MIN_RAW: #inner_type = {
+                    #[allow(unused_mut)]
+                    let mut min: #inner_type = #initial_min;
+                    #narrow_min
+                    min
+                };
+
+                /// The greatest value of the inner type, that satisfies the bounds of the validators.
+                pub const MAX_RAW: #inner_type = {
+                    #[allow(unused_mut)]
+                    let mut max: #inner_type = #initial_max;
+                    #narrow_max
+                    max
+                };
+            }
+        )
+    }
+
+    fn gen_widen_into(
+        type_name: &TypeName,
+        inner_type: &Self::InnerType,
+        guard: &Guard<Self::Sanitizer, Self::Validator>,
+        conditional: &ConditionalRules<Self::Sanitizer, Self::Validator>,
+        error: &ErrorAttributes,
+        constructor: &Ident,
+        constructor_vis: &Visibility,
+        targets: &[syn::Path],
+    ) -> TokenStream {
+        // The valid values form the range `MIN_RAW..=MAX_RAW` unless a sanitizer changes the value,
+        // a predicate rejects some of them or the base type of `extends = ` has its own guard
+        let is_predicate = |v: &IntegerValidator<T>| matches!(v, IntegerValidator::Predicate(_));
+        let is_range = guard.sanitizers().is_empty()
+            && conditional.sanitizers.is_empty()
+            && error.extends.is_none()
+            && guard
+                .validators()
+                .is_none_or(|validators| !validators.iter().any(is_predicate))
+            && !conditional
+                .validators
+                .iter()
+                .any(|rule| is_predicate(&rule.rule));
+        let widen = match guard {
+            Guard::WithoutValidation { .. } => quote!(Some(Self::#constructor(raw_value))),
+            Guard::WithValidation { .. } => quote!(Self::#constructor(raw_value).ok()),
+        };
+        // The helpers are constants rather than functions, so they do not show up
+        // among the associated functions suggested by the compiler
+        let helpers = quote!(
+            impl #type_name {
+                #[doc(hidden)]
+                #constructor_vis const __NUTYPE_IS_RANGE: bool = #is_range;
+
+                #[doc(hidden)]
+                #constructor_vis const __NUTYPE_WIDEN: fn(#inner_type) -> ::core::option::Option<Self> =
+                    |raw_value| #widen;
+            }
+        );
+
+        let conversions = targets.iter().map(|target| {
+            let target_name = target
+                .segments
+                .iter()
+                .map(|segment| segment.ident.to_string())
+                .collect::<Vec<_>>()
+                .join("::");
+            let not_range_msg = format!(
+                "{type_name} cannot be widened into {target_name}: {target_name} has a sanitizer, a predicate or `extends = `, so it cannot be checked at compile time"
+            );
+            let not_implied_msg = format!(
+                "{type_name} cannot be widened into {target_name}: not every valid value of {type_name} is a valid value of {target_name}"
+            );
+            quote!(
+                // The valid values of the target form a range, so as long as it contains
+                // the smallest and the greatest valid values of the source, it contains every
+                // value in between.
+                const _: () = {
+                    assert!(#target::__NUTYPE_IS_RANGE, #not_range_msg);
+                    assert!(
+                        #target::MIN_RAW <= #type_name::MIN_RAW && #type_name::MAX_RAW <= #target::MAX_RAW,
+                        #not_implied_msg
+                    );
+                };
+
+                impl ::core::convert::From<#type_name> for #target {
+                    fn from(value: #type_name) -> Self {
+                        match (#target::__NUTYPE_WIDEN)(value.into_inner()) {
+                            Some(widened) => widened,
+                            // The range of the target is asserted to contain the source above
+                            None => unreachable!(),
+                        }
+                    }
+                }
+            )
+        });
+
+        quote!(
+            #helpers
+            #(#conversions)*
+        )
+    }
+
+    fn gen_new_clamped(
+        type_name: &TypeName,
+        inner_type: &Self::InnerType,
+        sanitizers: &[Self::Sanitizer],
+        validators: &[Self::Validator],
+        constructor_vis: &Visibility,
+    ) -> TokenStream {
+        // A predicate cannot be satisfied by clamping
+        if validators
+            .iter()
+            .any(|v| matches!(v, IntegerValidator::Predicate(_)))
+        {
+            return quote!();
+        }
+
+        let mut lower_clamps = Vec::new();
+        let mut upper_clamps = Vec::new();
+        for validator in validators {
+            match validator {
+                IntegerValidator::Greater(exclusive_lower_bound) => lower_clamps.push(quote!(
+                    if value <= #exclusive_lower_bound {
+                        value = #exclusive_lower_bound + 1;
+                    }
+                )),
+                IntegerValidator::GreaterOrEqual(min) => lower_clamps.push(quote!(
+                    if value < #min {
+                        value = #min;
+                    }
+                )),
+                IntegerValidator::Less(exclusive_upper_bound) => upper_clamps.push(quote!(
+                    if value >= #exclusive_upper_bound {
+                        value = #exclusive_upper_bound - 1;
+                    }
+                )),
+                IntegerValidator::LessOrEqual(max) => upper_clamps.push(quote!(
+                    if value > #max {
+                        value = #max;
+                    }
+                )),
+                IntegerValidator::Predicate(_) => {}
+            }
+        }
+
+        gen_new_clamped(
+            type_name,
+            inner_type,
+            Self::gen_fn_sanitize(inner_type, sanitizers),
+            lower_clamps,
+            upper_clamps,
+            constructor_vis,
+        )
+    }
+
+    fn gen_factory_params(
+        inner_type: &Self::InnerType,
+        error_type_name: &ErrorTypeName,
+        validators: &[Self::Validator],
+        error: &ErrorAttributes,
+    ) -> Vec<FactoryParam> {
+        let gen_fields =
+            |bound: TokenStream| gen_variant_fields(error.fields, quote!(value: *val), Some(bound));
+
+        validators
+            .iter()
+            .filter_map(|validator| {
+                let (name, violated, variant, bound) = match validator {
+                    IntegerValidator::Greater(_) => (
+                        "greater",
+                        quote!(*val <= bound),
+                        quote!(GreaterViolated),
+                        quote!(min: bound),
+                    ),
+                    IntegerValidator::GreaterOrEqual(_) => (
+                        "greater_or_equal",
+                        quote!(*val < bound),
+                        quote!(GreaterOrEqualViolated),
+                        quote!(min: bound),
+                    ),
+                    IntegerValidator::Less(_) => (
+                        "less",
+                        quote!(*val >= bound),
+                        quote!(LessViolated),
+                        quote!(max: bound),
+                    ),
+                    IntegerValidator::LessOrEqual(_) => (
+                        "less_or_equal",
+                        quote!(*val > bound),
+                        quote!(LessOrEqualViolated),
+                        quote!(max: bound),
+                    ),
+                    IntegerValidator::Predicate(_) => return None,
+                };
+                let fields = gen_fields(bound);
+                Some(FactoryParam {
+                    name: format_ident!("{name}"),
+                    bound_type: inner_type.to_token_stream(),
+                    violated,
+                    error: quote!(#error_type_name::#variant #fields),
+                })
+            })
+            .collect()
+    }
+
+    fn gen_try_from_lossless(
+        type_name: &TypeName,
+        inner_type: &Self::InnerType,
+        maybe_error_type_name: Option<&ErrorTypeName>,
+    ) -> TokenStream {
+        inner_type
+            .lossless_sources()
+            .iter()
+            .map(|source_type| {
+                gen_impl_trait_try_from_lossless(
+                    type_name,
+                    inner_type,
+                    source_type,
+                    maybe_error_type_name,
+                )
+            })
+            .collect()
+    }
+
     fn gen_validation_error_type(
         type_name: &TypeName,
+        inner_type: &Self::InnerType,
+        error_type_name: &ErrorTypeName,
         validators: &[Self::Validator],
+        error: &ErrorAttributes,
     ) -> TokenStream {
-        gen_validation_error_type(type_name, validators)
+        gen_validation_error_type(type_name, inner_type, error_type_name, validators, error)
     }
 
     fn gen_traits(
@@ -133,6 +488,9 @@ where
         traits: HashSet<Self::TypedTrait>,
         maybe_default_value: Option<syn::Expr>,
         guard: &IntegerGuard<T>,
+        ops: OpsPolicy,
+        serde: &SerdeAttributes,
+        error_integrations: ErrorIntegrations,
     ) -> Result<GeneratedTraits, syn::Error> {
         gen_traits(
             type_name,
@@ -141,6 +499,9 @@ where
             traits,
             maybe_default_value,
             guard,
+            ops,
+            serde,
+            error_integrations,
         )
     }
 
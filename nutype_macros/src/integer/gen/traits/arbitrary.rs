@@ -23,10 +23,10 @@ pub fn gen_impl_trait_arbitrary<T: ToTokens>(
         let error_text =
             format!("Arbitrary generated an invalid value for {type_name}.\n\n{report_issue_msg}");
         quote!(
-            Self::new(inner_value).expect(#error_text)
+            Self::__nutype_new(inner_value).expect(#error_text)
         )
     } else {
-        quote!(Self::new(inner_value))
+        quote!(Self::__nutype_new(inner_value))
     };
 
     Ok(quote!(
@@ -46,12 +46,12 @@ pub fn gen_impl_trait_arbitrary<T: ToTokens>(
 }
 
 #[derive(Debug)]
-struct Boundary {
-    min: TokenStream,
-    max: TokenStream,
+pub(super) struct Boundary {
+    pub(super) min: TokenStream,
+    pub(super) max: TokenStream,
 }
 
-fn guard_to_boundary<T: ToTokens>(
+pub(super) fn guard_to_boundary<T: ToTokens>(
     inner_type: &IntegerInnerType,
     guard: &IntegerGuard<T>,
 ) -> Result<Boundary, syn::Error> {
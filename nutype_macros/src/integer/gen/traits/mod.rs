@@ -1,4 +1,7 @@
 mod arbitrary;
+mod num_traits;
+mod ops;
+mod rand;
 
 use std::collections::HashSet;
 
@@ -8,19 +11,27 @@ use quote::{quote, ToTokens};
 use crate::{
     common::{
         gen::traits::{
-            gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_default,
-            gen_impl_trait_deref, gen_impl_trait_display, gen_impl_trait_from,
-            gen_impl_trait_from_str, gen_impl_trait_into, gen_impl_trait_serde_deserialize,
-            gen_impl_trait_serde_serialize, gen_impl_trait_try_from, split_into_generatable_traits,
-            GeneratableTrait, GeneratableTraits, GeneratedTraits,
+            gen_impl_trait_actix_from_request, gen_impl_trait_as_ref, gen_impl_trait_borrow,
+            gen_impl_trait_clap_value_parser_factory, gen_impl_trait_default, gen_impl_trait_deref,
+            gen_impl_trait_display, gen_impl_trait_from, gen_impl_trait_from_str,
+            gen_impl_trait_into, gen_impl_trait_juniper_graphql_scalar,
+            gen_impl_trait_num_traits_from_primitive, gen_impl_trait_num_traits_one,
+            gen_impl_trait_num_traits_to_primitive, gen_impl_trait_num_traits_zero,
+            gen_impl_trait_rand_sample_uniform, gen_impl_trait_rocket_from_form_field,
+            gen_impl_trait_rocket_from_param, gen_impl_trait_serde_deserialize,
+            gen_impl_trait_serde_serialize, gen_impl_trait_serde_with_deserialize_as,
+            gen_impl_trait_serde_with_serialize_as, gen_impl_trait_try_from,
+            split_into_generatable_traits, ArithOp, GeneratableTrait, GeneratableTraits,
+            GeneratedTraits, IterOp,
         },
-        models::{ErrorTypeName, TypeName},
+        models::{ErrorIntegrations, ErrorTypeName, OpsPolicy, SerdeAttributes, TypeName},
     },
     integer::models::{IntegerDeriveTrait, IntegerGuard, IntegerInnerType},
 };
 
 type IntegerGeneratableTrait = GeneratableTrait<IntegerTransparentTrait, IntegerIrregularTrait>;
 
+#[allow(clippy::too_many_arguments)]
 pub fn gen_traits<T: ToTokens>(
     type_name: &TypeName,
     inner_type: &IntegerInnerType,
@@ -28,6 +39,9 @@ pub fn gen_traits<T: ToTokens>(
     traits: HashSet<IntegerDeriveTrait>,
     maybe_default_value: Option<syn::Expr>,
     guard: &IntegerGuard<T>,
+    ops: OpsPolicy,
+    serde: &SerdeAttributes,
+    error_integrations: ErrorIntegrations,
 ) -> Result<GeneratedTraits, syn::Error> {
     let GeneratableTraits {
         transparent_traits,
@@ -47,6 +61,9 @@ pub fn gen_traits<T: ToTokens>(
         irregular_traits,
         maybe_default_value,
         guard,
+        ops,
+        serde,
+        error_integrations,
     )?;
 
     Ok(GeneratedTraits {
@@ -121,6 +138,75 @@ impl From<IntegerDeriveTrait> for IntegerGeneratableTrait {
             IntegerDeriveTrait::ArbitraryArbitrary => {
                 IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::ArbitraryArbitrary)
             }
+            IntegerDeriveTrait::JuniperGraphQLScalar => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::JuniperGraphQLScalar)
+            }
+            IntegerDeriveTrait::RocketFromParam => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::RocketFromParam)
+            }
+            IntegerDeriveTrait::RocketFromFormField => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::RocketFromFormField)
+            }
+            IntegerDeriveTrait::ActixFromRequest => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::ActixFromRequest)
+            }
+            IntegerDeriveTrait::ClapValueParserFactory => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::ClapValueParserFactory)
+            }
+            IntegerDeriveTrait::RandDistribution => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::RandDistribution)
+            }
+            IntegerDeriveTrait::NumTraitsZero => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::NumTraitsZero)
+            }
+            IntegerDeriveTrait::NumTraitsOne => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::NumTraitsOne)
+            }
+            IntegerDeriveTrait::NumTraitsBounded => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::NumTraitsBounded)
+            }
+            IntegerDeriveTrait::NumTraitsToPrimitive => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::NumTraitsToPrimitive)
+            }
+            IntegerDeriveTrait::NumTraitsFromPrimitive => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::NumTraitsFromPrimitive)
+            }
+            IntegerDeriveTrait::Add => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Add)
+            }
+            IntegerDeriveTrait::Sub => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Sub)
+            }
+            IntegerDeriveTrait::Mul => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Mul)
+            }
+            IntegerDeriveTrait::Div => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Div)
+            }
+            IntegerDeriveTrait::AddAssign => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::AddAssign)
+            }
+            IntegerDeriveTrait::SubAssign => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::SubAssign)
+            }
+            IntegerDeriveTrait::MulAssign => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::MulAssign)
+            }
+            IntegerDeriveTrait::DivAssign => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::DivAssign)
+            }
+            IntegerDeriveTrait::Sum => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Sum)
+            }
+            IntegerDeriveTrait::Product => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Product)
+            }
+            IntegerDeriveTrait::SerdeWithSerializeAs => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::SerdeWithSerializeAs)
+            }
+            IntegerDeriveTrait::SerdeWithDeserializeAs => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::SerdeWithDeserializeAs)
+            }
         }
     }
 }
@@ -155,6 +241,29 @@ enum IntegerIrregularTrait {
     SerdeSerialize,
     SerdeDeserialize,
     ArbitraryArbitrary,
+    JuniperGraphQLScalar,
+    RocketFromParam,
+    RocketFromFormField,
+    ActixFromRequest,
+    ClapValueParserFactory,
+    RandDistribution,
+    NumTraitsZero,
+    NumTraitsOne,
+    NumTraitsBounded,
+    NumTraitsToPrimitive,
+    NumTraitsFromPrimitive,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    AddAssign,
+    SubAssign,
+    MulAssign,
+    DivAssign,
+    Sum,
+    Product,
+    SerdeWithSerializeAs,
+    SerdeWithDeserializeAs,
 }
 
 impl ToTokens for IntegerTransparentTrait {
@@ -174,6 +283,7 @@ impl ToTokens for IntegerTransparentTrait {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn gen_implemented_traits<T: ToTokens>(
     type_name: &TypeName,
     inner_type: &IntegerInnerType,
@@ -181,6 +291,9 @@ fn gen_implemented_traits<T: ToTokens>(
     impl_traits: Vec<IntegerIrregularTrait>,
     maybe_default_value: Option<syn::Expr>,
     guard: &IntegerGuard<T>,
+    ops: OpsPolicy,
+    serde: &SerdeAttributes,
+    error_integrations: ErrorIntegrations,
 ) -> Result<TokenStream, syn::Error> {
     impl_traits
         .iter()
@@ -188,7 +301,7 @@ fn gen_implemented_traits<T: ToTokens>(
             IntegerIrregularTrait::AsRef => Ok(gen_impl_trait_as_ref(type_name, inner_type)),
             IntegerIrregularTrait::Deref => Ok(gen_impl_trait_deref(type_name, inner_type)),
             IntegerIrregularTrait::FromStr => {
-                Ok(gen_impl_trait_from_str(type_name, inner_type, maybe_error_type_name.as_ref()))
+                Ok(gen_impl_trait_from_str(type_name, inner_type, maybe_error_type_name.as_ref(), error_integrations))
             }
             IntegerIrregularTrait::From => Ok(gen_impl_trait_from(type_name, inner_type)),
             IntegerIrregularTrait::Into => Ok(gen_impl_trait_into(type_name, inner_type)),
@@ -210,15 +323,167 @@ fn gen_implemented_traits<T: ToTokens>(
                     }
                 }
             }
-            IntegerIrregularTrait::SerdeSerialize => Ok(gen_impl_trait_serde_serialize(type_name)),
+            IntegerIrregularTrait::SerdeSerialize => Ok(gen_impl_trait_serde_serialize(
+                type_name, inner_type, serde,
+            )),
             IntegerIrregularTrait::SerdeDeserialize => Ok(gen_impl_trait_serde_deserialize(
                 type_name,
                 inner_type,
                 maybe_error_type_name.as_ref(),
+                &maybe_default_value,
+                serde,
             )),
             IntegerIrregularTrait::ArbitraryArbitrary => {
                 arbitrary::gen_impl_trait_arbitrary(type_name, inner_type, guard)
             }
+            IntegerIrregularTrait::JuniperGraphQLScalar => Ok(gen_impl_trait_juniper_graphql_scalar(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            )),
+            IntegerIrregularTrait::RocketFromParam => Ok(gen_impl_trait_rocket_from_param(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            )),
+            IntegerIrregularTrait::RocketFromFormField => Ok(gen_impl_trait_rocket_from_form_field(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            )),
+            IntegerIrregularTrait::ActixFromRequest => Ok(gen_impl_trait_actix_from_request(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            )),
+            IntegerIrregularTrait::ClapValueParserFactory => Ok(gen_impl_trait_clap_value_parser_factory(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            )),
+            IntegerIrregularTrait::RandDistribution => {
+                let impl_distribution = rand::gen_impl_trait_rand_distribution(type_name, inner_type, guard)?;
+                let impl_sample_uniform = gen_impl_trait_rand_sample_uniform(
+                    type_name,
+                    inner_type,
+                    maybe_error_type_name.as_ref(),
+                );
+                Ok(quote! {
+                    #impl_distribution
+                    #impl_sample_uniform
+                })
+            }
+            IntegerIrregularTrait::NumTraitsZero => Ok(gen_impl_trait_num_traits_zero(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                guard.validators().map(Vec::as_slice).unwrap_or_default(),
+            )),
+            IntegerIrregularTrait::NumTraitsOne => Ok(gen_impl_trait_num_traits_one(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                guard.validators().map(Vec::as_slice).unwrap_or_default(),
+            )),
+            IntegerIrregularTrait::NumTraitsBounded => num_traits::gen_impl_trait_num_traits_bounded(
+                type_name,
+                inner_type,
+                guard,
+            ),
+            IntegerIrregularTrait::NumTraitsToPrimitive => Ok(gen_impl_trait_num_traits_to_primitive(
+                type_name,
+            )),
+            IntegerIrregularTrait::NumTraitsFromPrimitive => Ok(gen_impl_trait_num_traits_from_primitive(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            )),
+            IntegerIrregularTrait::Add => ops::gen_impl_trait_arith_op_for_integer(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                ArithOp::Add,
+                ops,
+                guard,
+            ),
+            IntegerIrregularTrait::Sub => ops::gen_impl_trait_arith_op_for_integer(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                ArithOp::Sub,
+                ops,
+                guard,
+            ),
+            IntegerIrregularTrait::Mul => ops::gen_impl_trait_arith_op_for_integer(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                ArithOp::Mul,
+                ops,
+                guard,
+            ),
+            IntegerIrregularTrait::Div => ops::gen_impl_trait_arith_op_for_integer(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                ArithOp::Div,
+                ops,
+                guard,
+            ),
+            IntegerIrregularTrait::AddAssign => ops::gen_impl_trait_arith_assign_op_for_integer(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                ArithOp::Add,
+                ops,
+                guard,
+            ),
+            IntegerIrregularTrait::SubAssign => ops::gen_impl_trait_arith_assign_op_for_integer(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                ArithOp::Sub,
+                ops,
+                guard,
+            ),
+            IntegerIrregularTrait::MulAssign => ops::gen_impl_trait_arith_assign_op_for_integer(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                ArithOp::Mul,
+                ops,
+                guard,
+            ),
+            IntegerIrregularTrait::DivAssign => ops::gen_impl_trait_arith_assign_op_for_integer(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                ArithOp::Div,
+                ops,
+                guard,
+            ),
+            IntegerIrregularTrait::Sum => ops::gen_impl_trait_iter_op_for_integer(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                IterOp::Sum,
+                ops,
+                guard,
+            ),
+            IntegerIrregularTrait::Product => ops::gen_impl_trait_iter_op_for_integer(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                IterOp::Product,
+                ops,
+                guard,
+            ),
+            IntegerIrregularTrait::SerdeWithSerializeAs => Ok(
+                gen_impl_trait_serde_with_serialize_as(type_name, inner_type, serde),
+            ),
+            IntegerIrregularTrait::SerdeWithDeserializeAs => Ok(
+                gen_impl_trait_serde_with_deserialize_as(type_name, inner_type),
+            ),
         })
         .collect()
 }
@@ -0,0 +1,60 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+
+use crate::{
+    common::models::TypeName,
+    integer::models::{IntegerGuard, IntegerInnerType, IntegerValidator},
+};
+
+use super::arbitrary::{guard_to_boundary, Boundary};
+
+/// Implement `num_traits::Bounded` using the boundaries defined by the validators.
+pub fn gen_impl_trait_num_traits_bounded<T: ToTokens>(
+    type_name: &TypeName,
+    inner_type: &IntegerInnerType,
+    guard: &IntegerGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    if !guard.sanitizers().is_empty() {
+        let msg = "Cannot derive trait `Bounded` for a type with `with` sanitizer";
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
+    if let IntegerGuard::WithValidation { validators, .. } = guard {
+        let has_predicate = validators
+            .iter()
+            .any(|v| matches!(v, IntegerValidator::Predicate(_)));
+        if has_predicate {
+            let msg = "Cannot derive trait `Bounded` for a type with `predicate` validator";
+            return Err(syn::Error::new(Span::call_site(), msg));
+        }
+    }
+
+    let Boundary { min, max } = guard_to_boundary(inner_type, guard)?;
+    let min_error = format!("The lower boundary is not a valid value of {type_name}");
+    let max_error = format!("The upper boundary is not a valid value of {type_name}");
+
+    let (min_value, max_value) = if guard.has_validation() {
+        (
+            quote!(#type_name::__nutype_new(min).expect(#min_error)),
+            quote!(#type_name::__nutype_new(max).expect(#max_error)),
+        )
+    } else {
+        (
+            quote!(#type_name::__nutype_new(min)),
+            quote!(#type_name::__nutype_new(max)),
+        )
+    };
+
+    Ok(quote! {
+        impl ::num_traits::Bounded for #type_name {
+            fn min_value() -> Self {
+                let min: #inner_type = #min;
+                #min_value
+            }
+
+            fn max_value() -> Self {
+                let max: #inner_type = #max;
+                #max_value
+            }
+        }
+    })
+}
@@ -0,0 +1,141 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+
+use crate::{
+    common::{
+        gen::traits::{
+            gen_impl_trait_arith_assign_op, gen_impl_trait_arith_op, gen_impl_trait_iter_op,
+            ArithOp, IterOp,
+        },
+        models::{ErrorTypeName, OpsPolicy, TypeName},
+    },
+    integer::models::{IntegerGuard, IntegerInnerType},
+};
+
+use super::arbitrary::{guard_to_boundary, Boundary};
+
+/// Implement an arithmetic operator trait for an integer newtype.
+/// Overflow of the inner type is handled according to the ops policy:
+/// * `panic` panics on overflow (like `checked_*().expect()`)
+/// * `result` returns `Overflow` error on overflow and on division by zero
+/// * `saturate` saturates at the boundaries of the inner type and then clamps the value into the
+///   range defined by the validators.
+pub fn gen_impl_trait_arith_op_for_integer<T: ToTokens>(
+    type_name: &TypeName,
+    inner_type: &IntegerInnerType,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    op: ArithOp,
+    ops: OpsPolicy,
+    guard: &IntegerGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    let compute_value = gen_compute_value(type_name, inner_type, op, ops, guard)?;
+    Ok(gen_impl_trait_arith_op(
+        type_name,
+        maybe_error_type_name,
+        op,
+        ops,
+        compute_value,
+    ))
+}
+
+/// Implement an assigning arithmetic operator trait (e.g. `AddAssign`) for an integer newtype.
+/// The value is computed the same way as for the corresponding binary operator.
+pub fn gen_impl_trait_arith_assign_op_for_integer<T: ToTokens>(
+    type_name: &TypeName,
+    inner_type: &IntegerInnerType,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    op: ArithOp,
+    ops: OpsPolicy,
+    guard: &IntegerGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    let compute_value = gen_compute_value(type_name, inner_type, op, ops, guard)?;
+    Ok(gen_impl_trait_arith_assign_op(
+        type_name,
+        maybe_error_type_name,
+        op,
+        ops,
+        compute_value,
+    ))
+}
+
+/// Implement `Sum` or `Product` for an integer newtype.
+/// The elements are folded the same way as with the corresponding binary operator.
+/// With `ops = saturate` the sum or product of an empty iterator is clamped into the valid range.
+pub fn gen_impl_trait_iter_op_for_integer<T: ToTokens>(
+    type_name: &TypeName,
+    inner_type: &IntegerInnerType,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    iter_op: IterOp,
+    ops: OpsPolicy,
+    guard: &IntegerGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    let compute_value = gen_compute_value(type_name, inner_type, iter_op.arith_op(), ops, guard)?;
+    let identity = match iter_op {
+        IterOp::Sum => quote!(0),
+        IterOp::Product => quote!(1),
+    };
+    let empty_value = match ops {
+        OpsPolicy::Saturate => gen_clamp(inner_type, guard, identity)?,
+        OpsPolicy::Panic | OpsPolicy::Result => identity,
+    };
+    Ok(gen_impl_trait_iter_op(
+        type_name,
+        inner_type,
+        maybe_error_type_name,
+        iter_op,
+        ops,
+        empty_value,
+        compute_value,
+    ))
+}
+
+/// Generate an expression that computes the new inner value out of `lhs` and `rhs`.
+fn gen_compute_value<T: ToTokens>(
+    type_name: &TypeName,
+    inner_type: &IntegerInnerType,
+    op: ArithOp,
+    ops: OpsPolicy,
+    guard: &IntegerGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    let method_name = op.method_name();
+
+    let compute_value = match ops {
+        OpsPolicy::Panic => {
+            let checked_method = format_ident!("checked_{method_name}");
+            let error_text = match op {
+                ArithOp::Div => format!("{type_name}: attempt to divide by zero or with overflow"),
+                _ => format!("{type_name}: attempt to {method_name} with overflow"),
+            };
+            quote!(lhs.#checked_method(rhs).expect(#error_text))
+        }
+        OpsPolicy::Result => {
+            let checked_method = format_ident!("checked_{method_name}");
+            quote!(lhs.#checked_method(rhs).ok_or_else(#type_name::__nutype_overflow)?)
+        }
+        OpsPolicy::Saturate => {
+            let saturating_method = format_ident!("saturating_{method_name}");
+            gen_clamp(inner_type, guard, quote!(lhs.#saturating_method(rhs)))?
+        }
+    };
+
+    Ok(compute_value)
+}
+
+/// Clamp the value into the range defined by the validators.
+fn gen_clamp<T: ToTokens>(
+    inner_type: &IntegerInnerType,
+    guard: &IntegerGuard<T>,
+    value: TokenStream,
+) -> Result<TokenStream, syn::Error> {
+    match guard {
+        IntegerGuard::WithoutValidation { .. } => Ok(value),
+        // `predicate` is rejected with `ops = saturate` by `validate_saturate_with_predicate`
+        IntegerGuard::WithValidation { .. } => {
+            let Boundary { min, max } = guard_to_boundary(inner_type, guard)?;
+            Ok(quote!({
+                let value: #inner_type = #value;
+                value.clamp(#min, #max)
+            }))
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+
+use crate::{
+    common::models::TypeName,
+    integer::models::{IntegerGuard, IntegerInnerType, IntegerSanitizer, IntegerValidator},
+};
+
+use super::arbitrary::{guard_to_boundary, Boundary};
+
+/// Implement `Distribution<Type>` for `rand::distributions::Standard`.
+/// The values are sampled uniformly within the range defined by the validators.
+pub fn gen_impl_trait_rand_distribution<T: ToTokens>(
+    type_name: &TypeName,
+    inner_type: &IntegerInnerType,
+    guard: &IntegerGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    if let IntegerGuard::WithValidation {
+        sanitizers,
+        validators,
+    } = guard
+    {
+        let has_predicate = validators
+            .iter()
+            .any(|v| matches!(v, IntegerValidator::Predicate(_)));
+        if has_predicate {
+            let msg = "Cannot derive trait `Distribution` for a type with `predicate` validator";
+            return Err(syn::Error::new(Span::call_site(), msg));
+        }
+        // A sampled value could be sanitized out of the range
+        let has_with = sanitizers
+            .iter()
+            .any(|s| matches!(s, IntegerSanitizer::With(_)));
+        if has_with {
+            let msg = "Cannot derive trait `Distribution` for a type with `with` sanitizer and validations";
+            return Err(syn::Error::new(Span::call_site(), msg));
+        }
+    }
+
+    let Boundary { min, max } = guard_to_boundary(inner_type, guard)?;
+
+    let construct_value = if guard.has_validation() {
+        let error_text = format!("Distribution generated an invalid value for {type_name}");
+        quote!(#type_name::__nutype_new(inner_value).expect(#error_text))
+    } else {
+        quote!(#type_name::__nutype_new(inner_value))
+    };
+
+    Ok(quote! {
+        impl ::rand::distributions::Distribution<#type_name> for ::rand::distributions::Standard {
+            fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> #type_name {
+                let inner_value: #inner_type = rng.gen_range((#min)..=(#max));
+                #construct_value
+            }
+        }
+    })
+}
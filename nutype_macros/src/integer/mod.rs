@@ -10,7 +10,7 @@ use quote::ToTokens;
 
 use crate::common::{
     gen::GenerateNewtype,
-    models::{Attributes, GenerateParams, Guard, Newtype, SpannedDeriveTrait},
+    models::{Attributes, ConditionalRules, GenerateParams, Guard, Newtype, SpannedDeriveTrait},
 };
 
 use self::{
@@ -57,6 +57,7 @@ where
             IntegerInnerType,
             Self::TypedTrait,
             Guard<Self::Sanitizer, Self::Validator>,
+            ConditionalRules<Self::Sanitizer, Self::Validator>,
         >,
     ) -> Result<TokenStream, syn::Error> {
         IntegerNewtype::gen_nutype(params)
@@ -1,9 +1,12 @@
 use kinded::Kinded;
 use proc_macro2::TokenStream;
+use std::fmt::Display;
 
+use crate::common::gen::invariants::{describe_bound, describe_custom_function};
+use crate::common::gen::metadata::{metadata_of_bound, metadata_of_custom_function};
 use crate::common::models::{
-    impl_numeric_bound_on_vec_of, impl_numeric_bound_validator, Guard, RawGuard, SpannedItem,
-    TypeTrait, TypedCustomFunction, ValueOrExpr,
+    impl_numeric_bound_on_vec_of, impl_numeric_bound_validator, Describe, Guard, HasBound,
+    Metadata, RawGuard, SpannedItem, TypeTrait, TypedCustomFunction, ValueOrExpr,
 };
 
 // Sanitizer
@@ -32,6 +35,75 @@ pub enum IntegerValidator<T> {
 }
 
 impl_numeric_bound_validator!(IntegerValidator);
+
+impl<T> Describe for IntegerSanitizer<T> {
+    fn describe(&self) -> String {
+        match self {
+            Self::With(function) => {
+                describe_custom_function("with", function.path(), "transformed with")
+            }
+            Self::_Phantom(_) => unreachable!("IntegerSanitizer::_Phantom must not be used"),
+        }
+    }
+}
+
+impl<T> Metadata for IntegerSanitizer<T> {
+    fn metadata(&self) -> String {
+        match self {
+            Self::With(function) => metadata_of_custom_function("with", function.path()),
+            Self::_Phantom(_) => unreachable!("IntegerSanitizer::_Phantom must not be used"),
+        }
+    }
+}
+
+impl<T: Display> Describe for IntegerValidator<T> {
+    fn describe(&self) -> String {
+        match self {
+            Self::Greater(bound) => {
+                let (code, prose) = describe_bound(bound);
+                format!("`greater = {code}`: greater than {prose}")
+            }
+            Self::GreaterOrEqual(bound) => {
+                let (code, prose) = describe_bound(bound);
+                format!("`greater_or_equal = {code}`: greater than or equal to {prose}")
+            }
+            Self::Less(bound) => {
+                let (code, prose) = describe_bound(bound);
+                format!("`less = {code}`: less than {prose}")
+            }
+            Self::LessOrEqual(bound) => {
+                let (code, prose) = describe_bound(bound);
+                format!("`less_or_equal = {code}`: less than or equal to {prose}")
+            }
+            Self::Predicate(function) => {
+                describe_custom_function("predicate", function.path(), "satisfies")
+            }
+        }
+    }
+}
+
+impl<T: Display> Metadata for IntegerValidator<T> {
+    fn metadata(&self) -> String {
+        match self {
+            Self::Greater(bound) => metadata_of_bound("greater", bound),
+            Self::GreaterOrEqual(bound) => metadata_of_bound("greater_or_equal", bound),
+            Self::Less(bound) => metadata_of_bound("less", bound),
+            Self::LessOrEqual(bound) => metadata_of_bound("less_or_equal", bound),
+            Self::Predicate(function) => metadata_of_custom_function("predicate", function.path()),
+        }
+    }
+}
+
+impl<T> HasBound for IntegerValidator<T> {
+    fn has_bound(&self) -> bool {
+        match self {
+            Self::Greater(_) | Self::GreaterOrEqual(_) | Self::Less(_) | Self::LessOrEqual(_) => {
+                true
+            }
+            Self::Predicate(_) => false,
+        }
+    }
+}
 impl_numeric_bound_on_vec_of!(IntegerValidator);
 
 pub type SpannedIntegerValidator<T> = SpannedItem<IntegerValidator<T>>;
@@ -64,19 +136,59 @@ pub enum IntegerDeriveTrait {
     SerdeDeserialize,
     SchemarsJsonSchema,
     ArbitraryArbitrary,
+    JuniperGraphQLScalar,
+    RocketFromParam,
+    RocketFromFormField,
+    ActixFromRequest,
+    ClapValueParserFactory,
+    RandDistribution,
+    NumTraitsZero,
+    NumTraitsOne,
+    NumTraitsBounded,
+    NumTraitsToPrimitive,
+    NumTraitsFromPrimitive,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    AddAssign,
+    SubAssign,
+    MulAssign,
+    DivAssign,
+    Sum,
+    Product,
+    SerdeWithSerializeAs,
+    SerdeWithDeserializeAs,
 }
 
 impl TypeTrait for IntegerDeriveTrait {
     fn is_from_str(&self) -> bool {
         self == &IntegerDeriveTrait::FromStr
     }
+
+    fn is_debug(&self) -> bool {
+        self == &IntegerDeriveTrait::Debug
+    }
 }
 
 pub type IntegerRawGuard<T> = RawGuard<SpannedIntegerSanitizer<T>, SpannedIntegerValidator<T>>;
 pub type IntegerGuard<T> = Guard<IntegerSanitizer<T>, IntegerValidator<T>>;
 
-pub trait IntegerType {
+/// `Display` is used for the generated docs, e.g. the bounds of the validators.
+pub trait IntegerType: Display + Sized {
+    /// The bounds of the type are used to detect validators, which no value can satisfy.
+    const MIN: Self;
+    const MAX: Self;
+    const ZERO: Self;
+
+    #[allow(dead_code)]
     fn integer_inner_type() -> IntegerInnerType;
+
+    /// The next value, unless it overflows.
+    fn successor(&self) -> Option<Self>;
+
+    /// The previous value, unless it overflows.
+    fn predecessor(&self) -> Option<Self>;
 }
 
 macro_rules! define_integer_inner_type {
@@ -88,9 +200,21 @@ macro_rules! define_integer_inner_type {
 
         $(
             impl IntegerType for $tp {
+                const MIN: Self = <$tp>::MIN;
+                const MAX: Self = <$tp>::MAX;
+                const ZERO: Self = 0;
+
                 fn integer_inner_type() -> IntegerInnerType {
                     IntegerInnerType::$variant
                 }
+
+                fn successor(&self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(&self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
             }
         )*
 
@@ -131,3 +255,23 @@ define_integer_inner_type!(
     i128 => I128,
     isize => Isize
 );
+
+impl IntegerInnerType {
+    /// Integer types, that can be converted into the type without loss with `From`.
+    pub fn lossless_sources(&self) -> &'static [IntegerInnerType] {
+        use IntegerInnerType::*;
+        match self {
+            U8 | I8 => &[],
+            U16 => &[U8],
+            U32 => &[U8, U16],
+            U64 => &[U8, U16, U32],
+            U128 => &[U8, U16, U32, U64],
+            Usize => &[U8, U16],
+            I16 => &[U8, I8],
+            I32 => &[U8, U16, I8, I16],
+            I64 => &[U8, U16, U32, I8, I16, I32],
+            I128 => &[U8, U16, U32, U64, I8, I16, I32, I64],
+            Isize => &[U8, I8, I16],
+        }
+    }
+}
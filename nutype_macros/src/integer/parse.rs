@@ -4,13 +4,20 @@ use std::{
 };
 
 use crate::common::{
-    models::{Attributes, SpannedDeriveTrait},
+    models::{Attributes, DeriveTrait, OpsPolicy, Secret, SpannedDeriveTrait},
     parse::{
         parse_number_or_expr, parse_sanitizer_kind, parse_typed_custom_function,
         parse_validator_kind, ParseableAttributes,
     },
+    validate::{
+        detect_numeric_const_fn, detect_redundant_new_unchecked, validate_constructor_name,
+        validate_default_derive, validate_error_messages, validate_error_status_codes,
+        validate_factory, validate_local, validate_numeric_default, validate_numeric_iter_identity,
+        validate_numeric_ops_policy, validate_numeric_zero_one, validate_saturate_with_predicate,
+        validate_warnings,
+    },
 };
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use syn::{
     parse::{Parse, ParseStream},
     Token,
@@ -18,8 +25,8 @@ use syn::{
 
 use super::{
     models::{
-        IntegerGuard, IntegerRawGuard, IntegerSanitizer, IntegerSanitizerKind, IntegerValidator,
-        IntegerValidatorKind, SpannedIntegerSanitizer, SpannedIntegerValidator,
+        IntegerGuard, IntegerRawGuard, IntegerSanitizer, IntegerSanitizerKind, IntegerType,
+        IntegerValidator, IntegerValidatorKind, SpannedIntegerSanitizer, SpannedIntegerValidator,
     },
     validate::validate_number_meta,
 };
@@ -28,7 +35,7 @@ pub fn parse_attributes<T>(
     input: TokenStream,
 ) -> Result<Attributes<IntegerGuard<T>, SpannedDeriveTrait>, syn::Error>
 where
-    T: FromStr + PartialOrd + Clone,
+    T: IntegerType + FromStr + PartialOrd + Clone,
     <T as FromStr>::Err: Debug + Display,
 {
     let attrs: ParseableAttributes<SpannedIntegerSanitizer<T>, SpannedIntegerValidator<T>> =
@@ -40,17 +47,123 @@ where
         new_unchecked,
         default,
         derive_traits,
+        ops,
+        secret,
+        const_fn,
+        constructor,
+        constructor_vis,
+        factory,
+        try_from_lossless,
+        exact_bounds,
+        delegate,
+        accessor,
+        widen_into,
+        derive_unsafe,
+        repr,
+        local,
+        docs,
+        omit,
+        methods,
+        metadata,
+        debug_expansion,
+        strict,
+        serde,
+        mut error,
     } = attrs;
+    if exact_bounds {
+        let msg = "`exact_bounds` is supported only for float types.";
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
+    if let Some(method) = delegate.first() {
+        let msg = "`delegate` is supported only for String types.";
+        return Err(syn::Error::new(method.span(), msg));
+    }
+    if methods.leak {
+        let msg = "`methods(leak)` is supported only for String types.";
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
+    validate_constructor_name(&constructor, &derive_traits)?;
+    validate_default_derive(&derive_traits, &default)?;
+    if let (Some(target), Secret::On { .. }) = (widen_into.first(), secret) {
+        let msg = "`widen_into` cannot be used with `secret`, because secret types do not expose the inner value.";
+        return Err(syn::Error::new_spanned(target, msg));
+    }
+    validate_error_messages(&error.messages, &validators)?;
+    validate_error_status_codes(&error.status_codes, &validators)?;
+    validate_factory(
+        factory,
+        &validators,
+        |v| {
+            matches!(
+                v,
+                IntegerValidator::Greater(_)
+                    | IntegerValidator::GreaterOrEqual(_)
+                    | IntegerValidator::Less(_)
+                    | IntegerValidator::LessOrEqual(_)
+            )
+        },
+        "`greater`, `greater_or_equal`, `less`, `less_or_equal`",
+    )?;
+    validate_saturate_with_predicate(&ops, &validators, |v| {
+        matches!(v, IntegerValidator::Predicate(_))
+    })?;
     let raw_guard = IntegerRawGuard {
         sanitizers,
         validators,
     };
     let guard = validate_number_meta(raw_guard)?;
+    let warnings = validate_warnings(
+        detect_redundant_new_unchecked(&new_unchecked, &guard)
+            .into_iter()
+            .collect(),
+        strict,
+    )?;
+    validate_numeric_default(&guard, &default)?;
+    let ops = validate_numeric_ops_policy(ops, &guard, &derive_traits)?;
+    validate_numeric_iter_identity(&guard, ops, &derive_traits)?;
+    validate_numeric_zero_one(&guard, &derive_traits, |v| {
+        matches!(v, IntegerValidator::Predicate(_))
+    })?;
+    let local = validate_local(local, &guard, &error)?;
+    let const_fn = detect_numeric_const_fn(const_fn, &guard, &error);
+    error.overflow = ops == OpsPolicy::Result
+        && derive_traits.iter().any(|t| {
+            matches!(
+                t.item,
+                DeriveTrait::Add
+                    | DeriveTrait::Sub
+                    | DeriveTrait::Mul
+                    | DeriveTrait::Div
+                    | DeriveTrait::Sum
+                    | DeriveTrait::Product
+            )
+        });
     Ok(Attributes {
         new_unchecked,
         guard,
         default,
         derive_traits,
+        ops,
+        secret,
+        const_fn,
+        constructor,
+        constructor_vis,
+        factory,
+        try_from_lossless,
+        delegate,
+        accessor,
+        widen_into,
+        derive_unsafe,
+        repr,
+        local,
+        docs,
+        omit,
+        methods,
+        metadata,
+        debug_expansion,
+        warnings,
+        serde,
+        error,
     })
 }
 
@@ -3,18 +3,18 @@ use std::collections::HashSet;
 use proc_macro2::Span;
 
 use crate::common::{
-    models::{DeriveTrait, SpannedDeriveTrait},
+    models::{DeriveTrait, SpannedDeriveTrait, ValueOrExpr},
     validate::{validate_duplicates, validate_numeric_bounds},
 };
 
 use super::models::{
-    IntegerDeriveTrait, IntegerGuard, IntegerRawGuard, IntegerSanitizer, IntegerValidator,
-    SpannedIntegerSanitizer, SpannedIntegerValidator,
+    IntegerDeriveTrait, IntegerGuard, IntegerRawGuard, IntegerSanitizer, IntegerType,
+    IntegerValidator, SpannedIntegerSanitizer, SpannedIntegerValidator,
 };
 
 pub fn validate_number_meta<T>(raw_meta: IntegerRawGuard<T>) -> Result<IntegerGuard<T>, syn::Error>
 where
-    T: PartialOrd + Clone,
+    T: IntegerType + PartialOrd + Clone,
 {
     let IntegerRawGuard {
         sanitizers,
@@ -38,7 +38,7 @@ fn validate_validators<T>(
     validators: Vec<SpannedIntegerValidator<T>>,
 ) -> Result<Vec<IntegerValidator<T>>, syn::Error>
 where
-    T: PartialOrd + Clone,
+    T: IntegerType + PartialOrd + Clone,
 {
     validate_duplicates(&validators, |kind| {
         format!(
@@ -48,6 +48,21 @@ where
 
     validate_numeric_bounds(&validators)?;
 
+    // greater = MAX or less = MIN
+    //
+    for validator in &validators {
+        let msg = match &validator.item {
+            IntegerValidator::Greater(ValueOrExpr::Value(value)) if *value >= T::MAX => {
+                format!("`greater = {value}` cannot be satisfied by any value, because {value} is the maximum of the type.")
+            }
+            IntegerValidator::Less(ValueOrExpr::Value(value)) if *value <= T::MIN => {
+                format!("`less = {value}` cannot be satisfied by any value, because {value} is the minimum of the type.")
+            }
+            _ => continue,
+        };
+        return Err(syn::Error::new(validator.span(), msg));
+    }
+
     let validators: Vec<_> = validators.into_iter().map(|v| v.item).collect();
     Ok(validators)
 }
@@ -106,16 +121,47 @@ fn to_integer_derive_trait(
         DeriveTrait::SerdeDeserialize => Ok(IntegerDeriveTrait::SerdeDeserialize),
         DeriveTrait::SchemarsJsonSchema => Ok(IntegerDeriveTrait::SchemarsJsonSchema),
         DeriveTrait::ArbitraryArbitrary => Ok(IntegerDeriveTrait::ArbitraryArbitrary),
+        DeriveTrait::JuniperGraphQLScalar => Ok(IntegerDeriveTrait::JuniperGraphQLScalar),
+        DeriveTrait::RocketFromParam => Ok(IntegerDeriveTrait::RocketFromParam),
+        DeriveTrait::RocketFromFormField => Ok(IntegerDeriveTrait::RocketFromFormField),
+        DeriveTrait::ActixFromRequest => Ok(IntegerDeriveTrait::ActixFromRequest),
+        DeriveTrait::ClapValueParserFactory => Ok(IntegerDeriveTrait::ClapValueParserFactory),
+        DeriveTrait::RandDistribution => Ok(IntegerDeriveTrait::RandDistribution),
         DeriveTrait::TryFrom => Ok(IntegerDeriveTrait::TryFrom),
         DeriveTrait::From => {
             if has_validation {
                 Err(syn::Error::new(
                     span,
-                    "#[nutype] cannot derive `From` trait, because there is validation defined. Use `TryFrom` instead.",
+                    "#[nutype] cannot derive `From` trait, because there is validation defined.\nReplace `From` with `TryFrom` in `derive(...)`, or remove the validators.\nTo build values, which are known to be valid, without the checks, consider `new_unchecked` instead.",
                 ))
             } else {
                 Ok(IntegerDeriveTrait::From)
             }
         }
+        DeriveTrait::NumTraitsZero => Ok(IntegerDeriveTrait::NumTraitsZero),
+        DeriveTrait::NumTraitsOne => Ok(IntegerDeriveTrait::NumTraitsOne),
+        DeriveTrait::NumTraitsBounded => Ok(IntegerDeriveTrait::NumTraitsBounded),
+        DeriveTrait::NumTraitsToPrimitive => Ok(IntegerDeriveTrait::NumTraitsToPrimitive),
+        DeriveTrait::NumTraitsFromPrimitive => Ok(IntegerDeriveTrait::NumTraitsFromPrimitive),
+        DeriveTrait::Add => Ok(IntegerDeriveTrait::Add),
+        DeriveTrait::Sub => Ok(IntegerDeriveTrait::Sub),
+        DeriveTrait::Mul => Ok(IntegerDeriveTrait::Mul),
+        DeriveTrait::Div => Ok(IntegerDeriveTrait::Div),
+        DeriveTrait::AddAssign => Ok(IntegerDeriveTrait::AddAssign),
+        DeriveTrait::SubAssign => Ok(IntegerDeriveTrait::SubAssign),
+        DeriveTrait::MulAssign => Ok(IntegerDeriveTrait::MulAssign),
+        DeriveTrait::DivAssign => Ok(IntegerDeriveTrait::DivAssign),
+        DeriveTrait::Sum => Ok(IntegerDeriveTrait::Sum),
+        DeriveTrait::Product => Ok(IntegerDeriveTrait::Product),
+        DeriveTrait::ZeroizeZeroize => {
+            let msg = "Deriving `Zeroize` trait is supported only for string and any types.\nRemove `Zeroize` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::ZeroizeZeroizeOnDrop => {
+            let msg = "Deriving `ZeroizeOnDrop` trait is supported only for string and any types.\nRemove `ZeroizeOnDrop` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::SerdeWithSerializeAs => Ok(IntegerDeriveTrait::SerdeWithSerializeAs),
+        DeriveTrait::SerdeWithDeserializeAs => Ok(IntegerDeriveTrait::SerdeWithDeserializeAs),
     }
 }
@@ -13,12 +13,20 @@ mod utils;
 
 use any::AnyNewtype;
 use common::{
-    models::{InnerType, Newtype, TypedMeta},
-    parse::meta::parse_meta,
+    gen::{
+        gen_env_tracking,
+        guard_definition::{gen_guard_application, gen_guard_definition},
+    },
+    models::{GuardDefinition, InnerType, Newtype, TypedMeta},
+    parse::{
+        env_variable_names, guard_definition::split_guard_attribute, meta::parse_meta,
+        preset::expand_preset,
+    },
 };
 use float::{models::FloatInnerType, FloatNewtype};
 use integer::{models::IntegerInnerType, IntegerNewtype};
 use proc_macro2::TokenStream;
+use quote::quote;
 use string::StringNewtype;
 
 /// Defines sanitizers and validators on a newtype.
@@ -34,19 +42,41 @@ pub fn nutype(
         .into()
 }
 
+/// Defines reusable sanitizers and validators, which can be applied to multiple types
+/// with `#[nutype(guard = Name)]`.
+/// See the documentation for [nutype](https://docs.rs/nutype) crate for more information.
+#[proc_macro]
+pub fn nutype_guard(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    syn::parse::<GuardDefinition>(input)
+        .map(gen_guard_definition)
+        .unwrap_or_else(|e| syn::Error::to_compile_error(&e))
+        .into()
+}
+
 fn expand_nutype(
     attrs: TokenStream,
     type_definition: TokenStream,
 ) -> Result<TokenStream, syn::Error> {
+    let (maybe_guard, attrs) = split_guard_attribute(attrs)?;
+    if let Some(guard) = maybe_guard {
+        return Ok(gen_guard_application(&guard, attrs, type_definition));
+    }
+
     let meta = parse_meta(type_definition)?;
+    let attrs = expand_preset(attrs, &meta.inner_type)?;
+    let env_tracking = gen_env_tracking(&env_variable_names(&attrs));
     let (typed_meta, inner_type) = meta.into_typed_meta(attrs);
 
-    match inner_type {
+    let output = match inner_type {
         InnerType::String(tp) => StringNewtype::expand(typed_meta, tp),
         InnerType::Integer(inner) => expand_nutype_integer(typed_meta, inner),
         InnerType::Float(inner) => expand_nutype_float(typed_meta, inner),
         InnerType::Any(any_inner_type) => AnyNewtype::expand(typed_meta, any_inner_type),
-    }
+    }?;
+    Ok(quote! {
+        #output
+        #env_tracking
+    })
 }
 
 fn expand_nutype_integer(
@@ -3,56 +3,90 @@ use quote::quote;
 
 use crate::{
     common::{
-        gen::error::{gen_error_type_name, gen_impl_error_trait},
-        models::{ErrorTypeName, TypeName},
+        gen::error::{
+            gen_custom_message_arm, gen_error_derive, gen_impl_axum_into_response,
+            gen_impl_display_trait_override, gen_impl_error_code, gen_impl_error_status_code,
+            gen_impl_error_trait, gen_impl_miette_diagnostic, gen_impl_violated_constraint,
+            gen_variant_fields, gen_variant_pattern,
+        },
+        gen::extends::{gen_extends_arm, gen_extends_variant},
+        models::{ErrorAttributes, ErrorTypeName, TypeName},
     },
-    string::models::StringValidator,
+    string::models::{RegexDef, StringInnerType, StringValidator},
 };
 
 pub fn gen_validation_error_type(
     type_name: &TypeName,
+    inner_type: &StringInnerType,
+    error_type_name: &ErrorTypeName,
     validators: &[StringValidator],
+    error: &ErrorAttributes,
 ) -> TokenStream {
-    let error_type_name = gen_error_type_name(type_name);
-    let definition = gen_definition(&error_type_name, validators);
-    let impl_display_trait = gen_impl_display_trait(type_name, &error_type_name, validators);
-    let impl_error_trait = gen_impl_error_trait(&error_type_name);
+    let definition = gen_definition(inner_type, error_type_name, validators, error);
+    let impl_display_trait = gen_impl_display_trait_override(error_type_name, error, || {
+        gen_impl_display_trait(type_name, error_type_name, validators, error)
+    });
+    let impl_error_trait = gen_impl_error_trait(error_type_name, error);
+    let impl_violated_constraint = gen_impl_violated_constraint(error_type_name, validators, error);
+    let impl_error_code = gen_impl_error_code(type_name, error_type_name, validators, error);
+    let impl_error_status_code = gen_impl_error_status_code(error_type_name, validators, error);
+    let impl_axum_into_response = gen_impl_axum_into_response(error_type_name, error);
+    let impl_miette_diagnostic = gen_impl_miette_diagnostic(
+        error_type_name,
+        error,
+        gen_help_arms(error_type_name, validators, error),
+    );
+
+    let derive = gen_error_derive(error, quote!(Debug, Clone, PartialEq, Eq));
 
     quote! {
-        #[derive(Debug, Clone, PartialEq, Eq)]
+        #derive
         #definition
 
         #impl_display_trait
+        #impl_violated_constraint
+        #impl_error_code
+        #impl_error_status_code
         #impl_error_trait
+        #impl_axum_into_response
+        #impl_miette_diagnostic
     }
 }
 
-fn gen_definition(error_type_name: &ErrorTypeName, validators: &[StringValidator]) -> TokenStream {
+fn gen_definition(
+    inner_type: &StringInnerType,
+    error_type_name: &ErrorTypeName,
+    validators: &[StringValidator],
+    error: &ErrorAttributes,
+) -> TokenStream {
     let error_variants: TokenStream = validators
         .iter()
-        .map(|validator| match validator {
-            StringValidator::LenCharMax(_len) => {
-                quote!(LenCharMaxViolated,)
-            }
-            StringValidator::LenCharMin(_len) => {
-                quote!(LenCharMinViolated,)
-            }
-            StringValidator::NotEmpty => {
-                quote!(NotEmptyViolated,)
-            }
-            StringValidator::Predicate(_) => {
-                quote!(PredicateViolated,)
-            }
-            StringValidator::Regex(_) => {
-                quote!(RegexViolated,)
-            }
+        .map(|validator| {
+            let (variant, bound) = match validator {
+                StringValidator::LenCharMax(_) => {
+                    (quote!(LenCharMaxViolated), Some(quote!(max: usize)))
+                }
+                StringValidator::LenCharMin(_) => {
+                    (quote!(LenCharMinViolated), Some(quote!(min: usize)))
+                }
+                StringValidator::NotEmpty => (quote!(NotEmptyViolated), None),
+                StringValidator::Predicate(_) => (quote!(PredicateViolated), None),
+                StringValidator::Regex(_) => {
+                    (quote!(RegexViolated), Some(quote!(pattern: &'static str)))
+                }
+            };
+            let fields = gen_variant_fields(error.fields, quote!(value: #inner_type), bound);
+            quote!(#variant #fields,)
         })
         .collect();
 
+    let extends_variant = gen_extends_variant(error);
+
     quote! {
         #[allow(clippy::enum_variant_names)]
         pub enum #error_type_name {
             #error_variants
+            #extends_variant
         }
     }
 }
@@ -61,24 +95,37 @@ fn gen_impl_display_trait(
     type_name: &TypeName,
     error_type_name: &ErrorTypeName,
     validators: &[StringValidator],
+    error: &ErrorAttributes,
 ) -> TokenStream {
-    let match_arms = validators.iter().map(|validator| match validator {
-        StringValidator::LenCharMax(len_char_max) => quote! {
-             #error_type_name::LenCharMaxViolated => write!(f, "{} is too long. The value length must be less than {:#?} character(s).", stringify!(#type_name), #len_char_max)
-        },
-        StringValidator::LenCharMin(len_char_min) => quote! {
-             #error_type_name::LenCharMinViolated => write!(f, "{} is too short. The value length must be more than {:#?} character(s).", stringify!(#type_name), #len_char_min)
-        },
-        StringValidator::NotEmpty => quote! {
-             #error_type_name::NotEmptyViolated => write!(f, "{} is empty.", stringify!(#type_name))
-        },
-        StringValidator::Predicate(_) => quote! {
-             #error_type_name::PredicateViolated => write!(f, "{} failed the predicate test.", stringify!(#type_name))
-        },
-        StringValidator::Regex(_) => quote! {
-             #error_type_name::RegexViolated => write!(f, "{} violated the regular expression.", stringify!(#type_name))
-        },
+    let match_arms = validators.iter().map(|validator| {
+        if let Some(arm) = gen_custom_message_arm(error_type_name, validator, error) {
+            return arm;
+        }
+        let pattern = gen_variant_pattern(error, validator);
+        match validator {
+            StringValidator::LenCharMax(len_char_max) => quote! {
+                 #error_type_name::LenCharMaxViolated #pattern => write!(f, "{} is too long. The value length must be less than {:#?} character(s).", stringify!(#type_name), #len_char_max)
+            },
+            StringValidator::LenCharMin(len_char_min) => quote! {
+                 #error_type_name::LenCharMinViolated #pattern => write!(f, "{} is too short. The value length must be more than {:#?} character(s).", stringify!(#type_name), #len_char_min)
+            },
+            StringValidator::NotEmpty => quote! {
+                 #error_type_name::NotEmptyViolated #pattern => write!(f, "{} is empty.", stringify!(#type_name))
+            },
+            StringValidator::Predicate(_) => quote! {
+                 #error_type_name::PredicateViolated #pattern => write!(f, "{} failed the predicate test.", stringify!(#type_name))
+            },
+            StringValidator::Regex(_) => quote! {
+                 #error_type_name::RegexViolated #pattern => write!(f, "{} violated the regular expression.", stringify!(#type_name))
+            },
+        }
     });
+    let extends_arm = gen_extends_arm(
+        error_type_name,
+        error,
+        quote!(::core::fmt::Display::fmt(err, f)),
+    );
+    let match_arms = match_arms.chain(extends_arm);
 
     quote! {
         impl ::core::fmt::Display for #error_type_name {
@@ -90,3 +137,53 @@ fn gen_impl_display_trait(
         }
     }
 }
+
+fn gen_help_arms(
+    error_type_name: &ErrorTypeName,
+    validators: &[StringValidator],
+    error: &ErrorAttributes,
+) -> Vec<TokenStream> {
+    let len_char_min = validators.iter().find_map(|validator| match validator {
+        StringValidator::LenCharMin(len_char_min) => Some(len_char_min),
+        _ => None,
+    });
+    let len_char_max = validators.iter().find_map(|validator| match validator {
+        StringValidator::LenCharMax(len_char_max) => Some(len_char_max),
+        _ => None,
+    });
+    let len_help = match (len_char_min, len_char_max) {
+        (Some(min), Some(max)) => {
+            quote!(::std::format!("expected length between {} and {} characters", #min, #max))
+        }
+        (Some(min), None) => quote!(::std::format!("expected at least {} character(s)", #min)),
+        (None, Some(max)) => quote!(::std::format!("expected at most {} character(s)", #max)),
+        (None, None) => quote!(::std::string::String::new()),
+    };
+
+    validators
+        .iter()
+        .map(|validator| {
+            let pattern = gen_variant_pattern(error, validator);
+            match validator {
+                StringValidator::LenCharMax(_) => {
+                    quote!(#error_type_name::LenCharMaxViolated #pattern => Some(#len_help))
+                }
+                StringValidator::LenCharMin(_) => {
+                    quote!(#error_type_name::LenCharMinViolated #pattern => Some(#len_help))
+                }
+                StringValidator::NotEmpty => quote! {
+                    #error_type_name::NotEmptyViolated #pattern => Some(::std::string::String::from("expected a non-empty value"))
+                },
+                StringValidator::Predicate(_) => {
+                    quote!(#error_type_name::PredicateViolated #pattern => None)
+                }
+                StringValidator::Regex(RegexDef::StringLiteral(lit)) => quote! {
+                    #error_type_name::RegexViolated #pattern => Some(::std::format!("expected a value matching `{}`", #lit))
+                },
+                StringValidator::Regex(RegexDef::Path(_)) => quote! {
+                    #error_type_name::RegexViolated #pattern => Some(::std::string::String::from("expected a value matching the regular expression"))
+                },
+            }
+        })
+        .collect()
+}
@@ -4,16 +4,21 @@ pub mod traits;
 
 use std::collections::HashSet;
 
-use proc_macro2::TokenStream;
-use quote::quote;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::Visibility;
 
 use crate::{
     common::{
         gen::{
-            error::gen_error_type_name, tests::gen_test_should_have_valid_default_value,
-            traits::GeneratedTraits, GenerateNewtype,
+            alloc::gen_required_alloc_path, error::gen_variant_fields, factory::FactoryParam,
+            tests::gen_test_should_have_valid_default_value, traits::GeneratedTraits,
+            GenerateNewtype,
+        },
+        models::{
+            ErrorAttributes, ErrorIntegrations, ErrorTypeName, Guard, OpsPolicy, SerdeAttributes,
+            TypeName,
         },
-        models::{ErrorTypeName, Guard, TypeName},
     },
     string::models::{RegexDef, StringInnerType, StringSanitizer, StringValidator},
 };
@@ -39,39 +44,40 @@ impl GenerateNewtype for StringNewtype {
     const NEW_CONVERT_INTO_INNER_TYPE: bool = true;
 
     fn gen_fn_sanitize(
-        _inner_type: &Self::InnerType,
+        inner_type: &Self::InnerType,
         sanitizers: &[Self::Sanitizer],
     ) -> TokenStream {
+        let alloc = gen_required_alloc_path();
         let transformations: TokenStream = sanitizers
             .iter()
             .map(|san| match san {
                 StringSanitizer::Trim => {
                     // TODO: consider optimizing sequences of [trim, lowercase] and [trim, uppercase] to avoid
-                    // unnecessary allocation with `to_string()`
+                    // unnecessary allocation with `to_owned()`
                     quote!(
-                        let value: String = value.trim().to_string();
+                        let value: #inner_type = #alloc::borrow::ToOwned::to_owned(value.trim());
                     )
                 }
                 StringSanitizer::Lowercase => {
                     quote!(
-                        let value: String = value.to_lowercase();
+                        let value: #inner_type = value.to_lowercase();
                     )
                 }
                 StringSanitizer::Uppercase => {
                     quote!(
-                        let value: String = value.to_uppercase();
+                        let value: #inner_type = value.to_uppercase();
                     )
                 }
                 StringSanitizer::With(typed_custom_function) => {
                     quote!(
-                        let value: String = (#typed_custom_function)(value);
+                        let value: #inner_type = (#typed_custom_function)(value);
                     )
                 }
             })
             .collect();
 
         quote!(
-            fn sanitize(value: String) -> String {
+            fn sanitize(value: #inner_type) -> #inner_type {
                 #transformations
                 value
             }
@@ -80,51 +86,63 @@ impl GenerateNewtype for StringNewtype {
 
     fn gen_fn_validate(
         _inner_type: &Self::InnerType,
-        type_name: &TypeName,
+        error_name: &ErrorTypeName,
         validators: &[Self::Validator],
+        error: &ErrorAttributes,
     ) -> TokenStream {
-        let error_name = gen_error_type_name(type_name);
-
         // Indicates that `chars_count` variable needs to be set, which is used within
         // min_len and max_len validations.
         let mut requires_chars_count = false;
 
+        let gen_fields = |bound: Option<TokenStream>| {
+            gen_variant_fields(
+                error.fields,
+                quote!(value: ::core::convert::Into::into(val)),
+                bound,
+            )
+        };
+
         let validations: TokenStream = validators
             .iter()
             .map(|validator| match validator {
                 StringValidator::LenCharMax(max_len) => {
+                    let fields = gen_fields(Some(quote!(max: #max_len)));
                     requires_chars_count = true;
                     quote!(
                         if chars_count > #max_len {
-                            return Err(#error_name::LenCharMaxViolated);
+                            return Err(#error_name::LenCharMaxViolated #fields);
                         }
                     )
                 }
                 StringValidator::LenCharMin(min_len) => {
+                    let fields = gen_fields(Some(quote!(min: #min_len)));
                     requires_chars_count = true;
                     quote!(
                         if chars_count < #min_len {
-                            return Err(#error_name::LenCharMinViolated);
+                            return Err(#error_name::LenCharMinViolated #fields);
                         }
                     )
                 }
                 StringValidator::NotEmpty => {
+                    let fields = gen_fields(None);
                     quote!(
                         if val.is_empty() {
-                            return Err(#error_name::NotEmptyViolated);
+                            return Err(#error_name::NotEmptyViolated #fields);
                         }
                     )
                 }
                 StringValidator::Predicate(typed_custom_function) => {
+                    let fields = gen_fields(None);
                     quote!(
                         if !(#typed_custom_function)(&val) {
-                            return Err(#error_name::PredicateViolated);
+                            return Err(#error_name::PredicateViolated #fields);
                         }
                     )
                 }
                 StringValidator::Regex(regex_def) => {
                     match regex_def {
                         RegexDef::StringLiteral(regex_str_lit) => {
+                            let fields = gen_fields(Some(quote!(pattern: #regex_str_lit)));
                             quote!(
                                 lazy_static::lazy_static! {
                                     // Make up a sufficiently unique regex name to ensure that it does
@@ -132,15 +150,16 @@ impl GenerateNewtype for StringNewtype {
                                     static ref __NUTYPE_REGEX__: ::regex::Regex = ::regex::Regex::new(#regex_str_lit).expect("Nutype failed to a build a regex");
                                 }
                                 if !__NUTYPE_REGEX__.is_match(&val) {
-                                    return Err(#error_name::RegexViolated);
+                                    return Err(#error_name::RegexViolated #fields);
                                 }
                             )
 
                         }
                         RegexDef::Path(regex_path) => {
+                            let fields = gen_fields(Some(quote!(pattern: #regex_path.as_str())));
                             quote!(
                                 if !#regex_path.is_match(&val) {
-                                    return Err(#error_name::RegexViolated);
+                                    return Err(#error_name::RegexViolated #fields);
                                 }
                             )
                         }
@@ -166,11 +185,163 @@ impl GenerateNewtype for StringNewtype {
         )
     }
 
+    fn gen_delegated_methods(type_name: &TypeName, methods: &[Ident]) -> TokenStream {
+        if methods.is_empty() {
+            return quote!();
+        }
+        let methods = methods
+            .iter()
+            .map(|method| match method.to_string().as_str() {
+                "len" => quote!(
+                    /// Returns the length of the string in bytes.
+                    #[allow(clippy::len_without_is_empty)]
+                    pub fn len(&self) -> usize {
+                        self.0.len()
+                    }
+                ),
+                "is_empty" => quote!(
+                    /// Returns `true` if the string has a length of zero.
+                    pub fn is_empty(&self) -> bool {
+                        self.0.is_empty()
+                    }
+                ),
+                "chars" => quote!(
+                    /// Returns an iterator over the chars of the string.
+                    pub fn chars(&self) -> ::core::str::Chars<'_> {
+                        self.0.chars()
+                    }
+                ),
+                "as_str" => quote!(
+                    /// Extracts a string slice containing the entire string.
+                    pub fn as_str(&self) -> &str {
+                        self.0.as_str()
+                    }
+                ),
+                // The names are checked by `validate_delegate()`
+                _ => unreachable!("Unexpected delegated method `{method}`"),
+            });
+
+        quote!(
+            impl #type_name {
+                #(#methods)*
+            }
+        )
+    }
+
+    fn gen_borrowed_inner_type(_inner_type: &Self::InnerType) -> TokenStream {
+        quote!(str)
+    }
+
+    fn gen_new_interned(
+        type_name: &TypeName,
+        maybe_return_error_type_name: Option<&ErrorTypeName>,
+        constructor_vis: &Visibility,
+    ) -> TokenStream {
+        if !cfg!(feature = "intern") {
+            return quote!();
+        }
+
+        let (return_type, intern) = match maybe_return_error_type_name {
+            Some(return_error_type_name) => (
+                quote!(::core::result::Result<&'static Self, #return_error_type_name>),
+                quote!(Ok(intern(Self::__nutype_new(raw_value)?))),
+            ),
+            None => (
+                quote!(&'static Self),
+                quote!(intern(Self::__nutype_new(raw_value))),
+            ),
+        };
+
+        quote!(
+            impl #type_name {
+                /// Creates a value and interns it: equal values share the same storage, which is
+                /// allocated once and lives until the end of the program.
+                #constructor_vis fn new_interned(raw_value: &str) -> #return_type {
+                    static INTERNER: ::std::sync::Mutex<
+                        ::std::collections::BTreeMap<&'static str, &'static #type_name>,
+                    > = ::std::sync::Mutex::new(::std::collections::BTreeMap::new());
+
+                    fn intern(value: #type_name) -> &'static #type_name {
+                        let mut interner = INTERNER
+                            .lock()
+                            .unwrap_or_else(::std::sync::PoisonError::into_inner);
+                        if let Some(interned) = interner.get(value.0.as_str()) {
+                            return interned;
+                        }
+                        let interned: &'static #type_name = ::std::boxed::Box::leak(::std::boxed::Box::new(value));
+                        interner.insert(interned.0.as_str(), interned);
+                        interned
+                    }
+
+                    #intern
+                }
+            }
+        )
+    }
+
+    fn gen_leak(type_name: &TypeName) -> TokenStream {
+        let alloc = gen_required_alloc_path();
+        quote!(
+            impl #type_name {
+                /// Consumes the value and leaks the inner string, returning a `&'static str`.
+                /// Useful for long-lived identifiers built once at startup.
+                pub fn leak(self) -> &'static str {
+                    #alloc::string::String::leak(self.into_inner())
+                }
+            }
+        )
+    }
+
+    fn gen_factory_params(
+        _inner_type: &Self::InnerType,
+        error_type_name: &ErrorTypeName,
+        validators: &[Self::Validator],
+        error: &ErrorAttributes,
+    ) -> Vec<FactoryParam> {
+        let gen_fields = |bound: TokenStream| {
+            gen_variant_fields(
+                error.fields,
+                quote!(value: ::core::convert::Into::into(val)),
+                Some(bound),
+            )
+        };
+
+        validators
+            .iter()
+            .filter_map(|validator| match validator {
+                StringValidator::LenCharMin(_) => {
+                    let fields = gen_fields(quote!(min: bound));
+                    Some(FactoryParam {
+                        name: format_ident!("len_char_min"),
+                        bound_type: quote!(usize),
+                        violated: quote!(val.chars().count() < bound),
+                        error: quote!(#error_type_name::LenCharMinViolated #fields),
+                    })
+                }
+                StringValidator::LenCharMax(_) => {
+                    let fields = gen_fields(quote!(max: bound));
+                    Some(FactoryParam {
+                        name: format_ident!("len_char_max"),
+                        bound_type: quote!(usize),
+                        violated: quote!(val.chars().count() > bound),
+                        error: quote!(#error_type_name::LenCharMaxViolated #fields),
+                    })
+                }
+                StringValidator::NotEmpty
+                | StringValidator::Predicate(_)
+                | StringValidator::Regex(_) => None,
+            })
+            .collect()
+    }
+
     fn gen_validation_error_type(
         type_name: &TypeName,
+        inner_type: &Self::InnerType,
+        error_type_name: &ErrorTypeName,
         validators: &[Self::Validator],
+        error: &ErrorAttributes,
     ) -> TokenStream {
-        gen_validation_error_type(type_name, validators)
+        gen_validation_error_type(type_name, inner_type, error_type_name, validators, error)
     }
 
     fn gen_traits(
@@ -180,12 +351,16 @@ impl GenerateNewtype for StringNewtype {
         traits: HashSet<Self::TypedTrait>,
         maybe_default_value: Option<syn::Expr>,
         _guard: &StringGuard,
+        _ops: OpsPolicy,
+        serde: &SerdeAttributes,
+        _error_integrations: ErrorIntegrations,
     ) -> Result<GeneratedTraits, syn::Error> {
         Ok(gen_traits(
             type_name,
             maybe_error_type_name,
             traits,
             maybe_default_value,
+            serde,
         ))
     }
 
@@ -206,9 +381,14 @@ impl GenerateNewtype for StringNewtype {
             guard.has_validation(),
         );
 
+        let test_valid_regex = guard
+            .validators()
+            .and_then(|validators| tests::gen_test_should_have_valid_regex(validators));
+
         quote! {
             #test_len_char_min_vs_max
             #test_valid_default_value
+            #test_valid_regex
         }
     }
 }
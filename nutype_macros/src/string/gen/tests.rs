@@ -1,7 +1,10 @@
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 
-use crate::{common::models::TypeName, string::models::StringValidator};
+use crate::{
+    common::models::TypeName,
+    string::models::{RegexDef, StringValidator},
+};
 
 pub fn gen_test_should_have_consistent_len_char_boundaries(
     type_name: &TypeName,
@@ -30,7 +33,32 @@ pub fn gen_test_should_have_consistent_len_char_boundaries(
     Some(quote!(
         #[test]
         fn should_have_consistent_len_char_boundaries() {
-            assert!(#len_char_max >= #len_char_min, #msg);
+            assert!(#len_char_max >= #len_char_min, "{}", #msg);
+        }
+    ))
+}
+
+/// A regex given by path (e.g. a `lazy_static` constant) is built on the first use,
+/// so an invalid pattern is caught by the test rather than by the first call of `new()`.
+/// A string literal is checked at compile time.
+pub fn gen_test_should_have_valid_regex(validators: &[StringValidator]) -> Option<TokenStream> {
+    let regex_paths: Vec<_> = validators
+        .iter()
+        .flat_map(|v| match v {
+            StringValidator::Regex(RegexDef::Path(path)) => Some(path),
+            _ => None,
+        })
+        .collect();
+    if regex_paths.is_empty() {
+        return None;
+    }
+
+    Some(quote!(
+        #[test]
+        fn should_have_valid_regex() {
+            #(
+                let _ = #regex_paths.as_str();
+            )*
         }
     ))
 }
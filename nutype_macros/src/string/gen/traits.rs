@@ -5,14 +5,22 @@ use quote::{quote, ToTokens};
 
 use crate::{
     common::{
-        gen::traits::{
-            gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_default,
-            gen_impl_trait_deref, gen_impl_trait_display, gen_impl_trait_from, gen_impl_trait_into,
-            gen_impl_trait_serde_deserialize, gen_impl_trait_serde_serialize,
-            gen_impl_trait_try_from, split_into_generatable_traits, GeneratableTrait,
-            GeneratableTraits, GeneratedTraits,
+        gen::{
+            alloc::gen_required_alloc_path,
+            traits::{
+                gen_impl_trait_actix_from_request, gen_impl_trait_as_ref, gen_impl_trait_borrow,
+                gen_impl_trait_clap_value_parser_factory, gen_impl_trait_default,
+                gen_impl_trait_deref, gen_impl_trait_display, gen_impl_trait_from,
+                gen_impl_trait_into, gen_impl_trait_juniper_graphql_scalar,
+                gen_impl_trait_rocket_from_form_field, gen_impl_trait_rocket_from_param,
+                gen_impl_trait_serde_deserialize, gen_impl_trait_serde_serialize,
+                gen_impl_trait_serde_with_deserialize_as, gen_impl_trait_serde_with_serialize_as,
+                gen_impl_trait_try_from, gen_impl_trait_zeroize, gen_impl_trait_zeroize_on_drop,
+                split_into_generatable_traits, GeneratableTrait, GeneratableTraits,
+                GeneratedTraits,
+            },
         },
-        models::{ErrorTypeName, TypeName},
+        models::{ErrorTypeName, SerdeAttributes, TypeName},
     },
     string::models::{StringDeriveTrait, StringInnerType},
 };
@@ -47,6 +55,15 @@ enum StringIrregularTrait {
     Default,
     SerdeSerialize,
     SerdeDeserialize,
+    JuniperGraphQLScalar,
+    RocketFromParam,
+    RocketFromFormField,
+    ActixFromRequest,
+    ClapValueParserFactory,
+    ZeroizeZeroize,
+    ZeroizeZeroizeOnDrop,
+    SerdeWithSerializeAs,
+    SerdeWithDeserializeAs,
 }
 
 impl From<StringDeriveTrait> for StringGeneratableTrait {
@@ -109,6 +126,33 @@ impl From<StringDeriveTrait> for StringGeneratableTrait {
             StringDeriveTrait::SchemarsJsonSchema => {
                 StringGeneratableTrait::Transparent(StringTransparentTrait::SchemarsJsonSchema)
             }
+            StringDeriveTrait::JuniperGraphQLScalar => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::JuniperGraphQLScalar)
+            }
+            StringDeriveTrait::RocketFromParam => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::RocketFromParam)
+            }
+            StringDeriveTrait::RocketFromFormField => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::RocketFromFormField)
+            }
+            StringDeriveTrait::ActixFromRequest => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::ActixFromRequest)
+            }
+            StringDeriveTrait::ClapValueParserFactory => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::ClapValueParserFactory)
+            }
+            StringDeriveTrait::ZeroizeZeroize => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::ZeroizeZeroize)
+            }
+            StringDeriveTrait::ZeroizeZeroizeOnDrop => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::ZeroizeZeroizeOnDrop)
+            }
+            StringDeriveTrait::SerdeWithSerializeAs => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::SerdeWithSerializeAs)
+            }
+            StringDeriveTrait::SerdeWithDeserializeAs => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::SerdeWithDeserializeAs)
+            }
         }
     }
 }
@@ -134,6 +178,7 @@ pub fn gen_traits(
     maybe_error_type_name: Option<ErrorTypeName>,
     traits: HashSet<StringDeriveTrait>,
     maybe_default_value: Option<syn::Expr>,
+    serde: &SerdeAttributes,
 ) -> GeneratedTraits {
     let GeneratableTraits {
         transparent_traits,
@@ -151,6 +196,7 @@ pub fn gen_traits(
         maybe_error_type_name,
         maybe_default_value,
         irregular_traits,
+        serde,
     );
 
     GeneratedTraits {
@@ -164,6 +210,7 @@ fn gen_implemented_traits(
     maybe_error_type_name: Option<ErrorTypeName>,
     maybe_default_value: Option<syn::Expr>,
     impl_traits: Vec<StringIrregularTrait>,
+    serde: &SerdeAttributes,
 ) -> TokenStream {
     let inner_type = StringInnerType;
 
@@ -171,7 +218,7 @@ fn gen_implemented_traits(
         .iter()
         .map(|t| match t {
             StringIrregularTrait::AsRef => gen_impl_trait_as_ref(type_name, quote!(str)),
-            StringIrregularTrait::Deref => gen_impl_trait_deref(type_name, quote!(String)),
+            StringIrregularTrait::Deref => gen_impl_trait_deref(type_name, inner_type),
             StringIrregularTrait::FromStr => {
                 gen_impl_from_str(type_name, maybe_error_type_name.as_ref())
             }
@@ -193,12 +240,51 @@ fn gen_implemented_traits(
                     );
                 }
             },
-            StringIrregularTrait::SerdeSerialize => gen_impl_trait_serde_serialize(type_name),
+            StringIrregularTrait::SerdeSerialize => {
+                gen_impl_trait_serde_serialize(type_name, inner_type, serde)
+            }
             StringIrregularTrait::SerdeDeserialize => gen_impl_trait_serde_deserialize(
                 type_name,
                 inner_type,
                 maybe_error_type_name.as_ref(),
+                &maybe_default_value,
+                serde,
+            ),
+            StringIrregularTrait::JuniperGraphQLScalar => gen_impl_trait_juniper_graphql_scalar(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
             ),
+            StringIrregularTrait::RocketFromParam => gen_impl_trait_rocket_from_param(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            ),
+            StringIrregularTrait::RocketFromFormField => gen_impl_trait_rocket_from_form_field(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            ),
+            StringIrregularTrait::ActixFromRequest => gen_impl_trait_actix_from_request(
+                type_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            ),
+            StringIrregularTrait::ClapValueParserFactory => {
+                gen_impl_trait_clap_value_parser_factory(
+                    type_name,
+                    inner_type,
+                    maybe_error_type_name.as_ref(),
+                )
+            }
+            StringIrregularTrait::ZeroizeZeroize => gen_impl_trait_zeroize(type_name),
+            StringIrregularTrait::ZeroizeZeroizeOnDrop => gen_impl_trait_zeroize_on_drop(type_name),
+            StringIrregularTrait::SerdeWithSerializeAs => {
+                gen_impl_trait_serde_with_serialize_as(type_name, inner_type, serde)
+            }
+            StringIrregularTrait::SerdeWithDeserializeAs => {
+                gen_impl_trait_serde_with_deserialize_as(type_name, inner_type)
+            }
         })
         .collect()
 }
@@ -214,7 +300,7 @@ fn gen_impl_from_str(
 
                 #[inline]
                 fn from_str(raw_string: &str) -> ::core::result::Result<Self, Self::Err> {
-                    #type_name::new(raw_string)
+                    #type_name::__nutype_new(raw_string)
                 }
             }
         }
@@ -225,7 +311,7 @@ fn gen_impl_from_str(
 
                 #[inline]
                 fn from_str(raw_string: &str) -> ::core::result::Result<Self, Self::Err> {
-                    Ok(#type_name::new(raw_string))
+                    Ok(#type_name::__nutype_new(raw_string))
                 }
             }
         }
@@ -233,7 +319,7 @@ fn gen_impl_from_str(
 }
 
 fn gen_impl_from_str_and_string(type_name: &TypeName) -> TokenStream {
-    let impl_from_string = gen_impl_trait_from(type_name, quote!(String));
+    let impl_from_string = gen_impl_trait_from(type_name, StringInnerType);
     let impl_from_str = gen_impl_trait_from(type_name, quote!(&str));
 
     quote! {
@@ -247,17 +333,24 @@ fn gen_impl_try_from(
     maybe_error_type_name: Option<&ErrorTypeName>,
 ) -> TokenStream {
     let impl_try_from_string =
-        gen_impl_trait_try_from(type_name, quote!(String), maybe_error_type_name);
+        gen_impl_trait_try_from(type_name, StringInnerType, maybe_error_type_name);
     let impl_try_from_str = gen_impl_trait_try_from(type_name, quote!(&str), maybe_error_type_name);
+    let alloc = gen_required_alloc_path();
+    let impl_try_from_cow = gen_impl_trait_try_from(
+        type_name,
+        quote!(#alloc::borrow::Cow<'_, str>),
+        maybe_error_type_name,
+    );
 
     quote! {
         #impl_try_from_string
         #impl_try_from_str
+        #impl_try_from_cow
     }
 }
 
 fn gen_impl_borrow_str_and_string(type_name: &TypeName) -> TokenStream {
-    let impl_borrow_string = gen_impl_trait_borrow(type_name, quote!(String));
+    let impl_borrow_string = gen_impl_trait_borrow(type_name, StringInnerType);
     let impl_borrow_str = gen_impl_trait_borrow(type_name, quote!(str));
 
     quote! {
@@ -7,7 +7,7 @@ use std::collections::HashSet;
 
 use crate::common::{
     gen::GenerateNewtype,
-    models::{Attributes, GenerateParams, Newtype, SpannedDeriveTrait},
+    models::{Attributes, ConditionalRules, GenerateParams, Newtype, SpannedDeriveTrait},
 };
 
 use models::{StringDeriveTrait, StringSanitizer, StringValidator};
@@ -40,7 +40,12 @@ impl Newtype for StringNewtype {
     }
 
     fn generate(
-        params: GenerateParams<StringInnerType, Self::TypedTrait, StringGuard>,
+        params: GenerateParams<
+            StringInnerType,
+            Self::TypedTrait,
+            StringGuard,
+            ConditionalRules<Self::Sanitizer, Self::Validator>,
+        >,
     ) -> Result<TokenStream, syn::Error> {
         StringNewtype::gen_nutype(params)
     }
@@ -2,8 +2,18 @@ use kinded::Kinded;
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 
-use crate::common::models::{
-    Guard, RawGuard, SpannedItem, TypeTrait, TypedCustomFunction, ValueOrExpr,
+use crate::common::{
+    gen::{
+        alloc::gen_required_alloc_path,
+        invariants::{describe_bound, describe_custom_function},
+        metadata::{
+            metadata_of_bound, metadata_of_custom_function, metadata_of_kind, metadata_of_pattern,
+        },
+    },
+    models::{
+        Describe, Guard, HasBound, Metadata, RawGuard, SpannedItem, TypeTrait, TypedCustomFunction,
+        ValueOrExpr,
+    },
 };
 
 // Sanitizer
@@ -35,6 +45,80 @@ pub enum StringValidator {
     Regex(RegexDef),
 }
 
+impl Describe for StringSanitizer {
+    fn describe(&self) -> String {
+        match self {
+            Self::Trim => "`trim`: leading and trailing whitespace is removed".to_string(),
+            Self::Lowercase => "`lowercase`: converted to lowercase".to_string(),
+            Self::Uppercase => "`uppercase`: converted to uppercase".to_string(),
+            Self::With(function) => {
+                describe_custom_function("with", function.path(), "transformed with")
+            }
+        }
+    }
+}
+
+impl Metadata for StringSanitizer {
+    fn metadata(&self) -> String {
+        match self {
+            Self::Trim => metadata_of_kind("trim"),
+            Self::Lowercase => metadata_of_kind("lowercase"),
+            Self::Uppercase => metadata_of_kind("uppercase"),
+            Self::With(function) => metadata_of_custom_function("with", function.path()),
+        }
+    }
+}
+
+impl Describe for StringValidator {
+    fn describe(&self) -> String {
+        match self {
+            Self::LenCharMin(len) => {
+                let (code, prose) = describe_bound(len);
+                format!("`len_char_min = {code}`: at least {prose} character(s)")
+            }
+            Self::LenCharMax(len) => {
+                let (code, prose) = describe_bound(len);
+                format!("`len_char_max = {code}`: at most {prose} character(s)")
+            }
+            Self::NotEmpty => "`not_empty`: not empty".to_string(),
+            Self::Predicate(function) => {
+                describe_custom_function("predicate", function.path(), "satisfies")
+            }
+            Self::Regex(RegexDef::StringLiteral(regex)) => {
+                let regex = regex.value();
+                format!("`regex`: matches the regular expression `{regex}`")
+            }
+            Self::Regex(RegexDef::Path(path)) => {
+                describe_custom_function("regex", Some(path), "matches the regular expression")
+            }
+        }
+    }
+}
+
+impl Metadata for StringValidator {
+    fn metadata(&self) -> String {
+        match self {
+            Self::LenCharMin(len) => metadata_of_bound("len_char_min", len),
+            Self::LenCharMax(len) => metadata_of_bound("len_char_max", len),
+            Self::NotEmpty => metadata_of_kind("not_empty"),
+            Self::Predicate(function) => metadata_of_custom_function("predicate", function.path()),
+            Self::Regex(RegexDef::StringLiteral(regex)) => {
+                metadata_of_pattern("regex", &regex.value())
+            }
+            Self::Regex(RegexDef::Path(path)) => metadata_of_custom_function("regex", Some(path)),
+        }
+    }
+}
+
+impl HasBound for StringValidator {
+    fn has_bound(&self) -> bool {
+        match self {
+            Self::LenCharMin(_) | Self::LenCharMax(_) | Self::Regex(_) => true,
+            Self::NotEmpty | Self::Predicate(_) => false,
+        }
+    }
+}
+
 #[cfg_attr(not(feature = "regex"), allow(dead_code))]
 #[derive(Debug)]
 pub enum RegexDef {
@@ -74,13 +158,30 @@ pub enum StringDeriveTrait {
     SerdeSerialize,
     SerdeDeserialize,
     SchemarsJsonSchema,
+    JuniperGraphQLScalar,
+    RocketFromParam,
+    RocketFromFormField,
+    ActixFromRequest,
+    ClapValueParserFactory,
     // Arbitrary,
+    ZeroizeZeroize,
+    ZeroizeZeroizeOnDrop,
+    SerdeWithSerializeAs,
+    SerdeWithDeserializeAs,
 }
 
 impl TypeTrait for StringDeriveTrait {
     fn is_from_str(&self) -> bool {
         self == &Self::FromStr
     }
+
+    fn is_drop(&self) -> bool {
+        self == &Self::ZeroizeZeroizeOnDrop
+    }
+
+    fn is_debug(&self) -> bool {
+        self == &StringDeriveTrait::Debug
+    }
 }
 
 pub type StringRawGuard = RawGuard<SpannedStringSanitizer, SpannedStringValidator>;
@@ -91,6 +192,12 @@ pub struct StringInnerType;
 
 impl ToTokens for StringInnerType {
     fn to_tokens(&self, token_stream: &mut TokenStream) {
-        quote!(String).to_tokens(token_stream);
+        // Without `std` there is no `String` in the prelude
+        if cfg!(feature = "std") {
+            quote!(String).to_tokens(token_stream);
+        } else {
+            let alloc = gen_required_alloc_path();
+            quote!(#alloc::string::String).to_tokens(token_stream);
+        }
     }
 }
@@ -1,15 +1,20 @@
 use crate::{
     common::{
-        models::{Attributes, SpannedDeriveTrait, SpannedItem},
+        models::{Attributes, ConstFn, OpsPolicy, SpannedDeriveTrait, SpannedItem},
         parse::{
             parse_number_or_expr, parse_sanitizer_kind, parse_typed_custom_function_raw,
             parse_validator_kind, ParseableAttributes,
         },
+        validate::{
+            detect_redundant_new_unchecked, validate_default_derive, validate_error_messages,
+            validate_error_status_codes, validate_factory, validate_local, validate_no_ops_policy,
+            validate_warnings,
+        },
     },
     string::models::{StringGuard, StringRawGuard, StringSanitizer, StringValidator},
 };
 use cfg_if::cfg_if;
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use syn::{
     parse::{Parse, ParseStream},
     spanned::Spanned,
@@ -21,12 +26,16 @@ use super::{
         RegexDef, SpannedStringSanitizer, SpannedStringValidator, StringSanitizerKind,
         StringValidatorKind,
     },
-    validate::validate_string_meta,
+    validate::{validate_delegate, validate_string_default, validate_string_meta},
 };
 
 pub fn parse_attributes(
     input: TokenStream,
 ) -> Result<Attributes<StringGuard, SpannedDeriveTrait>, syn::Error> {
+    if !cfg!(feature = "alloc") {
+        let msg = "String types require the feature `alloc` (or `std`) of crate `nutype`.";
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
     let attrs: ParseableAttributes<SpannedStringSanitizer, SpannedStringValidator> =
         syn::parse2(input)?;
 
@@ -36,17 +45,100 @@ pub fn parse_attributes(
         new_unchecked,
         default,
         derive_traits,
+        ops,
+        secret,
+        const_fn,
+        constructor,
+        constructor_vis,
+        factory,
+        try_from_lossless,
+        exact_bounds,
+        delegate,
+        accessor,
+        widen_into,
+        derive_unsafe,
+        repr,
+        local,
+        docs,
+        omit,
+        methods,
+        metadata,
+        debug_expansion,
+        strict,
+        serde,
+        error,
     } = attrs;
+    if let Some(target) = widen_into.first() {
+        let msg = "`widen_into` is supported only for integer types.";
+        return Err(syn::Error::new_spanned(target, msg));
+    }
+    if const_fn == ConstFn::On {
+        let msg = "`const_fn` is not supported for String types, because a String cannot be built in const context.";
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
+    if exact_bounds {
+        let msg = "`exact_bounds` is supported only for float types.";
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
+    if try_from_lossless {
+        let msg = "`try_from_lossless` is supported only for integer and float types.";
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
+    validate_delegate(&delegate, secret)?;
+    validate_default_derive(&derive_traits, &default)?;
+    validate_error_messages(&error.messages, &validators)?;
+    validate_error_status_codes(&error.status_codes, &validators)?;
+    validate_factory(
+        factory,
+        &validators,
+        |v| {
+            matches!(
+                v,
+                StringValidator::LenCharMin(_) | StringValidator::LenCharMax(_)
+            )
+        },
+        "`len_char_min`, `len_char_max`",
+    )?;
     let raw_guard = StringRawGuard {
         sanitizers,
         validators,
     };
     let guard = validate_string_meta(raw_guard)?;
+    let warnings = validate_warnings(
+        detect_redundant_new_unchecked(&new_unchecked, &guard)
+            .into_iter()
+            .collect(),
+        strict,
+    )?;
+    validate_string_default(&guard, &default)?;
+    validate_no_ops_policy(ops)?;
+    let local = validate_local(local, &guard, &error)?;
     Ok(Attributes {
         new_unchecked,
         guard,
         default,
         derive_traits,
+        ops: OpsPolicy::default(),
+        secret,
+        const_fn,
+        constructor,
+        constructor_vis,
+        factory,
+        try_from_lossless,
+        delegate,
+        accessor,
+        widen_into,
+        derive_unsafe,
+        repr,
+        local,
+        docs,
+        omit,
+        methods,
+        metadata,
+        debug_expansion,
+        warnings,
+        serde,
+        error,
     })
 }
 
@@ -1,14 +1,15 @@
 use kinded::Kinded;
 use std::collections::HashSet;
 
-use proc_macro2::Span;
+use proc_macro2::{Ident, Span};
 
 use crate::{
     common::{
-        models::{DeriveTrait, SpannedDeriveTrait, ValueOrExpr},
+        models::{DeriveTrait, Secret, SpannedDeriveTrait, ValueOrExpr},
+        parse::strip_expr_groups,
         validate::validate_duplicates,
     },
-    string::models::{StringGuard, StringRawGuard, StringSanitizer, StringValidator},
+    string::models::{RegexDef, StringGuard, StringRawGuard, StringSanitizer, StringValidator},
 };
 
 use super::models::{
@@ -21,6 +22,7 @@ pub fn validate_string_meta(raw_meta: StringRawGuard) -> Result<StringGuard, syn
         validators,
     } = raw_meta;
 
+    validate_sanitizers_with_validators(&sanitizers, &validators)?;
     let validators = validate_validators(validators)?;
     let sanitizers = validate_sanitizers(sanitizers)?;
 
@@ -40,7 +42,7 @@ fn validate_validators(
     // Check duplicates
     //
     validate_duplicates(&validators, |kind| {
-        format!("Duplicated validators `{kind}`.\nDon't worry, you still remain ingenious!")
+        format!("Duplicated validator `{kind}`.\nDon't worry, you still remain ingenious!")
     })?;
 
     // len_char_max VS len_char_min
@@ -69,6 +71,19 @@ fn validate_validators(
         }
     }
 
+    // not_empty VS len_char_max = 0
+    //
+    if let Some((len_char_max_span, 0)) = maybe_len_char_max {
+        if validators
+            .iter()
+            .any(|v| matches!(v.item, StringValidator::NotEmpty))
+        {
+            let msg =
+                "`len_char_max = 0` together with `not_empty` cannot be satisfied by any value.";
+            return Err(syn::Error::new(len_char_max_span, msg));
+        }
+    }
+
     // Validate regex
     //
     #[cfg(feature = "regex")]
@@ -113,6 +128,164 @@ fn validate_sanitizers(
     Ok(sanitizers)
 }
 
+/// A string literal of `default = ` is sanitized and validated at compile time, so the default,
+/// that would be rejected by the constructor, is reported on the value.
+/// Custom sanitizers and validators (`with`, `predicate`, `regex`) are left to the runtime check.
+pub fn validate_string_default(
+    guard: &StringGuard,
+    default: &Option<syn::Expr>,
+) -> Result<(), syn::Error> {
+    let (Some(default), Some(validators)) = (default, guard.validators()) else {
+        return Ok(());
+    };
+    let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Str(lit),
+        ..
+    }) = strip_expr_groups(default)
+    else {
+        return Ok(());
+    };
+    let mut value = lit.value();
+    for sanitizer in guard.sanitizers() {
+        value = match sanitizer {
+            StringSanitizer::Trim => value.trim().to_string(),
+            StringSanitizer::Lowercase => value.to_lowercase(),
+            StringSanitizer::Uppercase => value.to_uppercase(),
+            StringSanitizer::With(_) => return Ok(()),
+        };
+    }
+    let len = value.chars().count();
+    for validator in validators {
+        let violation = match validator {
+            StringValidator::NotEmpty if value.is_empty() => "not_empty".to_string(),
+            StringValidator::LenCharMin(ValueOrExpr::Value(min)) if len < *min => {
+                format!("len_char_min = {min}")
+            }
+            StringValidator::LenCharMax(ValueOrExpr::Value(max)) if len > *max => {
+                format!("len_char_max = {max}")
+            }
+            _ => continue,
+        };
+        let msg =
+            format!("The default value {value:?} (after sanitization) violates `{violation}`.");
+        return Err(syn::Error::new_spanned(default, msg));
+    }
+    Ok(())
+}
+
+/// Detect sanitizers, which make the validators impossible to satisfy, e.g. `uppercase` together
+/// with `regex = "^[a-z]+$"`.
+fn validate_sanitizers_with_validators(
+    sanitizers: &[SpannedStringSanitizer],
+    validators: &[SpannedStringValidator],
+) -> Result<(), syn::Error> {
+    for validator in validators {
+        let StringValidator::Regex(RegexDef::StringLiteral(ref lit)) = validator.item else {
+            continue;
+        };
+        let Some(letter_case) = regex_letter_case(&lit.value()) else {
+            continue;
+        };
+        for sanitizer in sanitizers {
+            let conflicting_case = match sanitizer.item {
+                StringSanitizer::Lowercase => LetterCase::Upper,
+                StringSanitizer::Uppercase => LetterCase::Lower,
+                _ => continue,
+            };
+            if letter_case == conflicting_case {
+                let msg = format!(
+                    "Sanitizer `{}` conflicts with the regex, which matches only {letter_case} letters.",
+                    sanitizer.kind()
+                );
+                return Err(syn::Error::new(sanitizer.span, msg));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LetterCase {
+    Lower,
+    Upper,
+}
+
+impl std::fmt::Display for LetterCase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Lower => write!(f, "lowercase"),
+            Self::Upper => write!(f, "uppercase"),
+        }
+    }
+}
+
+/// The case of the ASCII letters, which the regex mentions as literals or within classes (e.g. `[a-z]`).
+/// Returns `None` if the regex mentions letters of both cases or no letters at all, or if it's
+/// case insensitive or has a negated class. Escape sequences (e.g. `\w` or `\p{L}`) and group
+/// syntax (e.g. `(?P<name>`) are skipped, so the check errs on the side of not reporting a conflict.
+fn regex_letter_case(regex: &str) -> Option<LetterCase> {
+    let mut has_lower = false;
+    let mut has_upper = false;
+    let mut chars = regex.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                // Skip the escaped char and the braced argument, e.g. `\p{Lu}` or `\x{41}`
+                chars.next();
+                if chars.peek() == Some(&'{') {
+                    chars.by_ref().find(|&c| c == '}');
+                }
+            }
+            '(' if chars.peek() == Some(&'?') => {
+                let group: String = chars
+                    .by_ref()
+                    .take_while(|&c| c != ':' && c != ')' && c != '>')
+                    .collect();
+                if group.contains('i') && !group.contains('<') && !group.contains('\'') {
+                    // Case insensitive flag, e.g. `(?i)` or `(?i:...)`
+                    return None;
+                }
+            }
+            // A negated class, e.g. `[^a-z]`, matches the letters of the other case
+            '[' if chars.peek() == Some(&'^') => return None,
+            c if c.is_ascii_lowercase() => has_lower = true,
+            c if c.is_ascii_uppercase() => has_upper = true,
+            _ => {}
+        }
+    }
+    match (has_lower, has_upper) {
+        (true, false) => Some(LetterCase::Lower),
+        (false, true) => Some(LetterCase::Upper),
+        _ => None,
+    }
+}
+
+/// Methods of `String`, that can be delegated to with `delegate(...)`.
+pub const DELEGATABLE_METHODS: [&str; 4] = ["len", "is_empty", "chars", "as_str"];
+
+pub fn validate_delegate(delegate: &[Ident], secret: Secret) -> Result<(), syn::Error> {
+    let mut seen: HashSet<String> = HashSet::new();
+    for method in delegate {
+        let name = method.to_string();
+        if !DELEGATABLE_METHODS.contains(&name.as_str()) {
+            let msg = format!(
+                "Method `{name}` cannot be delegated. Supported methods: {}.",
+                DELEGATABLE_METHODS.map(|m| format!("`{m}`")).join(", ")
+            );
+            return Err(syn::Error::new(method.span(), msg));
+        }
+        if !seen.insert(name) {
+            let msg = format!("Method `{method}` is delegated more than once.");
+            return Err(syn::Error::new(method.span(), msg));
+        }
+        if secret.is_on() {
+            let msg = "`delegate` cannot be used with `secret`, because secret types do not expose the inner value.";
+            return Err(syn::Error::new(method.span(), msg));
+        }
+    }
+    Ok(())
+}
+
 pub fn validate_string_derive_traits(
     guard: &StringGuard,
     spanned_derive_traits: Vec<SpannedDeriveTrait>,
@@ -152,26 +325,108 @@ fn to_string_derive_trait(
         DeriveTrait::SerdeSerialize => Ok(StringDeriveTrait::SerdeSerialize),
         DeriveTrait::SerdeDeserialize => Ok(StringDeriveTrait::SerdeDeserialize),
         DeriveTrait::SchemarsJsonSchema => Ok(StringDeriveTrait::SchemarsJsonSchema),
+        DeriveTrait::JuniperGraphQLScalar => Ok(StringDeriveTrait::JuniperGraphQLScalar),
+        DeriveTrait::RocketFromParam => Ok(StringDeriveTrait::RocketFromParam),
+        DeriveTrait::RocketFromFormField => Ok(StringDeriveTrait::RocketFromFormField),
+        DeriveTrait::ActixFromRequest => Ok(StringDeriveTrait::ActixFromRequest),
+        DeriveTrait::ClapValueParserFactory => Ok(StringDeriveTrait::ClapValueParserFactory),
         DeriveTrait::Copy => Err(syn::Error::new(
             span,
-            "Copy trait cannot be derived for a String based type",
+            "Copy trait cannot be derived for a String based type.\nRemove `Copy` from `derive(...)`, `Clone` can be derived instead.",
         )),
         DeriveTrait::From => {
             if has_validation {
                 Err(syn::Error::new(
                     span,
-                    "#[nutype] cannot derive `From` trait, because there is validation defined. Use `TryFrom` instead.",
+                    "#[nutype] cannot derive `From` trait, because there is validation defined.\nReplace `From` with `TryFrom` in `derive(...)`, or remove the validators.\nTo build values, which are known to be valid, without the checks, consider `new_unchecked` instead.",
                 ))
             } else {
                 Ok(StringDeriveTrait::From)
             }
         }
         DeriveTrait::TryFrom => Ok(StringDeriveTrait::TryFrom),
+        DeriveTrait::RandDistribution => {
+            let msg = "Deriving `Distribution` trait is supported only for integer and float types.\nRemove `Distribution` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
         DeriveTrait::ArbitraryArbitrary => {
             // TODO: Implement deriving Arbitrary
-            let msg = "Deriving Arbitrary trait for string types is not yet implemented";
+            let msg = "Deriving `Arbitrary` trait for string types is not yet implemented.\nRemove `Arbitrary` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::NumTraitsZero => {
+            let msg = "Deriving `Zero` trait is supported only for integer and float types.\nRemove `Zero` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::NumTraitsOne => {
+            let msg = "Deriving `One` trait is supported only for integer and float types.\nRemove `One` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::NumTraitsBounded => {
+            let msg = "Deriving `Bounded` trait is supported only for integer and float types.\nRemove `Bounded` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::NumTraitsToPrimitive => {
+            let msg = "Deriving `ToPrimitive` trait is supported only for integer and float types.\nRemove `ToPrimitive` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::NumTraitsFromPrimitive => {
+            let msg = "Deriving `FromPrimitive` trait is supported only for integer and float types.\nRemove `FromPrimitive` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::Add => {
+            let msg = "Deriving `Add` trait is supported only for integer and float types.\nRemove `Add` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::Sub => {
+            let msg = "Deriving `Sub` trait is supported only for integer and float types.\nRemove `Sub` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::Mul => {
+            let msg = "Deriving `Mul` trait is supported only for integer and float types.\nRemove `Mul` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::Div => {
+            let msg = "Deriving `Div` trait is supported only for integer and float types.\nRemove `Div` from `derive(...)`.";
             Err(syn::Error::new(span, msg))
         }
+        DeriveTrait::AddAssign => {
+            let msg = "Deriving `AddAssign` trait is supported only for integer and float types.\nRemove `AddAssign` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::SubAssign => {
+            let msg = "Deriving `SubAssign` trait is supported only for integer and float types.\nRemove `SubAssign` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::MulAssign => {
+            let msg = "Deriving `MulAssign` trait is supported only for integer and float types.\nRemove `MulAssign` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::DivAssign => {
+            let msg = "Deriving `DivAssign` trait is supported only for integer and float types.\nRemove `DivAssign` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::Sum => {
+            let msg = "Deriving `Sum` trait is supported only for integer and float types.\nRemove `Sum` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::Product => {
+            let msg = "Deriving `Product` trait is supported only for integer and float types.\nRemove `Product` from `derive(...)`.";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::ZeroizeZeroize => {
+            if has_validation {
+                Err(syn::Error::new(
+                    span,
+                    "#[nutype] cannot derive `Zeroize` trait, because there is validation defined and the zeroized value may violate it.\nDerive `ZeroizeOnDrop` instead, which zeroizes the value only when it is dropped.",
+                ))
+            } else {
+                Ok(StringDeriveTrait::ZeroizeZeroize)
+            }
+        }
+        DeriveTrait::ZeroizeZeroizeOnDrop => Ok(StringDeriveTrait::ZeroizeZeroizeOnDrop),
+        DeriveTrait::SerdeWithSerializeAs => Ok(StringDeriveTrait::SerdeWithSerializeAs),
+        DeriveTrait::SerdeWithDeserializeAs => Ok(StringDeriveTrait::SerdeWithDeserializeAs),
     }
 }
 
@@ -187,7 +442,10 @@ mod regex_validation {
                 let regex_str = lit.value();
                 match regex::Regex::new(&regex_str) {
                     Ok(_re) => Ok(()),
-                    Err(err) => Err(syn::Error::new(span, format!("{err}"))),
+                    Err(err) => {
+                        let msg = format!("The regex cannot be compiled.\n{err}");
+                        Err(syn::Error::new(span, msg))
+                    }
                 }
             }
             RegexDef::Path(_) => Ok(()),
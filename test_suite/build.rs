@@ -0,0 +1,6 @@
+fn main() {
+    // Bounds of the validators, which are read with `env!` in tests/integer.rs
+    println!("cargo:rustc-env=NUTYPE_TEST_FLAG_MIN=1");
+    println!("cargo:rustc-env=NUTYPE_TEST_FLAG_MAX=1");
+    println!("cargo:rerun-if-changed=build.rs");
+}
@@ -61,6 +61,7 @@ mod traits {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn test_clone() {
         let location = Location::new(Point::new(5, 8));
         let same_location = location.clone();
@@ -289,7 +290,7 @@ mod traits {
                     let err = serde_json::from_str::<LinePoint>("{\"x\":7,\"y\":9}").unwrap_err();
                     assert_eq!(
                         err.to_string(),
-                        "LinePoint failed the predicate test. Expected valid LinePoint"
+                        "Expected valid LinePoint, constraint `predicate` is violated: LinePoint failed the predicate test."
                     );
                 }
 
@@ -364,6 +365,295 @@ fn test_sanitize_with_untyped_mut_closure() {
     assert_eq!(pos.into_inner(), Point::new(100, 91))
 }
 
+#[cfg(test)]
+mod try_mutate {
+    use super::*;
+
+    #[test]
+    fn test_try_mutate() {
+        #[nutype(
+            validate(predicate = |p: &Point| p.x >= 0 && p.y >= 0),
+            derive(Debug, Clone, Copy, PartialEq),
+            methods(try_mutate)
+        )]
+        pub struct Position(Point);
+
+        let mut position = Position::new(Point::new(1, 2)).unwrap();
+        position.try_mutate(|p| p.x = 5).unwrap();
+        assert_eq!(position.into_inner(), Point::new(5, 2));
+
+        let err = position.try_mutate(|p| p.y = -1).unwrap_err();
+        assert_eq!(err, PositionError::PredicateViolated);
+        assert_eq!(position.into_inner(), Point::new(5, 2));
+    }
+
+    #[derive(Debug)]
+    pub struct Token(u32);
+
+    #[test]
+    fn test_inner_type_does_not_need_to_be_clone() {
+        #[nutype(validate(predicate = |t: &Token| t.0 > 0), derive(Debug))]
+        pub struct ValidToken(Token);
+
+        assert_eq!(ValidToken::new(Token(1)).unwrap().into_inner().0, 1);
+    }
+}
+
+#[cfg(test)]
+mod generics {
+    use super::*;
+
+    #[nutype(
+        validate(predicate = |v| !v.is_empty()),
+        derive(Debug, Clone, PartialEq, AsRef, Deref, Borrow, Into, TryFrom)
+    )]
+    pub struct NonEmptyVec<T>(Vec<T>);
+
+    #[nutype(
+        sanitize(with = |mut v: Vec<T>| { v.sort(); v }),
+        derive(Debug, From, Into, AsRef)
+    )]
+    pub struct SortedVec<T: Ord>(Vec<T>);
+
+    #[nutype(derive(Debug, AsRef))]
+    pub struct Labeled<'a, T>(Vec<(&'a str, T)>)
+    where
+        T: Copy;
+
+    #[nutype(derive(Debug, Display, PartialEq, Eq, PartialOrd, Ord, Hash))]
+    pub struct Boxed<T: std::fmt::Display + Ord + std::hash::Hash>(Box<T>);
+
+    #[test]
+    fn test_generic_type_with_validation() {
+        let v = NonEmptyVec::new(vec![1, 2, 3]).unwrap();
+        assert_eq!(v.len(), 3);
+        assert_eq!(v.clone().into_inner(), vec![1, 2, 3]);
+
+        let err = NonEmptyVec::<String>::new(vec![]).unwrap_err();
+        assert_eq!(err, NonEmptyVecError::PredicateViolated);
+
+        let v: NonEmptyVec<&str> = vec!["a"].try_into().unwrap();
+        let inner: Vec<&str> = v.into();
+        assert_eq!(inner, vec!["a"]);
+    }
+
+    #[test]
+    fn test_generic_type_with_bounds() {
+        let v = SortedVec::new(vec![3, 1, 2]);
+        assert_eq!(v.as_ref(), &vec![1, 2, 3]);
+
+        let v: SortedVec<char> = vec!['b', 'a'].into();
+        assert_eq!(v.into_inner(), vec!['a', 'b']);
+    }
+
+    #[test]
+    fn test_generic_type_with_lifetime_and_where_clause() {
+        let labeled = Labeled::new(vec![("one", 1)]);
+        assert_eq!(labeled.as_ref()[0], ("one", 1));
+    }
+
+    #[test]
+    fn test_generic_type_with_display() {
+        let boxed = Boxed::new(Box::new(42));
+        assert_eq!(boxed.to_string(), "42");
+        assert!(boxed < Boxed::new(Box::new(43)));
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+mod borrowed_deserialize {
+    use super::*;
+    use std::borrow::Cow;
+
+    #[nutype(
+        validate(predicate = |name| !name.is_empty()),
+        derive(Debug, PartialEq, AsRef, Serialize, Deserialize)
+    )]
+    pub struct Name<'a>(Cow<'a, str>);
+
+    #[derive(Debug, serde::Deserialize)]
+    struct Person<'a> {
+        #[serde(borrow)]
+        name: Name<'a>,
+    }
+
+    #[test]
+    fn test_borrows_from_input() {
+        let json = r#"{"name": "Ferris"}"#;
+        let person: Person = serde_json::from_str(json).unwrap();
+        assert!(matches!(person.name.as_ref(), Cow::Borrowed("Ferris")));
+    }
+
+    #[test]
+    fn test_owns_escaped_string() {
+        let name: Name = serde_json::from_str(r#""Fer\"ris""#).unwrap();
+        assert!(matches!(name.as_ref(), Cow::Owned(s) if s == "Fer\"ris"));
+    }
+
+    #[test]
+    fn test_validates() {
+        let err = serde_json::from_str::<Name>(r#""""#).unwrap_err();
+        assert!(err.to_string().contains("Expected valid Name"));
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let name = Name::new(Cow::Borrowed("Ferris")).unwrap();
+        let json = serde_json::to_string(&name).unwrap();
+        assert_eq!(json, r#""Ferris""#);
+        assert_eq!(serde_json::from_str::<Name>(&json).unwrap(), name);
+    }
+}
+
+#[cfg(test)]
+mod local {
+    use super::*;
+
+    #[test]
+    fn test_type_within_function_body() {
+        #[derive(Debug, Clone, PartialEq)]
+        struct Size {
+            width: u32,
+            height: u32,
+        }
+
+        #[nutype(local, derive(Debug, Clone, PartialEq))]
+        struct WindowSize(Size);
+
+        let size = WindowSize::new(Size {
+            width: 800,
+            height: 600,
+        });
+        assert_eq!(size.into_inner().width, 800);
+    }
+
+    #[test]
+    fn test_generic_type_within_function_body() {
+        #[derive(Debug, PartialEq)]
+        struct Item(u8);
+
+        #[nutype(local, derive(Debug, PartialEq))]
+        struct Items<T>(Vec<T>);
+
+        assert_eq!(Items::new(vec![Item(1)]).into_inner(), vec![Item(1)]);
+    }
+}
+
+#[cfg(test)]
+mod const_new {
+    use super::*;
+
+    #[test]
+    fn test_const_new_without_guard() {
+        #[nutype(derive(Debug, PartialEq))]
+        pub struct Origin(Point);
+
+        const ORIGIN: Origin = Origin::new(Point { x: 0, y: 0 });
+        assert_eq!(ORIGIN.into_inner(), Point::new(0, 0));
+    }
+}
+
+#[cfg(test)]
+mod named_field {
+    use super::*;
+
+    #[nutype(
+        sanitize(with = |mut values| { values.sort(); values }),
+        validate(predicate = |values| !values.is_empty()),
+        derive(Debug, Clone, PartialEq, AsRef)
+    )]
+    pub struct Scores {
+        values: Vec<u32>,
+    }
+
+    #[test]
+    fn test_named_field() {
+        let scores = Scores::new(vec![3, 1, 2]).unwrap();
+        assert_eq!(scores.as_ref(), &vec![1, 2, 3]);
+        assert_eq!(scores.into_inner(), vec![1, 2, 3]);
+        assert_eq!(Scores::new(vec![]), Err(ScoresError::PredicateViolated));
+    }
+}
+
+#[cfg(test)]
+mod multiple_fields {
+    use super::*;
+
+    #[nutype(
+        validate(predicate = |&(start, end)| start <= end),
+        derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Into, TryFrom)
+    )]
+    pub struct Range(i64, i64);
+
+    #[nutype(
+        sanitize(with = |(first, last): (String, String)| (first.trim().to_string(), last.trim().to_string())),
+        derive(Debug, PartialEq, AsRef)
+    )]
+    pub struct FullName(String, String);
+
+    #[test]
+    fn test_validation_of_the_whole_value() {
+        let range = Range::new((1, 10)).unwrap();
+        assert_eq!(range.into_inner(), (1, 10));
+        assert_eq!(Range::new((10, 1)), Err(RangeError::PredicateViolated));
+
+        let (start, end): (i64, i64) = Range::try_from((-5, 5)).unwrap().into();
+        assert_eq!((start, end), (-5, 5));
+    }
+
+    #[test]
+    fn test_sanitization_of_the_whole_value() {
+        let name = FullName::new((" Marcus ".to_string(), " Aurelius ".to_string()));
+        assert_eq!(
+            name.as_ref(),
+            &("Marcus".to_string(), "Aurelius".to_string())
+        );
+    }
+}
+
+#[cfg(test)]
+mod validate_only {
+    use super::*;
+
+    #[test]
+    fn test_validate_applies_sanitizers_to_a_clone() {
+        #[nutype(
+            sanitize(with = |p: Point| Point::new(p.x.abs(), p.y.abs())),
+            validate(predicate = |p: &Point| p.x < 10 && p.y < 10),
+            derive(Debug, Clone),
+            methods(validate)
+        )]
+        pub struct Position(Point);
+
+        let point = Point::new(-3, 4);
+        assert_eq!(Position::validate(&point), Ok(()));
+        assert_eq!(
+            Position::validate(&Point::new(-30, 4)),
+            Err(PositionError::PredicateViolated)
+        );
+        assert_eq!(point, Point::new(-3, 4));
+        assert_eq!(Position::new(point).unwrap().into_inner(), Point::new(3, 4));
+    }
+
+    #[derive(Debug)]
+    pub struct Token(u32);
+
+    #[test]
+    fn test_validate_without_sanitizers_does_not_need_clone() {
+        #[nutype(
+            validate(predicate = |t: &Token| t.0 > 0),
+            derive(Debug),
+            methods(validate)
+        )]
+        pub struct ValidToken(Token);
+
+        assert!(ValidToken::validate(&Token(1)).is_ok());
+        assert!(ValidToken::validate(&Token(0)).is_err());
+        assert_eq!(ValidToken::new(Token(1)).unwrap().into_inner().0, 1);
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "new_unchecked")]
 mod new_unchecked {
@@ -372,7 +662,7 @@ mod new_unchecked {
     #[nutype(
         derive(Debug),
         validate(predicate = |p: &Point| p.y == p.x ),
-        new_unchecked,
+        new_unchecked(no_debug_assert),
     )]
     pub struct LinePoint(Point);
 
@@ -382,3 +672,26 @@ mod new_unchecked {
         assert_eq!(line_point.into_inner(), Point::new(3, 4));
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "zeroize")]
+mod derive_zeroize {
+    use super::*;
+    use zeroize::Zeroize;
+
+    #[nutype(derive(Debug, AsRef, Zeroize, ZeroizeOnDrop))]
+    pub struct Key(Vec<u8>);
+
+    #[test]
+    fn test_zeroize() {
+        let mut key = Key::new(vec![1, 2, 3, 4]);
+        key.zeroize();
+        assert!(key.as_ref().is_empty());
+    }
+
+    #[test]
+    fn test_into_inner() {
+        let key = Key::new(vec![1, 2, 3, 4]);
+        assert_eq!(key.into_inner(), vec![1, 2, 3, 4]);
+    }
+}
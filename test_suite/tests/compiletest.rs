@@ -1,6 +1,40 @@
 #[cfg(feature = "ui")]
-#[test]
-fn ui() {
-    let t = trybuild::TestCases::new();
-    t.compile_fail("tests/ui/**/*.rs");
+nutype::compile_fail_tests! {
+    ui => "tests/ui/**/*.rs",
+}
+
+// `secret` needs `secrecy` feature, so these cases are checked only when it's enabled
+#[cfg(all(feature = "ui", feature = "secrecy"))]
+nutype::compile_fail_tests! {
+    ui_secrecy => "tests/ui_secrecy/**/*.rs",
+}
+
+// Deriving `Serialize` and `Deserialize` needs `serde` feature
+#[cfg(all(feature = "ui", feature = "serde"))]
+nutype::compile_fail_tests! {
+    ui_serde => "tests/ui_serde/**/*.rs",
+}
+
+// Serializing a secret needs `serde` feature as well
+#[cfg(all(feature = "ui", feature = "secrecy", feature = "serde"))]
+nutype::compile_fail_tests! {
+    ui_secrecy_serde => "tests/ui_secrecy_serde/**/*.rs",
+}
+
+// `Distribution` needs `rand` feature
+#[cfg(all(feature = "ui", feature = "rand"))]
+nutype::compile_fail_tests! {
+    ui_rand => "tests/ui_rand/**/*.rs",
+}
+
+// `Zero`, `One` and `Bounded` need `num-traits` feature
+#[cfg(all(feature = "ui", feature = "num-traits"))]
+nutype::compile_fail_tests! {
+    ui_num_traits => "tests/ui_num_traits/**/*.rs",
+}
+
+// `Zeroize` needs `zeroize` feature
+#[cfg(all(feature = "ui", feature = "zeroize"))]
+nutype::compile_fail_tests! {
+    ui_zeroize => "tests/ui_zeroize/**/*.rs",
 }
@@ -104,6 +104,7 @@ mod validators {
     }
 
     #[test]
+    #[allow(clippy::zero_divided_by_zero)]
     fn test_finite_f64() {
         #[nutype(validate(finite), derive(Debug, PartialEq))]
         struct Dist(f64);
@@ -122,6 +123,7 @@ mod validators {
     }
 
     #[test]
+    #[allow(clippy::zero_divided_by_zero)]
     fn test_finite_f32() {
         #[nutype(
             validate(finite),
@@ -139,6 +141,24 @@ mod validators {
         assert_eq!(Dist::new(-999.12).unwrap().into_inner(), -999.12);
     }
 
+    #[test]
+    #[allow(clippy::zero_divided_by_zero)]
+    fn test_not_nan() {
+        #[nutype(validate(not_nan), derive(Debug, PartialEq))]
+        struct Dist(f64);
+
+        // invalid
+        assert_eq!(Dist::new(f64::NAN), Err(DistError::NotNanViolated));
+        assert_eq!(Dist::new(0.0 / 0.0), Err(DistError::NotNanViolated));
+
+        // valid
+        assert_eq!(
+            Dist::new(f64::INFINITY).unwrap().into_inner(),
+            f64::INFINITY
+        );
+        assert_eq!(Dist::new(-999.12).unwrap().into_inner(), -999.12);
+    }
+
     mod when_boundaries_defined_as_constants {
         use super::*;
 
@@ -174,6 +194,25 @@ mod validators {
         }
     }
 
+    mod when_boundaries_defined_as_const_expressions {
+        use super::*;
+
+        const MAX_SCORE: f64 = 10.0;
+
+        #[nutype(
+            validate(greater_or_equal = MAX_SCORE - 2.0 * MAX_SCORE, less = MAX_SCORE / 2.0),
+            derive(Debug, PartialEq)
+        )]
+        struct Score(f64);
+
+        #[test]
+        fn test_boundaries_defined_as_const_expressions() {
+            assert_eq!(Score::new(-10.1), Err(ScoreError::GreaterOrEqualViolated));
+            assert_eq!(Score::new(-10.0).unwrap().into_inner(), -10.0);
+            assert_eq!(Score::new(5.0), Err(ScoreError::LessViolated));
+        }
+    }
+
     #[cfg(test)]
     mod with {
         use super::*;
@@ -258,6 +297,25 @@ mod validators {
                 "Percentage is too small. The value must be greater or equal to 0.0."
             );
         }
+
+        #[test]
+        fn test_error_with_value() {
+            #[nutype(
+                validate(finite, greater_or_equal = 0.0),
+                error_fields(value),
+                derive(Debug)
+            )]
+            struct Distance(f64);
+
+            assert_eq!(
+                Distance::new(-2.5).unwrap_err(),
+                DistanceError::GreaterOrEqualViolated { value: -2.5 }
+            );
+            assert!(matches!(
+                Distance::new(f64::INFINITY),
+                Err(DistanceError::FiniteViolated { value }) if value == f64::INFINITY
+            ));
+        }
     }
 }
 
@@ -428,6 +486,16 @@ mod traits {
         assert_eq!(error, DistError::LessOrEqualViolated);
     }
 
+    #[test]
+    fn test_trait_try_from_lossless() {
+        #[nutype(try_from_lossless, validate(finite), derive(Debug, TryFrom))]
+        pub struct Ratio(f64);
+
+        assert_eq!(Ratio::try_from(3u32).unwrap().into_inner(), 3.0);
+        assert_eq!(Ratio::try_from(-2i16).unwrap().into_inner(), -2.0);
+        assert_eq!(Ratio::try_from(0.5).unwrap().into_inner(), 0.5);
+    }
+
     #[test]
     fn test_trait_from_str_without_validation() {
         #[nutype(derive(Debug, FromStr))]
@@ -487,6 +555,22 @@ mod traits {
         assert_eq!(size1, size2);
     }
 
+    #[test]
+    fn test_trait_hash() {
+        #[nutype(validate(not_nan), derive(PartialEq, Eq, Hash, Debug))]
+        pub struct Size(f64);
+
+        should_implement_hash::<Size>();
+
+        let mut sizes = std::collections::HashSet::new();
+        sizes.insert(Size::new(35.7).unwrap());
+        sizes.insert(Size::new(357.0 / 10.0).unwrap());
+        sizes.insert(Size::new(0.0).unwrap());
+        sizes.insert(Size::new(-0.0).unwrap());
+        sizes.insert(Size::new(f64::INFINITY).unwrap());
+        assert_eq!(sizes.len(), 3);
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
@@ -535,6 +619,23 @@ mod traits {
             assert_eq!(sorted_raw_sizes, vec![2.0, 3.5, 5.5, 15.0, 44.5],);
         }
 
+        #[test]
+        fn test_trait_ord_with_not_nan() {
+            #[nutype(validate(not_nan), derive(PartialEq, Eq, PartialOrd, Ord))]
+            pub struct Size(f64);
+
+            let a: Size = Size::new(f64::NEG_INFINITY).unwrap();
+            let b: Size = Size::new(3.3).unwrap();
+            let c: Size = Size::new(f64::INFINITY).unwrap();
+
+            assert_eq!(a.cmp(&b), Ordering::Less);
+            assert_eq!(c.cmp(&b), Ordering::Greater);
+            assert_eq!(
+                Size::new(0.0).unwrap().cmp(&Size::new(-0.0).unwrap()),
+                Ordering::Equal
+            );
+        }
+
         #[cfg(test)]
         mod prop_tests {
             use super::*;
@@ -555,7 +656,7 @@ mod traits {
                     Ok(())
                 }
 
-                arbtest::builder().run(|u| prop(u));
+                arbtest::builder().run(prop);
             }
 
             #[test]
@@ -573,7 +674,7 @@ mod traits {
                     Ok(())
                 }
 
-                arbtest::builder().run(|u| prop(u));
+                arbtest::builder().run(prop);
             }
         }
     }
@@ -691,12 +792,197 @@ mod traits {
         #[test]
         #[should_panic(expected = "Default value for type `Number` is invalid")]
         fn test_default_with_validation_when_invalid() {
-            #[nutype(validate(less_or_equal = 20.0), default = 20.1, derive(Default))]
+            #[nutype(validate(less_or_equal = 20.0), default = 20.0 + 0.1, derive(Default))]
             pub struct Number(f64);
 
             Number::default();
         }
     }
+
+    #[cfg(test)]
+    mod trait_arithmetic_ops {
+        use super::*;
+
+        #[test]
+        fn test_ops_without_validation() {
+            #[nutype(derive(Debug, Clone, Copy, PartialEq, Add, Sub, Mul, Div))]
+            pub struct Number(f64);
+
+            assert_eq!(Number::new(1.5) + Number::new(2.0), Number::new(3.5));
+            assert_eq!(Number::new(1.5) - Number::new(2.0), Number::new(-0.5));
+            assert_eq!(Number::new(1.5) * Number::new(2.0), Number::new(3.0));
+            assert_eq!(Number::new(1.5) / Number::new(2.0), Number::new(0.75));
+        }
+
+        #[test]
+        #[should_panic(expected = "Ratio::add() produced an invalid value")]
+        fn test_ops_panic_when_invalid() {
+            #[nutype(
+                validate(greater_or_equal = 0.0, less_or_equal = 1.0),
+                derive(Debug, Add)
+            )]
+            pub struct Ratio(f32);
+
+            let _ = Ratio::new(0.5).unwrap() + Ratio::new(0.75).unwrap();
+        }
+
+        #[test]
+        fn test_ops_saturate() {
+            #[nutype(
+                ops = saturate,
+                validate(greater_or_equal = 0.0, less_or_equal = 1.0),
+                derive(Debug, Clone, Copy, PartialEq, Add, Sub, Mul, Div)
+            )]
+            pub struct Ratio(f64);
+
+            let ratio = |n: f64| Ratio::new(n).unwrap();
+
+            assert_eq!(ratio(0.75) + ratio(0.5), ratio(1.0));
+            assert_eq!(ratio(0.25) - ratio(0.5), ratio(0.0));
+            assert_eq!(ratio(0.5) * ratio(0.5), ratio(0.25));
+            assert_eq!(ratio(0.5) / ratio(0.25), ratio(1.0));
+        }
+
+        #[test]
+        fn test_ops_result() {
+            #[nutype(
+                ops = result,
+                validate(finite),
+                derive(Debug, Clone, Copy, PartialEq, Add, Sub, Mul, Div)
+            )]
+            pub struct Number(f64);
+
+            let one = Number::new(1.0).unwrap();
+            let zero = Number::new(0.0).unwrap();
+            assert_eq!(one + one, Number::new(2.0));
+            assert_eq!(one / zero, Err(NumberError::FiniteViolated));
+        }
+
+        #[test]
+        fn test_assign_ops_saturate() {
+            #[nutype(
+                ops = saturate,
+                validate(greater_or_equal = 0.0, less_or_equal = 1.0),
+                derive(Debug, PartialEq, AddAssign, SubAssign, MulAssign, DivAssign)
+            )]
+            pub struct Ratio(f64);
+
+            let mut ratio = Ratio::new(0.5).unwrap();
+            ratio *= Ratio::new(0.5).unwrap();
+            assert_eq!(ratio, Ratio::new(0.25).unwrap());
+            ratio /= Ratio::new(0.125).unwrap();
+            assert_eq!(ratio, Ratio::new(1.0).unwrap());
+            ratio -= Ratio::new(0.5).unwrap();
+            assert_eq!(ratio, Ratio::new(0.5).unwrap());
+            ratio += Ratio::new(0.75).unwrap();
+            assert_eq!(ratio, Ratio::new(1.0).unwrap());
+        }
+
+        #[test]
+        fn test_sum_and_product() {
+            #[nutype(validate(finite), derive(Debug, PartialEq, Sum, Product))]
+            pub struct Weight(f64);
+
+            let weights = vec![Weight::new(1.5).unwrap(), Weight::new(2.0).unwrap()];
+            let total: Weight = weights.iter().sum();
+            assert_eq!(total, Weight::new(3.5).unwrap());
+            let product: Weight = weights.into_iter().product();
+            assert_eq!(product, Weight::new(3.0).unwrap());
+        }
+
+        #[test]
+        fn test_sum_saturate_empty() {
+            #[nutype(
+                ops = saturate,
+                validate(greater_or_equal = 0.5, less_or_equal = 2.0),
+                derive(Debug, PartialEq, Sum)
+            )]
+            pub struct Factor(f64);
+
+            let empty: Vec<Factor> = vec![];
+            assert_eq!(empty.iter().sum::<Factor>(), Factor::new(0.5).unwrap());
+        }
+    }
+}
+
+#[cfg(test)]
+mod const_fn {
+    use nutype::nutype;
+
+    #[nutype(
+        const_fn,
+        validate(finite, greater_or_equal = 0.0, less_or_equal = 1.0),
+        derive(Debug)
+    )]
+    pub struct Probability(f64);
+
+    const CERTAIN: Probability = match Probability::new(1.0) {
+        Ok(probability) => probability,
+        Err(_) => panic!("Invalid probability"),
+    };
+
+    #[test]
+    fn test_const_new() {
+        assert_eq!(CERTAIN.into_inner(), 1.0);
+        assert_eq!(
+            Probability::new(1.5).unwrap_err(),
+            ProbabilityError::LessOrEqualViolated
+        );
+    }
+
+    #[test]
+    fn test_const_new_with_literal_bounds_without_const_fn() {
+        #[nutype(validate(greater = 0.0, less_or_equal = 100.0), derive(Debug))]
+        pub struct Percent(f32);
+
+        const FULL: Percent = Percent::from_literal(100.0);
+        assert_eq!(FULL.into_inner(), 100.0);
+        assert_eq!(
+            Percent::new(0.0).unwrap_err(),
+            PercentError::GreaterViolated
+        );
+    }
+
+    #[test]
+    fn test_well_known_constants() {
+        const IMPOSSIBLE: Probability = Probability::ZERO;
+        assert_eq!(IMPOSSIBLE.into_inner(), 0.0);
+    }
+}
+
+#[cfg(test)]
+mod raw_bounds {
+    use super::*;
+
+    #[nutype(const_fn, validate(greater_or_equal = -1.0, less_or_equal = 1.0), derive(Debug))]
+    pub struct Correlation(f64);
+
+    #[nutype(validate(greater_or_equal = 0.0, less_or_equal = Correlation::MAX_RAW), derive(Debug))]
+    pub struct PositiveCorrelation(f64);
+
+    #[test]
+    fn test_raw_bounds() {
+        assert_eq!(Correlation::MIN_RAW, -1.0);
+        assert_eq!(Correlation::MAX_RAW, 1.0);
+        assert_eq!(Correlation::MIN.into_inner(), -1.0);
+        assert_eq!(PositiveCorrelation::MIN_RAW, 0.0);
+        assert_eq!(PositiveCorrelation::MAX_RAW, 1.0);
+    }
+}
+
+#[cfg(test)]
+mod new_clamped {
+    use super::*;
+
+    #[test]
+    fn test_new_clamped() {
+        #[nutype(validate(greater_or_equal = 0.0, less_or_equal = 1.0))]
+        pub struct Ratio(f64);
+
+        assert_eq!(Ratio::new_clamped(-0.5).into_inner(), 0.0);
+        assert_eq!(Ratio::new_clamped(0.25).into_inner(), 0.25);
+        assert_eq!(Ratio::new_clamped(3.0).into_inner(), 1.0);
+    }
 }
 
 #[cfg(test)]
@@ -706,7 +992,7 @@ mod new_unchecked {
 
     #[test]
     fn test_new_unchecked() {
-        #[nutype(new_unchecked, validate(greater_or_equal = 50.0))]
+        #[nutype(new_unchecked(no_debug_assert), validate(greater_or_equal = 50.0))]
         pub struct Dist(f64);
 
         let dist = unsafe { Dist::new_unchecked(3.0) };
@@ -730,3 +1016,157 @@ mod derive_schemars_json_schema {
         let _schema = schema_for!(ProductWeight);
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "juniper")]
+mod derive_juniper_graphql_scalar {
+    use super::*;
+    use juniper::{graphql_object, EmptyMutation, EmptySubscription, RootNode, Value, Variables};
+
+    #[nutype(validate(finite, less = 100.0), derive(GraphQLScalar))]
+    pub struct Percentage(f64);
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn echo(input: Percentage) -> Percentage {
+            input
+        }
+    }
+
+    fn execute(query: &str) -> Result<Value, String> {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        juniper::execute_sync(query, None, &schema, &Variables::new(), &())
+            .map(|(value, _errors)| value)
+            .map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn test_valid_input() {
+        let value = execute(r#"{ echo(input: 12.5) }"#).unwrap();
+        assert_eq!(value.to_string(), r#"{"echo": 12.5}"#);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let err = execute(r#"{ echo(input: 100.5) }"#).unwrap_err();
+        assert!(err.contains("Percentage"));
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "clap")]
+mod derive_clap_value_parser_factory {
+    use super::*;
+    use clap::Parser;
+
+    #[nutype(
+        validate(finite, less = 100.0),
+        derive(Debug, Clone, PartialEq, ValueParserFactory)
+    )]
+    pub struct Percentage(f64);
+
+    #[derive(Parser, Debug)]
+    struct Cli {
+        #[arg(long)]
+        percentage: Percentage,
+    }
+
+    #[test]
+    fn test_valid_arg() {
+        let cli = Cli::try_parse_from(["app", "--percentage", "12.5"]).unwrap();
+        assert_eq!(cli.percentage, Percentage::new(12.5).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_arg() {
+        let err = Cli::try_parse_from(["app", "--percentage", "100.5"]).unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::ValueValidation);
+        assert!(err
+            .to_string()
+            .contains(&PercentageError::LessViolated.to_string()));
+    }
+}
+
+#[cfg(test)]
+mod preset {
+    use super::*;
+
+    #[nutype(preset = percentage, derive(Debug, PartialEq))]
+    pub struct Share(f64);
+
+    #[test]
+    fn test_percentage() {
+        assert_eq!(Share::new(12.5).unwrap().into_inner(), 12.5);
+        assert_eq!(Share::new(-0.1), Err(ShareError::GreaterOrEqualViolated));
+        assert_eq!(Share::new(100.1), Err(ShareError::LessOrEqualViolated));
+        assert_eq!(Share::new(f64::NAN), Err(ShareError::FiniteViolated));
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "rand")]
+mod derive_rand_distribution {
+    use super::*;
+    use rand::distributions::Uniform;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[nutype(
+        validate(greater = 0.0, less = 1.0),
+        derive(Debug, Clone, Copy, PartialEq, PartialOrd, Distribution)
+    )]
+    pub struct Probability(f64);
+
+    #[test]
+    fn test_standard_distribution() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let probability: Probability = rng.gen();
+            let value = probability.into_inner();
+            assert!(value > 0.0 && value < 1.0);
+        }
+    }
+
+    #[test]
+    fn test_uniform_distribution() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let low = Probability::new(0.25).unwrap();
+        let high = Probability::new(0.5).unwrap();
+        for _ in 0..1000 {
+            let probability = rng.sample(Uniform::new(low, high));
+            assert!(low <= probability && probability < high);
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "num-traits")]
+mod derive_num_traits {
+    use super::*;
+    use num_traits::{Bounded, FromPrimitive, ToPrimitive};
+
+    #[nutype(
+        validate(greater_or_equal = -1.0, less_or_equal = 1.0),
+        derive(Debug, PartialEq, Bounded, ToPrimitive, FromPrimitive)
+    )]
+    pub struct Correlation(f32);
+
+    #[test]
+    fn test_bounded() {
+        assert_eq!(Correlation::min_value(), Correlation::new(-1.0).unwrap());
+        assert_eq!(Correlation::max_value(), Correlation::new(1.0).unwrap());
+    }
+
+    #[test]
+    fn test_to_and_from_primitive() {
+        let correlation = Correlation::new(0.5).unwrap();
+        assert_eq!(correlation.to_f64(), Some(0.5));
+        assert_eq!(Correlation::from_f64(0.5), Some(correlation));
+        assert_eq!(Correlation::from_f64(1.5), None);
+    }
+}
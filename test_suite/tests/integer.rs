@@ -100,6 +100,19 @@ mod validators {
         assert_eq!(Age::new(25).unwrap().into_inner(), 25);
     }
 
+    #[test]
+    fn test_hex_and_binary_literals() {
+        #[nutype(validate(greater = 0b1, less_or_equal = 0xFF_FF), derive(Debug))]
+        struct Port(u32);
+
+        assert_eq!(Port::new(1).unwrap_err(), PortError::GreaterViolated);
+        assert_eq!(
+            Port::new(65536).unwrap_err(),
+            PortError::LessOrEqualViolated
+        );
+        assert_eq!(Port::new(65535).unwrap().into_inner(), 65535);
+    }
+
     mod when_boundaries_defined_as_constants {
         use super::*;
 
@@ -136,6 +149,39 @@ mod validators {
         }
     }
 
+    mod when_boundaries_defined_as_const_expressions {
+        use super::*;
+
+        mod limits {
+            pub const MAX_PORT: u16 = u16::MAX;
+        }
+
+        #[nutype(
+            validate(greater_or_equal = limits::MAX_PORT as u32 / 2, less = 1 << 16),
+            derive(Debug, PartialEq)
+        )]
+        struct HighPort(u32);
+
+        #[nutype(validate(greater = -(MAX_INNER - 1), less_or_equal = MAX_INNER - 1), derive(Debug, PartialEq))]
+        struct Offset(i32);
+
+        const MAX_INNER: i32 = 10;
+
+        #[test]
+        fn test_boundaries_defined_as_const_expressions() {
+            assert_eq!(
+                HighPort::new(32766),
+                Err(HighPortError::GreaterOrEqualViolated)
+            );
+            assert_eq!(HighPort::new(32767).unwrap().into_inner(), 32767);
+            assert_eq!(HighPort::new(65536), Err(HighPortError::LessViolated));
+
+            assert_eq!(Offset::new(-9), Err(OffsetError::GreaterViolated));
+            assert_eq!(Offset::new(-8).unwrap().into_inner(), -8);
+            assert_eq!(Offset::new(10), Err(OffsetError::LessOrEqualViolated));
+        }
+    }
+
     #[cfg(test)]
     mod with {
         use super::*;
@@ -207,6 +253,83 @@ mod validators {
     mod error {
         use super::*;
 
+        #[test]
+        fn test_error_code() {
+            #[nutype(validate(greater_or_equal = 100, less = 600))]
+            struct HTTPStatusCode(u16);
+
+            #[nutype(validate(greater_or_equal = 18), error_code_prefix = "user.age")]
+            struct Age(u8);
+
+            assert_eq!(
+                HTTPStatusCodeError::GreaterOrEqualViolated.code(),
+                "http_status_code.greater_or_equal"
+            );
+            assert_eq!(
+                HTTPStatusCodeError::LessViolated.code(),
+                "http_status_code.less"
+            );
+            assert_eq!(
+                AgeError::GreaterOrEqualViolated.code(),
+                "user.age.greater_or_equal"
+            );
+        }
+
+        #[test]
+        fn test_error_non_exhaustive() {
+            #[nutype(validate(greater_or_equal = 18), error_non_exhaustive, derive(Debug))]
+            struct Age(u8);
+
+            #[allow(unreachable_patterns)]
+            let code = match Age::new(17).unwrap_err() {
+                AgeError::GreaterOrEqualViolated => 1,
+                _ => 0,
+            };
+            assert_eq!(code, 1);
+        }
+
+        #[test]
+        fn test_error_derive() {
+            use std::collections::HashSet;
+
+            #[nutype(
+                validate(greater_or_equal = 18),
+                error_derive(Clone, Copy, PartialEq, Eq, Hash)
+            )]
+            struct Age(u8);
+
+            let errors: HashSet<AgeError> = [Age::new(1), Age::new(2)]
+                .into_iter()
+                .filter_map(Result::err)
+                .collect();
+            assert_eq!(errors.len(), 1);
+            assert!(errors.contains(&AgeError::GreaterOrEqualViolated));
+        }
+
+        #[test]
+        fn test_error_with_bounds() {
+            #[nutype(
+                validate(greater = 0, less_or_equal = 100, predicate = |n| n % 2 == 0),
+                error_fields(bounds),
+                derive(Debug)
+            )]
+            struct Even(i32);
+
+            assert_eq!(
+                Even::new(0).unwrap_err(),
+                EvenError::GreaterViolated { min: 0 }
+            );
+            assert_eq!(
+                Even::new(101).unwrap_err(),
+                EvenError::LessOrEqualViolated { max: 100 }
+            );
+            assert_eq!(Even::new(3).unwrap_err(), EvenError::PredicateViolated);
+            assert_eq!(
+                Even::new(101).unwrap_err().to_string(),
+                "Even is too big. The value must be less or equal to 100."
+            );
+        }
+
         #[test]
         fn test_error_display() {
             #[nutype(
@@ -225,6 +348,175 @@ mod validators {
                 "Age is too small. The value must be greater or equal to 18."
             );
         }
+
+        #[test]
+        fn test_custom_error_name() {
+            #[nutype(
+                validate(greater_or_equal = 18),
+                error = InvalidAge,
+                derive(Debug, PartialEq, FromStr)
+            )]
+            struct Age(u8);
+
+            let err: InvalidAge = Age::new(17).unwrap_err();
+            assert_eq!(err, InvalidAge::GreaterOrEqualViolated);
+
+            let parse_err = "17".parse::<Age>().unwrap_err();
+            assert!(matches!(
+                parse_err,
+                AgeParseError::Validate(InvalidAge::GreaterOrEqualViolated)
+            ));
+        }
+
+        mod custom_error_visibility {
+            mod encapsulated {
+                use nutype::nutype;
+
+                #[nutype(
+                    validate(less = 100),
+                    error = pub(crate) PercentageError,
+                    derive(Debug)
+                )]
+                pub struct Percentage(u8);
+            }
+
+            #[test]
+            fn test_error_visibility() {
+                let err: encapsulated::PercentageError =
+                    encapsulated::Percentage::new(100).unwrap_err();
+                assert!(matches!(err, encapsulated::PercentageError::LessViolated));
+            }
+
+            mod without_name {
+                use nutype::nutype;
+
+                #[nutype(validate(less = 100), error = pub(crate), derive(Debug))]
+                pub struct Percentage(u8);
+            }
+
+            #[test]
+            fn test_error_visibility_without_name() {
+                let err: without_name::PercentageError =
+                    without_name::Percentage::new(100).unwrap_err();
+                assert!(matches!(err, without_name::PercentageError::LessViolated));
+            }
+        }
+
+        mod error_module {
+            use nutype::nutype;
+
+            #[nutype(
+                validate(less_or_equal = 100),
+                error_module = percentage_errors,
+                derive(Debug, FromStr)
+            )]
+            pub struct Percentage(u8);
+
+            #[test]
+            fn test_error_types_within_module() {
+                use percentage_errors::{PercentageError, PercentageParseError};
+
+                let err: PercentageError = Percentage::new(101).unwrap_err();
+                assert_eq!(err, PercentageError::LessOrEqualViolated);
+
+                let parse_err: PercentageParseError = "101".parse::<Percentage>().unwrap_err();
+                assert!(matches!(
+                    parse_err,
+                    PercentageParseError::Validate(PercentageError::LessOrEqualViolated)
+                ));
+            }
+        }
+
+        mod shared_error_type {
+            use nutype::nutype;
+
+            #[derive(Debug, PartialEq)]
+            enum ValidationError {
+                Age(AgeError),
+                Percentage(PercentageError),
+            }
+
+            #[nutype(validate(greater_or_equal = 18), error_into(ValidationError))]
+            struct Age(u8);
+
+            #[nutype(validate(less_or_equal = 100), error_into(ValidationError))]
+            struct Percentage(u8);
+
+            fn validate(age: u8, percentage: u8) -> Result<(), ValidationError> {
+                Age::new(age)?;
+                Percentage::new(percentage)?;
+                Ok(())
+            }
+
+            #[test]
+            fn test_error_into_shared_error_type() {
+                assert_eq!(
+                    validate(17, 50),
+                    Err(ValidationError::Age(AgeError::GreaterOrEqualViolated))
+                );
+                assert_eq!(
+                    validate(18, 101),
+                    Err(ValidationError::Percentage(
+                        PercentageError::LessOrEqualViolated
+                    ))
+                );
+                assert_eq!(validate(18, 100), Ok(()));
+            }
+        }
+
+        mod user_defined_return_error_type {
+            use core::str::FromStr;
+            use nutype::nutype;
+
+            mod errors {
+                #[derive(Debug, PartialEq)]
+                pub enum UserError {
+                    Age(super::AgeError),
+                }
+
+                impl core::fmt::Display for UserError {
+                    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                        match self {
+                            UserError::Age(err) => write!(f, "invalid age: {err}"),
+                        }
+                    }
+                }
+
+                impl std::error::Error for UserError {}
+            }
+
+            #[nutype(
+                validate(greater_or_equal = 18),
+                error_return = errors::UserError,
+                derive(Debug, FromStr, TryFrom)
+            )]
+            struct Age(u8);
+
+            #[test]
+            fn test_new_returns_user_error() {
+                let err: errors::UserError = Age::new(17).unwrap_err();
+                assert_eq!(
+                    err,
+                    errors::UserError::Age(AgeError::GreaterOrEqualViolated)
+                );
+                assert_eq!(Age::new(18).unwrap().into_inner(), 18);
+            }
+
+            #[test]
+            fn test_traits_return_user_error() {
+                let err: errors::UserError = Age::try_from(17).unwrap_err();
+                assert_eq!(
+                    err,
+                    errors::UserError::Age(AgeError::GreaterOrEqualViolated)
+                );
+
+                let err = Age::from_str("17").unwrap_err();
+                assert_eq!(
+                    err.to_string(),
+                    "Failed to parse Age: invalid age: Age is too small. The value must be greater or equal to 18."
+                );
+            }
+        }
     }
 }
 
@@ -617,6 +909,37 @@ mod traits {
         );
     }
 
+    #[test]
+    fn test_trait_try_from_lossless() {
+        #[nutype(
+            try_from_lossless,
+            validate(less_or_equal = 1000),
+            derive(Debug, TryFrom)
+        )]
+        pub struct Amount(i64);
+
+        assert_eq!(Amount::try_from(7u8).unwrap().into_inner(), 7);
+        assert_eq!(Amount::try_from(-7i16).unwrap().into_inner(), -7);
+        assert_eq!(Amount::try_from(1000u32).unwrap().into_inner(), 1000);
+        assert_eq!(
+            Amount::try_from(1001i32).unwrap_err(),
+            AmountError::LessOrEqualViolated
+        );
+    }
+
+    #[test]
+    fn test_parse_with_derived_from_str() {
+        #[nutype(validate(less_or_equal = 99), derive(Debug, FromStr))]
+        pub struct Age(u8);
+
+        assert_eq!(Age::parse("42").unwrap().into_inner(), 42);
+        assert!(matches!(Age::parse("forty"), Err(AgeParseError::Parse(_))));
+        assert!(matches!(
+            Age::parse("100"),
+            Err(AgeParseError::Validate(AgeError::LessOrEqualViolated))
+        ));
+    }
+
     #[test]
     fn test_trait_display() {
         #[nutype(derive(Display))]
@@ -644,6 +967,22 @@ mod traits {
                 assert_eq!(offset_json, "-280");
             }
 
+            #[cfg(feature = "serde")]
+            #[test]
+            fn test_error_derive_serialize() {
+                #[nutype(
+                    validate(less_or_equal = 100),
+                    error_fields(bounds),
+                    error_derive(::serde::Serialize),
+                    derive(Debug)
+                )]
+                pub struct Percentage(u8);
+
+                let err = Percentage::new(101).unwrap_err();
+                let err_json = serde_json::to_string(&err).unwrap();
+                assert_eq!(err_json, r#"{"LessOrEqualViolated":{"max":100}}"#);
+            }
+
             #[cfg(feature = "serde")]
             #[test]
             fn test_trait_deserialize_without_validation() {
@@ -682,6 +1021,126 @@ mod traits {
                     assert_eq!(offset.into_inner(), 13);
                 }
             }
+
+            mod custom_functions {
+                use super::*;
+
+                fn serialize_cents<S: serde::Serializer>(
+                    cents: &u64,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    let decimal = format!("{}.{:02}", cents / 100, cents % 100);
+                    serializer.serialize_str(&decimal)
+                }
+
+                fn deserialize_cents<'de, D: serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<u64, D::Error> {
+                    let decimal = <String as serde::Deserialize>::deserialize(deserializer)?;
+                    let (units, cents) = decimal
+                        .split_once('.')
+                        .ok_or_else(|| serde::de::Error::custom("Expected a decimal"))?;
+                    let units: u64 = units.parse().map_err(serde::de::Error::custom)?;
+                    let cents: u64 = cents.parse().map_err(serde::de::Error::custom)?;
+                    Ok(units * 100 + cents)
+                }
+
+                #[nutype(
+                    validate(less_or_equal = 100_000),
+                    derive(Serialize, Deserialize, Debug, PartialEq),
+                    serde(serialize_with = serialize_cents, deserialize_with = deserialize_cents),
+                )]
+                pub struct Price(u64);
+
+                #[test]
+                fn test_serialize_with() {
+                    let price = Price::new(1205).unwrap();
+                    assert_eq!(serde_json::to_string(&price).unwrap(), "\"12.05\"");
+                }
+
+                #[test]
+                fn test_deserialize_with() {
+                    let price: Price = serde_json::from_str("\"7.50\"").unwrap();
+                    assert_eq!(price, Price::new(750).unwrap());
+
+                    // The value is still validated after deserialization
+                    let res: Result<Price, _> = serde_json::from_str("\"1000.01\"");
+                    assert!(res.is_err());
+
+                    let res: Result<Price, _> = serde_json::from_str("1205");
+                    assert!(res.is_err());
+                }
+
+                #[test]
+                fn test_roundtrip_with_ron() {
+                    let price = Price::new(99).unwrap();
+                    let serialized = ron::to_string(&price).unwrap();
+                    let deserialized: Price = ron::from_str(&serialized).unwrap();
+                    assert_eq!(deserialized, price);
+                }
+            }
+        }
+
+        mod wrapped {
+            use super::*;
+
+            #[nutype(
+                validate(greater_or_equal = 1),
+                derive(Serialize, Deserialize, PartialEq, Debug),
+                serde(wrap = "value")
+            )]
+            pub struct Quantity(u32);
+
+            #[test]
+            fn test_serialize() {
+                let quantity = Quantity::new(5).unwrap();
+                assert_eq!(serde_json::to_string(&quantity).unwrap(), r#"{"value":5}"#);
+            }
+
+            #[test]
+            fn test_deserialize() {
+                let quantity: Quantity = serde_json::from_str(r#"{"value":5}"#).unwrap();
+                assert_eq!(quantity, Quantity::new(5).unwrap());
+
+                // Unknown fields are ignored
+                let quantity: Quantity =
+                    serde_json::from_str(r#"{"unit":"kg","value":7}"#).unwrap();
+                assert_eq!(quantity, Quantity::new(7).unwrap());
+            }
+
+            #[test]
+            fn test_deserialize_errors() {
+                let err = serde_json::from_str::<Quantity>("5").unwrap_err();
+                assert!(err.to_string().contains("struct Quantity"));
+
+                let err = serde_json::from_str::<Quantity>("{}").unwrap_err();
+                assert!(err.to_string().contains("missing field `value`"));
+
+                let err = serde_json::from_str::<Quantity>(r#"{"value":1,"value":2}"#).unwrap_err();
+                assert!(err.to_string().contains("duplicate field `value`"));
+
+                let err = serde_json::from_str::<Quantity>(r#"{"value":0}"#).unwrap_err();
+                assert!(err
+                    .to_string()
+                    .contains("constraint `greater_or_equal` is violated"));
+            }
+
+            #[test]
+            fn test_ron_roundtrip() {
+                let quantity = Quantity::new(33).unwrap();
+                let serialized = ron::to_string(&quantity).unwrap();
+                assert_eq!(serialized, "(value:33)");
+                let deserialized: Quantity = ron::from_str(&serialized).unwrap();
+                assert_eq!(deserialized, quantity);
+            }
+
+            #[test]
+            fn test_rmp_roundtrip() {
+                let quantity = Quantity::new(102).unwrap();
+                let bytes = rmp_serde::to_vec(&quantity).unwrap();
+                let deserialized: Quantity = rmp_serde::from_slice(&bytes).unwrap();
+                assert_eq!(deserialized, quantity);
+            }
         }
 
         mod ron_format {
@@ -742,42 +1201,1420 @@ mod traits {
         #[test]
         #[should_panic(expected = "Default value for type `Number` is invalid")]
         fn test_default_with_validation_when_invalid() {
-            #[nutype(validate(less_or_equal = 20), default = 21, derive(Default))]
+            #[nutype(validate(less_or_equal = 20), default = 20 + 1, derive(Default))]
             pub struct Number(i16);
 
             Number::default();
         }
     }
-}
 
-#[cfg(test)]
-#[cfg(feature = "new_unchecked")]
-mod new_unchecked {
-    use super::*;
+    #[cfg(test)]
+    mod trait_arithmetic_ops {
+        use super::*;
 
-    #[test]
-    fn test_new_unchecked() {
-        #[nutype(new_unchecked, validate(greater_or_equal = 50))]
-        pub struct Dist(u32);
+        #[test]
+        fn test_ops_without_validation() {
+            #[nutype(derive(Debug, Clone, Copy, PartialEq, Add, Sub, Mul, Div))]
+            pub struct Number(i32);
+
+            assert_eq!(Number::new(5) + Number::new(3), Number::new(8));
+            assert_eq!(Number::new(5) - Number::new(3), Number::new(2));
+            assert_eq!(Number::new(5) * Number::new(3), Number::new(15));
+            assert_eq!(Number::new(15) / Number::new(3), Number::new(5));
+        }
 
-        let dist = unsafe { Dist::new_unchecked(3) };
-        assert_eq!(dist.into_inner(), 3);
-    }
-}
+        #[test]
+        fn test_ops_panic_when_valid() {
+            #[nutype(
+                validate(greater_or_equal = 0, less_or_equal = 100),
+                derive(Debug, Clone, Copy, PartialEq, Add, Sub, Mul, Div)
+            )]
+            pub struct Percentage(u8);
+
+            let ten = Percentage::new(10).unwrap();
+            let twenty = Percentage::new(20).unwrap();
+            assert_eq!(ten + twenty, Percentage::new(30).unwrap());
+            assert_eq!(twenty - ten, ten);
+            assert_eq!(ten * ten, Percentage::new(100).unwrap());
+            assert_eq!(twenty / ten, Percentage::new(2).unwrap());
+        }
 
-#[cfg(test)]
-#[cfg(feature = "schemars08")]
-mod derive_schemars_json_schema {
-    use super::*;
-    use schemars::{schema_for, JsonSchema};
+        #[test]
+        #[should_panic(expected = "Percentage::add() produced an invalid value")]
+        fn test_ops_panic_when_invalid() {
+            #[nutype(validate(less_or_equal = 100), derive(Debug, Add))]
+            pub struct Percentage(u8);
 
-    #[test]
-    fn test_json_schema_derive() {
-        #[nutype(derive(JsonSchema))]
-        pub struct CustomerId(i128);
+            let _ = Percentage::new(60).unwrap() + Percentage::new(50).unwrap();
+        }
 
-        assert_eq!(CustomerId::schema_name(), "CustomerId");
-        // Make sure it compiles
-        let _schema = schema_for!(CustomerId);
+        #[test]
+        #[should_panic(expected = "Number: attempt to add with overflow")]
+        fn test_ops_panic_on_overflow() {
+            #[nutype(derive(Debug, Add))]
+            pub struct Number(u8);
+
+            let _ = Number::new(200) + Number::new(100);
+        }
+
+        #[test]
+        fn test_ops_saturate() {
+            #[nutype(
+                ops = saturate,
+                validate(greater = -10, less_or_equal = 100),
+                derive(Debug, Clone, Copy, PartialEq, Add, Sub, Mul, Div)
+            )]
+            pub struct Level(i8);
+
+            let level = |n: i8| Level::new(n).unwrap();
+
+            assert_eq!(level(60) + level(50), level(100));
+            assert_eq!(level(0) - level(50), level(-9));
+            // Overflows i8 and saturates at the upper boundary.
+            assert_eq!(level(100) * level(100), level(100));
+            assert_eq!(level(100) / level(-5), level(-9));
+            assert_eq!(level(5) + level(3), level(8));
+        }
+
+        #[test]
+        fn test_ops_saturate_without_validation() {
+            #[nutype(ops = saturate, derive(Debug, PartialEq, Add, Sub))]
+            pub struct Number(u8);
+
+            assert_eq!(Number::new(200) + Number::new(100), Number::new(255));
+            assert_eq!(Number::new(1) - Number::new(2), Number::new(0));
+        }
+
+        #[test]
+        fn test_ops_result() {
+            #[nutype(
+                ops = result,
+                validate(less_or_equal = 100),
+                derive(Debug, Clone, Copy, PartialEq, Add, Sub, Mul, Div)
+            )]
+            pub struct Percentage(u16);
+
+            let ten = Percentage::new(10).unwrap();
+            let twenty = Percentage::new(20).unwrap();
+            assert_eq!(ten + twenty, Ok(Percentage::new(30).unwrap()));
+            assert_eq!(twenty * twenty, Err(PercentageError::LessOrEqualViolated));
+            assert_eq!(twenty / ten, Percentage::new(2));
+        }
+
+        #[test]
+        fn test_ops_result_overflow() {
+            #[nutype(
+                ops = result,
+                validate(greater_or_equal = -100),
+                derive(Debug, Clone, Copy, PartialEq, Add, Sub, Mul, Div, Sum)
+            )]
+            pub struct Balance(i8);
+
+            let max = Balance::new(i8::MAX).unwrap();
+            let min = Balance::new(-100).unwrap();
+            let zero = Balance::new(0).unwrap();
+            let one = Balance::new(1).unwrap();
+            assert_eq!(max + one, Err(BalanceError::Overflow));
+            assert_eq!(min - max, Err(BalanceError::Overflow));
+            assert_eq!(max * max, Err(BalanceError::Overflow));
+            assert_eq!(max / zero, Err(BalanceError::Overflow));
+            assert_eq!(min - one, Err(BalanceError::GreaterOrEqualViolated));
+            assert_eq!(max - one, Balance::new(i8::MAX - 1));
+
+            let total: Result<Balance, BalanceError> = [max, one].iter().sum();
+            assert_eq!(total, Err(BalanceError::Overflow));
+
+            let err = (max + one).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Balance overflowed. The result of the arithmetic operation does not fit into i8 or it is a division by zero."
+            );
+            assert_eq!(err.code(), "balance.overflow");
+        }
+
+        #[test]
+        fn test_assign_ops_without_validation() {
+            #[nutype(derive(Debug, PartialEq, AddAssign, SubAssign, MulAssign, DivAssign))]
+            pub struct Number(i32);
+
+            let mut number = Number::new(5);
+            number += Number::new(3);
+            assert_eq!(number, Number::new(8));
+            number -= Number::new(2);
+            assert_eq!(number, Number::new(6));
+            number *= Number::new(4);
+            assert_eq!(number, Number::new(24));
+            number /= Number::new(8);
+            assert_eq!(number, Number::new(3));
+        }
+
+        #[test]
+        #[should_panic(expected = "Percentage::add_assign() produced an invalid value")]
+        fn test_assign_ops_panic_when_invalid() {
+            #[nutype(validate(less_or_equal = 100), derive(Debug, AddAssign))]
+            pub struct Percentage(u8);
+
+            let mut percentage = Percentage::new(60).unwrap();
+            percentage += Percentage::new(50).unwrap();
+        }
+
+        #[test]
+        fn test_assign_ops_saturate() {
+            #[nutype(
+                ops = saturate,
+                validate(greater_or_equal = 1, less_or_equal = 100),
+                derive(Debug, PartialEq, AddAssign, SubAssign)
+            )]
+            pub struct Level(u8);
+
+            let mut level = Level::new(60).unwrap();
+            level += Level::new(50).unwrap();
+            assert_eq!(level, Level::new(100).unwrap());
+            level -= Level::new(100).unwrap();
+            assert_eq!(level, Level::new(1).unwrap());
+        }
+
+        #[test]
+        fn test_sum_and_product() {
+            #[nutype(derive(Debug, PartialEq, Sum, Product))]
+            pub struct Amount(u32);
+
+            let amounts = vec![Amount::new(2), Amount::new(3), Amount::new(4)];
+            let total: Amount = amounts.iter().sum();
+            assert_eq!(total, Amount::new(9));
+            let product: Amount = amounts.into_iter().product();
+            assert_eq!(product, Amount::new(24));
+
+            let empty: Vec<Amount> = vec![];
+            assert_eq!(empty.iter().sum::<Amount>(), Amount::new(0));
+            assert_eq!(empty.iter().product::<Amount>(), Amount::new(1));
+        }
+
+        #[test]
+        #[should_panic(expected = "Amount::sum() produced an invalid value")]
+        fn test_sum_panic_when_invalid() {
+            #[nutype(validate(less_or_equal = 10), derive(Debug, Sum))]
+            pub struct Amount(u32);
+
+            let amounts = vec![Amount::new(6).unwrap(), Amount::new(6).unwrap()];
+            let _total: Amount = amounts.into_iter().sum();
+        }
+
+        #[test]
+        fn test_sum_saturate() {
+            #[nutype(
+                ops = saturate,
+                validate(less_or_equal = 10),
+                derive(Debug, PartialEq, Sum)
+            )]
+            pub struct Amount(u8);
+
+            let amounts = [Amount::new(6).unwrap(), Amount::new(6).unwrap()];
+            let total: Amount = amounts.iter().sum();
+            assert_eq!(total, Amount::new(10).unwrap());
+        }
+
+        #[test]
+        fn test_sum_and_product_saturate_empty() {
+            #[nutype(
+                ops = saturate,
+                validate(greater_or_equal = 1, less_or_equal = 10),
+                derive(Debug, PartialEq, Sum, Product)
+            )]
+            pub struct Small(u8);
+
+            let empty: Vec<Small> = vec![];
+            assert_eq!(empty.iter().sum::<Small>(), Small::new(1).unwrap());
+            assert_eq!(empty.iter().product::<Small>(), Small::new(1).unwrap());
+
+            let smalls = [Small::new(3).unwrap(), Small::new(4).unwrap()];
+            assert_eq!(smalls.iter().sum::<Small>(), Small::new(7).unwrap());
+        }
+
+        #[test]
+        fn test_sum_and_product_result() {
+            #[nutype(
+                ops = result,
+                validate(less_or_equal = 10),
+                derive(Debug, PartialEq, Sum, Product)
+            )]
+            pub struct Amount(u32);
+
+            let amounts = [Amount::new(2).unwrap(), Amount::new(3).unwrap()];
+            let total: Result<Amount, AmountError> = amounts.iter().sum();
+            assert_eq!(total, Amount::new(5));
+
+            let product: Result<Amount, AmountError> =
+                amounts.iter().chain(amounts.iter()).product();
+            assert_eq!(product, Err(AmountError::LessOrEqualViolated));
+        }
+
+        #[test]
+        fn test_sum_result_empty() {
+            #[nutype(
+                ops = result,
+                validate(greater_or_equal = 1),
+                derive(Debug, PartialEq, Sum)
+            )]
+            pub struct Quantity(u32);
+
+            let empty: Vec<Quantity> = vec![];
+            let total: Result<Quantity, QuantityError> = empty.iter().sum();
+            assert_eq!(total, Err(QuantityError::GreaterOrEqualViolated));
+        }
+    }
+}
+
+#[cfg(test)]
+mod const_fn {
+    use nutype::nutype;
+
+    #[nutype(
+        const_fn,
+        validate(greater_or_equal = 1, less_or_equal = 12),
+        derive(Debug)
+    )]
+    pub struct Month(u8);
+
+    #[nutype(const_fn, derive(Debug))]
+    pub struct Count(u32);
+
+    const DECEMBER: Month = match Month::new(12) {
+        Ok(month) => month,
+        Err(_) => panic!("Invalid month"),
+    };
+
+    const ZERO: Count = Count::new(0);
+
+    const DECEMBER_NUMBER: u8 = DECEMBER.into_inner();
+
+    const JANUARY: Month = Month::from_literal(1);
+
+    #[test]
+    fn test_const_new() {
+        assert_eq!(DECEMBER_NUMBER, 12);
+        assert_eq!(ZERO.into_inner(), 0);
+        assert_eq!(Month::new(13).unwrap_err(), MonthError::LessOrEqualViolated);
+    }
+
+    #[test]
+    fn test_from_literal() {
+        assert_eq!(JANUARY.into_inner(), 1);
+    }
+
+    #[test]
+    fn test_well_known_constants() {
+        #[nutype(const_fn, validate(less_or_equal = 100), derive(Debug))]
+        pub struct Percentage(u8);
+
+        const NONE: Percentage = Percentage::ZERO;
+        assert_eq!(NONE.into_inner(), 0);
+        assert_eq!(Percentage::MIN.into_inner(), 0);
+
+        assert_eq!(Count::MAX.into_inner(), u32::MAX);
+    }
+
+    #[test]
+    fn test_const_new_with_literal_bounds_without_const_fn() {
+        #[nutype(validate(greater_or_equal = 1, less_or_equal = 7), derive(Debug))]
+        pub struct Weekday(u8);
+
+        const SUNDAY: Weekday = Weekday::from_literal(7);
+        const SUNDAY_NUMBER: u8 = SUNDAY.into_inner();
+        assert_eq!(SUNDAY_NUMBER, 7);
+        assert_eq!(
+            Weekday::new(8).unwrap_err(),
+            WeekdayError::LessOrEqualViolated
+        );
+    }
+
+    #[test]
+    fn test_const_new_without_guard() {
+        #[nutype(derive(Debug, PartialEq))]
+        pub struct UserId(u64);
+
+        const ADMIN: UserId = UserId::new(1);
+        assert_eq!(ADMIN, UserId::new(1));
+    }
+
+    #[test]
+    fn test_well_known_constants_without_const_fn() {
+        #[nutype(validate(greater = 0, less = 10), derive(Debug))]
+        pub struct Digit(i8);
+
+        assert_eq!(Digit::MIN.into_inner(), 1);
+        assert_eq!(Digit::MAX.into_inner(), 9);
+    }
+
+    #[test]
+    fn test_well_known_constants_without_guard() {
+        #[nutype(derive(Debug))]
+        pub struct Id(i64);
+
+        assert_eq!(Id::MIN.into_inner(), i64::MIN);
+        assert_eq!(Id::ZERO.into_inner(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid Month: the value violates the validation rules")]
+    fn test_from_literal_panics_at_runtime() {
+        let _ = Month::from_literal(13);
+    }
+}
+
+#[cfg(test)]
+mod raw_bounds {
+    use super::*;
+
+    #[nutype(const_fn, validate(greater = 0, less_or_equal = 65_535), derive(Debug))]
+    pub struct Port(u32);
+
+    #[nutype(
+        validate(greater_or_equal = 1024, less_or_equal = Port::MAX_RAW),
+        derive(Debug, PartialEq)
+    )]
+    pub struct UserPort(u32);
+
+    #[nutype(extends = UserPort, validate(less = 50_000), derive(Debug))]
+    pub struct EphemeralPort(u32);
+
+    #[test]
+    fn test_raw_bounds() {
+        assert_eq!(Port::MIN_RAW, 1);
+        assert_eq!(Port::MAX_RAW, 65_535);
+        assert_eq!(UserPort::MIN_RAW, 1024);
+        assert_eq!(UserPort::MAX_RAW, Port::MAX_RAW);
+        assert_eq!(
+            UserPort::new(65_536),
+            Err(UserPortError::LessOrEqualViolated)
+        );
+
+        // The bounds of the base type are taken into account
+        assert_eq!(EphemeralPort::MIN_RAW, 1024);
+        assert_eq!(EphemeralPort::MAX_RAW, 49_999);
+    }
+
+    #[test]
+    fn test_well_known_constants_respect_bounds() {
+        assert_eq!(Port::MIN.into_inner(), 1);
+        assert_eq!(Port::MAX.into_inner(), 65_535);
+    }
+
+    #[test]
+    fn test_without_validation() {
+        #[nutype(derive(Debug))]
+        pub struct Count(u8);
+
+        assert_eq!(Count::MIN_RAW, 0);
+        assert_eq!(Count::MAX_RAW, u8::MAX);
+    }
+}
+
+#[cfg(test)]
+mod map {
+    use super::*;
+
+    #[test]
+    fn test_try_map() {
+        #[nutype(
+            validate(less_or_equal = 100),
+            derive(Debug, Clone, Copy, PartialEq),
+            methods(try_map)
+        )]
+        pub struct Percentage(u8);
+
+        let percentage = Percentage::new(40).unwrap();
+        let doubled = percentage.try_map(|p| p * 2).unwrap();
+        assert_eq!(doubled.into_inner(), 80);
+
+        let err = doubled.try_map(|p| p * 2).unwrap_err();
+        assert_eq!(err, PercentageError::LessOrEqualViolated);
+    }
+
+    #[test]
+    fn test_map_applies_sanitizers() {
+        #[nutype(sanitize(with = |n: i32| n.clamp(0, 10)), methods(map))]
+        pub struct Level(i32);
+
+        let level = Level::new(7).map(|n| n + 5);
+        assert_eq!(level.into_inner(), 10);
+    }
+}
+
+#[cfg(test)]
+mod sanitize_only {
+    use super::*;
+
+    #[test]
+    fn test_sanitize() {
+        #[nutype(sanitize(with = |n: i32| n.clamp(0, 100)))]
+        pub struct Volume(i32);
+
+        assert_eq!(Volume::sanitize(150), 100);
+        assert_eq!(Volume::sanitize(-5), 0);
+        assert_eq!(Volume::new(150).into_inner(), 100);
+    }
+}
+
+#[cfg(test)]
+mod set {
+    use super::*;
+
+    #[test]
+    fn test_set() {
+        #[nutype(
+            validate(less_or_equal = 100),
+            derive(Debug, PartialEq, AsRef),
+            methods(set)
+        )]
+        pub struct Percentage(u8);
+
+        let mut percentage = Percentage::new(10).unwrap();
+        percentage.set(50).unwrap();
+        assert_eq!(percentage.as_ref(), &50);
+
+        assert_eq!(
+            percentage.set(101),
+            Err(PercentageError::LessOrEqualViolated)
+        );
+        assert_eq!(percentage.as_ref(), &50);
+    }
+}
+
+#[cfg(test)]
+mod widen_into {
+    use super::*;
+
+    const MIN_PORT: u16 = 1;
+
+    #[nutype(validate(greater_or_equal = MIN_PORT), derive(Debug, PartialEq))]
+    pub struct Port(u16);
+
+    #[nutype(
+        validate(greater = 1023, predicate = |port| *port != 8443),
+        widen_into(Port, RawPort),
+        derive(Debug)
+    )]
+    pub struct NonPrivilegedPort(u16);
+
+    #[nutype(derive(Debug, PartialEq))]
+    pub struct RawPort(u16);
+
+    #[test]
+    fn test_widen_into() {
+        let port: Port = NonPrivilegedPort::new(8080).unwrap().into();
+        assert_eq!(port, Port::new(8080).unwrap());
+
+        let raw_port = RawPort::from(NonPrivilegedPort::new(1024).unwrap());
+        assert_eq!(raw_port, RawPort::new(1024));
+    }
+}
+
+#[cfg(test)]
+mod try_new_all {
+    use super::*;
+
+    #[test]
+    fn test_try_new_all() {
+        #[nutype(
+            validate(less_or_equal = 100),
+            derive(Debug, PartialEq),
+            methods(try_new_all)
+        )]
+        pub struct Percentage(u8);
+
+        let values = Percentage::try_new_all([10, 20]).unwrap();
+        assert_eq!(
+            values,
+            vec![Percentage::new(10).unwrap(), Percentage::new(20).unwrap()]
+        );
+
+        assert_eq!(
+            Percentage::try_new_all([10, 200, 30, 250]),
+            Err((1, PercentageError::LessOrEqualViolated))
+        );
+    }
+
+    #[test]
+    fn test_try_new_all_collecting_violations() {
+        #[nutype(
+            validate(greater_or_equal = 10, predicate = |n| n % 2 == 0),
+            all_violations,
+            derive(Debug, PartialEq),
+            methods(try_new_all)
+        )]
+        pub struct Even(i32);
+
+        assert_eq!(
+            Even::try_new_all_collecting_violations([12, 3, 14, 15]),
+            Err(vec![
+                (
+                    1,
+                    vec![
+                        EvenError::GreaterOrEqualViolated,
+                        EvenError::PredicateViolated
+                    ]
+                ),
+                (3, vec![EvenError::PredicateViolated]),
+            ])
+        );
+        assert_eq!(
+            Even::try_new_all_collecting_violations([12, 14])
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+}
+
+#[cfg(test)]
+mod new_clamped {
+    use super::*;
+
+    #[test]
+    fn test_new_clamped_with_inclusive_bounds() {
+        #[nutype(validate(greater_or_equal = 1, less_or_equal = 12))]
+        pub struct Month(u8);
+
+        assert_eq!(Month::new_clamped(0).into_inner(), 1);
+        assert_eq!(Month::new_clamped(7).into_inner(), 7);
+        assert_eq!(Month::new_clamped(200).into_inner(), 12);
+    }
+
+    #[test]
+    fn test_new_clamped_with_exclusive_bounds() {
+        #[nutype(validate(greater = -10, less = 10))]
+        pub struct Offset(i32);
+
+        assert_eq!(Offset::new_clamped(-100).into_inner(), -9);
+        assert_eq!(Offset::new_clamped(100).into_inner(), 9);
+    }
+
+    #[test]
+    fn test_new_clamped_applies_sanitizers_first() {
+        #[nutype(
+            sanitize(with = |n: i32| n * 10),
+            validate(less_or_equal = 50)
+        )]
+        pub struct Score(i32);
+
+        assert_eq!(Score::new_clamped(3).into_inner(), 30);
+        assert_eq!(Score::new_clamped(7).into_inner(), 50);
+    }
+}
+
+#[cfg(test)]
+mod const_generics {
+    use super::*;
+
+    #[nutype(
+        validate(greater_or_equal = 1, less_or_equal = MAX),
+        derive(Debug, Clone, Copy, PartialEq, Eq, Display, FromStr, TryFrom, Into)
+    )]
+    pub struct Rating<const MAX: u8>(u8);
+
+    #[test]
+    fn test_bounds_from_const_generic() {
+        let stars = Rating::<5>::new(5).unwrap();
+        assert_eq!(stars.into_inner(), 5);
+        assert_eq!(Rating::<5>::new(6), Err(RatingError::LessOrEqualViolated));
+        assert!(Rating::<10>::new(6).is_ok());
+
+        let score: Rating<10> = "7".parse().unwrap();
+        assert_eq!(u8::from(score), 7);
+        assert_eq!(Rating::<10>::new_clamped(42).into_inner(), 10);
+    }
+
+    #[test]
+    fn test_custom_error_name() {
+        #[nutype(validate(less_or_equal = N), error = BoundedErr, derive(Debug))]
+        pub struct Bounded<const N: u32>(u32);
+
+        assert_eq!(Bounded::<3>::new(3).unwrap().into_inner(), 3);
+        let err: BoundedErr<3> = Bounded::<3>::new(4).unwrap_err();
+        assert_eq!(err, BoundedErr::LessOrEqualViolated);
+    }
+}
+
+#[cfg(test)]
+mod named_field {
+    use super::*;
+
+    #[nutype(
+        sanitize(with = |v| v.abs()),
+        validate(less_or_equal = 1_000_000),
+        derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, TryFrom, Into)
+    )]
+    pub struct Amount {
+        value: i64,
+    }
+
+    #[test]
+    fn test_named_field() {
+        let amount = Amount::new(-150).unwrap();
+        assert_eq!(amount.into_inner(), 150);
+        assert_eq!(
+            Amount::new(1_000_001),
+            Err(AmountError::LessOrEqualViolated)
+        );
+
+        let amount: Amount = 42.try_into().unwrap();
+        assert_eq!(i64::from(amount), 42);
+        assert!(amount < Amount::new(43).unwrap());
+    }
+}
+
+#[cfg(test)]
+mod extends {
+    use super::*;
+
+    #[nutype(validate(less_or_equal = 150), derive(Debug, PartialEq))]
+    pub struct Age(u8);
+
+    #[nutype(
+        extends = Age,
+        validate(greater_or_equal = 18, predicate = |age| age.is_multiple_of(2)),
+        all_violations,
+        derive(Debug, PartialEq)
+    )]
+    pub struct EvenAdultAge(u8);
+
+    #[test]
+    fn test_all_violations() {
+        assert_eq!(EvenAdultAge::new(20).unwrap().into_inner(), 20);
+        assert_eq!(
+            EvenAdultAge::new_collecting_violations(151),
+            Err(vec![
+                EvenAdultAgeError::AgeViolated(AgeError::LessOrEqualViolated),
+                EvenAdultAgeError::PredicateViolated,
+            ])
+        );
+        assert_eq!(
+            EvenAdultAge::new(152),
+            Err(EvenAdultAgeError::AgeViolated(
+                AgeError::LessOrEqualViolated
+            ))
+        );
+    }
+
+    #[test]
+    fn test_into_base_type() {
+        let age: Age = EvenAdultAge::new(30).unwrap().into();
+        assert_eq!(age, Age::new(30).unwrap());
+    }
+}
+
+#[cfg(test)]
+mod macro_rules_wrapper {
+    use super::*;
+
+    pub const MIN_DELTA: i32 = 10;
+
+    pub fn is_not_reserved<T: Into<u64> + Copy>(id: &T) -> bool {
+        (*id).into() != 42
+    }
+
+    macro_rules! define_id {
+        ($(#[$attr:meta])* $vis:vis $name:ident($ty:ty)) => {
+            #[nutype(
+                validate(greater = 0, predicate = $crate::macro_rules_wrapper::is_not_reserved),
+                derive(Debug, Clone, Copy, PartialEq, TryFrom)
+            )]
+            $(#[$attr])*
+            $vis struct $name($ty);
+        };
+    }
+
+    define_id!(
+        /// The id of a user.
+        pub UserId(u64)
+    );
+    define_id!(OrderId(u32));
+
+    macro_rules! define_range {
+        ($name:ident, $ty:ty, $min:expr, $max:expr, $predicate:expr) => {
+            #[nutype(
+                validate(greater_or_equal = $min, less_or_equal = ($max), predicate = $predicate),
+                derive(Debug, PartialEq)
+            )]
+            pub struct $name($ty);
+        };
+    }
+
+    define_range!(Delta, i32, -MIN_DELTA, 10, (|n: &i32| *n != 0));
+    define_range!(Level, i8, -5, (5), |n: &i8| n.is_positive());
+
+    #[test]
+    fn test_id() {
+        assert_eq!(UserId::try_from(7).unwrap().into_inner(), 7);
+        assert_eq!(OrderId::new(0), Err(OrderIdError::GreaterViolated));
+        assert_eq!(OrderId::new(42), Err(OrderIdError::PredicateViolated));
+    }
+
+    #[test]
+    fn test_interpolated_bounds() {
+        assert_eq!(Delta::new(-10).unwrap().into_inner(), -10);
+        assert_eq!(Delta::new(-11), Err(DeltaError::GreaterOrEqualViolated));
+        assert_eq!(Delta::new(11), Err(DeltaError::LessOrEqualViolated));
+        assert_eq!(Delta::new(0), Err(DeltaError::PredicateViolated));
+
+        assert_eq!(Level::new(5).unwrap().into_inner(), 5);
+        assert_eq!(Level::new(-1), Err(LevelError::PredicateViolated));
+    }
+}
+
+#[cfg(test)]
+mod preset {
+    use super::*;
+
+    #[nutype(preset = percentage, derive(Debug, PartialEq))]
+    pub struct Progress(u8);
+
+    #[nutype(preset = percentage, derive(Debug, PartialEq))]
+    pub struct Change(i32);
+
+    #[test]
+    fn test_percentage() {
+        assert_eq!(Progress::new(100).unwrap().into_inner(), 100);
+        assert_eq!(Progress::new(101), Err(ProgressError::LessOrEqualViolated));
+
+        assert_eq!(Change::new(0).unwrap().into_inner(), 0);
+        assert_eq!(Change::new(-1), Err(ChangeError::GreaterOrEqualViolated));
+    }
+}
+
+#[cfg(test)]
+mod conditional_rules {
+    use super::*;
+
+    #[nutype(
+        validate(greater_or_equal = 1, cfg_attr(not(test), less_or_equal = 10)),
+        derive(Debug, PartialEq)
+    )]
+    pub struct Limit(u32);
+
+    #[test]
+    fn test_disabled_rules() {
+        assert_eq!(Limit::new(1000).unwrap().into_inner(), 1000);
+        assert_eq!(Limit::new(0), Err(LimitError::GreaterOrEqualViolated));
+    }
+
+    #[test]
+    fn test_raw_bounds_of_enabled_rules() {
+        assert_eq!(Limit::MIN_RAW, 1);
+        assert_eq!(Limit::MAX_RAW, u32::MAX);
+    }
+}
+
+#[cfg(test)]
+mod env_bounds {
+    use super::*;
+
+    // The variables are set by build.rs of test_suite
+    #[nutype(
+        validate(
+            greater_or_equal = env!("NUTYPE_TEST_FLAG_MIN"),
+            less_or_equal = env!("NUTYPE_TEST_FLAG_MAX"),
+        ),
+        derive(Debug, PartialEq)
+    )]
+    pub struct Flag(u8);
+
+    #[test]
+    fn test_bounds_from_env() {
+        assert_eq!(Flag::new(1).unwrap().into_inner(), 1);
+        assert_eq!(Flag::new(2), Err(FlagError::LessOrEqualViolated));
+    }
+}
+
+#[cfg(test)]
+mod docs {
+    use super::*;
+
+    /// Age of a person in years.
+    #[nutype(
+        validate(less_or_equal = 150),
+        docs(
+            new = "Creates an age.",
+            error = "The reason why a number is not a valid age.",
+            parse_error = "The reason why a string cannot be parsed into an age.",
+        ),
+        derive(Debug, PartialEq, FromStr)
+    )]
+    pub struct Age(u8);
+
+    #[test]
+    fn test_documented_items() {
+        assert_eq!(Age::new(30).unwrap().into_inner(), 30);
+        assert!(matches!(
+            "200".parse::<Age>(),
+            Err(AgeParseError::Validate(AgeError::LessOrEqualViolated))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod accessor {
+    use super::*;
+
+    #[nutype(const_fn, validate(less_or_equal = 100), accessor = get, derive(Debug))]
+    pub struct Percent(u8);
+
+    const HALF: Percent = match Percent::new(50) {
+        Ok(percent) => percent,
+        Err(_) => panic!("50 is a valid percent"),
+    };
+
+    #[test]
+    fn test_const_accessor() {
+        const VALUE: u8 = *HALF.get();
+        assert_eq!(VALUE, 50);
+    }
+}
+
+#[cfg(test)]
+mod repr_transparent {
+    use super::*;
+
+    #[nutype(repr = transparent, validate(less_or_equal = 100), derive(Debug, PartialEq))]
+    pub struct Percentage(u8);
+
+    #[nutype(repr = transparent, derive(Debug, Clone, Copy, PartialEq))]
+    pub struct Celsius(i32);
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(std::mem::size_of::<Percentage>(), std::mem::size_of::<u8>());
+        assert_eq!(std::mem::align_of::<Celsius>(), std::mem::align_of::<i32>());
+    }
+
+    #[test]
+    fn test_vec_conversions_with_validation() {
+        let percentages = Percentage::try_from_inner_vec(vec![0, 50, 100]).unwrap();
+        assert_eq!(percentages[1], Percentage::new(50).unwrap());
+        assert_eq!(Percentage::into_inner_vec(percentages), vec![0, 50, 100]);
+
+        assert_eq!(
+            Percentage::try_from_inner_vec(vec![10, 101, 200]).unwrap_err(),
+            (1, PercentageError::LessOrEqualViolated)
+        );
+    }
+
+    #[test]
+    fn test_slice_conversions_with_validation() {
+        let raw = [1, 2, 3];
+        let percentages = Percentage::try_from_inner_slice(&raw).unwrap();
+        assert_eq!(Percentage::as_inner_slice(percentages), &raw);
+
+        assert_eq!(
+            Percentage::try_from_inner_slice(&[1, 255]).unwrap_err(),
+            (1, PercentageError::LessOrEqualViolated)
+        );
+    }
+
+    #[test]
+    fn test_conversions_without_guards() {
+        let temperatures = Celsius::from_inner_vec(vec![-10, 0, 25]);
+        assert_eq!(temperatures[0].into_inner(), -10);
+        assert_eq!(Celsius::from_inner_slice(&[36])[0], Celsius::new(36));
+    }
+}
+
+#[cfg(test)]
+mod factory {
+    use super::*;
+
+    #[test]
+    fn test_runtime_bounds() {
+        #[nutype(
+            factory,
+            validate(greater_or_equal = 1, less_or_equal = 1000),
+            error_fields(bounds),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Quota(u32);
+
+        let factory = Quota::with_less_or_equal(100).with_greater_or_equal(10);
+        assert_eq!(factory.build(50).unwrap().into_inner(), 50);
+        assert_eq!(
+            factory.build(500),
+            Err(QuotaError::LessOrEqualViolated { max: 100 })
+        );
+        assert_eq!(
+            factory.build(5),
+            Err(QuotaError::GreaterOrEqualViolated { min: 10 })
+        );
+        assert_eq!(
+            factory.build(0),
+            Err(QuotaError::GreaterOrEqualViolated { min: 1 })
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "new_unchecked")]
+mod new_unchecked {
+    use super::*;
+
+    #[test]
+    fn test_new_unchecked() {
+        #[nutype(new_unchecked(no_debug_assert), validate(greater_or_equal = 50))]
+        pub struct Dist(u32);
+
+        let dist = unsafe { Dist::new_unchecked(3) };
+        assert_eq!(dist.into_inner(), 3);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "schemars08")]
+mod derive_schemars_json_schema {
+    use super::*;
+    use schemars::{schema_for, JsonSchema};
+
+    #[test]
+    fn test_json_schema_derive() {
+        #[nutype(derive(JsonSchema))]
+        pub struct CustomerId(i128);
+
+        assert_eq!(CustomerId::schema_name(), "CustomerId");
+        // Make sure it compiles
+        let _schema = schema_for!(CustomerId);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "juniper")]
+mod derive_juniper_graphql_scalar {
+    use super::*;
+    use juniper::{graphql_object, EmptyMutation, EmptySubscription, RootNode, Value, Variables};
+
+    #[nutype(validate(greater_or_equal = 1), derive(GraphQLScalar))]
+    pub struct Quantity(i32);
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn echo(input: Quantity) -> Quantity {
+            input
+        }
+    }
+
+    fn execute(query: &str) -> Result<Value, String> {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        juniper::execute_sync(query, None, &schema, &Variables::new(), &())
+            .map(|(value, _errors)| value)
+            .map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn test_valid_input() {
+        let value = execute(r#"{ echo(input: 3) }"#).unwrap();
+        assert_eq!(value.to_string(), r#"{"echo": 3}"#);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let err = execute(r#"{ echo(input: 0) }"#).unwrap_err();
+        assert!(err.contains("Quantity"));
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "rocket")]
+mod derive_rocket {
+    use super::*;
+    use rocket::form::{FromFormField, ValueField};
+    use rocket::http::Status;
+    use rocket::request::FromParam;
+
+    #[nutype(
+        validate(greater_or_equal = 1),
+        derive(Debug, PartialEq, FromParam, FromFormField)
+    )]
+    pub struct Quantity(u32);
+
+    #[test]
+    fn test_from_param() {
+        assert_eq!(
+            Quantity::from_param("3").unwrap(),
+            Quantity::new(3).unwrap()
+        );
+
+        let errors = Quantity::from_param("0").unwrap_err();
+        assert_eq!(errors.status(), Status::UnprocessableEntity);
+    }
+
+    #[test]
+    fn test_from_form_field() {
+        let field = ValueField::from_value("3");
+        assert_eq!(
+            Quantity::from_value(field).unwrap(),
+            Quantity::new(3).unwrap()
+        );
+
+        let field = ValueField::from_value("0");
+        let errors = Quantity::from_value(field).unwrap_err();
+        assert_eq!(errors.status(), Status::UnprocessableEntity);
+        assert_eq!(
+            errors[0].to_string(),
+            QuantityError::GreaterOrEqualViolated.to_string()
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "actix")]
+mod derive_actix_from_request {
+    use super::*;
+    use actix_web::{dev::Payload, http::StatusCode, test::TestRequest, FromRequest};
+
+    #[nutype(validate(greater_or_equal = 1), derive(Debug, PartialEq, FromRequest))]
+    pub struct Quantity(u32);
+
+    fn extract(param: &str) -> Result<Quantity, actix_web::Error> {
+        let req = TestRequest::default()
+            .param("quantity", param.to_owned())
+            .to_http_request();
+        Quantity::from_request(&req, &mut Payload::None).into_inner()
+    }
+
+    #[test]
+    fn test_from_request() {
+        assert_eq!(extract("3").unwrap(), Quantity::new(3).unwrap());
+    }
+
+    #[test]
+    fn test_from_request_with_invalid_value() {
+        let err = extract("0").unwrap_err();
+        assert_eq!(
+            err.as_response_error().status_code(),
+            StatusCode::UNPROCESSABLE_ENTITY
+        );
+        assert_eq!(
+            err.to_string(),
+            QuantityError::GreaterOrEqualViolated.to_string()
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "clap")]
+mod derive_clap_value_parser_factory {
+    use super::*;
+    use clap::Parser;
+
+    #[nutype(
+        validate(greater_or_equal = 1),
+        derive(Debug, Clone, PartialEq, ValueParserFactory)
+    )]
+    pub struct Quantity(u32);
+
+    #[derive(Parser, Debug)]
+    struct Cli {
+        #[arg(long)]
+        quantity: Quantity,
+    }
+
+    #[test]
+    fn test_valid_arg() {
+        let cli = Cli::try_parse_from(["app", "--quantity", "3"]).unwrap();
+        assert_eq!(cli.quantity, Quantity::new(3).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_arg() {
+        let err = Cli::try_parse_from(["app", "--quantity", "0"]).unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::ValueValidation);
+        assert!(err
+            .to_string()
+            .contains(&QuantityError::GreaterOrEqualViolated.to_string()));
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "rand")]
+mod derive_rand_distribution {
+    use super::*;
+    use rand::distributions::{Distribution, Standard, Uniform};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[nutype(
+        validate(greater = 10, less_or_equal = 20),
+        derive(Debug, Clone, Copy, PartialEq, PartialOrd, Distribution)
+    )]
+    pub struct Level(u8);
+
+    #[test]
+    fn test_standard_distribution() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let level: Level = rng.gen();
+            assert!((11..=20).contains(&level.into_inner()));
+        }
+    }
+
+    #[test]
+    fn test_uniform_distribution() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let low = Level::new(12).unwrap();
+        let high = Level::new(14).unwrap();
+        let uniform = Uniform::new_inclusive(low, high);
+        for _ in 0..1000 {
+            let level = uniform.sample(&mut rng);
+            assert!(low <= level && level <= high);
+        }
+        let level: Level = Standard.sample(&mut rng);
+        assert!((11..=20).contains(&level.into_inner()));
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "num-traits")]
+mod derive_num_traits {
+    use super::*;
+    use num_traits::{Bounded, FromPrimitive, One, ToPrimitive, Zero};
+
+    #[nutype(
+        validate(less_or_equal = 100),
+        derive(Debug, PartialEq, Zero, One, Bounded, ToPrimitive, FromPrimitive)
+    )]
+    pub struct Score(u8);
+
+    // Zero and One require Add and Mul to be implemented
+    impl std::ops::Add for Score {
+        type Output = Score;
+
+        fn add(self, other: Score) -> Score {
+            Score::new(self.into_inner() + other.into_inner()).unwrap()
+        }
+    }
+
+    impl std::ops::Mul for Score {
+        type Output = Score;
+
+        fn mul(self, other: Score) -> Score {
+            Score::new(self.into_inner() * other.into_inner()).unwrap()
+        }
+    }
+
+    #[test]
+    fn test_zero_and_one() {
+        assert_eq!(Score::zero(), Score::new(0).unwrap());
+        assert!(Score::zero().is_zero());
+        assert_eq!(Score::one(), Score::new(1).unwrap());
+    }
+
+    const MAX_LEVEL: i16 = 10;
+
+    #[nutype(
+        validate(greater_or_equal = -MAX_LEVEL, less_or_equal = MAX_LEVEL),
+        derive(Debug, PartialEq, Zero, One)
+    )]
+    pub struct Level(i16);
+
+    impl std::ops::Add for Level {
+        type Output = Level;
+
+        fn add(self, other: Level) -> Level {
+            Level::new(self.into_inner() + other.into_inner()).unwrap()
+        }
+    }
+
+    impl std::ops::Mul for Level {
+        type Output = Level;
+
+        fn mul(self, other: Level) -> Level {
+            Level::new(self.into_inner() * other.into_inner()).unwrap()
+        }
+    }
+
+    #[test]
+    fn test_zero_and_one_with_expression_bounds() {
+        assert_eq!(Level::zero(), Level::new(0).unwrap());
+        assert_eq!(Level::one(), Level::new(1).unwrap());
+    }
+
+    #[test]
+    fn test_bounded() {
+        assert_eq!(Score::min_value(), Score::new(0).unwrap());
+        assert_eq!(Score::max_value(), Score::new(100).unwrap());
+    }
+
+    #[test]
+    fn test_to_primitive() {
+        let score = Score::new(42).unwrap();
+        assert_eq!(score.to_i64(), Some(42));
+        assert_eq!(score.to_f64(), Some(42.0));
+    }
+
+    #[test]
+    fn test_from_primitive() {
+        assert_eq!(Score::from_i64(42), Some(Score::new(42).unwrap()));
+        // Out of the inner type range
+        assert_eq!(Score::from_i64(-1), None);
+        // Violates the validation rules
+        assert_eq!(Score::from_i64(101), None);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "thiserror")]
+mod thiserror_errors {
+    use super::*;
+    use std::error::Error;
+    use std::str::FromStr;
+
+    #[nutype(
+        validate(greater_or_equal = 18),
+        derive(Debug, FromStr),
+        error_integrations(thiserror)
+    )]
+    pub struct Age(u8);
+
+    #[derive(Debug, thiserror::Error)]
+    enum RegistrationError {
+        #[error("invalid age")]
+        InvalidAge(#[from] AgeError),
+    }
+
+    fn register(age: u8) -> Result<Age, RegistrationError> {
+        let age = Age::new(age)?;
+        Ok(age)
+    }
+
+    #[test]
+    fn test_from_conversion() {
+        let err = register(7).unwrap_err();
+        let source = err.source().unwrap();
+        assert_eq!(
+            source.to_string(),
+            "Age is too small. The value must be greater or equal to 18."
+        );
+        assert!(register(21).is_ok());
+    }
+
+    #[test]
+    fn test_parse_error_source() {
+        let err = Age::from_str("abc").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse Age: invalid digit found in string"
+        );
+        assert!(err.source().unwrap().is::<std::num::ParseIntError>());
+
+        let err = Age::from_str("7").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse Age: Age is too small. The value must be greater or equal to 18."
+        );
+        assert!(err.source().unwrap().is::<AgeError>());
+    }
+
+    #[test]
+    fn test_parse_error_from_validation_error() {
+        let err: AgeParseError = AgeError::GreaterOrEqualViolated.into();
+        assert!(matches!(
+            err,
+            AgeParseError::Validate(AgeError::GreaterOrEqualViolated)
+        ));
+    }
+}
+
+mod metadata {
+    use super::*;
+
+    fn is_even(value: &i32) -> bool {
+        value % 2 == 0
+    }
+
+    #[nutype(metadata, validate(greater = -10, less_or_equal = 100, predicate = is_even))]
+    pub struct Even(i32);
+
+    #[test]
+    fn test_metadata() {
+        assert_eq!(
+            Even::NUTYPE_METADATA,
+            r#"{"name":"Even","inner_type":"i32","sanitizers":[],"validators":[{"kind":"greater","value":-10},{"kind":"less_or_equal","value":100},{"kind":"predicate","function":"is_even"}]}"#
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "miette")]
+mod miette_diagnostic {
+    use miette::Diagnostic;
+    use nutype::nutype;
+
+    #[test]
+    fn test_help_with_range() {
+        #[nutype(
+            validate(greater_or_equal = 0, less_or_equal = 100),
+            derive(Debug),
+            error_integrations(miette)
+        )]
+        struct Percentage(u8);
+
+        let err = Percentage::new(101).unwrap_err();
+        assert_eq!(
+            err.help().unwrap().to_string(),
+            "expected a value between 0 and 100"
+        );
+        assert_eq!(
+            Diagnostic::code(&err).unwrap().to_string(),
+            "percentage.less_or_equal"
+        );
+    }
+
+    #[test]
+    fn test_help_with_exclusive_bounds() {
+        #[nutype(
+            validate(greater = 0, less = 10),
+            derive(Debug),
+            error_integrations(miette)
+        )]
+        struct Digit(i32);
+
+        let err = Digit::new(0).unwrap_err();
+        assert_eq!(
+            err.help().unwrap().to_string(),
+            "expected a value greater than 0 and less than 10"
+        );
+    }
+
+    #[test]
+    fn test_help_with_predicate() {
+        #[nutype(
+            validate(predicate = |n| n % 2 == 0),
+            derive(Debug),
+            error_integrations(miette)
+        )]
+        struct Even(i32);
+
+        let err = Even::new(3).unwrap_err();
+        assert!(err.help().is_none());
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "web")]
+mod web_status_code {
+    use nutype::nutype;
+
+    #[test]
+    fn test_status_code() {
+        #[nutype(
+            validate(greater_or_equal = 18, predicate = |age| *age != 42),
+            status_codes(predicate = 409)
+        )]
+        struct Age(u8);
+
+        assert_eq!(AgeError::GreaterOrEqualViolated.status_code(), 422);
+        assert_eq!(AgeError::PredicateViolated.status_code(), 409);
     }
 }
@@ -226,6 +226,120 @@ mod validators {
         assert_eq!(EmailError::NotEmptyViolated.to_string(), "Email is empty.");
     }
 
+    #[test]
+    fn test_error_display_with_custom_messages() {
+        #[nutype(
+            validate(not_empty, len_char_min = 3, len_char_max = 20),
+            messages(len_char_min = "username must be at least 3 characters")
+        )]
+        pub struct Username(String);
+
+        assert_eq!(
+            UsernameError::LenCharMinViolated.to_string(),
+            "username must be at least 3 characters"
+        );
+        // Validators without a custom message keep the default one
+        assert_eq!(
+            UsernameError::NotEmptyViolated.to_string(),
+            "Username is empty."
+        );
+    }
+
+    #[test]
+    fn test_error_without_display() {
+        #[nutype(validate(not_empty), error_display = none)]
+        pub struct Username(String);
+
+        impl core::fmt::Display for UsernameError {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "custom")
+            }
+        }
+
+        assert_eq!(UsernameError::NotEmptyViolated.to_string(), "custom");
+    }
+
+    #[test]
+    fn test_error_with_bounds() {
+        #[nutype(
+            validate(not_empty, len_char_min = 3, len_char_max = 20),
+            error_fields(value, bounds),
+            derive(Debug)
+        )]
+        pub struct Username(String);
+
+        assert_eq!(
+            Username::new("ab").unwrap_err(),
+            UsernameError::LenCharMinViolated {
+                value: "ab".to_string(),
+                min: 3
+            }
+        );
+        assert_eq!(
+            Username::new("").unwrap_err(),
+            UsernameError::NotEmptyViolated {
+                value: "".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_all_violations() {
+        #[nutype(
+            sanitize(trim),
+            validate(len_char_min = 5, predicate = |s| s.chars().all(char::is_alphanumeric)),
+            all_violations,
+            derive(Debug)
+        )]
+        pub struct Username(String);
+
+        assert_eq!(
+            Username::new_collecting_violations(" a-b ").unwrap_err(),
+            vec![
+                UsernameError::LenCharMinViolated,
+                UsernameError::PredicateViolated
+            ]
+        );
+        assert_eq!(
+            Username::new_collecting_violations(" a-bcdef ").unwrap_err(),
+            vec![UsernameError::PredicateViolated]
+        );
+        assert_eq!(
+            Username::new_collecting_violations(" abcdef ")
+                .unwrap()
+                .into_inner(),
+            "abcdef"
+        );
+        // `new()` still stops on the first violation
+        assert_eq!(
+            Username::new(" a-b ").unwrap_err(),
+            UsernameError::LenCharMinViolated
+        );
+    }
+
+    #[test]
+    fn test_error_with_value() {
+        #[nutype(
+            sanitize(trim),
+            validate(len_char_max = 5),
+            error_fields(value),
+            derive(Debug)
+        )]
+        pub struct Login(String);
+
+        let err = Login::new("  too long  ").unwrap_err();
+        assert_eq!(
+            err,
+            LoginError::LenCharMaxViolated {
+                value: "too long".to_string()
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Login is too long. The value length must be less than 5 character(s)."
+        );
+    }
+
     mod when_boundaries_defined_as_constants {
         use super::*;
 
@@ -253,6 +367,63 @@ mod validators {
             );
         }
     }
+
+    mod when_boundaries_defined_as_const_expressions {
+        use super::*;
+
+        mod limits {
+            pub const MAX_NAME_LEN: usize = 8;
+        }
+
+        #[nutype(
+            validate(len_char_min = { limits::MAX_NAME_LEN / 4 }, len_char_max = limits::MAX_NAME_LEN * 2),
+            derive(Debug, PartialEq)
+        )]
+        struct Nickname(String);
+
+        #[test]
+        fn test_boundaries_defined_as_const_expressions() {
+            assert_eq!(Nickname::new("a"), Err(NicknameError::LenCharMinViolated));
+            assert_eq!(Nickname::new("ab").unwrap().into_inner(), "ab");
+            assert_eq!(
+                Nickname::new("a".repeat(17)),
+                Err(NicknameError::LenCharMaxViolated)
+            );
+        }
+    }
+
+    mod when_display_is_routed_through_function {
+        use super::*;
+        use core::fmt;
+
+        fn localize(error: &UsernameError, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match error {
+                UsernameError::NotEmptyViolated => write!(f, "Benutzername ist leer."),
+                UsernameError::LenCharMinViolated { min } => {
+                    write!(f, "Benutzername muss mindestens {min} Zeichen haben.")
+                }
+            }
+        }
+
+        #[nutype(
+            validate(not_empty, len_char_min = 3),
+            error_fields(bounds),
+            error_display = localize
+        )]
+        pub struct Username(String);
+
+        #[test]
+        fn test_error_display_with_custom_function() {
+            assert_eq!(
+                UsernameError::NotEmptyViolated.to_string(),
+                "Benutzername ist leer."
+            );
+            assert_eq!(
+                UsernameError::LenCharMinViolated { min: 3 }.to_string(),
+                "Benutzername muss mindestens 3 Zeichen haben."
+            );
+        }
+    }
 }
 
 #[cfg(test)]
@@ -380,7 +551,7 @@ mod derives {
 
         // Let's do something with deref-coercion:
         assert_eq!(name.len(), 4);
-        assert_eq!(name.is_empty(), false);
+        assert!(!name.is_empty());
     }
 
     #[test]
@@ -431,6 +602,20 @@ mod derives {
         assert_eq!(error, NameError::NotEmptyViolated);
     }
 
+    #[test]
+    fn test_try_from_cow() {
+        use std::borrow::Cow;
+
+        #[nutype(validate(not_empty), derive(Debug, TryFrom))]
+        pub struct Name(String);
+
+        let name = Name::try_from(Cow::Borrowed("Anna")).unwrap();
+        assert_eq!(name.into_inner(), "Anna");
+
+        let error = Name::try_from(Cow::Owned(String::new())).unwrap_err();
+        assert_eq!(error, NameError::NotEmptyViolated);
+    }
+
     #[test]
     fn test_trait_display() {
         #[nutype(derive(Display))]
@@ -463,11 +648,49 @@ mod derives {
         #[test]
         #[should_panic(expected = "Default value for type `Name` is invalid")]
         fn test_default_with_validation_when_invalid() {
-            #[nutype(validate(len_char_min = 5), default = "Nope", derive(Default))]
+            #[nutype(validate(len_char_min = 5), default = "Nope".to_string(), derive(Default))]
             pub struct Name(String);
 
             Name::default();
         }
+
+        #[test]
+        fn test_default_is_sanitized() {
+            #[nutype(
+                sanitize(trim, lowercase),
+                validate(len_char_min = 3),
+                default = "  Foo ",
+                derive(Default)
+            )]
+            pub struct Name(String);
+
+            assert_eq!(Name::default().into_inner(), "foo");
+        }
+
+        static GUEST_COUNTER: std::sync::atomic::AtomicUsize =
+            std::sync::atomic::AtomicUsize::new(0);
+
+        fn next_guest_name() -> String {
+            let n = GUEST_COUNTER.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            format!(" Guest-{n} ")
+        }
+
+        #[test]
+        fn test_default_with_expression() {
+            #[nutype(
+                sanitize(trim, lowercase),
+                validate(not_empty),
+                default = next_guest_name(),
+                derive(Default)
+            )]
+            pub struct Username(String);
+
+            // The expression is evaluated on every call and the result is sanitized
+            let first = Username::default().into_inner();
+            let second = Username::default().into_inner();
+            assert!(first.starts_with("guest-"));
+            assert_ne!(first, second);
+        }
     }
 
     #[cfg(feature = "serde")]
@@ -502,13 +725,16 @@ mod derives {
             fn test_trait_deserialize_with_validation() {
                 #[nutype(
                     validate(predicate = |address| address.contains('@') ),
-                    derive(Deserialize),
+                    derive(Deserialize, Debug),
                 )]
                 pub struct NaiveEmail(String);
 
                 {
-                    let res: Result<NaiveEmail, _> = serde_json::from_str("\"foobar\"");
-                    assert!(res.is_err());
+                    let err = serde_json::from_str::<NaiveEmail>("\"foobar\"").unwrap_err();
+                    assert_eq!(
+                        err.to_string(),
+                        "Expected valid NaiveEmail, constraint `predicate` is violated: NaiveEmail failed the predicate test."
+                    );
                 }
 
                 {
@@ -518,6 +744,79 @@ mod derives {
             }
         }
 
+        #[test]
+        fn test_serde_default() {
+            #[nutype(
+                validate(not_empty),
+                default = "localhost",
+                derive(Deserialize, Debug, PartialEq)
+            )]
+            pub struct Host(String);
+
+            #[derive(serde::Deserialize)]
+            struct Config {
+                #[serde(default = "Host::serde_default")]
+                host: Host,
+            }
+
+            let config: Config = serde_json::from_str("{}").unwrap();
+            assert_eq!(config.host, Host::new("localhost").unwrap());
+
+            let config: Config = serde_json::from_str(r#"{"host":"example.com"}"#).unwrap();
+            assert_eq!(config.host, Host::new("example.com").unwrap());
+        }
+
+        #[test]
+        fn test_deserialize_with_skip_sanitization() {
+            #[nutype(
+                sanitize(trim, lowercase),
+                validate(not_empty),
+                derive(Deserialize, Debug),
+                serde(skip_sanitization)
+            )]
+            pub struct Tag(String);
+
+            let tag: Tag = serde_json::from_str("\" Rust \"").unwrap();
+            assert_eq!(tag.into_inner(), " Rust ");
+
+            // Validation still runs
+            let res: Result<Tag, _> = serde_json::from_str("\"\"");
+            assert!(res.is_err());
+
+            // `new()` still sanitizes
+            assert_eq!(Tag::new(" Rust ").unwrap().into_inner(), "rust");
+        }
+
+        #[test]
+        fn test_deserialize_with_skip_sanitization_without_validation() {
+            #[nutype(sanitize(trim), derive(Deserialize), serde(skip_sanitization))]
+            pub struct Comment(String);
+
+            let comment: Comment = serde_json::from_str("\" hi \"").unwrap();
+            assert_eq!(comment.into_inner(), " hi ");
+        }
+
+        mod wrapped {
+            use super::*;
+
+            #[test]
+            fn test_wrapped_roundtrip() {
+                #[nutype(
+                    sanitize(trim),
+                    derive(Serialize, Deserialize, PartialEq, Debug),
+                    serde(wrap = "name")
+                )]
+                pub struct Name(String);
+
+                let name = Name::new("Tom");
+                let json = serde_json::to_string(&name).unwrap();
+                assert_eq!(json, r#"{"name":"Tom"}"#);
+
+                let deserialized: Name = serde_json::from_str(r#"{"name":"  Tom "}"#).unwrap();
+                assert_eq!(deserialized, name);
+            }
+        }
+
         mod ron_format {
             use super::*;
 
@@ -554,97 +853,1426 @@ mod derives {
     }
 }
 
-#[cfg(feature = "new_unchecked")]
-mod new_unchecked {
+mod constructor {
     use super::*;
 
     #[test]
-    fn test_new_unchecked() {
-        #[nutype(new_unchecked, sanitize(trim), validate(len_char_min = 8))]
-        pub struct Name(String);
+    fn test_custom_constructor_name() {
+        #[nutype(
+            constructor = parse,
+            sanitize(trim),
+            validate(not_empty),
+            derive(Debug, PartialEq, TryFrom, FromStr)
+        )]
+        pub struct Username(String);
 
-        let name = unsafe { Name::new_unchecked(" boo ".to_string()) };
-        assert_eq!(name.into_inner(), " boo ");
+        // The user can have their own `new` without a conflict
+        impl Username {
+            pub fn new() -> Self {
+                Self::parse("anonymous").unwrap()
+            }
+        }
+
+        assert_eq!(Username::parse("  bob ").unwrap().into_inner(), "bob");
+        assert_eq!(Username::parse("  "), Err(UsernameError::NotEmptyViolated));
+        assert_eq!(Username::new().into_inner(), "anonymous");
+
+        // Traits use the custom constructor as well
+        assert_eq!(Username::try_from(" bob ").unwrap().into_inner(), "bob");
+        assert_eq!("alice".parse::<Username>().unwrap().into_inner(), "alice");
+    }
+
+    mod constructor_visibility {
+        mod encapsulated {
+            use nutype::nutype;
+
+            #[nutype(
+                constructor = pub(super) try_new,
+                sanitize(trim),
+                validate(not_empty),
+                derive(Debug, AsRef, TryFrom)
+            )]
+            pub struct Username(String);
+
+            #[nutype(constructor = pub(crate), sanitize(lowercase), derive(Debug, AsRef))]
+            pub struct Tag(String);
+
+            impl Username {
+                pub fn anonymous() -> Self {
+                    Self::try_new("anonymous").unwrap()
+                }
+            }
+        }
+
+        use encapsulated::{Tag, Username};
+
+        #[test]
+        fn test_restricted_constructor() {
+            assert_eq!(Username::try_new(" bob ").unwrap().as_ref(), "bob");
+            assert_eq!(Username::anonymous().as_ref(), "anonymous");
+            assert_eq!(Tag::new("Rust").as_ref(), "rust");
+
+            // Traits stay public
+            assert_eq!(Username::try_from("alice").unwrap().as_ref(), "alice");
+        }
     }
 }
 
-#[cfg(test)]
-#[cfg(feature = "schemars08")]
-mod derive_schemars_json_schema {
+mod try_mutate {
     use super::*;
-    use schemars::{schema_for, JsonSchema};
+
+    #[nutype(
+        sanitize(trim),
+        validate(not_empty, len_char_max = 10),
+        derive(Debug, PartialEq),
+        methods(try_mutate)
+    )]
+    pub struct Username(String);
 
     #[test]
-    fn test_json_schema_derive() {
-        #[nutype(derive(JsonSchema))]
-        pub struct CustomerIdentifier(String);
+    fn test_try_mutate_applies_valid_change() {
+        let mut username = Username::new("bob").unwrap();
+        username.try_mutate(|name| name.push_str("_1 ")).unwrap();
+        assert_eq!(username.into_inner(), "bob_1");
+    }
 
-        assert_eq!(CustomerIdentifier::schema_name(), "CustomerIdentifier");
-        // Make sure it compiles
-        let _schema = schema_for!(CustomerIdentifier);
+    #[test]
+    fn test_try_mutate_rolls_back_invalid_change() {
+        let mut username = Username::new("bob").unwrap();
+        let err = username
+            .try_mutate(|name| name.push_str(" the builder"))
+            .unwrap_err();
+        assert_eq!(err, UsernameError::LenCharMaxViolated);
+        assert_eq!(username.into_inner(), "bob");
     }
 }
 
-#[cfg(test)]
-#[cfg(feature = "regex")]
-mod validation_with_regex {
+mod new_or_default {
     use super::*;
-    use lazy_static::lazy_static;
-    use once_cell::sync::Lazy;
-    use regex::Regex;
 
-    lazy_static! {
-        static ref PHONE_REGEX_LAZY_STATIC: Regex = Regex::new("^[0-9]{3}-[0-9]{3}$").unwrap();
-    }
+    #[nutype(
+        sanitize(trim, lowercase),
+        validate(not_empty),
+        default = "info",
+        derive(Debug, PartialEq)
+    )]
+    pub struct LogLevel(String);
 
-    static PHONE_REGEX_ONCE_CELL: Lazy<Regex> =
-        Lazy::new(|| Regex::new("[0-9]{3}-[0-9]{3}$").unwrap());
+    #[test]
+    fn test_new_or_default_keeps_valid_value() {
+        assert_eq!(LogLevel::new_or_default(" DEBUG ").into_inner(), "debug");
+    }
 
     #[test]
-    fn test_regex_as_string() {
-        #[nutype(validate(regex = "^[0-9]{3}-[0-9]{3}$"), derive(Debug, PartialEq))]
-        pub struct PhoneNumber(String);
+    fn test_new_or_default_falls_back_to_default() {
+        assert_eq!(LogLevel::new_or_default("  ").into_inner(), "info");
+    }
+}
 
-        // PredicateViolated
+mod validate_only {
+    use super::*;
+
+    #[nutype(
+        sanitize(trim),
+        validate(len_char_max = 5),
+        derive(Debug),
+        methods(validate)
+    )]
+    pub struct Nickname(String);
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(Nickname::validate("  bob  "), Ok(()));
         assert_eq!(
-            PhoneNumber::new("123456"),
-            Err(PhoneNumberError::RegexViolated)
+            Nickname::validate("alice-bob"),
+            Err(NicknameError::LenCharMaxViolated)
         );
+    }
 
-        // Valid
-        let inner = PhoneNumber::new("123-456").unwrap().into_inner();
-        assert_eq!(inner, "123-456".to_string());
+    #[nutype(validate(not_empty), derive(Debug))]
+    pub struct Comment(String);
+
+    impl Comment {
+        pub fn validate() -> &'static str {
+            "hand-written"
+        }
     }
 
     #[test]
-    fn test_regex_with_lazy_static() {
-        #[nutype(validate(regex = PHONE_REGEX_LAZY_STATIC), derive(Debug, PartialEq))]
-        pub struct PhoneNumber(String);
+    fn test_validate_is_not_generated_by_default() {
+        assert_eq!(Comment::validate(), "hand-written");
+    }
+}
 
-        // PredicateViolated
-        assert_eq!(
-            PhoneNumber::new("123456"),
-            Err(PhoneNumberError::RegexViolated)
-        );
+mod sanitize_only {
+    use super::*;
 
-        // Valid
-        let inner = PhoneNumber::new("123-456").unwrap().into_inner();
-        assert_eq!(inner, "123-456".to_string());
+    #[test]
+    fn test_sanitize() {
+        #[nutype(sanitize(trim, lowercase), validate(not_empty), derive(Debug))]
+        pub struct Email(String);
+
+        assert_eq!(Email::sanitize("  Bob@Example.COM "), "bob@example.com");
+        // Sanitizing does not validate
+        assert_eq!(Email::sanitize("   "), "");
     }
+}
+
+mod delegate {
+    use super::*;
 
     #[test]
-    fn test_regex_with_once_cell_lazy() {
-        #[nutype(validate(regex = PHONE_REGEX_ONCE_CELL), derive(Debug, PartialEq))]
-        pub struct PhoneNumber(String);
+    fn test_delegated_methods() {
+        #[nutype(
+            sanitize(trim),
+            validate(len_char_max = 10),
+            delegate(len, is_empty, chars, as_str)
+        )]
+        pub struct Title(String);
 
-        // PredicateViolated
-        assert_eq!(
-            PhoneNumber::new("123456"),
-            Err(PhoneNumberError::RegexViolated)
-        );
+        let title = Title::new(" Über ").unwrap();
+        assert_eq!(title.len(), 5);
+        assert!(!title.is_empty());
+        assert_eq!(title.chars().count(), 4);
+        assert_eq!(title.as_str(), "Über");
+    }
+}
 
-        // Valid
-        let inner = PhoneNumber::new("123-456").unwrap().into_inner();
-        assert_eq!(inner, "123-456".to_string());
+mod set {
+    use super::*;
+
+    #[test]
+    fn test_set() {
+        #[nutype(
+            sanitize(trim),
+            validate(not_empty),
+            derive(Debug, AsRef),
+            methods(set)
+        )]
+        pub struct Nickname(String);
+
+        let mut nickname = Nickname::new("bob").unwrap();
+        nickname.set(" alice ").unwrap();
+        assert_eq!(nickname.as_ref(), "alice");
+
+        assert_eq!(nickname.set("  "), Err(NicknameError::NotEmptyViolated));
+        assert_eq!(nickname.as_ref(), "alice");
+    }
+}
+
+mod try_new_all {
+    use super::*;
+
+    #[nutype(
+        sanitize(trim),
+        validate(not_empty),
+        derive(Debug, AsRef),
+        methods(try_new_all)
+    )]
+    pub struct Tag(String);
+
+    #[test]
+    fn test_try_new_all() {
+        let tags = Tag::try_new_all(vec![" a ", "b"]).unwrap();
+        assert_eq!(tags[0].as_ref(), "a");
+
+        let (index, err) = Tag::try_new_all(["a".to_string(), " ".to_string()]).unwrap_err();
+        assert_eq!(index, 1);
+        assert_eq!(err, TagError::NotEmptyViolated);
+    }
+}
+
+#[cfg(feature = "intern")]
+mod new_interned {
+    use super::*;
+
+    #[test]
+    fn test_new_interned_shares_storage() {
+        #[nutype(
+            sanitize(trim, uppercase),
+            validate(len_char_min = 3, len_char_max = 3),
+            derive(Debug)
+        )]
+        pub struct CurrencyCode(String);
+
+        let usd = CurrencyCode::new_interned("usd").unwrap();
+        let same_usd = CurrencyCode::new_interned(" USD ").unwrap();
+        let eur = CurrencyCode::new_interned("EUR").unwrap();
+        assert!(std::ptr::eq(usd, same_usd));
+        assert!(!std::ptr::eq(usd, eur));
+
+        assert_eq!(
+            CurrencyCode::new_interned("dollar").unwrap_err(),
+            CurrencyCodeError::LenCharMaxViolated
+        );
+    }
+
+    #[test]
+    fn test_new_interned_without_validation() {
+        #[nutype(sanitize(lowercase), derive(AsRef))]
+        pub struct Tag(String);
+
+        let tag: &'static Tag = Tag::new_interned("Rust");
+        assert_eq!(tag.as_ref(), "rust");
+    }
+}
+
+mod leak {
+    use super::*;
+
+    #[nutype(sanitize(trim), validate(not_empty), methods(leak))]
+    pub struct ServiceName(String);
+
+    #[test]
+    fn test_leak() {
+        let name: &'static str = ServiceName::new(" billing ").unwrap().leak();
+        assert_eq!(name, "billing");
+    }
+}
+
+mod const_generics {
+    use super::*;
+
+    #[nutype(
+        sanitize(trim, uppercase),
+        validate(len_char_min = N, len_char_max = N),
+        derive(Debug, Clone, PartialEq, Eq, AsRef, Display, FromStr, TryFrom)
+    )]
+    pub struct Code<const N: usize>(String);
+
+    #[nutype(
+        validate(not_empty, len_char_max = MAX),
+        error_fields(bounds),
+        derive(Debug, PartialEq)
+    )]
+    pub struct Label<const MAX: usize>(String);
+
+    pub type CurrencyCode = Code<3>;
+    pub type CountryCode = Code<2>;
+
+    #[test]
+    fn test_len_from_const_generic() {
+        let usd = CurrencyCode::new(" usd ").unwrap();
+        assert_eq!(usd.as_ref(), "USD");
+        assert_eq!(usd.to_string(), "USD");
+
+        let ua: CountryCode = "ua".parse().unwrap();
+        assert_eq!(ua.into_inner(), "UA");
+
+        assert_eq!(
+            CountryCode::try_from("usd"),
+            Err(CodeError::LenCharMaxViolated)
+        );
+        assert_eq!(
+            CurrencyCode::new("ua").unwrap_err().to_string(),
+            "Code is too short. The value length must be more than 3 character(s)."
+        );
+    }
+
+    #[test]
+    fn test_error_fields_from_const_generic() {
+        assert_eq!(
+            Label::<4>::new("hello"),
+            Err(LabelError::LenCharMaxViolated { max: 4 })
+        );
+        assert!(Label::<5>::new("hello").is_ok());
+    }
+}
+
+mod attribute_passthrough {
+    use super::*;
+
+    #[nutype(sanitize(trim), derive(Debug, AsRef))]
+    #[must_use]
+    #[cfg_attr(test, repr(transparent))]
+    pub struct Nickname(String);
+
+    #[nutype(derive(Debug))]
+    #[deprecated(note = "use Nickname instead")]
+    pub struct Alias(String);
+
+    #[nutype(derive(AsRef))]
+    #[cfg(target_pointer_width = "64")]
+    pub struct Platform(String);
+
+    #[nutype(derive(AsRef))]
+    #[cfg(not(target_pointer_width = "64"))]
+    pub struct Platform(String);
+
+    #[test]
+    fn test_attributes_are_forwarded() {
+        let nickname = Nickname::new(" neo ");
+        assert_eq!(nickname.as_ref(), "neo");
+        assert_eq!(
+            std::mem::size_of::<Nickname>(),
+            std::mem::size_of::<String>()
+        );
+
+        #[allow(deprecated)]
+        let alias = Alias::new("trinity");
+        assert_eq!(format!("{alias:?}"), r#"Alias("trinity")"#);
+    }
+
+    #[test]
+    fn test_cfg_applies_to_all_generated_items() {
+        assert_eq!(Platform::new("x").as_ref(), "x");
+    }
+}
+
+mod repr_transparent {
+    use super::*;
+
+    #[nutype(repr = transparent, validate(not_empty), derive(Debug, AsRef))]
+    pub struct Word(String);
+
+    #[test]
+    fn test_vec_conversions() {
+        let words =
+            Word::try_from_inner_vec(vec!["hello".to_string(), "world".to_string()]).unwrap();
+        assert_eq!(words[1].as_ref(), "world");
+        assert_eq!(Word::as_inner_slice(&words), &["hello", "world"]);
+        assert_eq!(Word::into_inner_vec(words), vec!["hello", "world"]);
+
+        let err = Word::try_from_inner_vec(vec!["".to_string()]).unwrap_err();
+        assert_eq!(err, (0, WordError::NotEmptyViolated));
+    }
+}
+
+mod extends {
+    use super::*;
+
+    #[nutype(
+        sanitize(trim),
+        validate(not_empty, len_char_max = 20),
+        derive(Debug, PartialEq, AsRef)
+    )]
+    pub struct Username(String);
+
+    #[nutype(
+        extends = Username,
+        sanitize(lowercase),
+        validate(len_char_max = 10),
+        derive(Debug, PartialEq, AsRef)
+    )]
+    pub struct ShortUsername(String);
+
+    #[test]
+    fn test_guard_of_base_type_runs_first() {
+        assert_eq!(ShortUsername::new("  Bob ").unwrap().as_ref(), "bob");
+        assert_eq!(
+            ShortUsername::new("   "),
+            Err(ShortUsernameError::UsernameViolated(
+                UsernameError::NotEmptyViolated
+            ))
+        );
+        assert_eq!(
+            ShortUsername::new("abcdefghijk"),
+            Err(ShortUsernameError::LenCharMaxViolated)
+        );
+    }
+
+    #[test]
+    fn test_error_of_base_type() {
+        let err = ShortUsername::new("").unwrap_err();
+        assert_eq!(err.to_string(), UsernameError::NotEmptyViolated.to_string());
+        assert_eq!(err.code(), "username.not_empty");
+        assert_eq!(err.violated_constraint(), "not_empty");
+    }
+
+    #[test]
+    fn test_into_base_type() {
+        let username: Username = ShortUsername::new(" Alice ").unwrap().into();
+        assert_eq!(username.as_ref(), "alice");
+    }
+
+    #[nutype(
+        sanitize(with = |s: String| format!("{s}!")),
+        validate(len_char_max = 5),
+        derive(Debug, AsRef)
+    )]
+    pub struct Shout(String);
+
+    #[nutype(extends = Shout, validate(not_empty), derive(Debug, AsRef))]
+    pub struct NonEmptyShout(String);
+
+    #[test]
+    fn test_into_base_type_does_not_sanitize_again() {
+        let shout = NonEmptyShout::new("abcd").unwrap();
+        assert_eq!(shout.as_ref(), "abcd!");
+        let shout: Shout = shout.into();
+        assert_eq!(shout.as_ref(), "abcd!");
+    }
+}
+
+mod guard {
+    use super::*;
+
+    mod rules {
+        nutype::nutype_guard! {
+            pub(crate) UsernameRules = sanitize(trim, lowercase), validate(len_char_min = 3, len_char_max = 20)
+        }
+    }
+
+    #[nutype(guard = rules::UsernameRules, derive(Debug, PartialEq, AsRef))]
+    pub struct Username(String);
+
+    #[nutype(derive(Debug, AsRef), guard = crate::guard::rules::UsernameRules)]
+    pub struct Nickname(String);
+
+    nutype::nutype_guard! {
+        TagRules = sanitize(trim, uppercase)
+    }
+
+    #[nutype(guard = TagRules, derive(AsRef))]
+    pub struct Tag(String);
+
+    #[test]
+    fn test_shared_rules() {
+        assert_eq!(Tag::new(" rust ").as_ref(), "RUST");
+
+        assert_eq!(Username::new("  Bob ").unwrap().as_ref(), "bob");
+        assert_eq!(Username::new("Al"), Err(UsernameError::LenCharMinViolated));
+
+        assert_eq!(Nickname::new(" BOBBY").unwrap().as_ref(), "bobby");
+        assert!(matches!(
+            Nickname::new("x".repeat(21)),
+            Err(NicknameError::LenCharMaxViolated)
+        ));
+    }
+}
+
+mod macro_rules_wrapper {
+    use super::*;
+
+    macro_rules! define_text {
+        ($vis:vis $name:ident($ty:ty), $max:expr, $message:expr, $sanitizer:expr) => {
+            #[nutype(
+                sanitize(trim, with = $sanitizer),
+                validate(not_empty, len_char_max = $max),
+                messages(len_char_max = $message),
+                derive(Debug, PartialEq, AsRef, FromStr)
+            )]
+            $vis struct $name($ty);
+        };
+    }
+
+    define_text!(pub Title(String), 10, "too long", |s| s.to_uppercase());
+    define_text!(
+        Comment(String),
+        (20),
+        "too long",
+        (|s: String| s.replace('\n', " "))
+    );
+
+    #[test]
+    fn test_interpolated_attributes() {
+        assert_eq!(Title::new(" hello ").unwrap().as_ref(), "HELLO");
+        assert_eq!(
+            Title::new("hello world!").unwrap_err().to_string(),
+            "too long"
+        );
+        assert_eq!("a\nb".parse::<Comment>().unwrap().as_ref(), "a b");
+    }
+}
+
+mod preset {
+    use super::*;
+
+    #[nutype(preset = email, derive(Debug, PartialEq, AsRef))]
+    pub struct Email(String);
+
+    #[nutype(preset = slug, derive(Debug, PartialEq, AsRef))]
+    pub struct Slug(String);
+
+    #[nutype(preset = uuid, derive(Debug, PartialEq, AsRef))]
+    pub struct Uuid(String);
+
+    #[nutype(
+        preset = url,
+        messages(predicate = "must start with http:// or https://"),
+        derive(Debug, PartialEq, AsRef)
+    )]
+    pub struct Url(String);
+
+    #[test]
+    fn test_email() {
+        assert_eq!(
+            Email::new(" Alice@Example.COM ").unwrap().as_ref(),
+            "alice@example.com"
+        );
+        assert_eq!(Email::new(""), Err(EmailError::NotEmptyViolated));
+        for invalid in [
+            "alice",
+            "@example.com",
+            "alice@example",
+            "a@b@c.com",
+            "a b@c.com",
+        ] {
+            assert_eq!(Email::new(invalid), Err(EmailError::PredicateViolated));
+        }
+        assert_eq!(
+            Email::new("alice").unwrap_err().to_string(),
+            "The value is not a valid email address."
+        );
+    }
+
+    #[test]
+    fn test_slug() {
+        assert_eq!(
+            Slug::new(" Hello-World-2 ").unwrap().as_ref(),
+            "hello-world-2"
+        );
+        for invalid in ["-hello", "hello-", "hello--world", "hello_world"] {
+            assert_eq!(Slug::new(invalid), Err(SlugError::PredicateViolated));
+        }
+    }
+
+    #[test]
+    fn test_uuid() {
+        assert_eq!(
+            Uuid::new("67E55044-10B1-426F-9247-BB680E5FE0C8")
+                .unwrap()
+                .as_ref(),
+            "67e55044-10b1-426f-9247-bb680e5fe0c8"
+        );
+        assert_eq!(
+            Uuid::new("67e55044-10b1-426f-9247"),
+            Err(UuidError::PredicateViolated)
+        );
+        assert_eq!(
+            Uuid::new("67e55044x10b1-426f-9247-bb680e5fe0c8"),
+            Err(UuidError::PredicateViolated)
+        );
+    }
+
+    #[test]
+    fn test_url_with_custom_message() {
+        assert_eq!(
+            Url::new(" https://example.com/path ").unwrap().as_ref(),
+            "https://example.com/path"
+        );
+        assert_eq!(Url::new("http:///path"), Err(UrlError::PredicateViolated));
+        assert_eq!(
+            Url::new("ftp://example.com").unwrap_err().to_string(),
+            "must start with http:// or https://"
+        );
+    }
+}
+
+mod conditional_rules {
+    use super::*;
+
+    #[nutype(
+        sanitize(trim, cfg_attr(any(), lowercase)),
+        validate(
+            not_empty,
+            cfg_attr(test, len_char_max = 20),
+            cfg_attr(not(test), len_char_max = 5),
+        ),
+        derive(Debug, PartialEq, AsRef)
+    )]
+    pub struct Nickname(String);
+
+    #[nutype(
+        validate(cfg(all(), not_empty, len_char_max = 3)),
+        derive(Debug, PartialEq, AsRef)
+    )]
+    pub struct Code(String);
+
+    #[test]
+    fn test_enabled_rules() {
+        assert_eq!(Nickname::new(" Bob ").unwrap().as_ref(), "Bob");
+        assert_eq!(
+            Nickname::new("a very long nickname").unwrap().as_ref(),
+            "a very long nickname"
+        );
+        assert_eq!(
+            Nickname::new("a much longer nickname"),
+            Err(NicknameError::LenCharMaxViolated)
+        );
+        assert_eq!(Nickname::new("  "), Err(NicknameError::NotEmptyViolated));
+    }
+
+    #[test]
+    fn test_multiple_rules_under_one_predicate() {
+        assert_eq!(Code::new("abc").unwrap().as_ref(), "abc");
+        assert_eq!(Code::new(""), Err(CodeError::NotEmptyViolated));
+        assert_eq!(Code::new("abcd"), Err(CodeError::LenCharMaxViolated));
+    }
+
+    #[nutype(
+        sanitize(cfg_attr(test, trim), lowercase),
+        validate(
+            cfg_attr(test, not_empty),
+            cfg_attr(not(test), len_char_min = 2),
+            cfg_attr(all(), len_char_max = 10),
+            cfg_attr(any(), len_char_max = 3),
+            cfg_attr(not(any()), predicate = |s: &str| !s.contains('!')),
+        ),
+        messages(predicate = "Tag must not shout."),
+        derive(Debug, PartialEq, AsRef)
+    )]
+    pub struct Tag(String);
+
+    #[test]
+    fn test_many_predicates() {
+        assert_eq!(Tag::new(" ABC ").unwrap().as_ref(), "abc");
+        assert_eq!(Tag::new(" "), Err(TagError::NotEmptyViolated));
+        assert_eq!(Tag::new("abcdefghijk"), Err(TagError::LenCharMaxViolated));
+        assert_eq!(Tag::new("abc!"), Err(TagError::PredicateViolated));
+    }
+
+    #[test]
+    fn test_error_of_enabled_rule() {
+        assert!(TagError::LenCharMaxViolated.to_string().contains("10"));
+        assert_eq!(
+            TagError::PredicateViolated.to_string(),
+            "Tag must not shout."
+        );
+    }
+
+    #[nutype(validate(cfg_attr(any(), not_empty)), derive(Debug, PartialEq, AsRef))]
+    pub struct Note(String);
+
+    #[test]
+    fn test_no_enabled_validators() {
+        let result: Result<Note, NoteError> = Note::new("");
+        assert_eq!(result.unwrap().as_ref(), "");
+    }
+}
+
+mod env_bounds {
+    use super::*;
+
+    // Cargo sets `CARGO_PKG_VERSION_*` for every build, the version of test_suite is 0.1.0
+    #[nutype(
+        validate(len_char_max = env!("CARGO_PKG_VERSION_MINOR")),
+        derive(Debug, PartialEq, AsRef)
+    )]
+    pub struct Initial(String);
+
+    #[test]
+    fn test_bounds_from_env() {
+        assert_eq!(Initial::new("a").unwrap().as_ref(), "a");
+        assert_eq!(Initial::new("ab"), Err(InitialError::LenCharMaxViolated));
+    }
+}
+
+mod docs {
+    use super::*;
+
+    /// A name of a user.
+    #[nutype(
+        sanitize(trim),
+        validate(not_empty, len_char_max = 20),
+        constructor = try_new,
+        docs(
+            try_new = "Creates a username out of a trimmed string.",
+            into_inner = "Returns the username as a string.",
+            error = "The reason why a string is not a valid username.",
+        ),
+        derive(Debug, PartialEq, FromStr)
+    )]
+    pub struct Username(String);
+
+    #[test]
+    fn test_documented_items() {
+        assert_eq!(Username::try_new(" bob ").unwrap().into_inner(), "bob");
+        assert_eq!(Username::try_new(""), Err(UsernameError::NotEmptyViolated));
+        assert_eq!("".parse::<Username>(), Err(UsernameError::NotEmptyViolated));
+    }
+}
+
+mod local {
+    use super::*;
+
+    #[test]
+    fn test_type_within_function_body() {
+        const DEFAULT_TAG: &str = "rust";
+
+        #[nutype(local, default = DEFAULT_TAG, derive(Debug, Default, AsRef))]
+        struct Tag(String);
+
+        assert_eq!(Tag::default().as_ref(), "rust");
+    }
+}
+
+#[cfg(feature = "derive_unsafe")]
+mod derive_unsafe {
+    use super::*;
+
+    #[nutype(
+        sanitize(trim),
+        validate(not_empty),
+        derive(Debug, AsRef),
+        derive_unsafe(derive_more::Display)
+    )]
+    #[display("@{_0}")]
+    pub struct Handle(String);
+
+    #[test]
+    fn test_third_party_derive() {
+        let handle = Handle::new(" nutype ").unwrap();
+        assert_eq!(handle.to_string(), "@nutype");
+    }
+}
+
+mod factory {
+    use super::*;
+
+    #[nutype(
+        factory,
+        sanitize(trim),
+        validate(not_empty, len_char_max = 20),
+        derive(Debug, PartialEq)
+    )]
+    pub struct Username(String);
+
+    #[test]
+    fn test_runtime_bound() {
+        let factory = Username::with_len_char_max(5);
+        assert_eq!(factory.build(" bob ").unwrap().into_inner(), "bob");
+        assert_eq!(
+            factory.build("bob marley"),
+            Err(UsernameError::LenCharMaxViolated)
+        );
+    }
+
+    #[test]
+    fn test_static_bounds_still_apply() {
+        let factory = UsernameFactory::default().with_len_char_max(50);
+        assert_eq!(factory.build("   "), Err(UsernameError::NotEmptyViolated));
+        assert_eq!(
+            factory.build("a".repeat(30)),
+            Err(UsernameError::LenCharMaxViolated)
+        );
+    }
+}
+
+#[cfg(feature = "new_unchecked")]
+mod new_unchecked {
+    use super::*;
+
+    #[test]
+    fn test_new_unchecked() {
+        #[nutype(
+            new_unchecked(no_debug_assert),
+            sanitize(trim),
+            validate(len_char_min = 8)
+        )]
+        pub struct Name(String);
+
+        let name = unsafe { Name::new_unchecked(" boo ".to_string()) };
+        assert_eq!(name.into_inner(), " boo ");
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "Invalid Name passed to new_unchecked()")]
+    fn test_new_unchecked_checks_value_in_debug() {
+        #[nutype(new_unchecked, sanitize(trim), validate(len_char_min = 8))]
+        pub struct Name(String);
+
+        // The value is validated as is, without sanitization
+        let _ = unsafe { Name::new_unchecked(" boo ".to_string()) };
+    }
+
+    #[test]
+    fn test_as_mut_unchecked() {
+        #[nutype(new_unchecked, sanitize(trim), validate(len_char_min = 3))]
+        pub struct Name(String);
+
+        let mut name = Name::new("Bob").unwrap();
+        unsafe { name.as_mut_unchecked().push_str(" Marley") };
+        assert_eq!(name.into_inner(), "Bob Marley");
+    }
+
+    #[test]
+    #[forbid(unsafe_code)]
+    fn test_safe_new_unchecked() {
+        #[nutype(new_unchecked(safe, no_debug_assert), validate(len_char_min = 8))]
+        pub struct Name(String);
+
+        let mut name = Name::new_unchecked("boo".to_string());
+        name.as_mut_unchecked().push('!');
+        assert_eq!(name.into_inner(), "boo!");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_with_skip_validation() {
+        #[nutype(
+            sanitize(trim),
+            validate(len_char_min = 8),
+            derive(Deserialize),
+            serde(skip_validation)
+        )]
+        pub struct Name(String);
+
+        let name: Name = serde_json::from_str("\" boo \"").unwrap();
+        assert_eq!(name.into_inner(), " boo ");
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "schemars08")]
+mod derive_schemars_json_schema {
+    use super::*;
+    use schemars::{schema_for, JsonSchema};
+
+    #[test]
+    fn test_json_schema_derive() {
+        #[nutype(derive(JsonSchema))]
+        pub struct CustomerIdentifier(String);
+
+        assert_eq!(CustomerIdentifier::schema_name(), "CustomerIdentifier");
+        // Make sure it compiles
+        let _schema = schema_for!(CustomerIdentifier);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "regex")]
+mod validation_with_regex {
+    use super::*;
+    use lazy_static::lazy_static;
+    use once_cell::sync::Lazy;
+    use regex::Regex;
+
+    lazy_static! {
+        static ref PHONE_REGEX_LAZY_STATIC: Regex = Regex::new("^[0-9]{3}-[0-9]{3}$").unwrap();
+    }
+
+    static PHONE_REGEX_ONCE_CELL: Lazy<Regex> =
+        Lazy::new(|| Regex::new("[0-9]{3}-[0-9]{3}$").unwrap());
+
+    // The regex is built by the generated test `should_have_valid_regex`
+    #[nutype(validate(regex = PHONE_REGEX_ONCE_CELL), derive(Debug))]
+    pub struct OfficePhone(String);
+
+    #[test]
+    fn test_regex_at_module_level() {
+        assert!(OfficePhone::new("123-456").is_ok());
+    }
+
+    #[test]
+    fn test_regex_as_string() {
+        #[nutype(validate(regex = "^[0-9]{3}-[0-9]{3}$"), derive(Debug, PartialEq))]
+        pub struct PhoneNumber(String);
+
+        // PredicateViolated
+        assert_eq!(
+            PhoneNumber::new("123456"),
+            Err(PhoneNumberError::RegexViolated)
+        );
+
+        // Valid
+        let inner = PhoneNumber::new("123-456").unwrap().into_inner();
+        assert_eq!(inner, "123-456".to_string());
+    }
+
+    #[test]
+    fn test_regex_with_lazy_static() {
+        #[nutype(validate(regex = PHONE_REGEX_LAZY_STATIC), derive(Debug, PartialEq))]
+        pub struct PhoneNumber(String);
+
+        // PredicateViolated
+        assert_eq!(
+            PhoneNumber::new("123456"),
+            Err(PhoneNumberError::RegexViolated)
+        );
+
+        // Valid
+        let inner = PhoneNumber::new("123-456").unwrap().into_inner();
+        assert_eq!(inner, "123-456".to_string());
+    }
+
+    #[test]
+    fn test_regex_with_once_cell_lazy() {
+        #[nutype(validate(regex = PHONE_REGEX_ONCE_CELL), derive(Debug, PartialEq))]
+        pub struct PhoneNumber(String);
+
+        // PredicateViolated
+        assert_eq!(
+            PhoneNumber::new("123456"),
+            Err(PhoneNumberError::RegexViolated)
+        );
+
+        // Valid
+        let inner = PhoneNumber::new("123-456").unwrap().into_inner();
+        assert_eq!(inner, "123-456".to_string());
+    }
+
+    #[test]
+    fn test_regex_pattern_in_error() {
+        #[nutype(
+            validate(regex = "^[0-9]{3}-[0-9]{3}$"),
+            error_fields(bounds),
+            derive(Debug, PartialEq)
+        )]
+        pub struct PhoneNumber(String);
+
+        #[nutype(
+            validate(regex = PHONE_REGEX_LAZY_STATIC),
+            error_fields(bounds),
+            derive(Debug, PartialEq)
+        )]
+        pub struct OtherPhoneNumber(String);
+
+        assert_eq!(
+            PhoneNumber::new("123456"),
+            Err(PhoneNumberError::RegexViolated {
+                pattern: "^[0-9]{3}-[0-9]{3}$"
+            })
+        );
+        assert_eq!(
+            OtherPhoneNumber::new("123456"),
+            Err(OtherPhoneNumberError::RegexViolated {
+                pattern: "^[0-9]{3}-[0-9]{3}$"
+            })
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "juniper")]
+mod derive_juniper_graphql_scalar {
+    use super::*;
+    use juniper::{graphql_object, EmptyMutation, EmptySubscription, RootNode, Value, Variables};
+
+    #[nutype(validate(not_empty), derive(GraphQLScalar))]
+    pub struct Username(String);
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn echo(input: Username) -> Username {
+            input
+        }
+    }
+
+    fn execute(query: &str) -> Result<Value, String> {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        );
+        juniper::execute_sync(query, None, &schema, &Variables::new(), &())
+            .map(|(value, _errors)| value)
+            .map_err(|err| err.to_string())
+    }
+
+    #[test]
+    fn test_valid_input() {
+        let value = execute(r#"{ echo(input: "bob") }"#).unwrap();
+        assert_eq!(value.to_string(), r#"{"echo": "bob"}"#);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let err = execute(r#"{ echo(input: "") }"#).unwrap_err();
+        assert!(err.contains("Username"));
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "rocket")]
+mod derive_rocket {
+    use super::*;
+    use rocket::form::{FromFormField, ValueField};
+    use rocket::http::Status;
+    use rocket::request::FromParam;
+
+    #[nutype(
+        validate(len_char_max = 5),
+        derive(Debug, PartialEq, FromParam, FromFormField)
+    )]
+    pub struct Username(String);
+
+    #[test]
+    fn test_from_param() {
+        assert_eq!(
+            Username::from_param("bob").unwrap(),
+            Username::new("bob").unwrap()
+        );
+
+        let errors = Username::from_param("bob_the_builder").unwrap_err();
+        assert_eq!(errors.status(), Status::UnprocessableEntity);
+    }
+
+    #[test]
+    fn test_from_form_field() {
+        let field = ValueField::from_value("bob");
+        assert_eq!(
+            Username::from_value(field).unwrap(),
+            Username::new("bob").unwrap()
+        );
+
+        let field = ValueField::from_value("bob_the_builder");
+        let errors = Username::from_value(field).unwrap_err();
+        assert_eq!(errors.status(), Status::UnprocessableEntity);
+        assert_eq!(
+            errors[0].to_string(),
+            UsernameError::LenCharMaxViolated.to_string()
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "actix")]
+mod derive_actix_from_request {
+    use super::*;
+    use actix_web::{dev::Payload, http::StatusCode, test::TestRequest, FromRequest};
+
+    #[nutype(validate(len_char_max = 5), derive(Debug, PartialEq, FromRequest))]
+    pub struct Username(String);
+
+    fn extract(param: &str) -> Result<Username, actix_web::Error> {
+        let req = TestRequest::default()
+            .param("username", param.to_owned())
+            .to_http_request();
+        Username::from_request(&req, &mut Payload::None).into_inner()
+    }
+
+    #[test]
+    fn test_from_request() {
+        assert_eq!(extract("bob").unwrap(), Username::new("bob").unwrap());
+    }
+
+    #[test]
+    fn test_from_request_with_invalid_value() {
+        let err = extract("bob_the_builder").unwrap_err();
+        assert_eq!(
+            err.as_response_error().status_code(),
+            StatusCode::UNPROCESSABLE_ENTITY
+        );
+        assert_eq!(
+            err.to_string(),
+            UsernameError::LenCharMaxViolated.to_string()
+        );
+    }
+
+    #[cfg(feature = "web")]
+    #[test]
+    fn test_from_request_with_status_code_override() {
+        #[nutype(
+            validate(len_char_max = 5, predicate = |name| name != "admin"),
+            status_codes(predicate = 409),
+            derive(Debug, FromRequest)
+        )]
+        pub struct Login(String);
+
+        let extract = |param: &str| {
+            let req = TestRequest::default()
+                .param("login", param.to_owned())
+                .to_http_request();
+            Login::from_request(&req, &mut Payload::None).into_inner()
+        };
+
+        let err = extract("admin").unwrap_err();
+        assert_eq!(err.as_response_error().status_code(), StatusCode::CONFLICT);
+        let err = extract("bob_the_builder").unwrap_err();
+        assert_eq!(
+            err.as_response_error().status_code(),
+            StatusCode::UNPROCESSABLE_ENTITY
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    mod serde_extractors {
+        use super::*;
+        use actix_web::web::{Json, Query};
+
+        #[nutype(validate(len_char_max = 5), derive(Debug, PartialEq, Deserialize))]
+        pub struct Nickname(String);
+
+        #[derive(serde::Deserialize)]
+        struct Params {
+            nickname: Nickname,
+        }
+
+        #[test]
+        fn test_query() {
+            let Query(params) = Query::<Params>::from_query("nickname=bob").unwrap();
+            assert_eq!(params.nickname, Nickname::new("bob").unwrap());
+
+            let err = Query::<Params>::from_query("nickname=bob_the_builder")
+                .err()
+                .unwrap();
+            assert!(err.to_string().contains("Nickname is too long"));
+        }
+
+        #[test]
+        fn test_json() {
+            let extract = |body: &str| {
+                let (req, mut payload) = TestRequest::default()
+                    .insert_header(("content-type", "application/json"))
+                    .set_payload(body.to_owned())
+                    .to_http_parts();
+                actix_web::rt::System::new()
+                    .block_on(Json::<Params>::from_request(&req, &mut payload))
+            };
+
+            let Json(params) = extract(r#"{"nickname":"bob"}"#).unwrap();
+            assert_eq!(params.nickname, Nickname::new("bob").unwrap());
+
+            let err = extract(r#"{"nickname":"bob_the_builder"}"#).err().unwrap();
+            assert_eq!(
+                err.as_response_error().status_code(),
+                StatusCode::BAD_REQUEST
+            );
+            assert!(err.to_string().contains("Nickname is too long"));
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(all(feature = "axum", feature = "serde"))]
+mod axum_integration {
+    use super::*;
+    use axum::http::StatusCode;
+    use axum::response::IntoResponse;
+
+    #[nutype(
+        validate(len_char_max = 5),
+        derive(Debug, Deserialize),
+        error_integrations(axum)
+    )]
+    pub struct Username(String);
+
+    fn body_text(response: axum::response::Response) -> String {
+        use std::future::Future;
+
+        let future = axum::body::to_bytes(response.into_body(), usize::MAX);
+        let mut future = std::pin::pin!(future);
+        let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+        match future.as_mut().poll(&mut cx) {
+            std::task::Poll::Ready(bytes) => String::from_utf8(bytes.unwrap().to_vec()).unwrap(),
+            std::task::Poll::Pending => panic!("Response body is not ready"),
+        }
+    }
+
+    #[test]
+    fn test_error_into_response() {
+        let response = UsernameError::LenCharMaxViolated.into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let expected_body = format!(
+            r#"{{"constraint":"len_char_max","message":"{}"}}"#,
+            UsernameError::LenCharMaxViolated
+        );
+        assert_eq!(body_text(response), expected_body);
+    }
+
+    #[cfg(feature = "web")]
+    #[test]
+    fn test_error_into_response_with_status_code_override() {
+        #[nutype(
+            validate(len_char_max = 5, predicate = |name| name != "admin"),
+            status_codes(predicate = 409),
+            error_integrations(axum)
+        )]
+        pub struct Login(String);
+
+        let response = LoginError::PredicateViolated.into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        let response = LoginError::LenCharMaxViolated.into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[test]
+    fn test_query_extraction() {
+        use axum::extract::Query;
+
+        #[derive(serde::Deserialize)]
+        struct Params {
+            username: Username,
+        }
+
+        let uri = "/users?username=bob".parse().unwrap();
+        let Query(params) = Query::<Params>::try_from_uri(&uri).unwrap();
+        assert_eq!(params.username.into_inner(), "bob");
+
+        let uri = "/users?username=bob_the_builder".parse().unwrap();
+        let rejection = Query::<Params>::try_from_uri(&uri).err().unwrap();
+        assert!(rejection.body_text().contains("Username is too long"));
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "clap")]
+mod derive_clap_value_parser_factory {
+    use super::*;
+    use clap::Parser;
+
+    #[nutype(
+        validate(len_char_max = 5),
+        derive(Debug, Clone, PartialEq, ValueParserFactory)
+    )]
+    pub struct Username(String);
+
+    #[derive(Parser, Debug)]
+    struct Cli {
+        #[arg(long)]
+        username: Username,
+    }
+
+    #[test]
+    fn test_valid_arg() {
+        let cli = Cli::try_parse_from(["app", "--username", "bob"]).unwrap();
+        assert_eq!(cli.username, Username::new("bob").unwrap());
+    }
+
+    #[test]
+    fn test_invalid_arg() {
+        let err = Cli::try_parse_from(["app", "--username", "bob_the_builder"]).unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::ValueValidation);
+        assert!(err
+            .to_string()
+            .contains(&UsernameError::LenCharMaxViolated.to_string()));
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "zeroize")]
+mod derive_zeroize {
+    use super::*;
+    use zeroize::{Zeroize, ZeroizeOnDrop};
+
+    #[nutype(derive(Debug, PartialEq, AsRef, Zeroize, ZeroizeOnDrop))]
+    pub struct ApiToken(String);
+
+    // With validation only `ZeroizeOnDrop` can be derived
+    #[nutype(validate(not_empty), derive(Debug, AsRef, ZeroizeOnDrop))]
+    pub struct ApiKey(String);
+
+    fn should_implement_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+
+    #[test]
+    fn test_zeroize() {
+        let mut token = ApiToken::new("secret");
+        token.zeroize();
+        assert_eq!(token.as_ref(), "");
+    }
+
+    #[test]
+    fn test_zeroize_on_drop() {
+        should_implement_zeroize_on_drop::<ApiToken>();
+        should_implement_zeroize_on_drop::<ApiKey>();
+    }
+
+    #[test]
+    fn test_into_inner() {
+        let token = ApiToken::new("secret");
+        assert_eq!(token.into_inner(), "secret");
+        let key = ApiKey::new("secret").unwrap();
+        assert_eq!(key.into_inner(), "secret");
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "secrecy")]
+mod secret {
+    use super::*;
+    use secrecy::zeroize::{Zeroize, ZeroizeOnDrop};
+    use secrecy::ExposeSecret;
+
+    #[nutype(secret, validate(not_empty), derive(Debug, PartialEq))]
+    pub struct Password(String);
+
+    fn should_implement_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+
+    #[test]
+    fn test_expose_secret() {
+        let password = Password::new("qwerty").unwrap();
+        assert_eq!(password.expose_secret(), "qwerty");
+    }
+
+    #[test]
+    fn test_zeroize() {
+        // `Zeroize` is implemented only for secrets without validation
+        #[nutype(secret)]
+        pub struct Pin(String);
+
+        let mut pin = Pin::new("1234");
+        pin.zeroize();
+        assert_eq!(pin.expose_secret(), "");
+    }
+
+    #[test]
+    fn test_zeroize_on_drop() {
+        should_implement_zeroize_on_drop::<Password>();
+    }
+
+    #[test]
+    fn test_debug_is_redacted() {
+        let password = Password::new("qwerty").unwrap();
+        assert_eq!(format!("{password:?}"), "Password([REDACTED])");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serializable_secret() {
+        #[nutype(secret(serialize), derive(Serialize))]
+        pub struct Token(String);
+
+        fn should_implement_serializable_secret<T: secrecy::SerializableSecret>() {}
+        should_implement_serializable_secret::<Token>();
+
+        let token = Token::new("abc");
+        assert_eq!(serde_json::to_string(&token).unwrap(), "\"abc\"");
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_with")]
+mod derive_serde_with {
+    use super::*;
+    use serde_with::serde_as;
+    use std::collections::BTreeMap;
+
+    #[nutype(
+        sanitize(trim, lowercase),
+        validate(predicate = |email| email.contains('@')),
+        derive(Serialize, Deserialize, SerializeAs, DeserializeAs)
+    )]
+    pub struct Email(String);
+
+    #[serde_as]
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Team {
+        #[serde_as(as = "Vec<Email>")]
+        emails: Vec<String>,
+
+        #[serde_as(as = "BTreeMap<Email, _>")]
+        roles: BTreeMap<String, u8>,
+    }
+
+    #[test]
+    fn test_deserialize_as() {
+        let json = r#"{"emails":[" Foo@Example.com"],"roles":{"BAR@example.com ":1}}"#;
+        let team: Team = serde_json::from_str(json).unwrap();
+        assert_eq!(team.emails, vec!["foo@example.com".to_string()]);
+        assert_eq!(team.roles.get("bar@example.com"), Some(&1));
+    }
+
+    #[test]
+    fn test_deserialize_as_with_invalid_value() {
+        let json = r#"{"emails":["foo"],"roles":{}}"#;
+        let err = serde_json::from_str::<Team>(json).unwrap_err();
+        assert!(err.to_string().contains("Expected valid Email"));
+    }
+
+    #[test]
+    fn test_serialize_as() {
+        let team = Team {
+            emails: vec!["foo@example.com".to_string()],
+            roles: BTreeMap::from([("bar@example.com".to_string(), 2)]),
+        };
+        assert_eq!(
+            serde_json::to_string(&team).unwrap(),
+            r#"{"emails":["foo@example.com"],"roles":{"bar@example.com":2}}"#
+        );
     }
 }
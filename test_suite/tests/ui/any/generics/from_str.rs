@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(derive(Debug, FromStr))]
+pub struct Wrapper<T>(Vec<T>);
+
+fn main() {}
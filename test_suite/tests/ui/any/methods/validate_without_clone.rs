@@ -0,0 +1,10 @@
+use nutype::nutype;
+
+#[nutype(
+    sanitize(with = |mut v: Vec<u8>| { v.sort(); v }),
+    validate(predicate = |v| !v.is_empty()),
+    methods(validate),
+)]
+pub struct SortedBytes(Vec<u8>);
+
+fn main() {}
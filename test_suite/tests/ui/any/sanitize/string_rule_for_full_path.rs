@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(trim), validate(not_empty))]
+pub struct Name(std::string::String);
+
+fn main() {}
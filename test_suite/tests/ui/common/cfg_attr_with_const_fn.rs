@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[nutype(
+    const_fn,
+    validate(cfg_attr(test, less_or_equal = 100)),
+)]
+pub struct Percentage(u8);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(not_empty, cfg_attr(test)))]
+pub struct Name(String);
+
+fn main() {}
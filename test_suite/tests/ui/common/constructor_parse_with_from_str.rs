@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(constructor = parse, validate(greater = 0), derive(FromStr))]
+pub struct Amount(u32);
+
+fn main() {}
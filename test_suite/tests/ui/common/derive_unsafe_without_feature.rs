@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(derive(Debug), derive_unsafe(derive_more::Display))]
+pub struct Handle(String);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(not_empty), docs(try_new = "Creates a name."))]
+pub struct Name(String);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(len_char_max = env!("NUTYPE_UNDEFINED_MAX_LEN")))]
+pub struct Name(String);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(error_module = errors, derive(Debug))]
+pub struct Name(String);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[nutype(validate(not_empty))]
+pub struct Username(String);
+
+#[nutype(extends = Username, sanitize(lowercase))]
+pub struct LowercaseUsername(String);
+
+fn main() {}
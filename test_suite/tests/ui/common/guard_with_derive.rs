@@ -0,0 +1,7 @@
+use nutype::nutype_guard;
+
+nutype_guard! {
+    NameRules = sanitize(trim), derive(Debug)
+}
+
+fn main() {}
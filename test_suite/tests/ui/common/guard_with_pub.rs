@@ -0,0 +1,7 @@
+use nutype::nutype_guard;
+
+nutype_guard! {
+    pub NameRules = sanitize(trim), validate(not_empty)
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use nutype::{nutype, nutype_guard};
+
+nutype_guard! {
+    pub(crate) NameRules = sanitize(trim), validate(not_empty)
+}
+
+#[nutype(guard = NameRules, validate(len_char_max = 20))]
+pub struct Name(String);
+
+fn main() {}
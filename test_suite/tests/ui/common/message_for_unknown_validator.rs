@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[nutype(
+    validate(not_empty),
+    messages(len_char_max = "name is too long"),
+)]
+pub struct Name(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(trim), methods(set), derive(Debug))]
+pub struct Name(String);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[nutype(derive(Debug))]
+pub struct Money {
+    amount: i64,
+    currency: String,
+}
+
+fn main() {}
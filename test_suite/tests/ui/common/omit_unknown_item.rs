@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(not_empty), omit(as_ref), derive(Debug))]
+pub struct Name(String);
+
+fn main() {}
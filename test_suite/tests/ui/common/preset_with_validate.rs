@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(preset = slug, validate(len_char_max = 20))]
+pub struct Slug(String);
+
+fn main() {}
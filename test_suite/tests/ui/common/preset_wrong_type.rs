@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(preset = email)]
+pub struct Email(u32);
+
+fn main() {}
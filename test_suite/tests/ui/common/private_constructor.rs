@@ -0,0 +1,15 @@
+mod encapsulated {
+    use nutype::nutype;
+
+    #[nutype(constructor = pub(self), validate(greater = 0))]
+    pub struct Amount(i64);
+
+    pub fn one() -> Amount {
+        Amount::new(1).unwrap()
+    }
+}
+
+fn main() {
+    let _one = encapsulated::one();
+    let _two = encapsulated::Amount::new(2);
+}
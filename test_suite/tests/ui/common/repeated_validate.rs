@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(not_empty), sanitize(trim), validate(len_char_max = 20))]
+pub struct Name(String);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(repr = C, derive(Debug))]
+pub struct Id(u64);
+
+fn main() {}
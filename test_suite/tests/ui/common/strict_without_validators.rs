@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(strict, sanitize(trim))]
+pub struct Comment(String);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(try_from_lossless, validate(greater = 0), derive(Debug))]
+pub struct Amount(u32);
+
+fn main() {}
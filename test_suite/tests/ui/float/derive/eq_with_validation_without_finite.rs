@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(greater = 0.0), derive(PartialEq, Eq))]
+pub struct Weight(f64);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(derive(PartialEq, Hash))]
+pub struct Size(f64);
+
+fn main() {}
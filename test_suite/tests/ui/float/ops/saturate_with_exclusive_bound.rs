@@ -0,0 +1,10 @@
+use nutype::nutype;
+
+#[nutype(
+    validate(greater = 0.0, less_or_equal = 1.0),
+    ops = saturate,
+    derive(Add),
+)]
+pub struct Ratio(f64);
+
+fn main() {}
@@ -0,0 +1,10 @@
+use nutype::nutype;
+
+#[nutype(
+    validate(greater_or_equal = 0.0, predicate = |x| x.fract() == 0.0),
+    ops = saturate,
+    derive(Add),
+)]
+pub struct Whole(f64);
+
+fn main() {}
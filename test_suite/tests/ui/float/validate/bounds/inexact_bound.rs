@@ -0,0 +1,8 @@
+#![deny(deprecated)]
+
+use nutype::nutype;
+
+#[nutype(exact_bounds, validate(greater_or_equal = 0.0, less_or_equal = 0.3))]
+pub struct Probability(f64);
+
+fn main() {}
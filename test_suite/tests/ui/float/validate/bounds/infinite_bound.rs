@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(greater_or_equal = 0.0, less_or_equal = -core::f32::NEG_INFINITY))]
+pub struct Distance(f32);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(greater_or_equal = f64::NAN))]
+pub struct Amount(f64);
+
+fn main() {}
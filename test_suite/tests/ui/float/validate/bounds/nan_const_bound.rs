@@ -0,0 +1,8 @@
+use nutype::nutype;
+
+const LIMIT: f64 = 0.0 / 0.0;
+
+#[nutype(validate(less_or_equal = LIMIT))]
+pub struct Amount(f64);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(less = "10.0"))]
+pub struct Weight(f64);
+
+fn main() {}
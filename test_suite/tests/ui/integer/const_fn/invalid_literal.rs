@@ -0,0 +1,10 @@
+use nutype::nutype;
+
+#[nutype(const_fn, validate(greater_or_equal = 1, less_or_equal = 12))]
+pub struct Month(u8);
+
+const INVALID: Month = Month::from_literal(13);
+
+fn main() {
+    let _ = INVALID;
+}
@@ -0,0 +1,10 @@
+use nutype::nutype;
+
+#[nutype(validate(greater_or_equal = 1, less_or_equal = 12))]
+pub struct Month(u8);
+
+const INVALID: Month = Month::ZERO;
+
+fn main() {
+    let _ = INVALID;
+}
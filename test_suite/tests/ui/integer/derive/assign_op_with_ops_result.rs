@@ -0,0 +1,10 @@
+use nutype::nutype;
+
+#[nutype(
+    ops = result,
+    validate(less_or_equal = 100),
+    derive(Debug, Add, AddAssign)
+)]
+pub struct Percentage(u8);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[nutype(
+    validate(greater_or_equal = 1, less_or_equal = 10),
+    derive(Debug, Sum)
+)]
+pub struct Small(u8);
+
+fn main() {}
@@ -0,0 +1,10 @@
+use nutype::nutype;
+
+#[nutype(
+    validate(predicate = |n| n % 2 == 0),
+    ops = saturate,
+    derive(Add),
+)]
+pub struct Even(i32);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(greater = 2.5))]
+pub struct Amount(i32);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(greater = 255))]
+pub struct Level(u8);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(less_or_equal = 100), default = 101, derive(Default))]
+pub struct Percentage(u8);
+
+fn main() {}
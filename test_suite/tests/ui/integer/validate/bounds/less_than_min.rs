@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(less = -128))]
+pub struct Offset(i8);
+
+fn main() {}
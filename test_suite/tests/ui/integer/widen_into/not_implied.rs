@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[nutype(validate(greater_or_equal = 1024))]
+pub struct NonPrivilegedPort(u16);
+
+#[nutype(const_fn, validate(less_or_equal = 8080), widen_into(NonPrivilegedPort))]
+pub struct LocalPort(u16);
+
+fn main() {}
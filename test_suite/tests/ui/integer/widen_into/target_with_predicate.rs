@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[nutype(validate(greater_or_equal = 2, less_or_equal = 100, predicate = |n| n % 2 == 0))]
+pub struct Even(u8);
+
+#[nutype(validate(greater_or_equal = 2, less_or_equal = 100), widen_into(Even))]
+pub struct Small(u8);
+
+fn main() {}
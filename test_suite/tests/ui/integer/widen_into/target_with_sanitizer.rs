@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[nutype(sanitize(with = |n| n.min(10)), validate(less_or_equal = 10))]
+pub struct Clamped(u8);
+
+#[nutype(validate(less_or_equal = 5), widen_into(Clamped))]
+pub struct Small(u8);
+
+fn main() {}
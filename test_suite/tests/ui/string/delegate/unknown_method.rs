@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(delegate(len, push_str))]
+pub struct Name(String);
+
+fn main() {}
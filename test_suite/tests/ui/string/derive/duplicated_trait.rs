@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(derive(Debug, Clone, Debug))]
+pub struct Name(String);
+
+fn main() {}
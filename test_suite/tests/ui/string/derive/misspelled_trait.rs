@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(derive(Debg))]
+pub struct Name(String);
+
+fn main() {}
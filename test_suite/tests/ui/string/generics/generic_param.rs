@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(derive(Debug))]
+pub struct Name<T>(String);
+
+fn main() {}
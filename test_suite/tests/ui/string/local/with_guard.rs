@@ -0,0 +1,8 @@
+use nutype::nutype;
+
+fn main() {
+    const MAX_LEN: usize = 5;
+
+    #[nutype(local, sanitize(trim), validate(len_char_max = MAX_LEN))]
+    struct Tag(String);
+}
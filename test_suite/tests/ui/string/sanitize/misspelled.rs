@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(lowercased))]
+pub struct Name(String);
+
+fn main() {}
@@ -0,0 +1,11 @@
+use nutype::nutype;
+
+#[nutype(
+    sanitize(trim),
+    validate(len_char_min = 3),
+    default = "  ab  ",
+    derive(Default)
+)]
+pub struct Name(String);
+
+fn main() {}
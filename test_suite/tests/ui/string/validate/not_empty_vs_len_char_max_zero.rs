@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(not_empty, len_char_max = 0))]
+pub struct Name(String);
+
+fn main() {}
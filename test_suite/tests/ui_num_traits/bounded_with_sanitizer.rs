@@ -0,0 +1,10 @@
+use nutype::nutype;
+
+#[nutype(
+    sanitize(with = |x| x.clamp(1.0, 10.0)),
+    validate(less_or_equal = 10.0),
+    derive(Bounded),
+)]
+pub struct Rating(f64);
+
+fn main() {}
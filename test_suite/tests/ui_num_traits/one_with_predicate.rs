@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[nutype(
+    validate(predicate = |x| x % 2 == 0),
+    derive(One),
+)]
+pub struct Even(i32);
+
+fn main() {}
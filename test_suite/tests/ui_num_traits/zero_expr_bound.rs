@@ -0,0 +1,19 @@
+use nutype::nutype;
+
+const MIN: i32 = 5;
+
+#[nutype(
+    validate(greater_or_equal = MIN),
+    derive(Zero),
+)]
+pub struct Level(i32);
+
+impl std::ops::Add for Level {
+    type Output = Level;
+
+    fn add(self, other: Level) -> Level {
+        Level::new(self.into_inner() + other.into_inner()).unwrap()
+    }
+}
+
+fn main() {}
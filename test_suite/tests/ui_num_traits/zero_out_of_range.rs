@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[nutype(
+    validate(greater = 0, less_or_equal = 100),
+    derive(Zero),
+)]
+pub struct Positive(u8);
+
+fn main() {}
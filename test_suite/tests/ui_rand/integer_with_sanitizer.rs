@@ -0,0 +1,10 @@
+use nutype::nutype;
+
+#[nutype(
+    sanitize(with = |x| x * 3),
+    validate(less_or_equal = 10),
+    derive(Distribution),
+)]
+pub struct Small(u8);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(secret, validate(not_empty), derive(Debug, Borrow))]
+pub struct Password(String);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[nutype(
+    derive(Deserialize),
+    serde(wrap = "name"),
+)]
+pub struct Name<'a>(std::borrow::Cow<'a, str>);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(derive(Deserialize))]
+pub struct Names<'a>(Vec<&'a str>);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[nutype(
+    validate(predicate = |key: &Vec<u8>| !key.is_empty()),
+    derive(Debug, Zeroize, ZeroizeOnDrop),
+)]
+pub struct Key(Vec<u8>);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[nutype(
+    validate(not_empty),
+    derive(Debug, Zeroize),
+)]
+pub struct ApiToken(String);
+
+fn main() {}